@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE user_table ADD COLUMN IF NOT EXISTS icon_url TEXT NOT NULL DEFAULT '';
\ No newline at end of file
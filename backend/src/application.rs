@@ -86,6 +86,7 @@ fn user_scope() -> Scope {
         .service(web::resource("/user")
             .route(web::patch().to(user::set_user_profile_handler))
             .route(web::get().to(user::get_user_profile_handler))
+            .route(web::delete().to(user::delete_account_handler))
         )
         .service(web::resource("/register")
             .route(web::post().to(user::register_handler))
@@ -126,7 +127,7 @@ fn user_scope() -> Scope {
         )
         // password
         .service(web::resource("/password_change")
-            .route(web::post().to(user::change_password))
+            .route(web::post().to(user::change_password_handler))
         )
 }
 
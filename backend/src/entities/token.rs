@@ -38,6 +38,8 @@ impl Claim {
     }
 
     pub fn user_id(self) -> String { self.user_id }
+
+    pub fn exp(&self) -> i64 { self.exp }
 }
 
 // impl From<Claim> for User {
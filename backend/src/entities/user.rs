@@ -9,4 +9,5 @@ pub struct UserTable {
     pub(crate) name: String,
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) password: String,
+    pub(crate) icon_url: String,
 }
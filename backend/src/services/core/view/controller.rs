@@ -30,11 +30,13 @@ pub(crate) async fn update_view(
     name: Option<String>,
     desc: Option<String>,
     thumbnail: Option<String>,
+    icon: Option<String>,
 ) -> Result<(), ServerError> {
     let (sql, args) = SqlBuilder::update(VIEW_TABLE)
         .add_some_arg("name", name)
         .add_some_arg("description", desc)
         .add_some_arg("thumbnail", thumbnail)
+        .add_some_arg("icon", icon)
         .add_some_arg("modified_time", Some(Utc::now()))
         .and_where_eq("id", view_id)
         .build()?;
@@ -83,6 +85,8 @@ pub(crate) async fn create_view(
         .desc(desc.as_ref())
         .thumbnail(thumbnail.as_ref())
         .view_type(params.view_type)
+        .created_by(user_id)
+        .icon(params.get_icon())
         .build()?;
 
     let _ = sqlx::query_with(&sql, args)
@@ -28,6 +28,8 @@ impl NewViewSqlBuilder {
             create_time: time,
             thumbnail: "".to_string(),
             view_type: ViewType::Doc.value(),
+            created_by: "".to_string(),
+            icon: "".to_string(),
         };
 
         Self { table }
@@ -48,6 +50,8 @@ impl NewViewSqlBuilder {
             create_time,
             thumbnail: "".to_string(),
             view_type: view.view_type.value(),
+            created_by: view.created_by,
+            icon: view.icon,
         };
         Ok(Self { table })
     }
@@ -72,6 +76,16 @@ impl NewViewSqlBuilder {
         self
     }
 
+    pub fn created_by(mut self, user_id: &str) -> Self {
+        self.table.created_by = user_id.to_owned();
+        self
+    }
+
+    pub fn icon(mut self, icon: &str) -> Self {
+        self.table.icon = icon.to_owned();
+        self
+    }
+
     pub fn build(self) -> Result<(String, PgArguments, View), ServerError> {
         let view: View = self.table.clone().into();
 
@@ -84,6 +98,8 @@ impl NewViewSqlBuilder {
             .add_field_with_arg("create_time", self.table.create_time)
             .add_field_with_arg("thumbnail", self.table.thumbnail)
             .add_field_with_arg("view_type", self.table.view_type)
+            .add_field_with_arg("created_by", self.table.created_by)
+            .add_field_with_arg("icon", self.table.icon)
             .build()?;
 
         Ok((sql, args, view))
@@ -114,6 +130,8 @@ pub struct ViewTable {
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) thumbnail: String,
     pub(crate) view_type: i32,
+    pub(crate) created_by: String,
+    pub(crate) icon: String,
 }
 impl std::convert::From<ViewTable> for View {
     fn from(table: ViewTable) -> Self {
@@ -128,6 +146,8 @@ impl std::convert::From<ViewTable> for View {
         view.set_belongings(RepeatedView::default());
         view.set_create_time(table.create_time.timestamp());
         view.set_modified_time(table.modified_time.timestamp());
+        view.set_created_by(table.created_by);
+        view.set_icon(table.icon);
 
         view
     }
@@ -88,12 +88,18 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         ),
     };
 
+    // The icon is cleared if it's empty
+    let icon = match params.has_icon() {
+        false => None,
+        true => Some(params.get_icon().to_owned()),
+    };
+
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to update app")?;
 
-    let _ = update_view(&mut transaction, view_id, name, desc, thumbnail).await?;
+    let _ = update_view(&mut transaction, view_id, name, desc, thumbnail, icon).await?;
 
     transaction
         .commit()
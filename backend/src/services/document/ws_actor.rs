@@ -100,6 +100,13 @@ impl DocumentWebSocketActor {
                     .await
                     .map_err(internal_error)?;
             },
+            DocumentClientWSDataType::ClientCursor => {
+                let _ = self
+                    .doc_manager
+                    .handle_client_cursor(user, document_client_data)
+                    .await
+                    .map_err(internal_error)?;
+            },
         }
 
         Ok(())
@@ -74,6 +74,12 @@ impl PostgresKV {
 
 pub(crate) struct PostgresTransaction<'a, 'b>(&'a mut DBTransaction<'b>);
 
+impl<'a, 'b> PostgresTransaction<'a, 'b> {
+    // Runs the KV operations within the caller's transaction, e.g. to delete the
+    // revisions together with the rows that own them.
+    pub(crate) fn new(transaction: &'a mut DBTransaction<'b>) -> Self { Self(transaction) }
+}
+
 #[async_trait]
 impl<'a, 'b> KVTransaction for PostgresTransaction<'a, 'b> {
     async fn get(&mut self, key: &str) -> Result<Option<Bytes>, ServerError> {
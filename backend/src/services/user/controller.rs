@@ -4,6 +4,15 @@ use crate::{
         token::Token,
         user::UserTable,
     },
+    services::{
+        core::{
+            app::persistence::APP_TABLE,
+            trash::persistence::TRASH_TABLE,
+            view::persistence::VIEW_TABLE,
+            workspace::persistence::WORKSPACE_TABLE,
+        },
+        kv::{KVTransaction, PostgresTransaction},
+    },
     util::{
         sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
         user_ext::{hash_password, verify_password},
@@ -17,9 +26,19 @@ use backend_service::{
 use chrono::Utc;
 use flowy_user_data_model::{
     parser::{UserEmail, UserName, UserPassword},
-    protobuf::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    protobuf::{
+        ChangePasswordParams,
+        DeleteAccountParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
 };
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgPool, Postgres, Row};
+use uuid::Uuid;
 
 pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInResponse, ServerError> {
     let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
@@ -37,6 +56,7 @@ pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInRespon
         .context("Failed to commit SQL transaction to sign in.")?;
 
     let token = Token::create_token(&user.id.to_string())?;
+    let expires_at = Token::decode_token(&token)?.exp();
     let logged_user = LoggedUser::new(&user.id.to_string());
 
     AUTHORIZED_USERS.store_auth(logged_user, true);
@@ -45,6 +65,7 @@ pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInRespon
     response_data.set_name(user.name);
     response_data.set_email(user.email);
     response_data.set_token(token.into());
+    response_data.set_expires_at(expires_at);
 
     Ok(response_data)
 }
@@ -110,6 +131,7 @@ pub(crate) async fn get_user_profile(
     user_profile.set_email(user_table.email);
     user_profile.set_name(user_table.name);
     user_profile.set_token(token.0);
+    user_profile.set_icon_url(user_table.icon_url);
     FlowyResponse::success().pb(user_profile)
 }
 
@@ -118,6 +140,16 @@ pub(crate) async fn set_user_profile(
     logged_user: LoggedUser,
     params: UpdateUserParams,
 ) -> Result<FlowyResponse, ServerError> {
+    // The password is only changed by change_password, which verifies the old one.
+    if params.has_password() {
+        return Err(ServerError::params_invalid().context("Use change_password to change the password"));
+    }
+    // The email is only changed by confirm_email_change, which verifies the new
+    // one.
+    if params.has_email() {
+        return Err(ServerError::params_invalid().context("Use request_email_change to change the email"));
+    }
+
     let mut transaction = pool
         .begin()
         .await
@@ -128,28 +160,14 @@ pub(crate) async fn set_user_profile(
         true => Some(UserName::parse(params.get_name().to_owned()).map_err(invalid_params)?.0),
     };
 
-    let email = match params.has_email() {
+    let icon_url = match params.has_icon_url() {
         false => None,
-        true => Some(
-            UserEmail::parse(params.get_email().to_owned())
-                .map_err(invalid_params)?
-                .0,
-        ),
-    };
-
-    let password = match params.has_password() {
-        false => None,
-        true => {
-            let password = UserPassword::parse(params.get_password().to_owned()).map_err(invalid_params)?;
-            let password = hash_password(password.as_ref())?;
-            Some(password)
-        },
+        true => Some(params.get_icon_url().to_owned()),
     };
 
     let (sql, args) = SqlBuilder::update("user_table")
         .add_some_arg("name", name)
-        .add_some_arg("email", email)
-        .add_some_arg("password", password)
+        .add_some_arg("icon_url", icon_url)
         .and_where_eq("id", &logged_user.as_uuid()?)
         .build()?;
 
@@ -166,6 +184,126 @@ pub(crate) async fn set_user_profile(
     Ok(FlowyResponse::success())
 }
 
+pub(crate) async fn change_password(
+    pool: &PgPool,
+    logged_user: LoggedUser,
+    params: ChangePasswordParams,
+) -> Result<FlowyResponse, ServerError> {
+    let new_password = UserPassword::parse(params.get_new_password().to_owned()).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to change password")?;
+
+    let id = logged_user.as_uuid()?;
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(id)
+        .fetch_one(&mut transaction)
+        .await
+        .map_err(|err| ServerError::internal().context(err))?;
+
+    match verify_password(params.get_old_password(), &user.password) {
+        Ok(true) => {},
+        _ => return Err(ServerError::password_not_match()),
+    }
+
+    let (sql, args) = SqlBuilder::update("user_table")
+        .add_field_with_arg("password", hash_password(new_password.as_ref())?)
+        .and_where_eq("id", &id)
+        .build()?;
+
+    sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to change password.")?;
+
+    Ok(FlowyResponse::success())
+}
+
+pub(crate) async fn delete_account(
+    pool: &PgPool,
+    logged_user: LoggedUser,
+    params: DeleteAccountParams,
+) -> Result<FlowyResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to delete account")?;
+
+    let id = logged_user.as_uuid()?;
+    let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE id = $1")
+        .bind(id)
+        .fetch_one(&mut transaction)
+        .await
+        .map_err(|err| ServerError::internal().context(err))?;
+
+    match verify_password(params.get_password(), &user.password) {
+        Ok(true) => {},
+        _ => return Err(ServerError::password_not_match()),
+    }
+
+    let _ = delete_user_data(&mut transaction, &logged_user.user_id).await?;
+    let (sql, args) = SqlBuilder::delete("user_table").and_where_eq("id", &id).build()?;
+    sqlx::query_with(&sql, args)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to delete account.")?;
+
+    AUTHORIZED_USERS.store_auth(logged_user, false);
+    Ok(FlowyResponse::success())
+}
+
+// None of the tables references the user_table, so the rows of the user are
+// deleted table by table. The views belong to the apps of the user or to the
+// other views, and their revisions are stored in the KV by the view id.
+async fn delete_user_data(transaction: &mut DBTransaction<'_>, user_id: &str) -> Result<(), ServerError> {
+    let view_ids = sqlx::query(
+        r#"WITH RECURSIVE user_views AS (
+            SELECT id FROM view_table WHERE belong_to_id IN (SELECT id::text FROM app_table WHERE user_id = $1)
+            UNION
+            SELECT view_table.id FROM view_table JOIN user_views ON view_table.belong_to_id = user_views.id::text
+        )
+        SELECT id FROM user_views"#,
+    )
+    .bind(user_id)
+    .fetch_all(transaction as &mut DBTransaction<'_>)
+    .await
+    .map_err(map_sqlx_error)?
+    .into_iter()
+    .map(|row| row.get("id"))
+    .collect::<Vec<Uuid>>();
+
+    for view_id in &view_ids {
+        let _ = PostgresTransaction::new(transaction)
+            .batch_delete_key_start_with(&view_id.to_string())
+            .await?;
+        let (sql, args) = SqlBuilder::delete(VIEW_TABLE).and_where_eq("id", view_id).build()?;
+        sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+
+    for table in &[APP_TABLE, WORKSPACE_TABLE, TRASH_TABLE] {
+        let (sql, args) = SqlBuilder::delete(table).and_where_eq("user_id", user_id).build()?;
+        sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(())
+}
+
 async fn is_email_exist(transaction: &mut DBTransaction<'_>, email: &str) -> Result<(), ServerError> {
     let result = sqlx::query(r#"SELECT email FROM user_table WHERE email = $1"#)
         .bind(email)
@@ -207,6 +345,7 @@ async fn insert_new_user(
 ) -> Result<SignUpResponse, ServerError> {
     let uuid = uuid::Uuid::new_v4();
     let token = Token::create_token(&uuid.to_string())?;
+    let expires_at = Token::decode_token(&token)?.exp();
     let password = hash_password(password)?;
     let _ = sqlx::query!(
         r#"
@@ -228,6 +367,7 @@ async fn insert_new_user(
     response.set_name(name.to_string());
     response.set_email(email.to_string());
     response.set_token(token.into());
+    response.set_expires_at(expires_at);
 
     Ok(response)
 }
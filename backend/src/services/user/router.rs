@@ -1,16 +1,29 @@
 use crate::{
     entities::{logged_user::LoggedUser, token::Token},
-    services::user::{get_user_profile, register_user, set_user_profile, sign_in, sign_out},
+    services::user::{
+        change_password,
+        delete_account,
+        get_user_profile,
+        register_user,
+        set_user_profile,
+        sign_in,
+        sign_out,
+    },
     util::serde_ext::parse_from_payload,
 };
 use actix_identity::Identity;
 use actix_web::{
     web::{Data, Payload},
-    HttpRequest,
     HttpResponse,
 };
 use backend_service::{errors::ServerError, response::FlowyResponse};
-use flowy_user_data_model::protobuf::{SignInParams, SignUpParams, UpdateUserParams};
+use flowy_user_data_model::protobuf::{
+    ChangePasswordParams,
+    DeleteAccountParams,
+    SignInParams,
+    SignUpParams,
+    UpdateUserParams,
+};
 use sqlx::PgPool;
 
 pub async fn sign_in_handler(payload: Payload, id: Identity, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
@@ -47,6 +60,18 @@ pub async fn set_user_profile_handler(
     Ok(response.into())
 }
 
+pub async fn delete_account_handler(
+    logged_user: LoggedUser,
+    id: Identity,
+    pool: Data<PgPool>,
+    payload: Payload,
+) -> Result<HttpResponse, ServerError> {
+    let params: DeleteAccountParams = parse_from_payload(payload).await?;
+    let response = delete_account(pool.get_ref(), logged_user, params).await?;
+    id.forget();
+    Ok(response.into())
+}
+
 pub async fn register_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
     let params: SignUpParams = parse_from_payload(payload).await?;
     let resp = register_user(pool.get_ref(), params).await?;
@@ -54,10 +79,12 @@ pub async fn register_handler(payload: Payload, pool: Data<PgPool>) -> Result<Ht
     Ok(resp.into())
 }
 
-pub async fn change_password(
-    _request: HttpRequest,
-    _payload: Payload,
-    _pool: Data<PgPool>,
+pub async fn change_password_handler(
+    logged_user: LoggedUser,
+    pool: Data<PgPool>,
+    payload: Payload,
 ) -> Result<HttpResponse, ServerError> {
-    unimplemented!()
+    let params: ChangePasswordParams = parse_from_payload(payload).await?;
+    let response = change_password(pool.get_ref(), logged_user, params).await?;
+    Ok(response.into())
 }
@@ -1,6 +1,13 @@
-use crate::util::helper::{spawn_user_server, TestUserServer};
+use crate::util::helper::{create_test_view, spawn_user_server, TestUserServer, ViewTest};
 use backend_service::errors::ErrorCode;
-use flowy_user_data_model::entities::{SignInParams, SignUpParams, SignUpResponse, UpdateUserParams};
+use flowy_user_data_model::entities::{
+    ChangePasswordParams,
+    DeleteAccountParams,
+    SignInParams,
+    SignUpParams,
+    SignUpResponse,
+    UpdateUserParams,
+};
 
 #[actix_rt::test]
 async fn user_register() {
@@ -67,22 +74,131 @@ async fn user_update_password() {
     let params = UpdateUserParams::new(&sign_up_resp.user_id).password("Hello123!");
     server.user_token = Some(sign_up_resp.token);
 
-    server.update_user_profile(params).await.unwrap();
+    // The password can only be changed by change_password
+    let error = server.update_user_profile(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+
+    let sign_in_params = SignInParams {
+        email: email.to_string(),
+        password: password.to_string(),
+        name: "rust".to_string(),
+    };
+    let _ = server.sign_in(sign_in_params).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn user_change_password() {
+    let mut server = spawn_user_server().await;
+    let email = "annie@appflowy.io";
+    let password = "HelloWorld123!";
+    let sign_up_resp = register_user(&server, email, password).await;
+    server.user_token = Some(sign_up_resp.token);
+
+    let new_password = "Hello123!";
+    server
+        .change_password(ChangePasswordParams::new(password, new_password))
+        .await
+        .unwrap();
+
+    let sign_in_params = SignInParams {
+        email: email.to_string(),
+        password: new_password.to_string(),
+        name: "rust".to_string(),
+    };
+    let _ = server.sign_in(sign_in_params).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn user_change_password_with_wrong_old_password() {
+    let mut server = spawn_user_server().await;
+    let email = "annie@appflowy.io";
+    let password = "HelloWorld123!";
+    let sign_up_resp = register_user(&server, email, password).await;
+    server.user_token = Some(sign_up_resp.token);
+
+    let error = server
+        .change_password(ChangePasswordParams::new("Wrong123!", "Hello123!"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch);
 
     let sign_in_params = SignInParams {
         email: email.to_string(),
         password: password.to_string(),
         name: "rust".to_string(),
     };
+    let _ = server.sign_in(sign_in_params).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn user_delete_account() {
+    let mut server = spawn_user_server().await;
+    let email = "annie@appflowy.io";
+    let password = "HelloWorld123!";
+    let sign_up_resp = register_user(&server, email, password).await;
+    server.user_token = Some(sign_up_resp.token);
+
+    server.delete_account(DeleteAccountParams::new(password)).await.unwrap();
+
+    let sign_in_params = SignInParams {
+        email: email.to_string(),
+        password: password.to_string(),
+        name: "rust".to_string(),
+    };
+    assert!(server.sign_in(sign_in_params).await.is_err());
+}
 
-    match server.sign_in(sign_in_params).await {
-        Ok(_) => {},
-        Err(e) => {
-            assert_eq!(e.code, ErrorCode::PasswordNotMatch);
-        },
+#[actix_rt::test]
+async fn user_delete_account_deletes_user_data() {
+    let test = ViewTest::new().await;
+    let trashed_view = create_test_view(&test.server, &test.app.id).await;
+    test.server.create_view_trash(&trashed_view.id).await;
+    let pool = test.server.inner.app_ctx.persistence.pg_pool();
+    let user_id = test.server.user_id().to_owned();
+    let count_rows = |sql: &'static str, arg: String| {
+        let pool = pool.clone();
+        async move {
+            let (count,): (i64,) = sqlx::query_as(sql).bind(arg).fetch_one(&pool).await.unwrap();
+            count
+        }
+    };
+    let revisions_sql = "SELECT COUNT(*) FROM kv_table WHERE id LIKE $1";
+    assert!(count_rows(revisions_sql, format!("{}%", test.view.id)).await > 0);
+
+    test.server
+        .delete_account(DeleteAccountParams::new("HelloAppFlowy123!"))
+        .await
+        .unwrap();
+
+    for sql in &[
+        "SELECT COUNT(*) FROM workspace_table WHERE user_id = $1",
+        "SELECT COUNT(*) FROM app_table WHERE user_id = $1",
+        "SELECT COUNT(*) FROM trash_table WHERE user_id = $1",
+    ] {
+        assert_eq!(count_rows(*sql, user_id.clone()).await, 0);
+    }
+    for view_id in &[&test.view.id, &trashed_view.id] {
+        let view_sql = "SELECT COUNT(*) FROM view_table WHERE id::text = $1";
+        assert_eq!(count_rows(view_sql, view_id.to_string()).await, 0);
+        assert_eq!(count_rows(revisions_sql, format!("{}%", view_id)).await, 0);
     }
 }
 
+#[actix_rt::test]
+async fn user_delete_account_with_wrong_password() {
+    let mut server = spawn_user_server().await;
+    let email = "annie@appflowy.io";
+    let password = "HelloWorld123!";
+    let sign_up_resp = register_user(&server, email, password).await;
+    server.user_token = Some(sign_up_resp.token);
+
+    let error = server
+        .delete_account(DeleteAccountParams::new("Wrong123!"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch);
+}
+
 #[actix_rt::test]
 async fn user_update_name() {
     let server = TestUserServer::new().await;
@@ -100,10 +216,13 @@ async fn user_update_email() {
     let server = TestUserServer::new().await;
     let email = "123@gmail.com".to_string();
     let params = UpdateUserParams::new(server.user_id()).email(&email);
-    server.update_user_profile(params).await.unwrap();
+
+    // The email can only be changed by confirm_email_change
+    let error = server.update_user_profile(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
 
     let user = server.get_user_profile().await;
-    assert_eq!(user.email, email);
+    assert_ne!(user.email, email);
 }
 
 #[allow(dead_code)]
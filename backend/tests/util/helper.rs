@@ -62,6 +62,18 @@ impl TestUserServer {
         Ok(())
     }
 
+    pub async fn change_password(&self, params: ChangePasswordParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/password_change", self.http_addr());
+        let _ = change_password_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
+    pub async fn delete_account(&self, params: DeleteAccountParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/user", self.http_addr());
+        let _ = delete_account_request(self.user_token(), params, &url).await?;
+        Ok(())
+    }
+
     pub async fn create_workspace(&self, params: CreateWorkspaceParams) -> Workspace {
         let url = format!("{}/api/workspace", self.http_addr());
         let workspace = create_workspace_request(self.user_token(), params, &url).await.unwrap();
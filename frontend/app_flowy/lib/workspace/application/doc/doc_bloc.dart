@@ -111,9 +111,9 @@ class DocBloc extends Bloc<DocEvent, DocState> {
     Log.debug("doc_id: $view.id - Send json: $json");
     final result = await docManager.composeDelta(json: json);
 
-    result.fold((rustDoc) {
+    result.fold((rustResult) {
       // final json = utf8.decode(doc.data);
-      final rustDelta = Delta.fromJson(jsonDecode(rustDoc.deltaJson));
+      final rustDelta = Delta.fromJson(jsonDecode(rustResult.delta.deltaJson));
       if (documentDelta != rustDelta) {
         Log.error("Receive : $rustDelta");
         Log.error("Expected : $documentDelta");
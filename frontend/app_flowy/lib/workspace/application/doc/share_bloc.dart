@@ -1,3 +1,5 @@
+import 'dart:convert';
+
 import 'package:app_flowy/workspace/domain/i_share.dart';
 import 'package:app_flowy/workspace/infrastructure/markdown/delta_markdown.dart';
 import 'package:flowy_sdk/protobuf/flowy-core-data-model/export.pb.dart';
@@ -6,6 +8,7 @@ import 'package:flowy_sdk/protobuf/flowy-error/errors.pb.dart';
 import 'package:freezed_annotation/freezed_annotation.dart';
 import 'package:flutter_bloc/flutter_bloc.dart';
 import 'package:dartz/dartz.dart';
+import 'package:fixnum/fixnum.dart';
 part 'share_bloc.freezed.dart';
 
 class DocShareBloc extends Bloc<DocShareEvent, DocShareState> {
@@ -33,6 +36,7 @@ class DocShareBloc extends Bloc<DocShareEvent, DocShareState> {
   ExportData _convertDeltaToMarkdown(ExportData value) {
     final result = deltaToMarkdown(value.data);
     value.data = result;
+    value.sizeInBytes = Int64(utf8.encode(result).length);
     return value;
   }
 }
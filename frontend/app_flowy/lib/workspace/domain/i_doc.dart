@@ -5,6 +5,6 @@ import 'package:flowy_sdk/protobuf/flowy-error/errors.pb.dart';
 
 abstract class IDoc {
   Future<Either<DocumentDelta, FlowyError>> readDoc();
-  Future<Either<DocumentDelta, FlowyError>> composeDelta({required String json});
+  Future<Either<DocumentDeltaResult, FlowyError>> composeDelta({required String json});
   Future<Either<Unit, FlowyError>> closeDoc();
 }
@@ -24,7 +24,7 @@ class IDocImpl extends IDoc {
   }
 
   @override
-  Future<Either<DocumentDelta, FlowyError>> composeDelta({required String json}) {
+  Future<Either<DocumentDeltaResult, FlowyError>> composeDelta({required String json}) {
     return repo.composeDelta(data: json);
   }
 }
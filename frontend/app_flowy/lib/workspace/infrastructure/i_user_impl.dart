@@ -76,7 +76,7 @@ class IUserListenerImpl extends IUserListener {
   @override
   void start() {
     _workspaceParser = WorkspaceNotificationParser(id: _user.token, callback: _notificationCallback);
-    _userParser = UserNotificationParser(id: _user.token, callback: _userNotificationCallback);
+    _userParser = UserNotificationParser(id: _user.id, callback: _userNotificationCallback);
     _subscription = RustStreamReceiver.listen((observable) {
       _workspaceParser.parse(observable);
       _userParser.parse(observable);
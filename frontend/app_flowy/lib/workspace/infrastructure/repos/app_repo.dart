@@ -9,6 +9,7 @@ import 'package:flowy_sdk/protobuf/flowy-core-data-model/app_create.pb.dart';
 import 'package:flowy_sdk/protobuf/flowy-core-data-model/app_query.pb.dart';
 import 'package:flowy_sdk/protobuf/flowy-core-data-model/app_update.pb.dart';
 import 'package:flowy_sdk/protobuf/flowy-core-data-model/view_create.pb.dart';
+import 'package:flowy_sdk/protobuf/flowy-core-data-model/view_delta.pb.dart';
 import 'package:flowy_sdk/protobuf/flowy-error/errors.pb.dart';
 import 'package:flowy_sdk/protobuf/flowy-core/observable.pb.dart';
 import 'package:flowy_sdk/rust_stream.dart';
@@ -67,6 +68,8 @@ class AppListenerRepository {
   AppViewsChangeCallback? _viewsChanged;
   AppUpdatedCallback? _update;
   late WorkspaceNotificationParser _parser;
+  List<View>? _views;
+  late WorkspaceNotificationParser _batchParser;
   String appId;
 
   AppListenerRepository({
@@ -77,7 +80,50 @@ class AppListenerRepository {
     _viewsChanged = viewsChanged;
     _update = update;
     _parser = WorkspaceNotificationParser(id: appId, callback: _bservableCallback);
-    _subscription = RustStreamReceiver.listen((observable) => _parser.parse(observable));
+    // The views of several apps that are changed at once are sent by one anonymous notification
+    _batchParser = WorkspaceNotificationParser(id: '', callback: _batchCallback);
+    _subscription = RustStreamReceiver.listen((observable) {
+      _parser.parse(observable);
+      _batchParser.parse(observable);
+    });
+  }
+
+  void _batchCallback(WorkspaceNotification ty, Either<Uint8List, FlowyError> result) {
+    if (_viewsChanged == null) {
+      return;
+    }
+
+    switch (ty) {
+      case WorkspaceNotification.AppsViewsChanged:
+        result.fold(
+          (payload) {
+            final repeatedAppViews = RepeatedAppViews.fromBuffer(payload);
+            for (final appViews in repeatedAppViews.items) {
+              if (appViews.belongToId == appId) {
+                _views = appViews.views.items;
+                _viewsChanged!(left(appViews.views.items));
+              }
+            }
+          },
+          (error) => Log.error(error),
+        );
+        break;
+      case WorkspaceNotification.AppsViewsDeltaChanged:
+        result.fold(
+          (payload) {
+            final repeatedDelta = RepeatedAppViewsDelta.fromBuffer(payload);
+            for (final delta in repeatedDelta.items) {
+              if (delta.belongToId == appId) {
+                _applyViewsDelta(delta);
+              }
+            }
+          },
+          (error) => Log.error(error),
+        );
+        break;
+      default:
+        break;
+    }
   }
 
   void _bservableCallback(WorkspaceNotification ty, Either<Uint8List, FlowyError> result) {
@@ -87,12 +133,21 @@ class AppListenerRepository {
           result.fold(
             (payload) {
               final repeatedView = RepeatedView.fromBuffer(payload);
+              _views = repeatedView.items;
               _viewsChanged!(left(repeatedView.items));
             },
             (error) => _viewsChanged!(right(error)),
           );
         }
         break;
+      case WorkspaceNotification.AppViewsDeltaChanged:
+        if (_viewsChanged != null) {
+          result.fold(
+            (payload) => _applyViewsDelta(AppViewsDelta.fromBuffer(payload)),
+            (error) => _viewsChanged!(right(error)),
+          );
+        }
+        break;
       case WorkspaceNotification.AppUpdated:
         if (_update != null) {
           result.fold(
@@ -109,6 +164,40 @@ class AppListenerRepository {
     }
   }
 
+  // The delta only carries the changed views, so it's applied to the views that
+  // were received last. They're read from the app if nothing was received yet.
+  Future<void> _applyViewsDelta(AppViewsDelta delta) async {
+    if (_views == null) {
+      final result = await AppRepository(appId: appId).getViews();
+      final failed = result.fold(
+        (views) {
+          _views = views;
+          return false;
+        },
+        (error) {
+          _viewsChanged!(right(error));
+          return true;
+        },
+      );
+      if (failed) {
+        return;
+      }
+    }
+
+    final views = List<View>.from(_views!);
+    views.removeWhere((view) => delta.removed.contains(view.id));
+    for (final view in [...delta.added, ...delta.updated]) {
+      final index = views.indexWhere((element) => element.id == view.id);
+      if (index == -1) {
+        views.add(view);
+      } else {
+        views[index] = view;
+      }
+    }
+    _views = views;
+    _viewsChanged!(left(views));
+  }
+
   Future<void> close() async {
     await _subscription?.cancel();
   }
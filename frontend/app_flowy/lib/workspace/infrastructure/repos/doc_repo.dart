@@ -15,7 +15,7 @@ class DocRepository {
     return WorkspaceEventOpenView(request).send();
   }
 
-  Future<Either<DocumentDelta, FlowyError>> composeDelta({required String data}) {
+  Future<Either<DocumentDeltaResult, FlowyError>> composeDelta({required String data}) {
     final request = DocumentDelta.create()
       ..docId = docId
       ..deltaJson = data;
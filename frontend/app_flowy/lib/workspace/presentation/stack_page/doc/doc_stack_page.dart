@@ -197,6 +197,9 @@ class DocShareButton extends StatelessWidget {
       case ExportType.Text:
         // TODO: Handle this case.
         break;
+      case ExportType.Html:
+        FlutterClipboard.copy(exportData.data).then((value) => Log.info('copied to clipboard'));
+        break;
     }
   }
 
@@ -302,6 +302,430 @@ class WorkspaceEventCloseView {
     }
 }
 
+class WorkspaceEventOpenViewOfflineFirst {
+     QueryViewRequest request;
+     WorkspaceEventOpenViewOfflineFirst(this.request);
+
+    Future<Either<DocumentDelta, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.OpenViewOfflineFirst.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(DocumentDelta.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadViewsBelongTo {
+     QueryViewsBelongToRequest request;
+     WorkspaceEventReadViewsBelongTo(this.request);
+
+    Future<Either<RepeatedView, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadViewsBelongTo.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedView.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventPreviewDeleteView {
+     QueryViewRequest request;
+     WorkspaceEventPreviewDeleteView(this.request);
+
+    Future<Either<RepeatedView, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.PreviewDeleteView.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedView.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventRenameView {
+     RenameViewRequest request;
+     WorkspaceEventRenameView(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.RenameView.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadViewSyncState {
+     QueryViewRequest request;
+     WorkspaceEventReadViewSyncState(this.request);
+
+    Future<Either<ViewSyncStatus, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadViewSyncState.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(ViewSyncStatus.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventDuplicateViewRecursive {
+     QueryViewRequest request;
+     WorkspaceEventDuplicateViewRecursive(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.DuplicateViewRecursive.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventTrashViewsBelongTo {
+     QueryAppRequest request;
+     WorkspaceEventTrashViewsBelongTo(this.request);
+
+    Future<Either<Unit, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.TrashViewsBelongTo.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (bytes) => left(unit),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventVerifyViewsConsistency {
+    WorkspaceEventVerifyViewsConsistency();
+
+    Future<Either<ConsistencyReport, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.VerifyViewsConsistency.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(ConsistencyReport.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventRepairOrphanedViews {
+     RepairViewsRequest request;
+     WorkspaceEventRepairOrphanedViews(this.request);
+
+    Future<Either<RepairReport, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.RepairOrphanedViews.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepairReport.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadOpenDocumentIds {
+    WorkspaceEventReadOpenDocumentIds();
+
+    Future<Either<RepeatedViewId, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.ReadOpenDocumentIds.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(RepeatedViewId.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventRestoreOpenViews {
+    WorkspaceEventRestoreOpenViews();
+
+    Future<Either<RepeatedDocumentDelta, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.RestoreOpenViews.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(RepeatedDocumentDelta.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventSetViewReadonly {
+     SetViewReadonlyRequest request;
+     WorkspaceEventSetViewReadonly(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.SetViewReadonly.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadTrashedViews {
+    WorkspaceEventReadTrashedViews();
+
+    Future<Either<RepeatedView, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.ReadTrashedViews.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(RepeatedView.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventRestoreView {
+     QueryViewRequest request;
+     WorkspaceEventRestoreView(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.RestoreView.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadViewAncestors {
+     QueryViewRequest request;
+     WorkspaceEventReadViewAncestors(this.request);
+
+    Future<Either<RepeatedView, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadViewAncestors.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedView.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventSetViewThumbnail {
+     SetViewThumbnailRequest request;
+     WorkspaceEventSetViewThumbnail(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.SetViewThumbnail.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadViewThumbnail {
+     QueryViewRequest request;
+     WorkspaceEventReadViewThumbnail(this.request);
+
+    Future<Either<ViewThumbnail, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadViewThumbnail.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(ViewThumbnail.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadViewsBelongToPaged {
+     QueryViewsPageRequest request;
+     WorkspaceEventReadViewsBelongToPaged(this.request);
+
+    Future<Either<ViewsPage, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadViewsBelongToPaged.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(ViewsPage.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCountViewsBelongTo {
+     QueryViewsCountRequest request;
+     WorkspaceEventCountViewsBelongTo(this.request);
+
+    Future<Either<ViewCount, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CountViewsBelongTo.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(ViewCount.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventCountViewsGroupedByParent {
+    WorkspaceEventCountViewsGroupedByParent();
+
+    Future<Either<RepeatedViewCount, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.CountViewsGroupedByParent.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(RepeatedViewCount.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventMergeLocalInto {
+     MergeLocalRequest request;
+     WorkspaceEventMergeLocalInto(this.request);
+
+    Future<Either<MergeReport, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.MergeLocalInto.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(MergeReport.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventImportView {
+     ImportViewRequest request;
+     WorkspaceEventImportView(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ImportView.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventFindDuplicateViews {
+     QueryViewsBelongToRequest request;
+     WorkspaceEventFindDuplicateViews(this.request);
+
+    Future<Either<RepeatedDuplicateViews, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.FindDuplicateViews.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedDuplicateViews.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventSetViewVisibility {
+     SetViewVisibilityRequest request;
+     WorkspaceEventSetViewVisibility(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.SetViewVisibility.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadAllUserViews {
+    WorkspaceEventReadAllUserViews();
+
+    Future<Either<RepeatedView, FlowyError>> send() {
+     final request = FFIRequest.create()
+        ..event = WorkspaceEvent.ReadAllUserViews.toString();
+
+     return Dispatch.asyncRequest(request).then((bytesResult) => bytesResult.fold(
+        (okBytes) => left(RepeatedView.fromBuffer(okBytes)),
+        (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+      ));
+    }
+}
+
+class WorkspaceEventSetViewIcon {
+     SetViewIconRequest request;
+     WorkspaceEventSetViewIcon(this.request);
+
+    Future<Either<View, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.SetViewIcon.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(View.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class WorkspaceEventReadTrash {
     WorkspaceEventReadTrash();
 
@@ -382,11 +806,62 @@ class WorkspaceEventApplyDocDelta {
      DocumentDelta request;
      WorkspaceEventApplyDocDelta(this.request);
 
-    Future<Either<DocumentDelta, FlowyError>> send() {
+    Future<Either<DocumentDeltaResult, FlowyError>> send() {
     final request = FFIRequest.create()
           ..event = WorkspaceEvent.ApplyDocDelta.toString()
           ..payload = requestToBytes(this.request);
 
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(DocumentDeltaResult.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadDocumentStats {
+     QueryViewRequest request;
+     WorkspaceEventReadDocumentStats(this.request);
+
+    Future<Either<DocumentStats, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadDocumentStats.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(DocumentStats.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventReadDocumentHistory {
+     QueryDocumentHistoryRequest request;
+     WorkspaceEventReadDocumentHistory(this.request);
+
+    Future<Either<RepeatedRevisionSummary, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ReadDocumentHistory.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedRevisionSummary.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
+class WorkspaceEventRestoreDocument {
+     RestoreDocumentRequest request;
+     WorkspaceEventRestoreDocument(this.request);
+
+    Future<Either<DocumentDelta, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.RestoreDocument.toString()
+          ..payload = requestToBytes(this.request);
+
     return Dispatch.asyncRequest(request)
         .then((bytesResult) => bytesResult.fold(
            (okBytes) => left(DocumentDelta.fromBuffer(okBytes)),
@@ -395,6 +870,23 @@ class WorkspaceEventApplyDocDelta {
     }
 }
 
+class WorkspaceEventCompactDocument {
+     QueryViewRequest request;
+     WorkspaceEventCompactDocument(this.request);
+
+    Future<Either<CompactReport, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.CompactDocument.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(CompactReport.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class WorkspaceEventExportDocument {
      ExportRequest request;
      WorkspaceEventExportDocument(this.request);
@@ -412,6 +904,23 @@ class WorkspaceEventExportDocument {
     }
 }
 
+class WorkspaceEventExportApp {
+     ExportAppRequest request;
+     WorkspaceEventExportApp(this.request);
+
+    Future<Either<RepeatedExportData, FlowyError>> send() {
+    final request = FFIRequest.create()
+          ..event = WorkspaceEvent.ExportApp.toString()
+          ..payload = requestToBytes(this.request);
+
+    return Dispatch.asyncRequest(request)
+        .then((bytesResult) => bytesResult.fold(
+           (okBytes) => left(RepeatedExportData.fromBuffer(okBytes)),
+           (errBytes) => right(FlowyError.fromBuffer(errBytes)),
+        ));
+    }
+}
+
 class NetworkEventUpdateNetworkType {
      NetworkState request;
      NetworkEventUpdateNetworkType(this.request);
@@ -13,6 +13,7 @@ class ErrorCode extends $pb.ProtobufEnum {
   static const ErrorCode Internal = ErrorCode._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Internal');
   static const ErrorCode UserUnauthorized = ErrorCode._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserUnauthorized');
   static const ErrorCode RecordNotFound = ErrorCode._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RecordNotFound');
+  static const ErrorCode SerdeError = ErrorCode._(4, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SerdeError');
   static const ErrorCode WorkspaceNameInvalid = ErrorCode._(100, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'WorkspaceNameInvalid');
   static const ErrorCode WorkspaceIdInvalid = ErrorCode._(101, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'WorkspaceIdInvalid');
   static const ErrorCode AppColorStyleInvalid = ErrorCode._(102, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppColorStyleInvalid');
@@ -26,7 +27,11 @@ class ErrorCode extends $pb.ProtobufEnum {
   static const ErrorCode ViewDescTooLong = ErrorCode._(123, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewDescTooLong');
   static const ErrorCode ViewDataInvalid = ErrorCode._(124, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewDataInvalid');
   static const ErrorCode ViewNameTooLong = ErrorCode._(125, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewNameTooLong');
+  static const ErrorCode ViewReadOnly = ErrorCode._(126, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewReadOnly');
+  static const ErrorCode ViewBusy = ErrorCode._(127, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewBusy');
+  static const ErrorCode UnsupportedViewConversion = ErrorCode._(128, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UnsupportedViewConversion');
   static const ErrorCode ConnectError = ErrorCode._(200, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ConnectError');
+  static const ErrorCode ServerUnavailable = ErrorCode._(201, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ServerUnavailable');
   static const ErrorCode EmailIsEmpty = ErrorCode._(300, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailIsEmpty');
   static const ErrorCode EmailFormatInvalid = ErrorCode._(301, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailFormatInvalid');
   static const ErrorCode EmailAlreadyExists = ErrorCode._(302, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailAlreadyExists');
@@ -40,11 +45,16 @@ class ErrorCode extends $pb.ProtobufEnum {
   static const ErrorCode UserNameIsEmpty = ErrorCode._(310, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserNameIsEmpty');
   static const ErrorCode UserIdInvalid = ErrorCode._(311, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserIdInvalid');
   static const ErrorCode UserNotExist = ErrorCode._(312, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserNotExist');
+  static const ErrorCode EmailChangeCodeInvalid = ErrorCode._(313, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailChangeCodeInvalid');
+  static const ErrorCode EmailChangeCodeExpired = ErrorCode._(314, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailChangeCodeExpired');
+  static const ErrorCode PasswordChangeRequiresOldPassword = ErrorCode._(315, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'PasswordChangeRequiresOldPassword');
+  static const ErrorCode EmailChangeRequiresVerification = ErrorCode._(316, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'EmailChangeRequiresVerification');
 
   static const $core.List<ErrorCode> values = <ErrorCode> [
     Internal,
     UserUnauthorized,
     RecordNotFound,
+    SerdeError,
     WorkspaceNameInvalid,
     WorkspaceIdInvalid,
     AppColorStyleInvalid,
@@ -58,7 +68,11 @@ class ErrorCode extends $pb.ProtobufEnum {
     ViewDescTooLong,
     ViewDataInvalid,
     ViewNameTooLong,
+    ViewReadOnly,
+    ViewBusy,
+    UnsupportedViewConversion,
     ConnectError,
+    ServerUnavailable,
     EmailIsEmpty,
     EmailFormatInvalid,
     EmailAlreadyExists,
@@ -72,6 +86,10 @@ class ErrorCode extends $pb.ProtobufEnum {
     UserNameIsEmpty,
     UserIdInvalid,
     UserNotExist,
+    EmailChangeCodeInvalid,
+    EmailChangeCodeExpired,
+    PasswordChangeRequiresOldPassword,
+    EmailChangeRequiresVerification,
   ];
 
   static final $core.Map<$core.int, ErrorCode> _byValue = $pb.ProtobufEnum.initByValue(values);
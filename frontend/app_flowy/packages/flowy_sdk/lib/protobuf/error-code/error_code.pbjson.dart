@@ -15,6 +15,7 @@ const ErrorCode$json = const {
     const {'1': 'Internal', '2': 0},
     const {'1': 'UserUnauthorized', '2': 2},
     const {'1': 'RecordNotFound', '2': 3},
+    const {'1': 'SerdeError', '2': 4},
     const {'1': 'WorkspaceNameInvalid', '2': 100},
     const {'1': 'WorkspaceIdInvalid', '2': 101},
     const {'1': 'AppColorStyleInvalid', '2': 102},
@@ -28,7 +29,11 @@ const ErrorCode$json = const {
     const {'1': 'ViewDescTooLong', '2': 123},
     const {'1': 'ViewDataInvalid', '2': 124},
     const {'1': 'ViewNameTooLong', '2': 125},
+    const {'1': 'ViewReadOnly', '2': 126},
+    const {'1': 'ViewBusy', '2': 127},
+    const {'1': 'UnsupportedViewConversion', '2': 128},
     const {'1': 'ConnectError', '2': 200},
+    const {'1': 'ServerUnavailable', '2': 201},
     const {'1': 'EmailIsEmpty', '2': 300},
     const {'1': 'EmailFormatInvalid', '2': 301},
     const {'1': 'EmailAlreadyExists', '2': 302},
@@ -42,8 +47,12 @@ const ErrorCode$json = const {
     const {'1': 'UserNameIsEmpty', '2': 310},
     const {'1': 'UserIdInvalid', '2': 311},
     const {'1': 'UserNotExist', '2': 312},
+    const {'1': 'EmailChangeCodeInvalid', '2': 313},
+    const {'1': 'EmailChangeCodeExpired', '2': 314},
+    const {'1': 'PasswordChangeRequiresOldPassword', '2': 315},
+    const {'1': 'EmailChangeRequiresVerification', '2': 316},
   ],
 };
 
 /// Descriptor for `ErrorCode`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List errorCodeDescriptor = $convert.base64Decode('CglFcnJvckNvZGUSDAoISW50ZXJuYWwQABIUChBVc2VyVW5hdXRob3JpemVkEAISEgoOUmVjb3JkTm90Rm91bmQQAxIYChRXb3Jrc3BhY2VOYW1lSW52YWxpZBBkEhYKEldvcmtzcGFjZUlkSW52YWxpZBBlEhgKFEFwcENvbG9yU3R5bGVJbnZhbGlkEGYSGAoUV29ya3NwYWNlRGVzY1Rvb0xvbmcQZxIYChRXb3Jrc3BhY2VOYW1lVG9vTG9uZxBoEhAKDEFwcElkSW52YWxpZBBuEhIKDkFwcE5hbWVJbnZhbGlkEG8SEwoPVmlld05hbWVJbnZhbGlkEHgSGAoUVmlld1RodW1ibmFpbEludmFsaWQQeRIRCg1WaWV3SWRJbnZhbGlkEHoSEwoPVmlld0Rlc2NUb29Mb25nEHsSEwoPVmlld0RhdGFJbnZhbGlkEHwSEwoPVmlld05hbWVUb29Mb25nEH0SEQoMQ29ubmVjdEVycm9yEMgBEhEKDEVtYWlsSXNFbXB0eRCsAhIXChJFbWFpbEZvcm1hdEludmFsaWQQrQISFwoSRW1haWxBbHJlYWR5RXhpc3RzEK4CEhQKD1Bhc3N3b3JkSXNFbXB0eRCvAhIUCg9QYXNzd29yZFRvb0xvbmcQsAISJQogUGFzc3dvcmRDb250YWluc0ZvcmJpZENoYXJhY3RlcnMQsQISGgoVUGFzc3dvcmRGb3JtYXRJbnZhbGlkELICEhUKEFBhc3N3b3JkTm90TWF0Y2gQswISFAoPVXNlck5hbWVUb29Mb25nELQCEicKIlVzZXJOYW1lQ29udGFpbkZvcmJpZGRlbkNoYXJhY3RlcnMQtQISFAoPVXNlck5hbWVJc0VtcHR5ELYCEhIKDVVzZXJJZEludmFsaWQQtwISEQoMVXNlck5vdEV4aXN0ELgC');
+final $typed_data.Uint8List errorCodeDescriptor = $convert.base64Decode('CglFcnJvckNvZGUSDAoISW50ZXJuYWwQABIUChBVc2VyVW5hdXRob3JpemVkEAISEgoOUmVjb3JkTm90Rm91bmQQAxIOCgpTZXJkZUVycm9yEAQSGAoUV29ya3NwYWNlTmFtZUludmFsaWQQZBIWChJXb3Jrc3BhY2VJZEludmFsaWQQZRIYChRBcHBDb2xvclN0eWxlSW52YWxpZBBmEhgKFFdvcmtzcGFjZURlc2NUb29Mb25nEGcSGAoUV29ya3NwYWNlTmFtZVRvb0xvbmcQaBIQCgxBcHBJZEludmFsaWQQbhISCg5BcHBOYW1lSW52YWxpZBBvEhMKD1ZpZXdOYW1lSW52YWxpZBB4EhgKFFZpZXdUaHVtYm5haWxJbnZhbGlkEHkSEQoNVmlld0lkSW52YWxpZBB6EhMKD1ZpZXdEZXNjVG9vTG9uZxB7EhMKD1ZpZXdEYXRhSW52YWxpZBB8EhMKD1ZpZXdOYW1lVG9vTG9uZxB9EhAKDFZpZXdSZWFkT25seRB+EgwKCFZpZXdCdXN5EH8SHgoZVW5zdXBwb3J0ZWRWaWV3Q29udmVyc2lvbhCAARIRCgxDb25uZWN0RXJyb3IQyAESFgoRU2VydmVyVW5hdmFpbGFibGUQyQESEQoMRW1haWxJc0VtcHR5EKwCEhcKEkVtYWlsRm9ybWF0SW52YWxpZBCtAhIXChJFbWFpbEFscmVhZHlFeGlzdHMQrgISFAoPUGFzc3dvcmRJc0VtcHR5EK8CEhQKD1Bhc3N3b3JkVG9vTG9uZxCwAhIlCiBQYXNzd29yZENvbnRhaW5zRm9yYmlkQ2hhcmFjdGVycxCxAhIaChVQYXNzd29yZEZvcm1hdEludmFsaWQQsgISFQoQUGFzc3dvcmROb3RNYXRjaBCzAhIUCg9Vc2VyTmFtZVRvb0xvbmcQtAISJwoiVXNlck5hbWVDb250YWluRm9yYmlkZGVuQ2hhcmFjdGVycxC1AhIUCg9Vc2VyTmFtZUlzRW1wdHkQtgISEgoNVXNlcklkSW52YWxpZBC3AhIRCgxVc2VyTm90RXhpc3QQuAISGwoWRW1haWxDaGFuZ2VDb2RlSW52YWxpZBC5AhIbChZFbWFpbENoYW5nZUNvZGVFeHBpcmVkELoCEiYKIVBhc3N3b3JkQ2hhbmdlUmVxdWlyZXNPbGRQYXNzd29yZBC7AhIkCh9FbWFpbENoYW5nZVJlcXVpcmVzVmVyaWZpY2F0aW9uELwC');
@@ -227,10 +227,21 @@ class ResetDocumentParams extends $pb.GeneratedMessage {
   $0.RepeatedRevision ensureRevisions() => $_ensure(1);
 }
 
+enum DocumentDelta_OneOfContentHash {
+  contentHash, 
+  notSet
+}
+
 class DocumentDelta extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, DocumentDelta_OneOfContentHash> _DocumentDelta_OneOfContentHashByTag = {
+    3 : DocumentDelta_OneOfContentHash.contentHash,
+    0 : DocumentDelta_OneOfContentHash.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentDelta', createEmptyInstance: create)
+    ..oo(0, [3])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'deltaJson')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'contentHash')
     ..hasRequiredFields = false
   ;
 
@@ -238,6 +249,7 @@ class DocumentDelta extends $pb.GeneratedMessage {
   factory DocumentDelta({
     $core.String? docId,
     $core.String? deltaJson,
+    $core.String? contentHash,
   }) {
     final _result = create();
     if (docId != null) {
@@ -246,6 +258,9 @@ class DocumentDelta extends $pb.GeneratedMessage {
     if (deltaJson != null) {
       _result.deltaJson = deltaJson;
     }
+    if (contentHash != null) {
+      _result.contentHash = contentHash;
+    }
     return _result;
   }
   factory DocumentDelta.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -269,6 +284,9 @@ class DocumentDelta extends $pb.GeneratedMessage {
   static DocumentDelta getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentDelta>(create);
   static DocumentDelta? _defaultInstance;
 
+  DocumentDelta_OneOfContentHash whichOneOfContentHash() => _DocumentDelta_OneOfContentHashByTag[$_whichOneof(0)]!;
+  void clearOneOfContentHash() => clearField($_whichOneof(0));
+
   @$pb.TagNumber(1)
   $core.String get docId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -286,6 +304,133 @@ class DocumentDelta extends $pb.GeneratedMessage {
   $core.bool hasDeltaJson() => $_has(1);
   @$pb.TagNumber(2)
   void clearDeltaJson() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get contentHash => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set contentHash($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasContentHash() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearContentHash() => clearField(3);
+}
+
+class RepeatedDocumentDelta extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedDocumentDelta', createEmptyInstance: create)
+    ..pc<DocumentDelta>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: DocumentDelta.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedDocumentDelta._() : super();
+  factory RepeatedDocumentDelta({
+    $core.Iterable<DocumentDelta>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedDocumentDelta.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedDocumentDelta.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedDocumentDelta clone() => RepeatedDocumentDelta()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedDocumentDelta copyWith(void Function(RepeatedDocumentDelta) updates) => super.copyWith((message) => updates(message as RepeatedDocumentDelta)) as RepeatedDocumentDelta; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDocumentDelta create() => RepeatedDocumentDelta._();
+  RepeatedDocumentDelta createEmptyInstance() => create();
+  static $pb.PbList<RepeatedDocumentDelta> createRepeated() => $pb.PbList<RepeatedDocumentDelta>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDocumentDelta getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedDocumentDelta>(create);
+  static RepeatedDocumentDelta? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<DocumentDelta> get items => $_getList(0);
+}
+
+class DocumentDeltaResult extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentDeltaResult', createEmptyInstance: create)
+    ..aOM<DocumentDelta>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'delta', subBuilder: DocumentDelta.create)
+    ..aOB(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'wasTransformed')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'appliedRevId')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentDeltaResult._() : super();
+  factory DocumentDeltaResult({
+    DocumentDelta? delta,
+    $core.bool? wasTransformed,
+    $fixnum.Int64? appliedRevId,
+  }) {
+    final _result = create();
+    if (delta != null) {
+      _result.delta = delta;
+    }
+    if (wasTransformed != null) {
+      _result.wasTransformed = wasTransformed;
+    }
+    if (appliedRevId != null) {
+      _result.appliedRevId = appliedRevId;
+    }
+    return _result;
+  }
+  factory DocumentDeltaResult.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentDeltaResult.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentDeltaResult clone() => DocumentDeltaResult()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentDeltaResult copyWith(void Function(DocumentDeltaResult) updates) => super.copyWith((message) => updates(message as DocumentDeltaResult)) as DocumentDeltaResult; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentDeltaResult create() => DocumentDeltaResult._();
+  DocumentDeltaResult createEmptyInstance() => create();
+  static $pb.PbList<DocumentDeltaResult> createRepeated() => $pb.PbList<DocumentDeltaResult>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentDeltaResult getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentDeltaResult>(create);
+  static DocumentDeltaResult? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  DocumentDelta get delta => $_getN(0);
+  @$pb.TagNumber(1)
+  set delta(DocumentDelta v) { setField(1, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDelta() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDelta() => clearField(1);
+  @$pb.TagNumber(1)
+  DocumentDelta ensureDelta() => $_ensure(0);
+
+  @$pb.TagNumber(2)
+  $core.bool get wasTransformed => $_getBF(1);
+  @$pb.TagNumber(2)
+  set wasTransformed($core.bool v) { $_setBool(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasWasTransformed() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearWasTransformed() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get appliedRevId => $_getI64(2);
+  @$pb.TagNumber(3)
+  set appliedRevId($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasAppliedRevId() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearAppliedRevId() => clearField(3);
 }
 
 class NewDocUser extends $pb.GeneratedMessage {
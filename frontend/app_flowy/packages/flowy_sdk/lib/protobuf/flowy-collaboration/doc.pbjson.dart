@@ -49,11 +49,37 @@ const DocumentDelta$json = const {
   '2': const [
     const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
     const {'1': 'delta_json', '3': 2, '4': 1, '5': 9, '10': 'deltaJson'},
+    const {'1': 'content_hash', '3': 3, '4': 1, '5': 9, '9': 0, '10': 'contentHash'},
+  ],
+  '8': const [
+    const {'1': 'one_of_content_hash'},
   ],
 };
 
 /// Descriptor for `DocumentDelta`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List documentDeltaDescriptor = $convert.base64Decode('Cg1Eb2N1bWVudERlbHRhEhUKBmRvY19pZBgBIAEoCVIFZG9jSWQSHQoKZGVsdGFfanNvbhgCIAEoCVIJZGVsdGFKc29u');
+final $typed_data.Uint8List documentDeltaDescriptor = $convert.base64Decode('Cg1Eb2N1bWVudERlbHRhEhUKBmRvY19pZBgBIAEoCVIFZG9jSWQSHQoKZGVsdGFfanNvbhgCIAEoCVIJZGVsdGFKc29uEiMKDGNvbnRlbnRfaGFzaBgDIAEoCUgAUgtjb250ZW50SGFzaEIVChNvbmVfb2ZfY29udGVudF9oYXNo');
+@$core.Deprecated('Use repeatedDocumentDeltaDescriptor instead')
+const RepeatedDocumentDelta$json = const {
+  '1': 'RepeatedDocumentDelta',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.DocumentDelta', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedDocumentDelta`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedDocumentDeltaDescriptor = $convert.base64Decode('ChVSZXBlYXRlZERvY3VtZW50RGVsdGESJAoFaXRlbXMYASADKAsyDi5Eb2N1bWVudERlbHRhUgVpdGVtcw==');
+@$core.Deprecated('Use documentDeltaResultDescriptor instead')
+const DocumentDeltaResult$json = const {
+  '1': 'DocumentDeltaResult',
+  '2': const [
+    const {'1': 'delta', '3': 1, '4': 1, '5': 11, '6': '.DocumentDelta', '10': 'delta'},
+    const {'1': 'was_transformed', '3': 2, '4': 1, '5': 8, '10': 'wasTransformed'},
+    const {'1': 'applied_rev_id', '3': 3, '4': 1, '5': 3, '10': 'appliedRevId'},
+  ],
+};
+
+/// Descriptor for `DocumentDeltaResult`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentDeltaResultDescriptor = $convert.base64Decode('ChNEb2N1bWVudERlbHRhUmVzdWx0EiQKBWRlbHRhGAEgASgLMg4uRG9jdW1lbnREZWx0YVIFZGVsdGESJwoPd2FzX3RyYW5zZm9ybWVkGAIgASgIUg53YXNUcmFuc2Zvcm1lZBIkCg5hcHBsaWVkX3Jldl9pZBgDIAEoA1IMYXBwbGllZFJldklk');
 @$core.Deprecated('Use newDocUserDescriptor instead')
 const NewDocUser$json = const {
   '1': 'NewDocUser',
@@ -308,3 +308,194 @@ class RevisionRange extends $pb.GeneratedMessage {
   void clearEnd() => clearField(3);
 }
 
+class RevisionSummary extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RevisionSummary', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'userId')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'timestamp')
+    ..hasRequiredFields = false
+  ;
+
+  RevisionSummary._() : super();
+  factory RevisionSummary({
+    $fixnum.Int64? revId,
+    $core.String? userId,
+    $fixnum.Int64? timestamp,
+  }) {
+    final _result = create();
+    if (revId != null) {
+      _result.revId = revId;
+    }
+    if (userId != null) {
+      _result.userId = userId;
+    }
+    if (timestamp != null) {
+      _result.timestamp = timestamp;
+    }
+    return _result;
+  }
+  factory RevisionSummary.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RevisionSummary.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RevisionSummary clone() => RevisionSummary()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RevisionSummary copyWith(void Function(RevisionSummary) updates) => super.copyWith((message) => updates(message as RevisionSummary)) as RevisionSummary; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RevisionSummary create() => RevisionSummary._();
+  RevisionSummary createEmptyInstance() => create();
+  static $pb.PbList<RevisionSummary> createRepeated() => $pb.PbList<RevisionSummary>();
+  @$core.pragma('dart2js:noInline')
+  static RevisionSummary getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RevisionSummary>(create);
+  static RevisionSummary? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get revId => $_getI64(0);
+  @$pb.TagNumber(1)
+  set revId($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasRevId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearRevId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get userId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set userId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasUserId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearUserId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get timestamp => $_getI64(2);
+  @$pb.TagNumber(3)
+  set timestamp($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasTimestamp() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearTimestamp() => clearField(3);
+}
+
+class RepeatedRevisionSummary extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedRevisionSummary', createEmptyInstance: create)
+    ..pc<RevisionSummary>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: RevisionSummary.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedRevisionSummary._() : super();
+  factory RepeatedRevisionSummary({
+    $core.Iterable<RevisionSummary>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedRevisionSummary.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedRevisionSummary.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedRevisionSummary clone() => RepeatedRevisionSummary()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedRevisionSummary copyWith(void Function(RepeatedRevisionSummary) updates) => super.copyWith((message) => updates(message as RepeatedRevisionSummary)) as RepeatedRevisionSummary; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedRevisionSummary create() => RepeatedRevisionSummary._();
+  RepeatedRevisionSummary createEmptyInstance() => create();
+  static $pb.PbList<RepeatedRevisionSummary> createRepeated() => $pb.PbList<RepeatedRevisionSummary>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedRevisionSummary getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedRevisionSummary>(create);
+  static RepeatedRevisionSummary? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<RevisionSummary> get items => $_getList(0);
+}
+
+class CompactReport extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CompactReport', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revisionsReclaimed')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'bytesReclaimed')
+    ..hasRequiredFields = false
+  ;
+
+  CompactReport._() : super();
+  factory CompactReport({
+    $fixnum.Int64? revId,
+    $fixnum.Int64? revisionsReclaimed,
+    $fixnum.Int64? bytesReclaimed,
+  }) {
+    final _result = create();
+    if (revId != null) {
+      _result.revId = revId;
+    }
+    if (revisionsReclaimed != null) {
+      _result.revisionsReclaimed = revisionsReclaimed;
+    }
+    if (bytesReclaimed != null) {
+      _result.bytesReclaimed = bytesReclaimed;
+    }
+    return _result;
+  }
+  factory CompactReport.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CompactReport.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CompactReport clone() => CompactReport()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CompactReport copyWith(void Function(CompactReport) updates) => super.copyWith((message) => updates(message as CompactReport)) as CompactReport; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CompactReport create() => CompactReport._();
+  CompactReport createEmptyInstance() => create();
+  static $pb.PbList<CompactReport> createRepeated() => $pb.PbList<CompactReport>();
+  @$core.pragma('dart2js:noInline')
+  static CompactReport getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CompactReport>(create);
+  static CompactReport? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get revId => $_getI64(0);
+  @$pb.TagNumber(1)
+  set revId($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasRevId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearRevId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get revisionsReclaimed => $_getI64(1);
+  @$pb.TagNumber(2)
+  set revisionsReclaimed($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasRevisionsReclaimed() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearRevisionsReclaimed() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get bytesReclaimed => $_getI64(2);
+  @$pb.TagNumber(3)
+  set bytesReclaimed($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasBytesReclaimed() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearBytesReclaimed() => clearField(3);
+}
+
@@ -78,3 +78,37 @@ const RevisionRange$json = const {
 
 /// Descriptor for `RevisionRange`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List revisionRangeDescriptor = $convert.base64Decode('Cg1SZXZpc2lvblJhbmdlEhUKBmRvY19pZBgBIAEoCVIFZG9jSWQSFAoFc3RhcnQYAiABKANSBXN0YXJ0EhAKA2VuZBgDIAEoA1IDZW5k');
+@$core.Deprecated('Use revisionSummaryDescriptor instead')
+const RevisionSummary$json = const {
+  '1': 'RevisionSummary',
+  '2': const [
+    const {'1': 'rev_id', '3': 1, '4': 1, '5': 3, '10': 'revId'},
+    const {'1': 'user_id', '3': 2, '4': 1, '5': 9, '10': 'userId'},
+    const {'1': 'timestamp', '3': 3, '4': 1, '5': 3, '10': 'timestamp'},
+  ],
+};
+
+/// Descriptor for `RevisionSummary`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List revisionSummaryDescriptor = $convert.base64Decode('Cg9SZXZpc2lvblN1bW1hcnkSFQoGcmV2X2lkGAEgASgDUgVyZXZJZBIXCgd1c2VyX2lkGAIgASgJUgZ1c2VySWQSHAoJdGltZXN0YW1wGAMgASgDUgl0aW1lc3RhbXA=');
+@$core.Deprecated('Use repeatedRevisionSummaryDescriptor instead')
+const RepeatedRevisionSummary$json = const {
+  '1': 'RepeatedRevisionSummary',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.RevisionSummary', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedRevisionSummary`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedRevisionSummaryDescriptor = $convert.base64Decode('ChdSZXBlYXRlZFJldmlzaW9uU3VtbWFyeRImCgVpdGVtcxgBIAMoCzIQLlJldmlzaW9uU3VtbWFyeVIFaXRlbXM=');
+@$core.Deprecated('Use compactReportDescriptor instead')
+const CompactReport$json = const {
+  '1': 'CompactReport',
+  '2': const [
+    const {'1': 'rev_id', '3': 1, '4': 1, '5': 3, '10': 'revId'},
+    const {'1': 'revisions_reclaimed', '3': 2, '4': 1, '5': 3, '10': 'revisionsReclaimed'},
+    const {'1': 'bytes_reclaimed', '3': 3, '4': 1, '5': 3, '10': 'bytesReclaimed'},
+  ],
+};
+
+/// Descriptor for `CompactReport`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List compactReportDescriptor = $convert.base64Decode('Cg1Db21wYWN0UmVwb3J0EhUKBnJldl9pZBgBIAEoA1IFcmV2SWQSLwoTcmV2aXNpb25zX3JlY2xhaW1lZBgCIAEoA1IScmV2aXNpb25zUmVjbGFpbWVkEicKD2J5dGVzX3JlY2xhaW1lZBgDIAEoA1IOYnl0ZXNSZWNsYWltZWQ=');
@@ -12,10 +12,12 @@ import 'package:protobuf/protobuf.dart' as $pb;
 class DocumentClientWSDataType extends $pb.ProtobufEnum {
   static const DocumentClientWSDataType ClientPushRev = DocumentClientWSDataType._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ClientPushRev');
   static const DocumentClientWSDataType ClientPing = DocumentClientWSDataType._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ClientPing');
+  static const DocumentClientWSDataType ClientCursor = DocumentClientWSDataType._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ClientCursor');
 
   static const $core.List<DocumentClientWSDataType> values = <DocumentClientWSDataType> [
     ClientPushRev,
     ClientPing,
+    ClientCursor,
   ];
 
   static final $core.Map<$core.int, DocumentClientWSDataType> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -29,12 +31,14 @@ class DocumentServerWSDataType extends $pb.ProtobufEnum {
   static const DocumentServerWSDataType ServerPushRev = DocumentServerWSDataType._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ServerPushRev');
   static const DocumentServerWSDataType ServerPullRev = DocumentServerWSDataType._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ServerPullRev');
   static const DocumentServerWSDataType UserConnect = DocumentServerWSDataType._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserConnect');
+  static const DocumentServerWSDataType UserCursor = DocumentServerWSDataType._(4, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserCursor');
 
   static const $core.List<DocumentServerWSDataType> values = <DocumentServerWSDataType> [
     ServerAck,
     ServerPushRev,
     ServerPullRev,
     UserConnect,
+    UserCursor,
   ];
 
   static final $core.Map<$core.int, DocumentServerWSDataType> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -14,11 +14,12 @@ const DocumentClientWSDataType$json = const {
   '2': const [
     const {'1': 'ClientPushRev', '2': 0},
     const {'1': 'ClientPing', '2': 1},
+    const {'1': 'ClientCursor', '2': 2},
   ],
 };
 
 /// Descriptor for `DocumentClientWSDataType`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List documentClientWSDataTypeDescriptor = $convert.base64Decode('ChhEb2N1bWVudENsaWVudFdTRGF0YVR5cGUSEQoNQ2xpZW50UHVzaFJldhAAEg4KCkNsaWVudFBpbmcQAQ==');
+final $typed_data.Uint8List documentClientWSDataTypeDescriptor = $convert.base64Decode('ChhEb2N1bWVudENsaWVudFdTRGF0YVR5cGUSEQoNQ2xpZW50UHVzaFJldhAAEg4KCkNsaWVudFBpbmcQARIQCgxDbGllbnRDdXJzb3IQAg==');
 @$core.Deprecated('Use documentServerWSDataTypeDescriptor instead')
 const DocumentServerWSDataType$json = const {
   '1': 'DocumentServerWSDataType',
@@ -27,11 +28,12 @@ const DocumentServerWSDataType$json = const {
     const {'1': 'ServerPushRev', '2': 1},
     const {'1': 'ServerPullRev', '2': 2},
     const {'1': 'UserConnect', '2': 3},
+    const {'1': 'UserCursor', '2': 4},
   ],
 };
 
 /// Descriptor for `DocumentServerWSDataType`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List documentServerWSDataTypeDescriptor = $convert.base64Decode('ChhEb2N1bWVudFNlcnZlcldTRGF0YVR5cGUSDQoJU2VydmVyQWNrEAASEQoNU2VydmVyUHVzaFJldhABEhEKDVNlcnZlclB1bGxSZXYQAhIPCgtVc2VyQ29ubmVjdBAD');
+final $typed_data.Uint8List documentServerWSDataTypeDescriptor = $convert.base64Decode('ChhEb2N1bWVudFNlcnZlcldTRGF0YVR5cGUSDQoJU2VydmVyQWNrEAASEQoNU2VydmVyUHVzaFJldhABEhEKDVNlcnZlclB1bGxSZXYQAhIPCgtVc2VyQ29ubmVjdBADEg4KClVzZXJDdXJzb3IQBA==');
 @$core.Deprecated('Use documentClientWSDataDescriptor instead')
 const DocumentClientWSData$json = const {
   '1': 'DocumentClientWSData',
@@ -69,3 +71,14 @@ const NewDocumentUser$json = const {
 
 /// Descriptor for `NewDocumentUser`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List newDocumentUserDescriptor = $convert.base64Decode('Cg9OZXdEb2N1bWVudFVzZXISFwoHdXNlcl9pZBgBIAEoCVIGdXNlcklkEhUKBmRvY19pZBgCIAEoCVIFZG9jSWQSIwoNcmV2aXNpb25fZGF0YRgDIAEoDFIMcmV2aXNpb25EYXRh');
+@$core.Deprecated('Use cursorUpdateDescriptor instead')
+const CursorUpdate$json = const {
+  '1': 'CursorUpdate',
+  '2': const [
+    const {'1': 'user_id', '3': 1, '4': 1, '5': 9, '10': 'userId'},
+    const {'1': 'position', '3': 2, '4': 1, '5': 3, '10': 'position'},
+  ],
+};
+
+/// Descriptor for `CursorUpdate`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List cursorUpdateDescriptor = $convert.base64Decode('CgxDdXJzb3JVcGRhdGUSFwoHdXNlcl9pZBgBIAEoCVIGdXNlcklkEhoKCHBvc2l0aW9uGAIgASgDUghwb3NpdGlvbg==');
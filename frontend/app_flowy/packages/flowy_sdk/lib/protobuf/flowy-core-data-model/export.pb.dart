@@ -7,6 +7,7 @@
 
 import 'dart:core' as $core;
 
+import 'package:fixnum/fixnum.dart' as $fixnum;
 import 'package:protobuf/protobuf.dart' as $pb;
 
 import 'export.pbenum.dart';
@@ -78,6 +79,8 @@ class ExportData extends $pb.GeneratedMessage {
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ExportData', createEmptyInstance: create)
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'data')
     ..e<ExportType>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'exportType', $pb.PbFieldType.OE, defaultOrMaker: ExportType.Text, valueOf: ExportType.valueOf, enumValues: ExportType.values)
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'fileName')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'sizeInBytes')
     ..hasRequiredFields = false
   ;
 
@@ -85,6 +88,8 @@ class ExportData extends $pb.GeneratedMessage {
   factory ExportData({
     $core.String? data,
     ExportType? exportType,
+    $core.String? fileName,
+    $fixnum.Int64? sizeInBytes,
   }) {
     final _result = create();
     if (data != null) {
@@ -93,6 +98,12 @@ class ExportData extends $pb.GeneratedMessage {
     if (exportType != null) {
       _result.exportType = exportType;
     }
+    if (fileName != null) {
+      _result.fileName = fileName;
+    }
+    if (sizeInBytes != null) {
+      _result.sizeInBytes = sizeInBytes;
+    }
     return _result;
   }
   factory ExportData.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -133,5 +144,125 @@ class ExportData extends $pb.GeneratedMessage {
   $core.bool hasExportType() => $_has(1);
   @$pb.TagNumber(2)
   void clearExportType() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get fileName => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set fileName($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasFileName() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearFileName() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get sizeInBytes => $_getI64(3);
+  @$pb.TagNumber(4)
+  set sizeInBytes($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasSizeInBytes() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearSizeInBytes() => clearField(4);
+}
+
+class ExportAppRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ExportAppRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'appId')
+    ..e<ExportType>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'exportType', $pb.PbFieldType.OE, defaultOrMaker: ExportType.Text, valueOf: ExportType.valueOf, enumValues: ExportType.values)
+    ..hasRequiredFields = false
+  ;
+
+  ExportAppRequest._() : super();
+  factory ExportAppRequest({
+    $core.String? appId,
+    ExportType? exportType,
+  }) {
+    final _result = create();
+    if (appId != null) {
+      _result.appId = appId;
+    }
+    if (exportType != null) {
+      _result.exportType = exportType;
+    }
+    return _result;
+  }
+  factory ExportAppRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ExportAppRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ExportAppRequest clone() => ExportAppRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ExportAppRequest copyWith(void Function(ExportAppRequest) updates) => super.copyWith((message) => updates(message as ExportAppRequest)) as ExportAppRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ExportAppRequest create() => ExportAppRequest._();
+  ExportAppRequest createEmptyInstance() => create();
+  static $pb.PbList<ExportAppRequest> createRepeated() => $pb.PbList<ExportAppRequest>();
+  @$core.pragma('dart2js:noInline')
+  static ExportAppRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ExportAppRequest>(create);
+  static ExportAppRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get appId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set appId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasAppId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearAppId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  ExportType get exportType => $_getN(1);
+  @$pb.TagNumber(2)
+  set exportType(ExportType v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasExportType() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearExportType() => clearField(2);
+}
+
+class RepeatedExportData extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedExportData', createEmptyInstance: create)
+    ..pc<ExportData>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: ExportData.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedExportData._() : super();
+  factory RepeatedExportData({
+    $core.Iterable<ExportData>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedExportData.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedExportData.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedExportData clone() => RepeatedExportData()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedExportData copyWith(void Function(RepeatedExportData) updates) => super.copyWith((message) => updates(message as RepeatedExportData)) as RepeatedExportData; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedExportData create() => RepeatedExportData._();
+  RepeatedExportData createEmptyInstance() => create();
+  static $pb.PbList<RepeatedExportData> createRepeated() => $pb.PbList<RepeatedExportData>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedExportData getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedExportData>(create);
+  static RepeatedExportData? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<ExportData> get items => $_getList(0);
 }
 
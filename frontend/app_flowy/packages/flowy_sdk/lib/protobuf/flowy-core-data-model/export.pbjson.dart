@@ -15,11 +15,12 @@ const ExportType$json = const {
     const {'1': 'Text', '2': 0},
     const {'1': 'Markdown', '2': 1},
     const {'1': 'Link', '2': 2},
+    const {'1': 'Html', '2': 3},
   ],
 };
 
 /// Descriptor for `ExportType`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List exportTypeDescriptor = $convert.base64Decode('CgpFeHBvcnRUeXBlEggKBFRleHQQABIMCghNYXJrZG93bhABEggKBExpbmsQAg==');
+final $typed_data.Uint8List exportTypeDescriptor = $convert.base64Decode('CgpFeHBvcnRUeXBlEggKBFRleHQQABIMCghNYXJrZG93bhABEggKBExpbmsQAhIICgRIdG1sEAM=');
 @$core.Deprecated('Use exportRequestDescriptor instead')
 const ExportRequest$json = const {
   '1': 'ExportRequest',
@@ -37,8 +38,31 @@ const ExportData$json = const {
   '2': const [
     const {'1': 'data', '3': 1, '4': 1, '5': 9, '10': 'data'},
     const {'1': 'export_type', '3': 2, '4': 1, '5': 14, '6': '.ExportType', '10': 'exportType'},
+    const {'1': 'file_name', '3': 3, '4': 1, '5': 9, '10': 'fileName'},
+    const {'1': 'size_in_bytes', '3': 4, '4': 1, '5': 3, '10': 'sizeInBytes'},
   ],
 };
 
 /// Descriptor for `ExportData`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List exportDataDescriptor = $convert.base64Decode('CgpFeHBvcnREYXRhEhIKBGRhdGEYASABKAlSBGRhdGESLAoLZXhwb3J0X3R5cGUYAiABKA4yCy5FeHBvcnRUeXBlUgpleHBvcnRUeXBl');
+final $typed_data.Uint8List exportDataDescriptor = $convert.base64Decode('CgpFeHBvcnREYXRhEhIKBGRhdGEYASABKAlSBGRhdGESLAoLZXhwb3J0X3R5cGUYAiABKA4yCy5FeHBvcnRUeXBlUgpleHBvcnRUeXBlEhsKCWZpbGVfbmFtZRgDIAEoCVIIZmlsZU5hbWUSIgoNc2l6ZV9pbl9ieXRlcxgEIAEoA1ILc2l6ZUluQnl0ZXM=');
+@$core.Deprecated('Use exportAppRequestDescriptor instead')
+const ExportAppRequest$json = const {
+  '1': 'ExportAppRequest',
+  '2': const [
+    const {'1': 'app_id', '3': 1, '4': 1, '5': 9, '10': 'appId'},
+    const {'1': 'export_type', '3': 2, '4': 1, '5': 14, '6': '.ExportType', '10': 'exportType'},
+  ],
+};
+
+/// Descriptor for `ExportAppRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List exportAppRequestDescriptor = $convert.base64Decode('ChBFeHBvcnRBcHBSZXF1ZXN0EhUKBmFwcF9pZBgBIAEoCVIFYXBwSWQSLAoLZXhwb3J0X3R5cGUYAiABKA4yCy5FeHBvcnRUeXBlUgpleHBvcnRUeXBl');
+@$core.Deprecated('Use repeatedExportDataDescriptor instead')
+const RepeatedExportData$json = const {
+  '1': 'RepeatedExportData',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.ExportData', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedExportData`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedExportDataDescriptor = $convert.base64Decode('ChJSZXBlYXRlZEV4cG9ydERhdGESIQoFaXRlbXMYASADKAsyCy5FeHBvcnREYXRhUgVpdGVtcw==');
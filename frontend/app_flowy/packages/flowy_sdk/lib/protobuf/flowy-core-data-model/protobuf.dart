@@ -12,3 +12,10 @@ export './app_update.pb.dart';
 export './view_query.pb.dart';
 export './trash_create.pb.dart';
 export './export.pb.dart';
+export './view_stats.pb.dart';
+export './view_sync.pb.dart';
+export './view_progress.pb.dart';
+export './view_consistency.pb.dart';
+export './view_delta.pb.dart';
+export './view_thumbnail.pb.dart';
+export './view_merge.pb.dart';
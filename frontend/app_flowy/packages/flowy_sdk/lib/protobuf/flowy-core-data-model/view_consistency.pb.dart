@@ -0,0 +1,173 @@
+///
+//  Generated code. Do not modify.
+//  source: view_consistency.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'view_consistency.pbenum.dart';
+
+export 'view_consistency.pbenum.dart';
+
+class ConsistencyReport extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ConsistencyReport', createEmptyInstance: create)
+    ..pPS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'orphanedViews')
+    ..pPS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'orphanedDocuments')
+    ..hasRequiredFields = false
+  ;
+
+  ConsistencyReport._() : super();
+  factory ConsistencyReport({
+    $core.Iterable<$core.String>? orphanedViews,
+    $core.Iterable<$core.String>? orphanedDocuments,
+  }) {
+    final _result = create();
+    if (orphanedViews != null) {
+      _result.orphanedViews.addAll(orphanedViews);
+    }
+    if (orphanedDocuments != null) {
+      _result.orphanedDocuments.addAll(orphanedDocuments);
+    }
+    return _result;
+  }
+  factory ConsistencyReport.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ConsistencyReport.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ConsistencyReport clone() => ConsistencyReport()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ConsistencyReport copyWith(void Function(ConsistencyReport) updates) => super.copyWith((message) => updates(message as ConsistencyReport)) as ConsistencyReport; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ConsistencyReport create() => ConsistencyReport._();
+  ConsistencyReport createEmptyInstance() => create();
+  static $pb.PbList<ConsistencyReport> createRepeated() => $pb.PbList<ConsistencyReport>();
+  @$core.pragma('dart2js:noInline')
+  static ConsistencyReport getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ConsistencyReport>(create);
+  static ConsistencyReport? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<$core.String> get orphanedViews => $_getList(0);
+
+  @$pb.TagNumber(2)
+  $core.List<$core.String> get orphanedDocuments => $_getList(1);
+}
+
+class RepairViewsRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepairViewsRequest', createEmptyInstance: create)
+    ..e<RepairStrategy>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'strategy', $pb.PbFieldType.OE, defaultOrMaker: RepairStrategy.CreateEmptyDocument, valueOf: RepairStrategy.valueOf, enumValues: RepairStrategy.values)
+    ..hasRequiredFields = false
+  ;
+
+  RepairViewsRequest._() : super();
+  factory RepairViewsRequest({
+    RepairStrategy? strategy,
+  }) {
+    final _result = create();
+    if (strategy != null) {
+      _result.strategy = strategy;
+    }
+    return _result;
+  }
+  factory RepairViewsRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepairViewsRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepairViewsRequest clone() => RepairViewsRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepairViewsRequest copyWith(void Function(RepairViewsRequest) updates) => super.copyWith((message) => updates(message as RepairViewsRequest)) as RepairViewsRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepairViewsRequest create() => RepairViewsRequest._();
+  RepairViewsRequest createEmptyInstance() => create();
+  static $pb.PbList<RepairViewsRequest> createRepeated() => $pb.PbList<RepairViewsRequest>();
+  @$core.pragma('dart2js:noInline')
+  static RepairViewsRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepairViewsRequest>(create);
+  static RepairViewsRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  RepairStrategy get strategy => $_getN(0);
+  @$pb.TagNumber(1)
+  set strategy(RepairStrategy v) { setField(1, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasStrategy() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearStrategy() => clearField(1);
+}
+
+class RepairReport extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepairReport', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createdDocuments')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'deletedViews')
+    ..hasRequiredFields = false
+  ;
+
+  RepairReport._() : super();
+  factory RepairReport({
+    $fixnum.Int64? createdDocuments,
+    $fixnum.Int64? deletedViews,
+  }) {
+    final _result = create();
+    if (createdDocuments != null) {
+      _result.createdDocuments = createdDocuments;
+    }
+    if (deletedViews != null) {
+      _result.deletedViews = deletedViews;
+    }
+    return _result;
+  }
+  factory RepairReport.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepairReport.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepairReport clone() => RepairReport()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepairReport copyWith(void Function(RepairReport) updates) => super.copyWith((message) => updates(message as RepairReport)) as RepairReport; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepairReport create() => RepairReport._();
+  RepairReport createEmptyInstance() => create();
+  static $pb.PbList<RepairReport> createRepeated() => $pb.PbList<RepairReport>();
+  @$core.pragma('dart2js:noInline')
+  static RepairReport getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepairReport>(create);
+  static RepairReport? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get createdDocuments => $_getI64(0);
+  @$pb.TagNumber(1)
+  set createdDocuments($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasCreatedDocuments() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearCreatedDocuments() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get deletedViews => $_getI64(1);
+  @$pb.TagNumber(2)
+  set deletedViews($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasDeletedViews() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearDeletedViews() => clearField(2);
+}
+
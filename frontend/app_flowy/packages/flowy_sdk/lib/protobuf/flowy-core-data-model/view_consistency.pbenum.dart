@@ -0,0 +1,26 @@
+///
+//  Generated code. Do not modify.
+//  source: view_consistency.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class RepairStrategy extends $pb.ProtobufEnum {
+  static const RepairStrategy CreateEmptyDocument = RepairStrategy._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CreateEmptyDocument');
+  static const RepairStrategy DeleteDanglingView = RepairStrategy._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteDanglingView');
+
+  static const $core.List<RepairStrategy> values = <RepairStrategy> [
+    CreateEmptyDocument,
+    DeleteDanglingView,
+  ];
+
+  static final $core.Map<$core.int, RepairStrategy> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static RepairStrategy? valueOf($core.int value) => _byValue[value];
+
+  const RepairStrategy._($core.int v, $core.String n) : super(v, n);
+}
+
@@ -0,0 +1,53 @@
+///
+//  Generated code. Do not modify.
+//  source: view_consistency.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use repairStrategyDescriptor instead')
+const RepairStrategy$json = const {
+  '1': 'RepairStrategy',
+  '2': const [
+    const {'1': 'CreateEmptyDocument', '2': 0},
+    const {'1': 'DeleteDanglingView', '2': 1},
+  ],
+};
+
+/// Descriptor for `RepairStrategy`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List repairStrategyDescriptor = $convert.base64Decode('Cg5SZXBhaXJTdHJhdGVneRIXChNDcmVhdGVFbXB0eURvY3VtZW50EAASFgoSRGVsZXRlRGFuZ2xpbmdWaWV3EAE=');
+@$core.Deprecated('Use consistencyReportDescriptor instead')
+const ConsistencyReport$json = const {
+  '1': 'ConsistencyReport',
+  '2': const [
+    const {'1': 'orphaned_views', '3': 1, '4': 3, '5': 9, '10': 'orphanedViews'},
+    const {'1': 'orphaned_documents', '3': 2, '4': 3, '5': 9, '10': 'orphanedDocuments'},
+  ],
+};
+
+/// Descriptor for `ConsistencyReport`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List consistencyReportDescriptor = $convert.base64Decode('ChFDb25zaXN0ZW5jeVJlcG9ydBIlCg5vcnBoYW5lZF92aWV3cxgBIAMoCVINb3JwaGFuZWRWaWV3cxItChJvcnBoYW5lZF9kb2N1bWVudHMYAiADKAlSEW9ycGhhbmVkRG9jdW1lbnRz');
+@$core.Deprecated('Use repairViewsRequestDescriptor instead')
+const RepairViewsRequest$json = const {
+  '1': 'RepairViewsRequest',
+  '2': const [
+    const {'1': 'strategy', '3': 1, '4': 1, '5': 14, '6': '.RepairStrategy', '10': 'strategy'},
+  ],
+};
+
+/// Descriptor for `RepairViewsRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repairViewsRequestDescriptor = $convert.base64Decode('ChJSZXBhaXJWaWV3c1JlcXVlc3QSKwoIc3RyYXRlZ3kYASABKA4yDy5SZXBhaXJTdHJhdGVneVIIc3RyYXRlZ3k=');
+@$core.Deprecated('Use repairReportDescriptor instead')
+const RepairReport$json = const {
+  '1': 'RepairReport',
+  '2': const [
+    const {'1': 'created_documents', '3': 1, '4': 1, '5': 3, '10': 'createdDocuments'},
+    const {'1': 'deleted_views', '3': 2, '4': 1, '5': 3, '10': 'deletedViews'},
+  ],
+};
+
+/// Descriptor for `RepairReport`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repairReportDescriptor = $convert.base64Decode('CgxSZXBhaXJSZXBvcnQSKwoRY3JlYXRlZF9kb2N1bWVudHMYASABKANSEGNyZWF0ZWREb2N1bWVudHMSIwoNZGVsZXRlZF92aWV3cxgCIAEoA1IMZGVsZXRlZFZpZXdz');
@@ -19,18 +19,51 @@ enum CreateViewRequest_OneOfThumbnail {
   notSet
 }
 
+enum CreateViewRequest_OneOfInsertAfter {
+  insertAfter, 
+  notSet
+}
+
+enum CreateViewRequest_OneOfTemplate {
+  template, 
+  notSet
+}
+
+enum CreateViewRequest_OneOfIcon {
+  icon, 
+  notSet
+}
+
 class CreateViewRequest extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, CreateViewRequest_OneOfThumbnail> _CreateViewRequest_OneOfThumbnailByTag = {
     4 : CreateViewRequest_OneOfThumbnail.thumbnail,
     0 : CreateViewRequest_OneOfThumbnail.notSet
   };
+  static const $core.Map<$core.int, CreateViewRequest_OneOfInsertAfter> _CreateViewRequest_OneOfInsertAfterByTag = {
+    6 : CreateViewRequest_OneOfInsertAfter.insertAfter,
+    0 : CreateViewRequest_OneOfInsertAfter.notSet
+  };
+  static const $core.Map<$core.int, CreateViewRequest_OneOfTemplate> _CreateViewRequest_OneOfTemplateByTag = {
+    7 : CreateViewRequest_OneOfTemplate.template,
+    0 : CreateViewRequest_OneOfTemplate.notSet
+  };
+  static const $core.Map<$core.int, CreateViewRequest_OneOfIcon> _CreateViewRequest_OneOfIconByTag = {
+    8 : CreateViewRequest_OneOfIcon.icon,
+    0 : CreateViewRequest_OneOfIcon.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateViewRequest', createEmptyInstance: create)
     ..oo(0, [4])
+    ..oo(1, [6])
+    ..oo(2, [7])
+    ..oo(3, [8])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'thumbnail')
     ..e<ViewType>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewType', $pb.PbFieldType.OE, defaultOrMaker: ViewType.Blank, valueOf: ViewType.valueOf, enumValues: ViewType.values)
+    ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'insertAfter')
+    ..aOS(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'template')
+    ..aOS(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'icon')
     ..hasRequiredFields = false
   ;
 
@@ -41,6 +74,9 @@ class CreateViewRequest extends $pb.GeneratedMessage {
     $core.String? desc,
     $core.String? thumbnail,
     ViewType? viewType,
+    $core.String? insertAfter,
+    $core.String? template,
+    $core.String? icon,
   }) {
     final _result = create();
     if (belongToId != null) {
@@ -58,6 +94,15 @@ class CreateViewRequest extends $pb.GeneratedMessage {
     if (viewType != null) {
       _result.viewType = viewType;
     }
+    if (insertAfter != null) {
+      _result.insertAfter = insertAfter;
+    }
+    if (template != null) {
+      _result.template = template;
+    }
+    if (icon != null) {
+      _result.icon = icon;
+    }
     return _result;
   }
   factory CreateViewRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -84,6 +129,15 @@ class CreateViewRequest extends $pb.GeneratedMessage {
   CreateViewRequest_OneOfThumbnail whichOneOfThumbnail() => _CreateViewRequest_OneOfThumbnailByTag[$_whichOneof(0)]!;
   void clearOneOfThumbnail() => clearField($_whichOneof(0));
 
+  CreateViewRequest_OneOfInsertAfter whichOneOfInsertAfter() => _CreateViewRequest_OneOfInsertAfterByTag[$_whichOneof(1)]!;
+  void clearOneOfInsertAfter() => clearField($_whichOneof(1));
+
+  CreateViewRequest_OneOfTemplate whichOneOfTemplate() => _CreateViewRequest_OneOfTemplateByTag[$_whichOneof(2)]!;
+  void clearOneOfTemplate() => clearField($_whichOneof(2));
+
+  CreateViewRequest_OneOfIcon whichOneOfIcon() => _CreateViewRequest_OneOfIconByTag[$_whichOneof(3)]!;
+  void clearOneOfIcon() => clearField($_whichOneof(3));
+
   @$pb.TagNumber(1)
   $core.String get belongToId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -128,10 +182,57 @@ class CreateViewRequest extends $pb.GeneratedMessage {
   $core.bool hasViewType() => $_has(4);
   @$pb.TagNumber(5)
   void clearViewType() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.String get insertAfter => $_getSZ(5);
+  @$pb.TagNumber(6)
+  set insertAfter($core.String v) { $_setString(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasInsertAfter() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearInsertAfter() => clearField(6);
+
+  @$pb.TagNumber(7)
+  $core.String get template => $_getSZ(6);
+  @$pb.TagNumber(7)
+  set template($core.String v) { $_setString(6, v); }
+  @$pb.TagNumber(7)
+  $core.bool hasTemplate() => $_has(6);
+  @$pb.TagNumber(7)
+  void clearTemplate() => clearField(7);
+
+  @$pb.TagNumber(8)
+  $core.String get icon => $_getSZ(7);
+  @$pb.TagNumber(8)
+  set icon($core.String v) { $_setString(7, v); }
+  @$pb.TagNumber(8)
+  $core.bool hasIcon() => $_has(7);
+  @$pb.TagNumber(8)
+  void clearIcon() => clearField(8);
+}
+
+enum CreateViewParams_OneOfInsertAfter {
+  insertAfter, 
+  notSet
+}
+
+enum CreateViewParams_OneOfTemplate {
+  template, 
+  notSet
 }
 
 class CreateViewParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, CreateViewParams_OneOfInsertAfter> _CreateViewParams_OneOfInsertAfterByTag = {
+    8 : CreateViewParams_OneOfInsertAfter.insertAfter,
+    0 : CreateViewParams_OneOfInsertAfter.notSet
+  };
+  static const $core.Map<$core.int, CreateViewParams_OneOfTemplate> _CreateViewParams_OneOfTemplateByTag = {
+    9 : CreateViewParams_OneOfTemplate.template,
+    0 : CreateViewParams_OneOfTemplate.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateViewParams', createEmptyInstance: create)
+    ..oo(0, [8])
+    ..oo(1, [9])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
@@ -139,6 +240,9 @@ class CreateViewParams extends $pb.GeneratedMessage {
     ..e<ViewType>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewType', $pb.PbFieldType.OE, defaultOrMaker: ViewType.Blank, valueOf: ViewType.valueOf, enumValues: ViewType.values)
     ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewData')
     ..aOS(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'insertAfter')
+    ..aOS(9, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'template')
+    ..aOS(10, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'icon')
     ..hasRequiredFields = false
   ;
 
@@ -151,6 +255,9 @@ class CreateViewParams extends $pb.GeneratedMessage {
     ViewType? viewType,
     $core.String? viewData,
     $core.String? viewId,
+    $core.String? insertAfter,
+    $core.String? template,
+    $core.String? icon,
   }) {
     final _result = create();
     if (belongToId != null) {
@@ -174,6 +281,15 @@ class CreateViewParams extends $pb.GeneratedMessage {
     if (viewId != null) {
       _result.viewId = viewId;
     }
+    if (insertAfter != null) {
+      _result.insertAfter = insertAfter;
+    }
+    if (template != null) {
+      _result.template = template;
+    }
+    if (icon != null) {
+      _result.icon = icon;
+    }
     return _result;
   }
   factory CreateViewParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -197,6 +313,12 @@ class CreateViewParams extends $pb.GeneratedMessage {
   static CreateViewParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateViewParams>(create);
   static CreateViewParams? _defaultInstance;
 
+  CreateViewParams_OneOfInsertAfter whichOneOfInsertAfter() => _CreateViewParams_OneOfInsertAfterByTag[$_whichOneof(0)]!;
+  void clearOneOfInsertAfter() => clearField($_whichOneof(0));
+
+  CreateViewParams_OneOfTemplate whichOneOfTemplate() => _CreateViewParams_OneOfTemplateByTag[$_whichOneof(1)]!;
+  void clearOneOfTemplate() => clearField($_whichOneof(1));
+
   @$pb.TagNumber(1)
   $core.String get belongToId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -259,6 +381,33 @@ class CreateViewParams extends $pb.GeneratedMessage {
   $core.bool hasViewId() => $_has(6);
   @$pb.TagNumber(7)
   void clearViewId() => clearField(7);
+
+  @$pb.TagNumber(8)
+  $core.String get insertAfter => $_getSZ(7);
+  @$pb.TagNumber(8)
+  set insertAfter($core.String v) { $_setString(7, v); }
+  @$pb.TagNumber(8)
+  $core.bool hasInsertAfter() => $_has(7);
+  @$pb.TagNumber(8)
+  void clearInsertAfter() => clearField(8);
+
+  @$pb.TagNumber(9)
+  $core.String get template => $_getSZ(8);
+  @$pb.TagNumber(9)
+  set template($core.String v) { $_setString(8, v); }
+  @$pb.TagNumber(9)
+  $core.bool hasTemplate() => $_has(8);
+  @$pb.TagNumber(9)
+  void clearTemplate() => clearField(9);
+
+  @$pb.TagNumber(10)
+  $core.String get icon => $_getSZ(9);
+  @$pb.TagNumber(10)
+  set icon($core.String v) { $_setString(9, v); }
+  @$pb.TagNumber(10)
+  $core.bool hasIcon() => $_has(9);
+  @$pb.TagNumber(10)
+  void clearIcon() => clearField(10);
 }
 
 class View extends $pb.GeneratedMessage {
@@ -272,6 +421,10 @@ class View extends $pb.GeneratedMessage {
     ..aOM<RepeatedView>(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongings', subBuilder: RepeatedView.create)
     ..aInt64(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'modifiedTime')
     ..aInt64(9, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
+    ..aOS(10, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ownerId')
+    ..e<ViewVisibility>(11, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'visibility', $pb.PbFieldType.OE, defaultOrMaker: ViewVisibility.Private, valueOf: ViewVisibility.valueOf, enumValues: ViewVisibility.values)
+    ..aOS(12, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createdBy')
+    ..aOS(13, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'icon')
     ..hasRequiredFields = false
   ;
 
@@ -286,6 +439,10 @@ class View extends $pb.GeneratedMessage {
     RepeatedView? belongings,
     $fixnum.Int64? modifiedTime,
     $fixnum.Int64? createTime,
+    $core.String? ownerId,
+    ViewVisibility? visibility,
+    $core.String? createdBy,
+    $core.String? icon,
   }) {
     final _result = create();
     if (id != null) {
@@ -315,6 +472,18 @@ class View extends $pb.GeneratedMessage {
     if (createTime != null) {
       _result.createTime = createTime;
     }
+    if (ownerId != null) {
+      _result.ownerId = ownerId;
+    }
+    if (visibility != null) {
+      _result.visibility = visibility;
+    }
+    if (createdBy != null) {
+      _result.createdBy = createdBy;
+    }
+    if (icon != null) {
+      _result.icon = icon;
+    }
     return _result;
   }
   factory View.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -420,6 +589,42 @@ class View extends $pb.GeneratedMessage {
   $core.bool hasCreateTime() => $_has(8);
   @$pb.TagNumber(9)
   void clearCreateTime() => clearField(9);
+
+  @$pb.TagNumber(10)
+  $core.String get ownerId => $_getSZ(9);
+  @$pb.TagNumber(10)
+  set ownerId($core.String v) { $_setString(9, v); }
+  @$pb.TagNumber(10)
+  $core.bool hasOwnerId() => $_has(9);
+  @$pb.TagNumber(10)
+  void clearOwnerId() => clearField(10);
+
+  @$pb.TagNumber(11)
+  ViewVisibility get visibility => $_getN(10);
+  @$pb.TagNumber(11)
+  set visibility(ViewVisibility v) { setField(11, v); }
+  @$pb.TagNumber(11)
+  $core.bool hasVisibility() => $_has(10);
+  @$pb.TagNumber(11)
+  void clearVisibility() => clearField(11);
+
+  @$pb.TagNumber(12)
+  $core.String get createdBy => $_getSZ(11);
+  @$pb.TagNumber(12)
+  set createdBy($core.String v) { $_setString(11, v); }
+  @$pb.TagNumber(12)
+  $core.bool hasCreatedBy() => $_has(11);
+  @$pb.TagNumber(12)
+  void clearCreatedBy() => clearField(12);
+
+  @$pb.TagNumber(13)
+  $core.String get icon => $_getSZ(12);
+  @$pb.TagNumber(13)
+  set icon($core.String v) { $_setString(12, v); }
+  @$pb.TagNumber(13)
+  $core.bool hasIcon() => $_has(12);
+  @$pb.TagNumber(13)
+  void clearIcon() => clearField(13);
 }
 
 class RepeatedView extends $pb.GeneratedMessage {
@@ -463,3 +668,141 @@ class RepeatedView extends $pb.GeneratedMessage {
   $core.List<View> get items => $_getList(0);
 }
 
+class CreateViewResult extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateViewResult', createEmptyInstance: create)
+    ..aOM<View>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'view', subBuilder: View.create)
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'initialRevId')
+    ..hasRequiredFields = false
+  ;
+
+  CreateViewResult._() : super();
+  factory CreateViewResult({
+    View? view,
+    $fixnum.Int64? initialRevId,
+  }) {
+    final _result = create();
+    if (view != null) {
+      _result.view = view;
+    }
+    if (initialRevId != null) {
+      _result.initialRevId = initialRevId;
+    }
+    return _result;
+  }
+  factory CreateViewResult.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateViewResult.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateViewResult clone() => CreateViewResult()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateViewResult copyWith(void Function(CreateViewResult) updates) => super.copyWith((message) => updates(message as CreateViewResult)) as CreateViewResult; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateViewResult create() => CreateViewResult._();
+  CreateViewResult createEmptyInstance() => create();
+  static $pb.PbList<CreateViewResult> createRepeated() => $pb.PbList<CreateViewResult>();
+  @$core.pragma('dart2js:noInline')
+  static CreateViewResult getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateViewResult>(create);
+  static CreateViewResult? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  View get view => $_getN(0);
+  @$pb.TagNumber(1)
+  set view(View v) { setField(1, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasView() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearView() => clearField(1);
+  @$pb.TagNumber(1)
+  View ensureView() => $_ensure(0);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get initialRevId => $_getI64(1);
+  @$pb.TagNumber(2)
+  set initialRevId($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasInitialRevId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearInitialRevId() => clearField(2);
+}
+
+class ImportViewRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ImportViewRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'deltaJson')
+    ..hasRequiredFields = false
+  ;
+
+  ImportViewRequest._() : super();
+  factory ImportViewRequest({
+    $core.String? belongToId,
+    $core.String? name,
+    $core.String? deltaJson,
+  }) {
+    final _result = create();
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (name != null) {
+      _result.name = name;
+    }
+    if (deltaJson != null) {
+      _result.deltaJson = deltaJson;
+    }
+    return _result;
+  }
+  factory ImportViewRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ImportViewRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ImportViewRequest clone() => ImportViewRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ImportViewRequest copyWith(void Function(ImportViewRequest) updates) => super.copyWith((message) => updates(message as ImportViewRequest)) as ImportViewRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ImportViewRequest create() => ImportViewRequest._();
+  ImportViewRequest createEmptyInstance() => create();
+  static $pb.PbList<ImportViewRequest> createRepeated() => $pb.PbList<ImportViewRequest>();
+  @$core.pragma('dart2js:noInline')
+  static ImportViewRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ImportViewRequest>(create);
+  static ImportViewRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get belongToId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set belongToId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBelongToId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBelongToId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get name => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set name($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasName() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearName() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get deltaJson => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set deltaJson($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasDeltaJson() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearDeltaJson() => clearField(3);
+}
+
@@ -24,3 +24,20 @@ class ViewType extends $pb.ProtobufEnum {
   const ViewType._($core.int v, $core.String n) : super(v, n);
 }
 
+class ViewVisibility extends $pb.ProtobufEnum {
+  static const ViewVisibility Private = ViewVisibility._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Private');
+  static const ViewVisibility Workspace = ViewVisibility._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Workspace');
+  static const ViewVisibility Public = ViewVisibility._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Public');
+
+  static const $core.List<ViewVisibility> values = <ViewVisibility> [
+    Private,
+    Workspace,
+    Public,
+  ];
+
+  static final $core.Map<$core.int, ViewVisibility> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static ViewVisibility? valueOf($core.int value) => _byValue[value];
+
+  const ViewVisibility._($core.int v, $core.String n) : super(v, n);
+}
+
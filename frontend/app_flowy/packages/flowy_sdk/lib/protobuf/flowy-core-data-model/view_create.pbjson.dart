@@ -19,6 +19,18 @@ const ViewType$json = const {
 
 /// Descriptor for `ViewType`. Decode as a `google.protobuf.EnumDescriptorProto`.
 final $typed_data.Uint8List viewTypeDescriptor = $convert.base64Decode('CghWaWV3VHlwZRIJCgVCbGFuaxAAEgcKA0RvYxAB');
+@$core.Deprecated('Use viewVisibilityDescriptor instead')
+const ViewVisibility$json = const {
+  '1': 'ViewVisibility',
+  '2': const [
+    const {'1': 'Private', '2': 0},
+    const {'1': 'Workspace', '2': 1},
+    const {'1': 'Public', '2': 2},
+  ],
+};
+
+/// Descriptor for `ViewVisibility`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List viewVisibilityDescriptor = $convert.base64Decode('Cg5WaWV3VmlzaWJpbGl0eRILCgdQcml2YXRlEAASDQoJV29ya3NwYWNlEAESCgoGUHVibGljEAI=');
 @$core.Deprecated('Use createViewRequestDescriptor instead')
 const CreateViewRequest$json = const {
   '1': 'CreateViewRequest',
@@ -28,14 +40,20 @@ const CreateViewRequest$json = const {
     const {'1': 'desc', '3': 3, '4': 1, '5': 9, '10': 'desc'},
     const {'1': 'thumbnail', '3': 4, '4': 1, '5': 9, '9': 0, '10': 'thumbnail'},
     const {'1': 'view_type', '3': 5, '4': 1, '5': 14, '6': '.ViewType', '10': 'viewType'},
+    const {'1': 'insert_after', '3': 6, '4': 1, '5': 9, '9': 1, '10': 'insertAfter'},
+    const {'1': 'template', '3': 7, '4': 1, '5': 9, '9': 2, '10': 'template'},
+    const {'1': 'icon', '3': 8, '4': 1, '5': 9, '9': 3, '10': 'icon'},
   ],
   '8': const [
     const {'1': 'one_of_thumbnail'},
+    const {'1': 'one_of_insert_after'},
+    const {'1': 'one_of_template'},
+    const {'1': 'one_of_icon'},
   ],
 };
 
 /// Descriptor for `CreateViewRequest`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List createViewRequestDescriptor = $convert.base64Decode('ChFDcmVhdGVWaWV3UmVxdWVzdBIgCgxiZWxvbmdfdG9faWQYASABKAlSCmJlbG9uZ1RvSWQSEgoEbmFtZRgCIAEoCVIEbmFtZRISCgRkZXNjGAMgASgJUgRkZXNjEh4KCXRodW1ibmFpbBgEIAEoCUgAUgl0aHVtYm5haWwSJgoJdmlld190eXBlGAUgASgOMgkuVmlld1R5cGVSCHZpZXdUeXBlQhIKEG9uZV9vZl90aHVtYm5haWw=');
+final $typed_data.Uint8List createViewRequestDescriptor = $convert.base64Decode('ChFDcmVhdGVWaWV3UmVxdWVzdBIgCgxiZWxvbmdfdG9faWQYASABKAlSCmJlbG9uZ1RvSWQSEgoEbmFtZRgCIAEoCVIEbmFtZRISCgRkZXNjGAMgASgJUgRkZXNjEh4KCXRodW1ibmFpbBgEIAEoCUgAUgl0aHVtYm5haWwSJgoJdmlld190eXBlGAUgASgOMgkuVmlld1R5cGVSCHZpZXdUeXBlEiMKDGluc2VydF9hZnRlchgGIAEoCUgBUgtpbnNlcnRBZnRlchIcCgh0ZW1wbGF0ZRgHIAEoCUgCUgh0ZW1wbGF0ZRIUCgRpY29uGAggASgJSANSBGljb25CEgoQb25lX29mX3RodW1ibmFpbEIVChNvbmVfb2ZfaW5zZXJ0X2FmdGVyQhEKD29uZV9vZl90ZW1wbGF0ZUINCgtvbmVfb2ZfaWNvbg==');
 @$core.Deprecated('Use createViewParamsDescriptor instead')
 const CreateViewParams$json = const {
   '1': 'CreateViewParams',
@@ -47,11 +65,18 @@ const CreateViewParams$json = const {
     const {'1': 'view_type', '3': 5, '4': 1, '5': 14, '6': '.ViewType', '10': 'viewType'},
     const {'1': 'view_data', '3': 6, '4': 1, '5': 9, '10': 'viewData'},
     const {'1': 'view_id', '3': 7, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'insert_after', '3': 8, '4': 1, '5': 9, '9': 0, '10': 'insertAfter'},
+    const {'1': 'template', '3': 9, '4': 1, '5': 9, '9': 1, '10': 'template'},
+    const {'1': 'icon', '3': 10, '4': 1, '5': 9, '10': 'icon'},
+  ],
+  '8': const [
+    const {'1': 'one_of_insert_after'},
+    const {'1': 'one_of_template'},
   ],
 };
 
 /// Descriptor for `CreateViewParams`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List createViewParamsDescriptor = $convert.base64Decode('ChBDcmVhdGVWaWV3UGFyYW1zEiAKDGJlbG9uZ190b19pZBgBIAEoCVIKYmVsb25nVG9JZBISCgRuYW1lGAIgASgJUgRuYW1lEhIKBGRlc2MYAyABKAlSBGRlc2MSHAoJdGh1bWJuYWlsGAQgASgJUgl0aHVtYm5haWwSJgoJdmlld190eXBlGAUgASgOMgkuVmlld1R5cGVSCHZpZXdUeXBlEhsKCXZpZXdfZGF0YRgGIAEoCVIIdmlld0RhdGESFwoHdmlld19pZBgHIAEoCVIGdmlld0lk');
+final $typed_data.Uint8List createViewParamsDescriptor = $convert.base64Decode('ChBDcmVhdGVWaWV3UGFyYW1zEiAKDGJlbG9uZ190b19pZBgBIAEoCVIKYmVsb25nVG9JZBISCgRuYW1lGAIgASgJUgRuYW1lEhIKBGRlc2MYAyABKAlSBGRlc2MSHAoJdGh1bWJuYWlsGAQgASgJUgl0aHVtYm5haWwSJgoJdmlld190eXBlGAUgASgOMgkuVmlld1R5cGVSCHZpZXdUeXBlEhsKCXZpZXdfZGF0YRgGIAEoCVIIdmlld0RhdGESFwoHdmlld19pZBgHIAEoCVIGdmlld0lkEiMKDGluc2VydF9hZnRlchgIIAEoCUgAUgtpbnNlcnRBZnRlchIcCgh0ZW1wbGF0ZRgJIAEoCUgBUgh0ZW1wbGF0ZRISCgRpY29uGAogASgJUgRpY29uQhUKE29uZV9vZl9pbnNlcnRfYWZ0ZXJCEQoPb25lX29mX3RlbXBsYXRl');
 @$core.Deprecated('Use viewDescriptor instead')
 const View$json = const {
   '1': 'View',
@@ -65,11 +90,15 @@ const View$json = const {
     const {'1': 'belongings', '3': 7, '4': 1, '5': 11, '6': '.RepeatedView', '10': 'belongings'},
     const {'1': 'modified_time', '3': 8, '4': 1, '5': 3, '10': 'modifiedTime'},
     const {'1': 'create_time', '3': 9, '4': 1, '5': 3, '10': 'createTime'},
+    const {'1': 'owner_id', '3': 10, '4': 1, '5': 9, '10': 'ownerId'},
+    const {'1': 'visibility', '3': 11, '4': 1, '5': 14, '6': '.ViewVisibility', '10': 'visibility'},
+    const {'1': 'created_by', '3': 12, '4': 1, '5': 9, '10': 'createdBy'},
+    const {'1': 'icon', '3': 13, '4': 1, '5': 9, '10': 'icon'},
   ],
 };
 
 /// Descriptor for `View`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List viewDescriptor = $convert.base64Decode('CgRWaWV3Eg4KAmlkGAEgASgJUgJpZBIgCgxiZWxvbmdfdG9faWQYAiABKAlSCmJlbG9uZ1RvSWQSEgoEbmFtZRgDIAEoCVIEbmFtZRISCgRkZXNjGAQgASgJUgRkZXNjEiYKCXZpZXdfdHlwZRgFIAEoDjIJLlZpZXdUeXBlUgh2aWV3VHlwZRIYCgd2ZXJzaW9uGAYgASgDUgd2ZXJzaW9uEi0KCmJlbG9uZ2luZ3MYByABKAsyDS5SZXBlYXRlZFZpZXdSCmJlbG9uZ2luZ3MSIwoNbW9kaWZpZWRfdGltZRgIIAEoA1IMbW9kaWZpZWRUaW1lEh8KC2NyZWF0ZV90aW1lGAkgASgDUgpjcmVhdGVUaW1l');
+final $typed_data.Uint8List viewDescriptor = $convert.base64Decode('CgRWaWV3Eg4KAmlkGAEgASgJUgJpZBIgCgxiZWxvbmdfdG9faWQYAiABKAlSCmJlbG9uZ1RvSWQSEgoEbmFtZRgDIAEoCVIEbmFtZRISCgRkZXNjGAQgASgJUgRkZXNjEiYKCXZpZXdfdHlwZRgFIAEoDjIJLlZpZXdUeXBlUgh2aWV3VHlwZRIYCgd2ZXJzaW9uGAYgASgDUgd2ZXJzaW9uEi0KCmJlbG9uZ2luZ3MYByABKAsyDS5SZXBlYXRlZFZpZXdSCmJlbG9uZ2luZ3MSIwoNbW9kaWZpZWRfdGltZRgIIAEoA1IMbW9kaWZpZWRUaW1lEh8KC2NyZWF0ZV90aW1lGAkgASgDUgpjcmVhdGVUaW1lEhkKCG93bmVyX2lkGAogASgJUgdvd25lcklkEi8KCnZpc2liaWxpdHkYCyABKA4yDy5WaWV3VmlzaWJpbGl0eVIKdmlzaWJpbGl0eRIdCgpjcmVhdGVkX2J5GAwgASgJUgljcmVhdGVkQnkSEgoEaWNvbhgNIAEoCVIEaWNvbg==');
 @$core.Deprecated('Use repeatedViewDescriptor instead')
 const RepeatedView$json = const {
   '1': 'RepeatedView',
@@ -80,3 +109,26 @@ const RepeatedView$json = const {
 
 /// Descriptor for `RepeatedView`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List repeatedViewDescriptor = $convert.base64Decode('CgxSZXBlYXRlZFZpZXcSGwoFaXRlbXMYASADKAsyBS5WaWV3UgVpdGVtcw==');
+@$core.Deprecated('Use createViewResultDescriptor instead')
+const CreateViewResult$json = const {
+  '1': 'CreateViewResult',
+  '2': const [
+    const {'1': 'view', '3': 1, '4': 1, '5': 11, '6': '.View', '10': 'view'},
+    const {'1': 'initial_rev_id', '3': 2, '4': 1, '5': 3, '10': 'initialRevId'},
+  ],
+};
+
+/// Descriptor for `CreateViewResult`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createViewResultDescriptor = $convert.base64Decode('ChBDcmVhdGVWaWV3UmVzdWx0EhkKBHZpZXcYASABKAsyBS5WaWV3UgR2aWV3EiQKDmluaXRpYWxfcmV2X2lkGAIgASgDUgxpbml0aWFsUmV2SWQ=');
+@$core.Deprecated('Use importViewRequestDescriptor instead')
+const ImportViewRequest$json = const {
+  '1': 'ImportViewRequest',
+  '2': const [
+    const {'1': 'belong_to_id', '3': 1, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
+    const {'1': 'delta_json', '3': 3, '4': 1, '5': 9, '10': 'deltaJson'},
+  ],
+};
+
+/// Descriptor for `ImportViewRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List importViewRequestDescriptor = $convert.base64Decode('ChFJbXBvcnRWaWV3UmVxdWVzdBIgCgxiZWxvbmdfdG9faWQYASABKAlSCmJlbG9uZ1RvSWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIdCgpkZWx0YV9qc29uGAMgASgJUglkZWx0YUpzb24=');
@@ -0,0 +1,229 @@
+///
+//  Generated code. Do not modify.
+//  source: view_delta.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'view_create.pb.dart' as $0;
+
+class AppViewsDelta extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'AppViewsDelta', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..pc<$0.View>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'added', $pb.PbFieldType.PM, subBuilder: $0.View.create)
+    ..pc<$0.View>(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'updated', $pb.PbFieldType.PM, subBuilder: $0.View.create)
+    ..pPS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'removed')
+    ..hasRequiredFields = false
+  ;
+
+  AppViewsDelta._() : super();
+  factory AppViewsDelta({
+    $core.String? belongToId,
+    $core.Iterable<$0.View>? added,
+    $core.Iterable<$0.View>? updated,
+    $core.Iterable<$core.String>? removed,
+  }) {
+    final _result = create();
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (added != null) {
+      _result.added.addAll(added);
+    }
+    if (updated != null) {
+      _result.updated.addAll(updated);
+    }
+    if (removed != null) {
+      _result.removed.addAll(removed);
+    }
+    return _result;
+  }
+  factory AppViewsDelta.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory AppViewsDelta.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  AppViewsDelta clone() => AppViewsDelta()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  AppViewsDelta copyWith(void Function(AppViewsDelta) updates) => super.copyWith((message) => updates(message as AppViewsDelta)) as AppViewsDelta; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static AppViewsDelta create() => AppViewsDelta._();
+  AppViewsDelta createEmptyInstance() => create();
+  static $pb.PbList<AppViewsDelta> createRepeated() => $pb.PbList<AppViewsDelta>();
+  @$core.pragma('dart2js:noInline')
+  static AppViewsDelta getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<AppViewsDelta>(create);
+  static AppViewsDelta? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get belongToId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set belongToId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBelongToId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBelongToId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.List<$0.View> get added => $_getList(1);
+
+  @$pb.TagNumber(3)
+  $core.List<$0.View> get updated => $_getList(2);
+
+  @$pb.TagNumber(4)
+  $core.List<$core.String> get removed => $_getList(3);
+}
+
+class AppViews extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'AppViews', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..aOM<$0.RepeatedView>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'views', subBuilder: $0.RepeatedView.create)
+    ..hasRequiredFields = false
+  ;
+
+  AppViews._() : super();
+  factory AppViews({
+    $core.String? belongToId,
+    $0.RepeatedView? views,
+  }) {
+    final _result = create();
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (views != null) {
+      _result.views = views;
+    }
+    return _result;
+  }
+  factory AppViews.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory AppViews.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  AppViews clone() => AppViews()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  AppViews copyWith(void Function(AppViews) updates) => super.copyWith((message) => updates(message as AppViews)) as AppViews; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static AppViews create() => AppViews._();
+  AppViews createEmptyInstance() => create();
+  static $pb.PbList<AppViews> createRepeated() => $pb.PbList<AppViews>();
+  @$core.pragma('dart2js:noInline')
+  static AppViews getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<AppViews>(create);
+  static AppViews? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get belongToId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set belongToId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBelongToId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBelongToId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $0.RepeatedView get views => $_getN(1);
+  @$pb.TagNumber(2)
+  set views($0.RepeatedView v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasViews() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearViews() => clearField(2);
+  @$pb.TagNumber(2)
+  $0.RepeatedView ensureViews() => $_ensure(1);
+}
+
+class RepeatedAppViews extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedAppViews', createEmptyInstance: create)
+    ..pc<AppViews>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: AppViews.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedAppViews._() : super();
+  factory RepeatedAppViews({
+    $core.Iterable<AppViews>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedAppViews.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedAppViews.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedAppViews clone() => RepeatedAppViews()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedAppViews copyWith(void Function(RepeatedAppViews) updates) => super.copyWith((message) => updates(message as RepeatedAppViews)) as RepeatedAppViews; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedAppViews create() => RepeatedAppViews._();
+  RepeatedAppViews createEmptyInstance() => create();
+  static $pb.PbList<RepeatedAppViews> createRepeated() => $pb.PbList<RepeatedAppViews>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedAppViews getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedAppViews>(create);
+  static RepeatedAppViews? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<AppViews> get items => $_getList(0);
+}
+
+class RepeatedAppViewsDelta extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedAppViewsDelta', createEmptyInstance: create)
+    ..pc<AppViewsDelta>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: AppViewsDelta.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedAppViewsDelta._() : super();
+  factory RepeatedAppViewsDelta({
+    $core.Iterable<AppViewsDelta>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedAppViewsDelta.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedAppViewsDelta.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedAppViewsDelta clone() => RepeatedAppViewsDelta()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedAppViewsDelta copyWith(void Function(RepeatedAppViewsDelta) updates) => super.copyWith((message) => updates(message as RepeatedAppViewsDelta)) as RepeatedAppViewsDelta; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedAppViewsDelta create() => RepeatedAppViewsDelta._();
+  RepeatedAppViewsDelta createEmptyInstance() => create();
+  static $pb.PbList<RepeatedAppViewsDelta> createRepeated() => $pb.PbList<RepeatedAppViewsDelta>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedAppViewsDelta getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedAppViewsDelta>(create);
+  static RepeatedAppViewsDelta? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<AppViewsDelta> get items => $_getList(0);
+}
+
@@ -0,0 +1,54 @@
+///
+//  Generated code. Do not modify.
+//  source: view_delta.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use appViewsDeltaDescriptor instead')
+const AppViewsDelta$json = const {
+  '1': 'AppViewsDelta',
+  '2': const [
+    const {'1': 'belong_to_id', '3': 1, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'added', '3': 2, '4': 3, '5': 11, '6': '.View', '10': 'added'},
+    const {'1': 'updated', '3': 3, '4': 3, '5': 11, '6': '.View', '10': 'updated'},
+    const {'1': 'removed', '3': 4, '4': 3, '5': 9, '10': 'removed'},
+  ],
+};
+
+/// Descriptor for `AppViewsDelta`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List appViewsDeltaDescriptor = $convert.base64Decode('Cg1BcHBWaWV3c0RlbHRhEiAKDGJlbG9uZ190b19pZBgBIAEoCVIKYmVsb25nVG9JZBIbCgVhZGRlZBgCIAMoCzIFLlZpZXdSBWFkZGVkEh8KB3VwZGF0ZWQYAyADKAsyBS5WaWV3Ugd1cGRhdGVkEhgKB3JlbW92ZWQYBCADKAlSB3JlbW92ZWQ=');
+@$core.Deprecated('Use appViewsDescriptor instead')
+const AppViews$json = const {
+  '1': 'AppViews',
+  '2': const [
+    const {'1': 'belong_to_id', '3': 1, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'views', '3': 2, '4': 1, '5': 11, '6': '.RepeatedView', '10': 'views'},
+  ],
+};
+
+/// Descriptor for `AppViews`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List appViewsDescriptor = $convert.base64Decode('CghBcHBWaWV3cxIgCgxiZWxvbmdfdG9faWQYASABKAlSCmJlbG9uZ1RvSWQSIwoFdmlld3MYAiABKAsyDS5SZXBlYXRlZFZpZXdSBXZpZXdz');
+@$core.Deprecated('Use repeatedAppViewsDescriptor instead')
+const RepeatedAppViews$json = const {
+  '1': 'RepeatedAppViews',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.AppViews', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedAppViews`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedAppViewsDescriptor = $convert.base64Decode('ChBSZXBlYXRlZEFwcFZpZXdzEh8KBWl0ZW1zGAEgAygLMgkuQXBwVmlld3NSBWl0ZW1z');
+@$core.Deprecated('Use repeatedAppViewsDeltaDescriptor instead')
+const RepeatedAppViewsDelta$json = const {
+  '1': 'RepeatedAppViewsDelta',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.AppViewsDelta', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedAppViewsDelta`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedAppViewsDeltaDescriptor = $convert.base64Decode('ChVSZXBlYXRlZEFwcFZpZXdzRGVsdGESJAoFaXRlbXMYASADKAsyDi5BcHBWaWV3c0RlbHRhUgVpdGVtcw==');
@@ -0,0 +1,120 @@
+///
+//  Generated code. Do not modify.
+//  source: view_merge.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class MergeLocalRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'MergeLocalRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'targetUserId')
+    ..hasRequiredFields = false
+  ;
+
+  MergeLocalRequest._() : super();
+  factory MergeLocalRequest({
+    $core.String? targetUserId,
+  }) {
+    final _result = create();
+    if (targetUserId != null) {
+      _result.targetUserId = targetUserId;
+    }
+    return _result;
+  }
+  factory MergeLocalRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory MergeLocalRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  MergeLocalRequest clone() => MergeLocalRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  MergeLocalRequest copyWith(void Function(MergeLocalRequest) updates) => super.copyWith((message) => updates(message as MergeLocalRequest)) as MergeLocalRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static MergeLocalRequest create() => MergeLocalRequest._();
+  MergeLocalRequest createEmptyInstance() => create();
+  static $pb.PbList<MergeLocalRequest> createRepeated() => $pb.PbList<MergeLocalRequest>();
+  @$core.pragma('dart2js:noInline')
+  static MergeLocalRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<MergeLocalRequest>(create);
+  static MergeLocalRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get targetUserId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set targetUserId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasTargetUserId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearTargetUserId() => clearField(1);
+}
+
+class MergeReport extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'MergeReport', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'mergedApps')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'mergedViews')
+    ..hasRequiredFields = false
+  ;
+
+  MergeReport._() : super();
+  factory MergeReport({
+    $fixnum.Int64? mergedApps,
+    $fixnum.Int64? mergedViews,
+  }) {
+    final _result = create();
+    if (mergedApps != null) {
+      _result.mergedApps = mergedApps;
+    }
+    if (mergedViews != null) {
+      _result.mergedViews = mergedViews;
+    }
+    return _result;
+  }
+  factory MergeReport.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory MergeReport.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  MergeReport clone() => MergeReport()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  MergeReport copyWith(void Function(MergeReport) updates) => super.copyWith((message) => updates(message as MergeReport)) as MergeReport; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static MergeReport create() => MergeReport._();
+  MergeReport createEmptyInstance() => create();
+  static $pb.PbList<MergeReport> createRepeated() => $pb.PbList<MergeReport>();
+  @$core.pragma('dart2js:noInline')
+  static MergeReport getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<MergeReport>(create);
+  static MergeReport? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get mergedApps => $_getI64(0);
+  @$pb.TagNumber(1)
+  set mergedApps($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasMergedApps() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearMergedApps() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get mergedViews => $_getI64(1);
+  @$pb.TagNumber(2)
+  set mergedViews($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasMergedViews() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearMergedViews() => clearField(2);
+}
+
@@ -0,0 +1,31 @@
+///
+//  Generated code. Do not modify.
+//  source: view_merge.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use mergeLocalRequestDescriptor instead')
+const MergeLocalRequest$json = const {
+  '1': 'MergeLocalRequest',
+  '2': const [
+    const {'1': 'target_user_id', '3': 1, '4': 1, '5': 9, '10': 'targetUserId'},
+  ],
+};
+
+/// Descriptor for `MergeLocalRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List mergeLocalRequestDescriptor = $convert.base64Decode('ChFNZXJnZUxvY2FsUmVxdWVzdBIkCg50YXJnZXRfdXNlcl9pZBgBIAEoCVIMdGFyZ2V0VXNlcklk');
+@$core.Deprecated('Use mergeReportDescriptor instead')
+const MergeReport$json = const {
+  '1': 'MergeReport',
+  '2': const [
+    const {'1': 'merged_apps', '3': 1, '4': 1, '5': 3, '10': 'mergedApps'},
+    const {'1': 'merged_views', '3': 2, '4': 1, '5': 3, '10': 'mergedViews'},
+  ],
+};
+
+/// Descriptor for `MergeReport`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List mergeReportDescriptor = $convert.base64Decode('CgtNZXJnZVJlcG9ydBIfCgttZXJnZWRfYXBwcxgBIAEoA1IKbWVyZ2VkQXBwcxIhCgxtZXJnZWRfdmlld3MYAiABKANSC21lcmdlZFZpZXdz');
@@ -0,0 +1,22 @@
+///
+//  Generated code. Do not modify.
+//  source: view_progress.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use viewProgressDescriptor instead')
+const ViewProgress$json = const {
+  '1': 'ViewProgress',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'processed', '3': 2, '4': 1, '5': 3, '10': 'processed'},
+    const {'1': 'total', '3': 3, '4': 1, '5': 3, '10': 'total'},
+  ],
+};
+
+/// Descriptor for `ViewProgress`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewProgressDescriptor = $convert.base64Decode('CgxWaWV3UHJvZ3Jlc3MSFwoHdmlld19pZBgBIAEoCVIGdmlld0lkEhwKCXByb2Nlc3NlZBgCIAEoA1IJcHJvY2Vzc2VkEhQKBXRvdGFsGAMgASgDUgV0b3RhbA==');
@@ -7,8 +7,11 @@
 
 import 'dart:core' as $core;
 
+import 'package:fixnum/fixnum.dart' as $fixnum;
 import 'package:protobuf/protobuf.dart' as $pb;
 
+import 'view_create.pb.dart' as $0;
+
 class QueryViewRequest extends $pb.GeneratedMessage {
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryViewRequest', createEmptyInstance: create)
     ..pPS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewIds')
@@ -97,6 +100,436 @@ class ViewId extends $pb.GeneratedMessage {
   void clearViewId() => clearField(1);
 }
 
+class QueryViewsBelongToRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryViewsBelongToRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..e<$0.ViewType>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewType', $pb.PbFieldType.OE, defaultOrMaker: $0.ViewType.Blank, valueOf: $0.ViewType.valueOf, enumValues: $0.ViewType.values)
+    ..hasRequiredFields = false
+  ;
+
+  QueryViewsBelongToRequest._() : super();
+  factory QueryViewsBelongToRequest({
+    $core.String? belongToId,
+    $0.ViewType? viewType,
+  }) {
+    final _result = create();
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (viewType != null) {
+      _result.viewType = viewType;
+    }
+    return _result;
+  }
+  factory QueryViewsBelongToRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory QueryViewsBelongToRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  QueryViewsBelongToRequest clone() => QueryViewsBelongToRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  QueryViewsBelongToRequest copyWith(void Function(QueryViewsBelongToRequest) updates) => super.copyWith((message) => updates(message as QueryViewsBelongToRequest)) as QueryViewsBelongToRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static QueryViewsBelongToRequest create() => QueryViewsBelongToRequest._();
+  QueryViewsBelongToRequest createEmptyInstance() => create();
+  static $pb.PbList<QueryViewsBelongToRequest> createRepeated() => $pb.PbList<QueryViewsBelongToRequest>();
+  @$core.pragma('dart2js:noInline')
+  static QueryViewsBelongToRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<QueryViewsBelongToRequest>(create);
+  static QueryViewsBelongToRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get belongToId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set belongToId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBelongToId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBelongToId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $0.ViewType get viewType => $_getN(1);
+  @$pb.TagNumber(2)
+  set viewType($0.ViewType v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasViewType() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearViewType() => clearField(2);
+}
+
+class QueryViewsPageRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryViewsPageRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'offset')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'limit')
+    ..hasRequiredFields = false
+  ;
+
+  QueryViewsPageRequest._() : super();
+  factory QueryViewsPageRequest({
+    $core.String? belongToId,
+    $fixnum.Int64? offset,
+    $fixnum.Int64? limit,
+  }) {
+    final _result = create();
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (offset != null) {
+      _result.offset = offset;
+    }
+    if (limit != null) {
+      _result.limit = limit;
+    }
+    return _result;
+  }
+  factory QueryViewsPageRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory QueryViewsPageRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  QueryViewsPageRequest clone() => QueryViewsPageRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  QueryViewsPageRequest copyWith(void Function(QueryViewsPageRequest) updates) => super.copyWith((message) => updates(message as QueryViewsPageRequest)) as QueryViewsPageRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static QueryViewsPageRequest create() => QueryViewsPageRequest._();
+  QueryViewsPageRequest createEmptyInstance() => create();
+  static $pb.PbList<QueryViewsPageRequest> createRepeated() => $pb.PbList<QueryViewsPageRequest>();
+  @$core.pragma('dart2js:noInline')
+  static QueryViewsPageRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<QueryViewsPageRequest>(create);
+  static QueryViewsPageRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get belongToId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set belongToId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBelongToId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBelongToId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get offset => $_getI64(1);
+  @$pb.TagNumber(2)
+  set offset($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasOffset() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearOffset() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get limit => $_getI64(2);
+  @$pb.TagNumber(3)
+  set limit($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasLimit() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearLimit() => clearField(3);
+}
+
+class ViewsPage extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ViewsPage', createEmptyInstance: create)
+    ..aOM<$0.RepeatedView>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', subBuilder: $0.RepeatedView.create)
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'total')
+    ..hasRequiredFields = false
+  ;
+
+  ViewsPage._() : super();
+  factory ViewsPage({
+    $0.RepeatedView? items,
+    $fixnum.Int64? total,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items = items;
+    }
+    if (total != null) {
+      _result.total = total;
+    }
+    return _result;
+  }
+  factory ViewsPage.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ViewsPage.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ViewsPage clone() => ViewsPage()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ViewsPage copyWith(void Function(ViewsPage) updates) => super.copyWith((message) => updates(message as ViewsPage)) as ViewsPage; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ViewsPage create() => ViewsPage._();
+  ViewsPage createEmptyInstance() => create();
+  static $pb.PbList<ViewsPage> createRepeated() => $pb.PbList<ViewsPage>();
+  @$core.pragma('dart2js:noInline')
+  static ViewsPage getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ViewsPage>(create);
+  static ViewsPage? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $0.RepeatedView get items => $_getN(0);
+  @$pb.TagNumber(1)
+  set items($0.RepeatedView v) { setField(1, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasItems() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearItems() => clearField(1);
+  @$pb.TagNumber(1)
+  $0.RepeatedView ensureItems() => $_ensure(0);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get total => $_getI64(1);
+  @$pb.TagNumber(2)
+  set total($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTotal() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTotal() => clearField(2);
+}
+
+class QueryViewsCountRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryViewsCountRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..hasRequiredFields = false
+  ;
+
+  QueryViewsCountRequest._() : super();
+  factory QueryViewsCountRequest({
+    $core.String? belongToId,
+  }) {
+    final _result = create();
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    return _result;
+  }
+  factory QueryViewsCountRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory QueryViewsCountRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  QueryViewsCountRequest clone() => QueryViewsCountRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  QueryViewsCountRequest copyWith(void Function(QueryViewsCountRequest) updates) => super.copyWith((message) => updates(message as QueryViewsCountRequest)) as QueryViewsCountRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static QueryViewsCountRequest create() => QueryViewsCountRequest._();
+  QueryViewsCountRequest createEmptyInstance() => create();
+  static $pb.PbList<QueryViewsCountRequest> createRepeated() => $pb.PbList<QueryViewsCountRequest>();
+  @$core.pragma('dart2js:noInline')
+  static QueryViewsCountRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<QueryViewsCountRequest>(create);
+  static QueryViewsCountRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get belongToId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set belongToId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBelongToId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBelongToId() => clearField(1);
+}
+
+class ViewCount extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ViewCount', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'count')
+    ..hasRequiredFields = false
+  ;
+
+  ViewCount._() : super();
+  factory ViewCount({
+    $core.String? belongToId,
+    $fixnum.Int64? count,
+  }) {
+    final _result = create();
+    if (belongToId != null) {
+      _result.belongToId = belongToId;
+    }
+    if (count != null) {
+      _result.count = count;
+    }
+    return _result;
+  }
+  factory ViewCount.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ViewCount.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ViewCount clone() => ViewCount()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ViewCount copyWith(void Function(ViewCount) updates) => super.copyWith((message) => updates(message as ViewCount)) as ViewCount; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ViewCount create() => ViewCount._();
+  ViewCount createEmptyInstance() => create();
+  static $pb.PbList<ViewCount> createRepeated() => $pb.PbList<ViewCount>();
+  @$core.pragma('dart2js:noInline')
+  static ViewCount getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ViewCount>(create);
+  static ViewCount? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get belongToId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set belongToId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasBelongToId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearBelongToId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get count => $_getI64(1);
+  @$pb.TagNumber(2)
+  set count($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCount() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCount() => clearField(2);
+}
+
+class RepeatedViewCount extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedViewCount', createEmptyInstance: create)
+    ..pc<ViewCount>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: ViewCount.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedViewCount._() : super();
+  factory RepeatedViewCount({
+    $core.Iterable<ViewCount>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedViewCount.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedViewCount.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedViewCount clone() => RepeatedViewCount()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedViewCount copyWith(void Function(RepeatedViewCount) updates) => super.copyWith((message) => updates(message as RepeatedViewCount)) as RepeatedViewCount; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedViewCount create() => RepeatedViewCount._();
+  RepeatedViewCount createEmptyInstance() => create();
+  static $pb.PbList<RepeatedViewCount> createRepeated() => $pb.PbList<RepeatedViewCount>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedViewCount getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedViewCount>(create);
+  static RepeatedViewCount? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<ViewCount> get items => $_getList(0);
+}
+
+class DuplicateViews extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DuplicateViews', createEmptyInstance: create)
+    ..pPS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewIds')
+    ..hasRequiredFields = false
+  ;
+
+  DuplicateViews._() : super();
+  factory DuplicateViews({
+    $core.Iterable<$core.String>? viewIds,
+  }) {
+    final _result = create();
+    if (viewIds != null) {
+      _result.viewIds.addAll(viewIds);
+    }
+    return _result;
+  }
+  factory DuplicateViews.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DuplicateViews.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DuplicateViews clone() => DuplicateViews()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DuplicateViews copyWith(void Function(DuplicateViews) updates) => super.copyWith((message) => updates(message as DuplicateViews)) as DuplicateViews; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DuplicateViews create() => DuplicateViews._();
+  DuplicateViews createEmptyInstance() => create();
+  static $pb.PbList<DuplicateViews> createRepeated() => $pb.PbList<DuplicateViews>();
+  @$core.pragma('dart2js:noInline')
+  static DuplicateViews getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DuplicateViews>(create);
+  static DuplicateViews? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<$core.String> get viewIds => $_getList(0);
+}
+
+class RepeatedDuplicateViews extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedDuplicateViews', createEmptyInstance: create)
+    ..pc<DuplicateViews>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: DuplicateViews.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedDuplicateViews._() : super();
+  factory RepeatedDuplicateViews({
+    $core.Iterable<DuplicateViews>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedDuplicateViews.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedDuplicateViews.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedDuplicateViews clone() => RepeatedDuplicateViews()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedDuplicateViews copyWith(void Function(RepeatedDuplicateViews) updates) => super.copyWith((message) => updates(message as RepeatedDuplicateViews)) as RepeatedDuplicateViews; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDuplicateViews create() => RepeatedDuplicateViews._();
+  RepeatedDuplicateViews createEmptyInstance() => create();
+  static $pb.PbList<RepeatedDuplicateViews> createRepeated() => $pb.PbList<RepeatedDuplicateViews>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDuplicateViews getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedDuplicateViews>(create);
+  static RepeatedDuplicateViews? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<DuplicateViews> get items => $_getList(0);
+}
+
 class RepeatedViewId extends $pb.GeneratedMessage {
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedViewId', createEmptyInstance: create)
     ..pPS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items')
@@ -138,3 +571,125 @@ class RepeatedViewId extends $pb.GeneratedMessage {
   $core.List<$core.String> get items => $_getList(0);
 }
 
+class QueryDocumentHistoryRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'QueryDocumentHistoryRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'limit')
+    ..hasRequiredFields = false
+  ;
+
+  QueryDocumentHistoryRequest._() : super();
+  factory QueryDocumentHistoryRequest({
+    $core.String? docId,
+    $fixnum.Int64? limit,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (limit != null) {
+      _result.limit = limit;
+    }
+    return _result;
+  }
+  factory QueryDocumentHistoryRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory QueryDocumentHistoryRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  QueryDocumentHistoryRequest clone() => QueryDocumentHistoryRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  QueryDocumentHistoryRequest copyWith(void Function(QueryDocumentHistoryRequest) updates) => super.copyWith((message) => updates(message as QueryDocumentHistoryRequest)) as QueryDocumentHistoryRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static QueryDocumentHistoryRequest create() => QueryDocumentHistoryRequest._();
+  QueryDocumentHistoryRequest createEmptyInstance() => create();
+  static $pb.PbList<QueryDocumentHistoryRequest> createRepeated() => $pb.PbList<QueryDocumentHistoryRequest>();
+  @$core.pragma('dart2js:noInline')
+  static QueryDocumentHistoryRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<QueryDocumentHistoryRequest>(create);
+  static QueryDocumentHistoryRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get limit => $_getI64(1);
+  @$pb.TagNumber(2)
+  set limit($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasLimit() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearLimit() => clearField(2);
+}
+
+class RestoreDocumentRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RestoreDocumentRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revId')
+    ..hasRequiredFields = false
+  ;
+
+  RestoreDocumentRequest._() : super();
+  factory RestoreDocumentRequest({
+    $core.String? docId,
+    $fixnum.Int64? revId,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (revId != null) {
+      _result.revId = revId;
+    }
+    return _result;
+  }
+  factory RestoreDocumentRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RestoreDocumentRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RestoreDocumentRequest clone() => RestoreDocumentRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RestoreDocumentRequest copyWith(void Function(RestoreDocumentRequest) updates) => super.copyWith((message) => updates(message as RestoreDocumentRequest)) as RestoreDocumentRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RestoreDocumentRequest create() => RestoreDocumentRequest._();
+  RestoreDocumentRequest createEmptyInstance() => create();
+  static $pb.PbList<RestoreDocumentRequest> createRepeated() => $pb.PbList<RestoreDocumentRequest>();
+  @$core.pragma('dart2js:noInline')
+  static RestoreDocumentRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RestoreDocumentRequest>(create);
+  static RestoreDocumentRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get revId => $_getI64(1);
+  @$pb.TagNumber(2)
+  set revId($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasRevId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearRevId() => clearField(2);
+}
+
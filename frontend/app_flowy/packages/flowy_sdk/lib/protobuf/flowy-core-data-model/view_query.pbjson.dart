@@ -28,6 +28,91 @@ const ViewId$json = const {
 
 /// Descriptor for `ViewId`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List viewIdDescriptor = $convert.base64Decode('CgZWaWV3SWQSFwoHdmlld19pZBgBIAEoCVIGdmlld0lk');
+@$core.Deprecated('Use queryViewsBelongToRequestDescriptor instead')
+const QueryViewsBelongToRequest$json = const {
+  '1': 'QueryViewsBelongToRequest',
+  '2': const [
+    const {'1': 'belong_to_id', '3': 1, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'view_type', '3': 2, '4': 1, '5': 14, '6': '.ViewType', '10': 'viewType'},
+  ],
+};
+
+/// Descriptor for `QueryViewsBelongToRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryViewsBelongToRequestDescriptor = $convert.base64Decode('ChlRdWVyeVZpZXdzQmVsb25nVG9SZXF1ZXN0EiAKDGJlbG9uZ190b19pZBgBIAEoCVIKYmVsb25nVG9JZBImCgl2aWV3X3R5cGUYAiABKA4yCS5WaWV3VHlwZVIIdmlld1R5cGU=');
+@$core.Deprecated('Use queryViewsPageRequestDescriptor instead')
+const QueryViewsPageRequest$json = const {
+  '1': 'QueryViewsPageRequest',
+  '2': const [
+    const {'1': 'belong_to_id', '3': 1, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'offset', '3': 2, '4': 1, '5': 3, '10': 'offset'},
+    const {'1': 'limit', '3': 3, '4': 1, '5': 3, '10': 'limit'},
+  ],
+};
+
+/// Descriptor for `QueryViewsPageRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryViewsPageRequestDescriptor = $convert.base64Decode('ChVRdWVyeVZpZXdzUGFnZVJlcXVlc3QSIAoMYmVsb25nX3RvX2lkGAEgASgJUgpiZWxvbmdUb0lkEhYKBm9mZnNldBgCIAEoA1IGb2Zmc2V0EhQKBWxpbWl0GAMgASgDUgVsaW1pdA==');
+@$core.Deprecated('Use viewsPageDescriptor instead')
+const ViewsPage$json = const {
+  '1': 'ViewsPage',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 1, '5': 11, '6': '.RepeatedView', '10': 'items'},
+    const {'1': 'total', '3': 2, '4': 1, '5': 3, '10': 'total'},
+  ],
+};
+
+/// Descriptor for `ViewsPage`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewsPageDescriptor = $convert.base64Decode('CglWaWV3c1BhZ2USIwoFaXRlbXMYASABKAsyDS5SZXBlYXRlZFZpZXdSBWl0ZW1zEhQKBXRvdGFsGAIgASgDUgV0b3RhbA==');
+@$core.Deprecated('Use queryViewsCountRequestDescriptor instead')
+const QueryViewsCountRequest$json = const {
+  '1': 'QueryViewsCountRequest',
+  '2': const [
+    const {'1': 'belong_to_id', '3': 1, '4': 1, '5': 9, '10': 'belongToId'},
+  ],
+};
+
+/// Descriptor for `QueryViewsCountRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryViewsCountRequestDescriptor = $convert.base64Decode('ChZRdWVyeVZpZXdzQ291bnRSZXF1ZXN0EiAKDGJlbG9uZ190b19pZBgBIAEoCVIKYmVsb25nVG9JZA==');
+@$core.Deprecated('Use viewCountDescriptor instead')
+const ViewCount$json = const {
+  '1': 'ViewCount',
+  '2': const [
+    const {'1': 'belong_to_id', '3': 1, '4': 1, '5': 9, '10': 'belongToId'},
+    const {'1': 'count', '3': 2, '4': 1, '5': 3, '10': 'count'},
+  ],
+};
+
+/// Descriptor for `ViewCount`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewCountDescriptor = $convert.base64Decode('CglWaWV3Q291bnQSIAoMYmVsb25nX3RvX2lkGAEgASgJUgpiZWxvbmdUb0lkEhQKBWNvdW50GAIgASgDUgVjb3VudA==');
+@$core.Deprecated('Use repeatedViewCountDescriptor instead')
+const RepeatedViewCount$json = const {
+  '1': 'RepeatedViewCount',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.ViewCount', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedViewCount`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedViewCountDescriptor = $convert.base64Decode('ChFSZXBlYXRlZFZpZXdDb3VudBIgCgVpdGVtcxgBIAMoCzIKLlZpZXdDb3VudFIFaXRlbXM=');
+@$core.Deprecated('Use duplicateViewsDescriptor instead')
+const DuplicateViews$json = const {
+  '1': 'DuplicateViews',
+  '2': const [
+    const {'1': 'view_ids', '3': 1, '4': 3, '5': 9, '10': 'viewIds'},
+  ],
+};
+
+/// Descriptor for `DuplicateViews`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List duplicateViewsDescriptor = $convert.base64Decode('Cg5EdXBsaWNhdGVWaWV3cxIZCgh2aWV3X2lkcxgBIAMoCVIHdmlld0lkcw==');
+@$core.Deprecated('Use repeatedDuplicateViewsDescriptor instead')
+const RepeatedDuplicateViews$json = const {
+  '1': 'RepeatedDuplicateViews',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.DuplicateViews', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedDuplicateViews`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedDuplicateViewsDescriptor = $convert.base64Decode('ChZSZXBlYXRlZER1cGxpY2F0ZVZpZXdzEiUKBWl0ZW1zGAEgAygLMg8uRHVwbGljYXRlVmlld3NSBWl0ZW1z');
 @$core.Deprecated('Use repeatedViewIdDescriptor instead')
 const RepeatedViewId$json = const {
   '1': 'RepeatedViewId',
@@ -38,3 +123,25 @@ const RepeatedViewId$json = const {
 
 /// Descriptor for `RepeatedViewId`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List repeatedViewIdDescriptor = $convert.base64Decode('Cg5SZXBlYXRlZFZpZXdJZBIUCgVpdGVtcxgBIAMoCVIFaXRlbXM=');
+@$core.Deprecated('Use queryDocumentHistoryRequestDescriptor instead')
+const QueryDocumentHistoryRequest$json = const {
+  '1': 'QueryDocumentHistoryRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'limit', '3': 2, '4': 1, '5': 3, '10': 'limit'},
+  ],
+};
+
+/// Descriptor for `QueryDocumentHistoryRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List queryDocumentHistoryRequestDescriptor = $convert.base64Decode('ChtRdWVyeURvY3VtZW50SGlzdG9yeVJlcXVlc3QSFQoGZG9jX2lkGAEgASgJUgVkb2NJZBIUCgVsaW1pdBgCIAEoA1IFbGltaXQ=');
+@$core.Deprecated('Use restoreDocumentRequestDescriptor instead')
+const RestoreDocumentRequest$json = const {
+  '1': 'RestoreDocumentRequest',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'rev_id', '3': 2, '4': 1, '5': 3, '10': 'revId'},
+  ],
+};
+
+/// Descriptor for `RestoreDocumentRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List restoreDocumentRequestDescriptor = $convert.base64Decode('ChZSZXN0b3JlRG9jdW1lbnRSZXF1ZXN0EhUKBmRvY19pZBgBIAEoCVIFZG9jSWQSFQoGcmV2X2lkGAIgASgDUgVyZXZJZA==');
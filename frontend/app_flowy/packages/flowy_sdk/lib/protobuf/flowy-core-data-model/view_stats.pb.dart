@@ -0,0 +1,87 @@
+///
+//  Generated code. Do not modify.
+//  source: view_stats.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class DocumentStats extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocumentStats', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'wordCount')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'charCount')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'lineCount')
+    ..hasRequiredFields = false
+  ;
+
+  DocumentStats._() : super();
+  factory DocumentStats({
+    $fixnum.Int64? wordCount,
+    $fixnum.Int64? charCount,
+    $fixnum.Int64? lineCount,
+  }) {
+    final _result = create();
+    if (wordCount != null) {
+      _result.wordCount = wordCount;
+    }
+    if (charCount != null) {
+      _result.charCount = charCount;
+    }
+    if (lineCount != null) {
+      _result.lineCount = lineCount;
+    }
+    return _result;
+  }
+  factory DocumentStats.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocumentStats.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocumentStats clone() => DocumentStats()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocumentStats copyWith(void Function(DocumentStats) updates) => super.copyWith((message) => updates(message as DocumentStats)) as DocumentStats; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocumentStats create() => DocumentStats._();
+  DocumentStats createEmptyInstance() => create();
+  static $pb.PbList<DocumentStats> createRepeated() => $pb.PbList<DocumentStats>();
+  @$core.pragma('dart2js:noInline')
+  static DocumentStats getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocumentStats>(create);
+  static DocumentStats? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get wordCount => $_getI64(0);
+  @$pb.TagNumber(1)
+  set wordCount($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasWordCount() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearWordCount() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get charCount => $_getI64(1);
+  @$pb.TagNumber(2)
+  set charCount($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasCharCount() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearCharCount() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get lineCount => $_getI64(2);
+  @$pb.TagNumber(3)
+  set lineCount($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasLineCount() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearLineCount() => clearField(3);
+}
+
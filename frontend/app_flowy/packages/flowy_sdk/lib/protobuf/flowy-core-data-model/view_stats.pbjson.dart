@@ -0,0 +1,22 @@
+///
+//  Generated code. Do not modify.
+//  source: view_stats.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use documentStatsDescriptor instead')
+const DocumentStats$json = const {
+  '1': 'DocumentStats',
+  '2': const [
+    const {'1': 'word_count', '3': 1, '4': 1, '5': 3, '10': 'wordCount'},
+    const {'1': 'char_count', '3': 2, '4': 1, '5': 3, '10': 'charCount'},
+    const {'1': 'line_count', '3': 3, '4': 1, '5': 3, '10': 'lineCount'},
+  ],
+};
+
+/// Descriptor for `DocumentStats`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List documentStatsDescriptor = $convert.base64Decode('Cg1Eb2N1bWVudFN0YXRzEh0KCndvcmRfY291bnQYASABKANSCXdvcmRDb3VudBIdCgpjaGFyX2NvdW50GAIgASgDUgljaGFyQ291bnQSHQoKbGluZV9jb3VudBgDIAEoA1IJbGluZUNvdW50');
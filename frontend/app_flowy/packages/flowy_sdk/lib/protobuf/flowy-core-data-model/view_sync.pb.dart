@@ -0,0 +1,76 @@
+///
+//  Generated code. Do not modify.
+//  source: view_sync.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'view_sync.pbenum.dart';
+
+export 'view_sync.pbenum.dart';
+
+class ViewSyncStatus extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ViewSyncStatus', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..e<ViewSyncState>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'state', $pb.PbFieldType.OE, defaultOrMaker: ViewSyncState.Synced, valueOf: ViewSyncState.valueOf, enumValues: ViewSyncState.values)
+    ..hasRequiredFields = false
+  ;
+
+  ViewSyncStatus._() : super();
+  factory ViewSyncStatus({
+    $core.String? viewId,
+    ViewSyncState? state,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (state != null) {
+      _result.state = state;
+    }
+    return _result;
+  }
+  factory ViewSyncStatus.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ViewSyncStatus.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ViewSyncStatus clone() => ViewSyncStatus()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ViewSyncStatus copyWith(void Function(ViewSyncStatus) updates) => super.copyWith((message) => updates(message as ViewSyncStatus)) as ViewSyncStatus; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ViewSyncStatus create() => ViewSyncStatus._();
+  ViewSyncStatus createEmptyInstance() => create();
+  static $pb.PbList<ViewSyncStatus> createRepeated() => $pb.PbList<ViewSyncStatus>();
+  @$core.pragma('dart2js:noInline')
+  static ViewSyncStatus getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ViewSyncStatus>(create);
+  static ViewSyncStatus? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  ViewSyncState get state => $_getN(1);
+  @$pb.TagNumber(2)
+  set state(ViewSyncState v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasState() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearState() => clearField(2);
+}
+
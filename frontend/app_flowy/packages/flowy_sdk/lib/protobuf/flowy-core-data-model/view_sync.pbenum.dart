@@ -0,0 +1,28 @@
+///
+//  Generated code. Do not modify.
+//  source: view_sync.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class ViewSyncState extends $pb.ProtobufEnum {
+  static const ViewSyncState Synced = ViewSyncState._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Synced');
+  static const ViewSyncState Pending = ViewSyncState._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Pending');
+  static const ViewSyncState Failed = ViewSyncState._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Failed');
+
+  static const $core.List<ViewSyncState> values = <ViewSyncState> [
+    Synced,
+    Pending,
+    Failed,
+  ];
+
+  static final $core.Map<$core.int, ViewSyncState> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static ViewSyncState? valueOf($core.int value) => _byValue[value];
+
+  const ViewSyncState._($core.int v, $core.String n) : super(v, n);
+}
+
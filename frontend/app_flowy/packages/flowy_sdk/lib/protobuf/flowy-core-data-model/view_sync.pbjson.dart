@@ -0,0 +1,33 @@
+///
+//  Generated code. Do not modify.
+//  source: view_sync.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use viewSyncStateDescriptor instead')
+const ViewSyncState$json = const {
+  '1': 'ViewSyncState',
+  '2': const [
+    const {'1': 'Synced', '2': 0},
+    const {'1': 'Pending', '2': 1},
+    const {'1': 'Failed', '2': 2},
+  ],
+};
+
+/// Descriptor for `ViewSyncState`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List viewSyncStateDescriptor = $convert.base64Decode('Cg1WaWV3U3luY1N0YXRlEgoKBlN5bmNlZBAAEgsKB1BlbmRpbmcQARIKCgZGYWlsZWQQAg==');
+@$core.Deprecated('Use viewSyncStatusDescriptor instead')
+const ViewSyncStatus$json = const {
+  '1': 'ViewSyncStatus',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'state', '3': 2, '4': 1, '5': 14, '6': '.ViewSyncState', '10': 'state'},
+  ],
+};
+
+/// Descriptor for `ViewSyncStatus`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewSyncStatusDescriptor = $convert.base64Decode('Cg5WaWV3U3luY1N0YXR1cxIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSJAoFc3RhdGUYAiABKA4yDi5WaWV3U3luY1N0YXRlUgVzdGF0ZQ==');
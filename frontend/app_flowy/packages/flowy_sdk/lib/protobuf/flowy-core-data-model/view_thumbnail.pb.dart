@@ -0,0 +1,147 @@
+///
+//  Generated code. Do not modify.
+//  source: view_thumbnail.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class SetViewThumbnailRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SetViewThumbnailRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..a<$core.List<$core.int>>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'data', $pb.PbFieldType.OY)
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'mime')
+    ..hasRequiredFields = false
+  ;
+
+  SetViewThumbnailRequest._() : super();
+  factory SetViewThumbnailRequest({
+    $core.String? viewId,
+    $core.List<$core.int>? data,
+    $core.String? mime,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (data != null) {
+      _result.data = data;
+    }
+    if (mime != null) {
+      _result.mime = mime;
+    }
+    return _result;
+  }
+  factory SetViewThumbnailRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SetViewThumbnailRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SetViewThumbnailRequest clone() => SetViewThumbnailRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SetViewThumbnailRequest copyWith(void Function(SetViewThumbnailRequest) updates) => super.copyWith((message) => updates(message as SetViewThumbnailRequest)) as SetViewThumbnailRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SetViewThumbnailRequest create() => SetViewThumbnailRequest._();
+  SetViewThumbnailRequest createEmptyInstance() => create();
+  static $pb.PbList<SetViewThumbnailRequest> createRepeated() => $pb.PbList<SetViewThumbnailRequest>();
+  @$core.pragma('dart2js:noInline')
+  static SetViewThumbnailRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SetViewThumbnailRequest>(create);
+  static SetViewThumbnailRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.List<$core.int> get data => $_getN(1);
+  @$pb.TagNumber(2)
+  set data($core.List<$core.int> v) { $_setBytes(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasData() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearData() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get mime => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set mime($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasMime() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearMime() => clearField(3);
+}
+
+class ViewThumbnail extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ViewThumbnail', createEmptyInstance: create)
+    ..a<$core.List<$core.int>>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'data', $pb.PbFieldType.OY)
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'mime')
+    ..hasRequiredFields = false
+  ;
+
+  ViewThumbnail._() : super();
+  factory ViewThumbnail({
+    $core.List<$core.int>? data,
+    $core.String? mime,
+  }) {
+    final _result = create();
+    if (data != null) {
+      _result.data = data;
+    }
+    if (mime != null) {
+      _result.mime = mime;
+    }
+    return _result;
+  }
+  factory ViewThumbnail.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ViewThumbnail.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ViewThumbnail clone() => ViewThumbnail()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ViewThumbnail copyWith(void Function(ViewThumbnail) updates) => super.copyWith((message) => updates(message as ViewThumbnail)) as ViewThumbnail; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ViewThumbnail create() => ViewThumbnail._();
+  ViewThumbnail createEmptyInstance() => create();
+  static $pb.PbList<ViewThumbnail> createRepeated() => $pb.PbList<ViewThumbnail>();
+  @$core.pragma('dart2js:noInline')
+  static ViewThumbnail getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ViewThumbnail>(create);
+  static ViewThumbnail? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<$core.int> get data => $_getN(0);
+  @$pb.TagNumber(1)
+  set data($core.List<$core.int> v) { $_setBytes(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasData() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearData() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get mime => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set mime($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasMime() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearMime() => clearField(2);
+}
+
@@ -0,0 +1,33 @@
+///
+//  Generated code. Do not modify.
+//  source: view_thumbnail.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use setViewThumbnailRequestDescriptor instead')
+const SetViewThumbnailRequest$json = const {
+  '1': 'SetViewThumbnailRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'data', '3': 2, '4': 1, '5': 12, '10': 'data'},
+    const {'1': 'mime', '3': 3, '4': 1, '5': 9, '10': 'mime'},
+  ],
+};
+
+/// Descriptor for `SetViewThumbnailRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List setViewThumbnailRequestDescriptor = $convert.base64Decode('ChdTZXRWaWV3VGh1bWJuYWlsUmVxdWVzdBIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSEgoEZGF0YRgCIAEoDFIEZGF0YRISCgRtaW1lGAMgASgJUgRtaW1l');
+@$core.Deprecated('Use viewThumbnailDescriptor instead')
+const ViewThumbnail$json = const {
+  '1': 'ViewThumbnail',
+  '2': const [
+    const {'1': 'data', '3': 1, '4': 1, '5': 12, '10': 'data'},
+    const {'1': 'mime', '3': 2, '4': 1, '5': 9, '10': 'mime'},
+  ],
+};
+
+/// Descriptor for `ViewThumbnail`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List viewThumbnailDescriptor = $convert.base64Decode('Cg1WaWV3VGh1bWJuYWlsEhIKBGRhdGEYASABKAxSBGRhdGESEgoEbWltZRgCIAEoCVIEbWltZQ==');
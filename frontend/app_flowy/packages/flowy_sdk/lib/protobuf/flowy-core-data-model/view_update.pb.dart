@@ -9,6 +9,8 @@ import 'dart:core' as $core;
 
 import 'package:protobuf/protobuf.dart' as $pb;
 
+import 'view_create.pb.dart' as $0;
+
 enum UpdateViewRequest_OneOfName {
   name, 
   notSet
@@ -24,6 +26,16 @@ enum UpdateViewRequest_OneOfThumbnail {
   notSet
 }
 
+enum UpdateViewRequest_OneOfIsReadonly {
+  isReadonly, 
+  notSet
+}
+
+enum UpdateViewRequest_OneOfIcon {
+  icon, 
+  notSet
+}
+
 class UpdateViewRequest extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, UpdateViewRequest_OneOfName> _UpdateViewRequest_OneOfNameByTag = {
     2 : UpdateViewRequest_OneOfName.name,
@@ -37,14 +49,26 @@ class UpdateViewRequest extends $pb.GeneratedMessage {
     4 : UpdateViewRequest_OneOfThumbnail.thumbnail,
     0 : UpdateViewRequest_OneOfThumbnail.notSet
   };
+  static const $core.Map<$core.int, UpdateViewRequest_OneOfIsReadonly> _UpdateViewRequest_OneOfIsReadonlyByTag = {
+    5 : UpdateViewRequest_OneOfIsReadonly.isReadonly,
+    0 : UpdateViewRequest_OneOfIsReadonly.notSet
+  };
+  static const $core.Map<$core.int, UpdateViewRequest_OneOfIcon> _UpdateViewRequest_OneOfIconByTag = {
+    6 : UpdateViewRequest_OneOfIcon.icon,
+    0 : UpdateViewRequest_OneOfIcon.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateViewRequest', createEmptyInstance: create)
     ..oo(0, [2])
     ..oo(1, [3])
     ..oo(2, [4])
+    ..oo(3, [5])
+    ..oo(4, [6])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'thumbnail')
+    ..aOB(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isReadonly')
+    ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'icon')
     ..hasRequiredFields = false
   ;
 
@@ -54,6 +78,8 @@ class UpdateViewRequest extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? desc,
     $core.String? thumbnail,
+    $core.bool? isReadonly,
+    $core.String? icon,
   }) {
     final _result = create();
     if (viewId != null) {
@@ -68,6 +94,12 @@ class UpdateViewRequest extends $pb.GeneratedMessage {
     if (thumbnail != null) {
       _result.thumbnail = thumbnail;
     }
+    if (isReadonly != null) {
+      _result.isReadonly = isReadonly;
+    }
+    if (icon != null) {
+      _result.icon = icon;
+    }
     return _result;
   }
   factory UpdateViewRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -100,6 +132,12 @@ class UpdateViewRequest extends $pb.GeneratedMessage {
   UpdateViewRequest_OneOfThumbnail whichOneOfThumbnail() => _UpdateViewRequest_OneOfThumbnailByTag[$_whichOneof(2)]!;
   void clearOneOfThumbnail() => clearField($_whichOneof(2));
 
+  UpdateViewRequest_OneOfIsReadonly whichOneOfIsReadonly() => _UpdateViewRequest_OneOfIsReadonlyByTag[$_whichOneof(3)]!;
+  void clearOneOfIsReadonly() => clearField($_whichOneof(3));
+
+  UpdateViewRequest_OneOfIcon whichOneOfIcon() => _UpdateViewRequest_OneOfIconByTag[$_whichOneof(4)]!;
+  void clearOneOfIcon() => clearField($_whichOneof(4));
+
   @$pb.TagNumber(1)
   $core.String get viewId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -135,6 +173,268 @@ class UpdateViewRequest extends $pb.GeneratedMessage {
   $core.bool hasThumbnail() => $_has(3);
   @$pb.TagNumber(4)
   void clearThumbnail() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.bool get isReadonly => $_getBF(4);
+  @$pb.TagNumber(5)
+  set isReadonly($core.bool v) { $_setBool(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasIsReadonly() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearIsReadonly() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.String get icon => $_getSZ(5);
+  @$pb.TagNumber(6)
+  set icon($core.String v) { $_setString(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasIcon() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearIcon() => clearField(6);
+}
+
+class RenameViewRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RenameViewRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..hasRequiredFields = false
+  ;
+
+  RenameViewRequest._() : super();
+  factory RenameViewRequest({
+    $core.String? viewId,
+    $core.String? name,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (name != null) {
+      _result.name = name;
+    }
+    return _result;
+  }
+  factory RenameViewRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RenameViewRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RenameViewRequest clone() => RenameViewRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RenameViewRequest copyWith(void Function(RenameViewRequest) updates) => super.copyWith((message) => updates(message as RenameViewRequest)) as RenameViewRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RenameViewRequest create() => RenameViewRequest._();
+  RenameViewRequest createEmptyInstance() => create();
+  static $pb.PbList<RenameViewRequest> createRepeated() => $pb.PbList<RenameViewRequest>();
+  @$core.pragma('dart2js:noInline')
+  static RenameViewRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RenameViewRequest>(create);
+  static RenameViewRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get name => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set name($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasName() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearName() => clearField(2);
+}
+
+class SetViewReadonlyRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SetViewReadonlyRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOB(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isReadonly')
+    ..hasRequiredFields = false
+  ;
+
+  SetViewReadonlyRequest._() : super();
+  factory SetViewReadonlyRequest({
+    $core.String? viewId,
+    $core.bool? isReadonly,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (isReadonly != null) {
+      _result.isReadonly = isReadonly;
+    }
+    return _result;
+  }
+  factory SetViewReadonlyRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SetViewReadonlyRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SetViewReadonlyRequest clone() => SetViewReadonlyRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SetViewReadonlyRequest copyWith(void Function(SetViewReadonlyRequest) updates) => super.copyWith((message) => updates(message as SetViewReadonlyRequest)) as SetViewReadonlyRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SetViewReadonlyRequest create() => SetViewReadonlyRequest._();
+  SetViewReadonlyRequest createEmptyInstance() => create();
+  static $pb.PbList<SetViewReadonlyRequest> createRepeated() => $pb.PbList<SetViewReadonlyRequest>();
+  @$core.pragma('dart2js:noInline')
+  static SetViewReadonlyRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SetViewReadonlyRequest>(create);
+  static SetViewReadonlyRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.bool get isReadonly => $_getBF(1);
+  @$pb.TagNumber(2)
+  set isReadonly($core.bool v) { $_setBool(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasIsReadonly() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearIsReadonly() => clearField(2);
+}
+
+class SetViewVisibilityRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SetViewVisibilityRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..e<$0.ViewVisibility>(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'visibility', $pb.PbFieldType.OE, defaultOrMaker: $0.ViewVisibility.Private, valueOf: $0.ViewVisibility.valueOf, enumValues: $0.ViewVisibility.values)
+    ..hasRequiredFields = false
+  ;
+
+  SetViewVisibilityRequest._() : super();
+  factory SetViewVisibilityRequest({
+    $core.String? viewId,
+    $0.ViewVisibility? visibility,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (visibility != null) {
+      _result.visibility = visibility;
+    }
+    return _result;
+  }
+  factory SetViewVisibilityRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SetViewVisibilityRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SetViewVisibilityRequest clone() => SetViewVisibilityRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SetViewVisibilityRequest copyWith(void Function(SetViewVisibilityRequest) updates) => super.copyWith((message) => updates(message as SetViewVisibilityRequest)) as SetViewVisibilityRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SetViewVisibilityRequest create() => SetViewVisibilityRequest._();
+  SetViewVisibilityRequest createEmptyInstance() => create();
+  static $pb.PbList<SetViewVisibilityRequest> createRepeated() => $pb.PbList<SetViewVisibilityRequest>();
+  @$core.pragma('dart2js:noInline')
+  static SetViewVisibilityRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SetViewVisibilityRequest>(create);
+  static SetViewVisibilityRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $0.ViewVisibility get visibility => $_getN(1);
+  @$pb.TagNumber(2)
+  set visibility($0.ViewVisibility v) { setField(2, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasVisibility() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearVisibility() => clearField(2);
+}
+
+class SetViewIconRequest extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SetViewIconRequest', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'icon')
+    ..hasRequiredFields = false
+  ;
+
+  SetViewIconRequest._() : super();
+  factory SetViewIconRequest({
+    $core.String? viewId,
+    $core.String? icon,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (icon != null) {
+      _result.icon = icon;
+    }
+    return _result;
+  }
+  factory SetViewIconRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SetViewIconRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SetViewIconRequest clone() => SetViewIconRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SetViewIconRequest copyWith(void Function(SetViewIconRequest) updates) => super.copyWith((message) => updates(message as SetViewIconRequest)) as SetViewIconRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SetViewIconRequest create() => SetViewIconRequest._();
+  SetViewIconRequest createEmptyInstance() => create();
+  static $pb.PbList<SetViewIconRequest> createRepeated() => $pb.PbList<SetViewIconRequest>();
+  @$core.pragma('dart2js:noInline')
+  static SetViewIconRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SetViewIconRequest>(create);
+  static SetViewIconRequest? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get icon => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set icon($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasIcon() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearIcon() => clearField(2);
 }
 
 enum UpdateViewParams_OneOfName {
@@ -152,6 +452,21 @@ enum UpdateViewParams_OneOfThumbnail {
   notSet
 }
 
+enum UpdateViewParams_OneOfIsReadonly {
+  isReadonly, 
+  notSet
+}
+
+enum UpdateViewParams_OneOfVisibility {
+  visibility, 
+  notSet
+}
+
+enum UpdateViewParams_OneOfIcon {
+  icon, 
+  notSet
+}
+
 class UpdateViewParams extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, UpdateViewParams_OneOfName> _UpdateViewParams_OneOfNameByTag = {
     2 : UpdateViewParams_OneOfName.name,
@@ -165,14 +480,32 @@ class UpdateViewParams extends $pb.GeneratedMessage {
     4 : UpdateViewParams_OneOfThumbnail.thumbnail,
     0 : UpdateViewParams_OneOfThumbnail.notSet
   };
+  static const $core.Map<$core.int, UpdateViewParams_OneOfIsReadonly> _UpdateViewParams_OneOfIsReadonlyByTag = {
+    5 : UpdateViewParams_OneOfIsReadonly.isReadonly,
+    0 : UpdateViewParams_OneOfIsReadonly.notSet
+  };
+  static const $core.Map<$core.int, UpdateViewParams_OneOfVisibility> _UpdateViewParams_OneOfVisibilityByTag = {
+    6 : UpdateViewParams_OneOfVisibility.visibility,
+    0 : UpdateViewParams_OneOfVisibility.notSet
+  };
+  static const $core.Map<$core.int, UpdateViewParams_OneOfIcon> _UpdateViewParams_OneOfIconByTag = {
+    7 : UpdateViewParams_OneOfIcon.icon,
+    0 : UpdateViewParams_OneOfIcon.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateViewParams', createEmptyInstance: create)
     ..oo(0, [2])
     ..oo(1, [3])
     ..oo(2, [4])
+    ..oo(3, [5])
+    ..oo(4, [6])
+    ..oo(5, [7])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'thumbnail')
+    ..aOB(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isReadonly')
+    ..a<$core.int>(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'visibility', $pb.PbFieldType.O3)
+    ..aOS(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'icon')
     ..hasRequiredFields = false
   ;
 
@@ -182,6 +515,9 @@ class UpdateViewParams extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? desc,
     $core.String? thumbnail,
+    $core.bool? isReadonly,
+    $core.int? visibility,
+    $core.String? icon,
   }) {
     final _result = create();
     if (viewId != null) {
@@ -196,6 +532,15 @@ class UpdateViewParams extends $pb.GeneratedMessage {
     if (thumbnail != null) {
       _result.thumbnail = thumbnail;
     }
+    if (isReadonly != null) {
+      _result.isReadonly = isReadonly;
+    }
+    if (visibility != null) {
+      _result.visibility = visibility;
+    }
+    if (icon != null) {
+      _result.icon = icon;
+    }
     return _result;
   }
   factory UpdateViewParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -228,6 +573,15 @@ class UpdateViewParams extends $pb.GeneratedMessage {
   UpdateViewParams_OneOfThumbnail whichOneOfThumbnail() => _UpdateViewParams_OneOfThumbnailByTag[$_whichOneof(2)]!;
   void clearOneOfThumbnail() => clearField($_whichOneof(2));
 
+  UpdateViewParams_OneOfIsReadonly whichOneOfIsReadonly() => _UpdateViewParams_OneOfIsReadonlyByTag[$_whichOneof(3)]!;
+  void clearOneOfIsReadonly() => clearField($_whichOneof(3));
+
+  UpdateViewParams_OneOfVisibility whichOneOfVisibility() => _UpdateViewParams_OneOfVisibilityByTag[$_whichOneof(4)]!;
+  void clearOneOfVisibility() => clearField($_whichOneof(4));
+
+  UpdateViewParams_OneOfIcon whichOneOfIcon() => _UpdateViewParams_OneOfIconByTag[$_whichOneof(5)]!;
+  void clearOneOfIcon() => clearField($_whichOneof(5));
+
   @$pb.TagNumber(1)
   $core.String get viewId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -263,5 +617,32 @@ class UpdateViewParams extends $pb.GeneratedMessage {
   $core.bool hasThumbnail() => $_has(3);
   @$pb.TagNumber(4)
   void clearThumbnail() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.bool get isReadonly => $_getBF(4);
+  @$pb.TagNumber(5)
+  set isReadonly($core.bool v) { $_setBool(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasIsReadonly() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearIsReadonly() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.int get visibility => $_getIZ(5);
+  @$pb.TagNumber(6)
+  set visibility($core.int v) { $_setSignedInt32(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasVisibility() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearVisibility() => clearField(6);
+
+  @$pb.TagNumber(7)
+  $core.String get icon => $_getSZ(6);
+  @$pb.TagNumber(7)
+  set icon($core.String v) { $_setString(6, v); }
+  @$pb.TagNumber(7)
+  $core.bool hasIcon() => $_has(6);
+  @$pb.TagNumber(7)
+  void clearIcon() => clearField(7);
 }
 
@@ -16,16 +16,64 @@ const UpdateViewRequest$json = const {
     const {'1': 'name', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'name'},
     const {'1': 'desc', '3': 3, '4': 1, '5': 9, '9': 1, '10': 'desc'},
     const {'1': 'thumbnail', '3': 4, '4': 1, '5': 9, '9': 2, '10': 'thumbnail'},
+    const {'1': 'is_readonly', '3': 5, '4': 1, '5': 8, '9': 3, '10': 'isReadonly'},
+    const {'1': 'icon', '3': 6, '4': 1, '5': 9, '9': 4, '10': 'icon'},
   ],
   '8': const [
     const {'1': 'one_of_name'},
     const {'1': 'one_of_desc'},
     const {'1': 'one_of_thumbnail'},
+    const {'1': 'one_of_is_readonly'},
+    const {'1': 'one_of_icon'},
   ],
 };
 
 /// Descriptor for `UpdateViewRequest`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List updateViewRequestDescriptor = $convert.base64Decode('ChFVcGRhdGVWaWV3UmVxdWVzdBIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSFAoEbmFtZRgCIAEoCUgAUgRuYW1lEhQKBGRlc2MYAyABKAlIAVIEZGVzYxIeCgl0aHVtYm5haWwYBCABKAlIAlIJdGh1bWJuYWlsQg0KC29uZV9vZl9uYW1lQg0KC29uZV9vZl9kZXNjQhIKEG9uZV9vZl90aHVtYm5haWw=');
+final $typed_data.Uint8List updateViewRequestDescriptor = $convert.base64Decode('ChFVcGRhdGVWaWV3UmVxdWVzdBIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSFAoEbmFtZRgCIAEoCUgAUgRuYW1lEhQKBGRlc2MYAyABKAlIAVIEZGVzYxIeCgl0aHVtYm5haWwYBCABKAlIAlIJdGh1bWJuYWlsEiEKC2lzX3JlYWRvbmx5GAUgASgISANSCmlzUmVhZG9ubHkSFAoEaWNvbhgGIAEoCUgEUgRpY29uQg0KC29uZV9vZl9uYW1lQg0KC29uZV9vZl9kZXNjQhIKEG9uZV9vZl90aHVtYm5haWxCFAoSb25lX29mX2lzX3JlYWRvbmx5Qg0KC29uZV9vZl9pY29u');
+@$core.Deprecated('Use renameViewRequestDescriptor instead')
+const RenameViewRequest$json = const {
+  '1': 'RenameViewRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
+  ],
+};
+
+/// Descriptor for `RenameViewRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List renameViewRequestDescriptor = $convert.base64Decode('ChFSZW5hbWVWaWV3UmVxdWVzdBIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSEgoEbmFtZRgCIAEoCVIEbmFtZQ==');
+@$core.Deprecated('Use setViewReadonlyRequestDescriptor instead')
+const SetViewReadonlyRequest$json = const {
+  '1': 'SetViewReadonlyRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'is_readonly', '3': 2, '4': 1, '5': 8, '10': 'isReadonly'},
+  ],
+};
+
+/// Descriptor for `SetViewReadonlyRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List setViewReadonlyRequestDescriptor = $convert.base64Decode('ChZTZXRWaWV3UmVhZG9ubHlSZXF1ZXN0EhcKB3ZpZXdfaWQYASABKAlSBnZpZXdJZBIfCgtpc19yZWFkb25seRgCIAEoCFIKaXNSZWFkb25seQ==');
+@$core.Deprecated('Use setViewVisibilityRequestDescriptor instead')
+const SetViewVisibilityRequest$json = const {
+  '1': 'SetViewVisibilityRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'visibility', '3': 2, '4': 1, '5': 14, '6': '.ViewVisibility', '10': 'visibility'},
+  ],
+};
+
+/// Descriptor for `SetViewVisibilityRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List setViewVisibilityRequestDescriptor = $convert.base64Decode('ChhTZXRWaWV3VmlzaWJpbGl0eVJlcXVlc3QSFwoHdmlld19pZBgBIAEoCVIGdmlld0lkEi8KCnZpc2liaWxpdHkYAiABKA4yDy5WaWV3VmlzaWJpbGl0eVIKdmlzaWJpbGl0eQ==');
+@$core.Deprecated('Use setViewIconRequestDescriptor instead')
+const SetViewIconRequest$json = const {
+  '1': 'SetViewIconRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'icon', '3': 2, '4': 1, '5': 9, '10': 'icon'},
+  ],
+};
+
+/// Descriptor for `SetViewIconRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List setViewIconRequestDescriptor = $convert.base64Decode('ChJTZXRWaWV3SWNvblJlcXVlc3QSFwoHdmlld19pZBgBIAEoCVIGdmlld0lkEhIKBGljb24YAiABKAlSBGljb24=');
 @$core.Deprecated('Use updateViewParamsDescriptor instead')
 const UpdateViewParams$json = const {
   '1': 'UpdateViewParams',
@@ -34,13 +82,19 @@ const UpdateViewParams$json = const {
     const {'1': 'name', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'name'},
     const {'1': 'desc', '3': 3, '4': 1, '5': 9, '9': 1, '10': 'desc'},
     const {'1': 'thumbnail', '3': 4, '4': 1, '5': 9, '9': 2, '10': 'thumbnail'},
+    const {'1': 'is_readonly', '3': 5, '4': 1, '5': 8, '9': 3, '10': 'isReadonly'},
+    const {'1': 'visibility', '3': 6, '4': 1, '5': 5, '9': 4, '10': 'visibility'},
+    const {'1': 'icon', '3': 7, '4': 1, '5': 9, '9': 5, '10': 'icon'},
   ],
   '8': const [
     const {'1': 'one_of_name'},
     const {'1': 'one_of_desc'},
     const {'1': 'one_of_thumbnail'},
+    const {'1': 'one_of_is_readonly'},
+    const {'1': 'one_of_visibility'},
+    const {'1': 'one_of_icon'},
   ],
 };
 
 /// Descriptor for `UpdateViewParams`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List updateViewParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVWaWV3UGFyYW1zEhcKB3ZpZXdfaWQYASABKAlSBnZpZXdJZBIUCgRuYW1lGAIgASgJSABSBG5hbWUSFAoEZGVzYxgDIAEoCUgBUgRkZXNjEh4KCXRodW1ibmFpbBgEIAEoCUgCUgl0aHVtYm5haWxCDQoLb25lX29mX25hbWVCDQoLb25lX29mX2Rlc2NCEgoQb25lX29mX3RodW1ibmFpbA==');
+final $typed_data.Uint8List updateViewParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVWaWV3UGFyYW1zEhcKB3ZpZXdfaWQYASABKAlSBnZpZXdJZBIUCgRuYW1lGAIgASgJSABSBG5hbWUSFAoEZGVzYxgDIAEoCUgBUgRkZXNjEh4KCXRodW1ibmFpbBgEIAEoCUgCUgl0aHVtYm5haWwSIQoLaXNfcmVhZG9ubHkYBSABKAhIA1IKaXNSZWFkb25seRIgCgp2aXNpYmlsaXR5GAYgASgFSARSCnZpc2liaWxpdHkSFAoEaWNvbhgHIAEoCUgFUgRpY29uQg0KC29uZV9vZl9uYW1lQg0KC29uZV9vZl9kZXNjQhIKEG9uZV9vZl90aHVtYm5haWxCFAoSb25lX29mX2lzX3JlYWRvbmx5QhMKEW9uZV9vZl92aXNpYmlsaXR5Qg0KC29uZV9vZl9pY29u');
@@ -28,13 +28,44 @@ class WorkspaceEvent extends $pb.ProtobufEnum {
   static const WorkspaceEvent CopyLink = WorkspaceEvent._(206, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CopyLink');
   static const WorkspaceEvent OpenView = WorkspaceEvent._(207, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'OpenView');
   static const WorkspaceEvent CloseView = WorkspaceEvent._(208, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CloseView');
+  static const WorkspaceEvent OpenViewOfflineFirst = WorkspaceEvent._(209, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'OpenViewOfflineFirst');
+  static const WorkspaceEvent ReadViewsBelongTo = WorkspaceEvent._(210, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadViewsBelongTo');
+  static const WorkspaceEvent PreviewDeleteView = WorkspaceEvent._(211, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'PreviewDeleteView');
+  static const WorkspaceEvent RenameView = WorkspaceEvent._(212, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RenameView');
+  static const WorkspaceEvent ReadViewSyncState = WorkspaceEvent._(213, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadViewSyncState');
+  static const WorkspaceEvent DuplicateViewRecursive = WorkspaceEvent._(214, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DuplicateViewRecursive');
+  static const WorkspaceEvent TrashViewsBelongTo = WorkspaceEvent._(215, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'TrashViewsBelongTo');
+  static const WorkspaceEvent VerifyViewsConsistency = WorkspaceEvent._(216, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'VerifyViewsConsistency');
+  static const WorkspaceEvent RepairOrphanedViews = WorkspaceEvent._(217, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RepairOrphanedViews');
+  static const WorkspaceEvent ReadOpenDocumentIds = WorkspaceEvent._(218, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadOpenDocumentIds');
+  static const WorkspaceEvent RestoreOpenViews = WorkspaceEvent._(219, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RestoreOpenViews');
+  static const WorkspaceEvent SetViewReadonly = WorkspaceEvent._(220, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SetViewReadonly');
+  static const WorkspaceEvent ReadTrashedViews = WorkspaceEvent._(221, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadTrashedViews');
+  static const WorkspaceEvent RestoreView = WorkspaceEvent._(222, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RestoreView');
+  static const WorkspaceEvent ReadViewAncestors = WorkspaceEvent._(223, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadViewAncestors');
+  static const WorkspaceEvent SetViewThumbnail = WorkspaceEvent._(224, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SetViewThumbnail');
+  static const WorkspaceEvent ReadViewThumbnail = WorkspaceEvent._(225, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadViewThumbnail');
+  static const WorkspaceEvent ReadViewsBelongToPaged = WorkspaceEvent._(226, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadViewsBelongToPaged');
+  static const WorkspaceEvent CountViewsBelongTo = WorkspaceEvent._(227, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CountViewsBelongTo');
+  static const WorkspaceEvent CountViewsGroupedByParent = WorkspaceEvent._(228, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CountViewsGroupedByParent');
+  static const WorkspaceEvent MergeLocalInto = WorkspaceEvent._(229, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'MergeLocalInto');
+  static const WorkspaceEvent ImportView = WorkspaceEvent._(230, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ImportView');
+  static const WorkspaceEvent FindDuplicateViews = WorkspaceEvent._(231, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'FindDuplicateViews');
+  static const WorkspaceEvent SetViewVisibility = WorkspaceEvent._(232, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SetViewVisibility');
+  static const WorkspaceEvent ReadAllUserViews = WorkspaceEvent._(233, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadAllUserViews');
+  static const WorkspaceEvent SetViewIcon = WorkspaceEvent._(234, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'SetViewIcon');
   static const WorkspaceEvent ReadTrash = WorkspaceEvent._(300, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadTrash');
   static const WorkspaceEvent PutbackTrash = WorkspaceEvent._(301, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'PutbackTrash');
   static const WorkspaceEvent DeleteTrash = WorkspaceEvent._(302, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteTrash');
   static const WorkspaceEvent RestoreAll = WorkspaceEvent._(303, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RestoreAll');
   static const WorkspaceEvent DeleteAll = WorkspaceEvent._(304, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DeleteAll');
   static const WorkspaceEvent ApplyDocDelta = WorkspaceEvent._(400, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ApplyDocDelta');
+  static const WorkspaceEvent ReadDocumentStats = WorkspaceEvent._(401, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadDocumentStats');
+  static const WorkspaceEvent ReadDocumentHistory = WorkspaceEvent._(402, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ReadDocumentHistory');
+  static const WorkspaceEvent RestoreDocument = WorkspaceEvent._(403, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'RestoreDocument');
+  static const WorkspaceEvent CompactDocument = WorkspaceEvent._(404, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CompactDocument');
   static const WorkspaceEvent ExportDocument = WorkspaceEvent._(500, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ExportDocument');
+  static const WorkspaceEvent ExportApp = WorkspaceEvent._(501, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ExportApp');
 
   static const $core.List<WorkspaceEvent> values = <WorkspaceEvent> [
     CreateWorkspace,
@@ -55,13 +86,44 @@ class WorkspaceEvent extends $pb.ProtobufEnum {
     CopyLink,
     OpenView,
     CloseView,
+    OpenViewOfflineFirst,
+    ReadViewsBelongTo,
+    PreviewDeleteView,
+    RenameView,
+    ReadViewSyncState,
+    DuplicateViewRecursive,
+    TrashViewsBelongTo,
+    VerifyViewsConsistency,
+    RepairOrphanedViews,
+    ReadOpenDocumentIds,
+    RestoreOpenViews,
+    SetViewReadonly,
+    ReadTrashedViews,
+    RestoreView,
+    ReadViewAncestors,
+    SetViewThumbnail,
+    ReadViewThumbnail,
+    ReadViewsBelongToPaged,
+    CountViewsBelongTo,
+    CountViewsGroupedByParent,
+    MergeLocalInto,
+    ImportView,
+    FindDuplicateViews,
+    SetViewVisibility,
+    ReadAllUserViews,
+    SetViewIcon,
     ReadTrash,
     PutbackTrash,
     DeleteTrash,
     RestoreAll,
     DeleteAll,
     ApplyDocDelta,
+    ReadDocumentStats,
+    ReadDocumentHistory,
+    RestoreDocument,
+    CompactDocument,
     ExportDocument,
+    ExportApp,
   ];
 
   static final $core.Map<$core.int, WorkspaceEvent> _byValue = $pb.ProtobufEnum.initByValue(values);
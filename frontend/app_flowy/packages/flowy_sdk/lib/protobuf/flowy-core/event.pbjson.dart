@@ -30,15 +30,46 @@ const WorkspaceEvent$json = const {
     const {'1': 'CopyLink', '2': 206},
     const {'1': 'OpenView', '2': 207},
     const {'1': 'CloseView', '2': 208},
+    const {'1': 'OpenViewOfflineFirst', '2': 209},
+    const {'1': 'ReadViewsBelongTo', '2': 210},
+    const {'1': 'PreviewDeleteView', '2': 211},
+    const {'1': 'RenameView', '2': 212},
+    const {'1': 'ReadViewSyncState', '2': 213},
+    const {'1': 'DuplicateViewRecursive', '2': 214},
+    const {'1': 'TrashViewsBelongTo', '2': 215},
+    const {'1': 'VerifyViewsConsistency', '2': 216},
+    const {'1': 'RepairOrphanedViews', '2': 217},
+    const {'1': 'ReadOpenDocumentIds', '2': 218},
+    const {'1': 'RestoreOpenViews', '2': 219},
+    const {'1': 'SetViewReadonly', '2': 220},
+    const {'1': 'ReadTrashedViews', '2': 221},
+    const {'1': 'RestoreView', '2': 222},
+    const {'1': 'ReadViewAncestors', '2': 223},
+    const {'1': 'SetViewThumbnail', '2': 224},
+    const {'1': 'ReadViewThumbnail', '2': 225},
+    const {'1': 'ReadViewsBelongToPaged', '2': 226},
+    const {'1': 'CountViewsBelongTo', '2': 227},
+    const {'1': 'CountViewsGroupedByParent', '2': 228},
+    const {'1': 'MergeLocalInto', '2': 229},
+    const {'1': 'ImportView', '2': 230},
+    const {'1': 'FindDuplicateViews', '2': 231},
+    const {'1': 'SetViewVisibility', '2': 232},
+    const {'1': 'ReadAllUserViews', '2': 233},
+    const {'1': 'SetViewIcon', '2': 234},
     const {'1': 'ReadTrash', '2': 300},
     const {'1': 'PutbackTrash', '2': 301},
     const {'1': 'DeleteTrash', '2': 302},
     const {'1': 'RestoreAll', '2': 303},
     const {'1': 'DeleteAll', '2': 304},
     const {'1': 'ApplyDocDelta', '2': 400},
+    const {'1': 'ReadDocumentStats', '2': 401},
+    const {'1': 'ReadDocumentHistory', '2': 402},
+    const {'1': 'RestoreDocument', '2': 403},
+    const {'1': 'CompactDocument', '2': 404},
     const {'1': 'ExportDocument', '2': 500},
+    const {'1': 'ExportApp', '2': 501},
   ],
 };
 
 /// Descriptor for `WorkspaceEvent`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List workspaceEventDescriptor = $convert.base64Decode('Cg5Xb3Jrc3BhY2VFdmVudBITCg9DcmVhdGVXb3Jrc3BhY2UQABIUChBSZWFkQ3VyV29ya3NwYWNlEAESEgoOUmVhZFdvcmtzcGFjZXMQAhITCg9EZWxldGVXb3Jrc3BhY2UQAxIRCg1PcGVuV29ya3NwYWNlEAQSFQoRUmVhZFdvcmtzcGFjZUFwcHMQBRINCglDcmVhdGVBcHAQZRINCglEZWxldGVBcHAQZhILCgdSZWFkQXBwEGcSDQoJVXBkYXRlQXBwEGgSDwoKQ3JlYXRlVmlldxDJARINCghSZWFkVmlldxDKARIPCgpVcGRhdGVWaWV3EMsBEg8KCkRlbGV0ZVZpZXcQzAESEgoNRHVwbGljYXRlVmlldxDNARINCghDb3B5TGluaxDOARINCghPcGVuVmlldxDPARIOCglDbG9zZVZpZXcQ0AESDgoJUmVhZFRyYXNoEKwCEhEKDFB1dGJhY2tUcmFzaBCtAhIQCgtEZWxldGVUcmFzaBCuAhIPCgpSZXN0b3JlQWxsEK8CEg4KCURlbGV0ZUFsbBCwAhISCg1BcHBseURvY0RlbHRhEJADEhMKDkV4cG9ydERvY3VtZW50EPQD');
+final $typed_data.Uint8List workspaceEventDescriptor = $convert.base64Decode('Cg5Xb3Jrc3BhY2VFdmVudBITCg9DcmVhdGVXb3Jrc3BhY2UQABIUChBSZWFkQ3VyV29ya3NwYWNlEAESEgoOUmVhZFdvcmtzcGFjZXMQAhITCg9EZWxldGVXb3Jrc3BhY2UQAxIRCg1PcGVuV29ya3NwYWNlEAQSFQoRUmVhZFdvcmtzcGFjZUFwcHMQBRINCglDcmVhdGVBcHAQZRINCglEZWxldGVBcHAQZhILCgdSZWFkQXBwEGcSDQoJVXBkYXRlQXBwEGgSDwoKQ3JlYXRlVmlldxDJARINCghSZWFkVmlldxDKARIPCgpVcGRhdGVWaWV3EMsBEg8KCkRlbGV0ZVZpZXcQzAESEgoNRHVwbGljYXRlVmlldxDNARINCghDb3B5TGluaxDOARINCghPcGVuVmlldxDPARIOCglDbG9zZVZpZXcQ0AESGQoUT3BlblZpZXdPZmZsaW5lRmlyc3QQ0QESFgoRUmVhZFZpZXdzQmVsb25nVG8Q0gESFgoRUHJldmlld0RlbGV0ZVZpZXcQ0wESDwoKUmVuYW1lVmlldxDUARIWChFSZWFkVmlld1N5bmNTdGF0ZRDVARIbChZEdXBsaWNhdGVWaWV3UmVjdXJzaXZlENYBEhcKElRyYXNoVmlld3NCZWxvbmdUbxDXARIbChZWZXJpZnlWaWV3c0NvbnNpc3RlbmN5ENgBEhgKE1JlcGFpck9ycGhhbmVkVmlld3MQ2QESGAoTUmVhZE9wZW5Eb2N1bWVudElkcxDaARIVChBSZXN0b3JlT3BlblZpZXdzENsBEhQKD1NldFZpZXdSZWFkb25seRDcARIVChBSZWFkVHJhc2hlZFZpZXdzEN0BEhAKC1Jlc3RvcmVWaWV3EN4BEhYKEVJlYWRWaWV3QW5jZXN0b3JzEN8BEhUKEFNldFZpZXdUaHVtYm5haWwQ4AESFgoRUmVhZFZpZXdUaHVtYm5haWwQ4QESGwoWUmVhZFZpZXdzQmVsb25nVG9QYWdlZBDiARIXChJDb3VudFZpZXdzQmVsb25nVG8Q4wESHgoZQ291bnRWaWV3c0dyb3VwZWRCeVBhcmVudBDkARITCg5NZXJnZUxvY2FsSW50bxDlARIPCgpJbXBvcnRWaWV3EOYBEhcKEkZpbmREdXBsaWNhdGVWaWV3cxDnARIWChFTZXRWaWV3VmlzaWJpbGl0eRDoARIVChBSZWFkQWxsVXNlclZpZXdzEOkBEhAKC1NldFZpZXdJY29uEOoBEg4KCVJlYWRUcmFzaBCsAhIRCgxQdXRiYWNrVHJhc2gQrQISEAoLRGVsZXRlVHJhc2gQrgISDwoKUmVzdG9yZUFsbBCvAhIOCglEZWxldGVBbGwQsAISEgoNQXBwbHlEb2NEZWx0YRCQAxIWChFSZWFkRG9jdW1lbnRTdGF0cxCRAxIYChNSZWFkRG9jdW1lbnRIaXN0b3J5EJIDEhQKD1Jlc3RvcmVEb2N1bWVudBCTAxIUCg9Db21wYWN0RG9jdW1lbnQQlAMSEwoORXhwb3J0RG9jdW1lbnQQ9AMSDgoJRXhwb3J0QXBwEPUD');
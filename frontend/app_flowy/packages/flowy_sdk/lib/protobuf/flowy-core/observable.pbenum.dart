@@ -18,9 +18,15 @@ class WorkspaceNotification extends $pb.ProtobufEnum {
   static const WorkspaceNotification WorkspaceAppsChanged = WorkspaceNotification._(14, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'WorkspaceAppsChanged');
   static const WorkspaceNotification AppUpdated = WorkspaceNotification._(21, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppUpdated');
   static const WorkspaceNotification AppViewsChanged = WorkspaceNotification._(24, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppViewsChanged');
+  static const WorkspaceNotification AppViewsDeltaChanged = WorkspaceNotification._(25, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppViewsDeltaChanged');
+  static const WorkspaceNotification AppsViewsChanged = WorkspaceNotification._(26, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppsViewsChanged');
+  static const WorkspaceNotification AppsViewsDeltaChanged = WorkspaceNotification._(27, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'AppsViewsDeltaChanged');
   static const WorkspaceNotification ViewUpdated = WorkspaceNotification._(31, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewUpdated');
   static const WorkspaceNotification ViewDeleted = WorkspaceNotification._(32, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewDeleted');
   static const WorkspaceNotification ViewRestored = WorkspaceNotification._(33, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewRestored');
+  static const WorkspaceNotification ViewProgressChanged = WorkspaceNotification._(34, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewProgressChanged');
+  static const WorkspaceNotification ViewSyncStateChanged = WorkspaceNotification._(35, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewSyncStateChanged');
+  static const WorkspaceNotification ViewContentRefreshed = WorkspaceNotification._(36, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'ViewContentRefreshed');
   static const WorkspaceNotification UserUnauthorized = WorkspaceNotification._(100, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserUnauthorized');
   static const WorkspaceNotification TrashUpdated = WorkspaceNotification._(1000, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'TrashUpdated');
 
@@ -33,9 +39,15 @@ class WorkspaceNotification extends $pb.ProtobufEnum {
     WorkspaceAppsChanged,
     AppUpdated,
     AppViewsChanged,
+    AppViewsDeltaChanged,
+    AppsViewsChanged,
+    AppsViewsDeltaChanged,
     ViewUpdated,
     ViewDeleted,
     ViewRestored,
+    ViewProgressChanged,
+    ViewSyncStateChanged,
+    ViewContentRefreshed,
     UserUnauthorized,
     TrashUpdated,
   ];
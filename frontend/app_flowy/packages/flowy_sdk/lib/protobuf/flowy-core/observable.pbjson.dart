@@ -20,13 +20,19 @@ const WorkspaceNotification$json = const {
     const {'1': 'WorkspaceAppsChanged', '2': 14},
     const {'1': 'AppUpdated', '2': 21},
     const {'1': 'AppViewsChanged', '2': 24},
+    const {'1': 'AppViewsDeltaChanged', '2': 25},
+    const {'1': 'AppsViewsChanged', '2': 26},
+    const {'1': 'AppsViewsDeltaChanged', '2': 27},
     const {'1': 'ViewUpdated', '2': 31},
     const {'1': 'ViewDeleted', '2': 32},
     const {'1': 'ViewRestored', '2': 33},
+    const {'1': 'ViewProgressChanged', '2': 34},
+    const {'1': 'ViewSyncStateChanged', '2': 35},
+    const {'1': 'ViewContentRefreshed', '2': 36},
     const {'1': 'UserUnauthorized', '2': 100},
     const {'1': 'TrashUpdated', '2': 1000},
   ],
 };
 
 /// Descriptor for `WorkspaceNotification`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List workspaceNotificationDescriptor = $convert.base64Decode('ChVXb3Jrc3BhY2VOb3RpZmljYXRpb24SCwoHVW5rbm93bhAAEhcKE1VzZXJDcmVhdGVXb3Jrc3BhY2UQChIXChNVc2VyRGVsZXRlV29ya3NwYWNlEAsSFAoQV29ya3NwYWNlVXBkYXRlZBAMEhgKFFdvcmtzcGFjZUxpc3RVcGRhdGVkEA0SGAoUV29ya3NwYWNlQXBwc0NoYW5nZWQQDhIOCgpBcHBVcGRhdGVkEBUSEwoPQXBwVmlld3NDaGFuZ2VkEBgSDwoLVmlld1VwZGF0ZWQQHxIPCgtWaWV3RGVsZXRlZBAgEhAKDFZpZXdSZXN0b3JlZBAhEhQKEFVzZXJVbmF1dGhvcml6ZWQQZBIRCgxUcmFzaFVwZGF0ZWQQ6Ac=');
+final $typed_data.Uint8List workspaceNotificationDescriptor = $convert.base64Decode('ChVXb3Jrc3BhY2VOb3RpZmljYXRpb24SCwoHVW5rbm93bhAAEhcKE1VzZXJDcmVhdGVXb3Jrc3BhY2UQChIXChNVc2VyRGVsZXRlV29ya3NwYWNlEAsSFAoQV29ya3NwYWNlVXBkYXRlZBAMEhgKFFdvcmtzcGFjZUxpc3RVcGRhdGVkEA0SGAoUV29ya3NwYWNlQXBwc0NoYW5nZWQQDhIOCgpBcHBVcGRhdGVkEBUSEwoPQXBwVmlld3NDaGFuZ2VkEBgSGAoUQXBwVmlld3NEZWx0YUNoYW5nZWQQGRIUChBBcHBzVmlld3NDaGFuZ2VkEBoSGQoVQXBwc1ZpZXdzRGVsdGFDaGFuZ2VkEBsSDwoLVmlld1VwZGF0ZWQQHxIPCgtWaWV3RGVsZXRlZBAgEhAKDFZpZXdSZXN0b3JlZBAhEhcKE1ZpZXdQcm9ncmVzc0NoYW5nZWQQIhIYChRWaWV3U3luY1N0YXRlQ2hhbmdlZBAjEhgKFFZpZXdDb250ZW50UmVmcmVzaGVkECQSFAoQVXNlclVuYXV0aG9yaXplZBBkEhEKDFRyYXNoVXBkYXRlZBDoBw==');
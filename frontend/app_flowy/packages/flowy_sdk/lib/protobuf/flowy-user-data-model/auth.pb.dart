@@ -7,6 +7,7 @@
 
 import 'dart:core' as $core;
 
+import 'package:fixnum/fixnum.dart' as $fixnum;
 import 'package:protobuf/protobuf.dart' as $pb;
 
 class SignInRequest extends $pb.GeneratedMessage {
@@ -165,6 +166,7 @@ class SignInResponse extends $pb.GeneratedMessage {
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'token')
+    ..aInt64(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'expiresAt')
     ..hasRequiredFields = false
   ;
 
@@ -174,6 +176,7 @@ class SignInResponse extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? email,
     $core.String? token,
+    $fixnum.Int64? expiresAt,
   }) {
     final _result = create();
     if (userId != null) {
@@ -188,6 +191,9 @@ class SignInResponse extends $pb.GeneratedMessage {
     if (token != null) {
       _result.token = token;
     }
+    if (expiresAt != null) {
+      _result.expiresAt = expiresAt;
+    }
     return _result;
   }
   factory SignInResponse.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -246,6 +252,15 @@ class SignInResponse extends $pb.GeneratedMessage {
   $core.bool hasToken() => $_has(3);
   @$pb.TagNumber(4)
   void clearToken() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $fixnum.Int64 get expiresAt => $_getI64(4);
+  @$pb.TagNumber(5)
+  set expiresAt($fixnum.Int64 v) { $_setInt64(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasExpiresAt() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearExpiresAt() => clearField(5);
 }
 
 class SignUpRequest extends $pb.GeneratedMessage {
@@ -404,6 +419,7 @@ class SignUpResponse extends $pb.GeneratedMessage {
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'token')
+    ..aInt64(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'expiresAt')
     ..hasRequiredFields = false
   ;
 
@@ -413,6 +429,7 @@ class SignUpResponse extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? email,
     $core.String? token,
+    $fixnum.Int64? expiresAt,
   }) {
     final _result = create();
     if (userId != null) {
@@ -427,6 +444,9 @@ class SignUpResponse extends $pb.GeneratedMessage {
     if (token != null) {
       _result.token = token;
     }
+    if (expiresAt != null) {
+      _result.expiresAt = expiresAt;
+    }
     return _result;
   }
   factory SignUpResponse.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -485,5 +505,14 @@ class SignUpResponse extends $pb.GeneratedMessage {
   $core.bool hasToken() => $_has(3);
   @$pb.TagNumber(4)
   void clearToken() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $fixnum.Int64 get expiresAt => $_getI64(4);
+  @$pb.TagNumber(5)
+  set expiresAt($fixnum.Int64 v) { $_setInt64(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasExpiresAt() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearExpiresAt() => clearField(5);
 }
 
@@ -62,6 +62,7 @@ class UserProfile extends $pb.GeneratedMessage {
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'token')
+    ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'iconUrl')
     ..hasRequiredFields = false
   ;
 
@@ -71,6 +72,7 @@ class UserProfile extends $pb.GeneratedMessage {
     $core.String? email,
     $core.String? name,
     $core.String? token,
+    $core.String? iconUrl,
   }) {
     final _result = create();
     if (id != null) {
@@ -85,6 +87,9 @@ class UserProfile extends $pb.GeneratedMessage {
     if (token != null) {
       _result.token = token;
     }
+    if (iconUrl != null) {
+      _result.iconUrl = iconUrl;
+    }
     return _result;
   }
   factory UserProfile.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -143,6 +148,15 @@ class UserProfile extends $pb.GeneratedMessage {
   $core.bool hasToken() => $_has(3);
   @$pb.TagNumber(4)
   void clearToken() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.String get iconUrl => $_getSZ(4);
+  @$pb.TagNumber(5)
+  set iconUrl($core.String v) { $_setString(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasIconUrl() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearIconUrl() => clearField(5);
 }
 
 enum UpdateUserRequest_OneOfName {
@@ -160,6 +174,11 @@ enum UpdateUserRequest_OneOfPassword {
   notSet
 }
 
+enum UpdateUserRequest_OneOfIconUrl {
+  iconUrl, 
+  notSet
+}
+
 class UpdateUserRequest extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, UpdateUserRequest_OneOfName> _UpdateUserRequest_OneOfNameByTag = {
     2 : UpdateUserRequest_OneOfName.name,
@@ -173,14 +192,20 @@ class UpdateUserRequest extends $pb.GeneratedMessage {
     4 : UpdateUserRequest_OneOfPassword.password,
     0 : UpdateUserRequest_OneOfPassword.notSet
   };
+  static const $core.Map<$core.int, UpdateUserRequest_OneOfIconUrl> _UpdateUserRequest_OneOfIconUrlByTag = {
+    5 : UpdateUserRequest_OneOfIconUrl.iconUrl,
+    0 : UpdateUserRequest_OneOfIconUrl.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateUserRequest', createEmptyInstance: create)
     ..oo(0, [2])
     ..oo(1, [3])
     ..oo(2, [4])
+    ..oo(3, [5])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'password')
+    ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'iconUrl')
     ..hasRequiredFields = false
   ;
 
@@ -190,6 +215,7 @@ class UpdateUserRequest extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? email,
     $core.String? password,
+    $core.String? iconUrl,
   }) {
     final _result = create();
     if (id != null) {
@@ -204,6 +230,9 @@ class UpdateUserRequest extends $pb.GeneratedMessage {
     if (password != null) {
       _result.password = password;
     }
+    if (iconUrl != null) {
+      _result.iconUrl = iconUrl;
+    }
     return _result;
   }
   factory UpdateUserRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -236,6 +265,9 @@ class UpdateUserRequest extends $pb.GeneratedMessage {
   UpdateUserRequest_OneOfPassword whichOneOfPassword() => _UpdateUserRequest_OneOfPasswordByTag[$_whichOneof(2)]!;
   void clearOneOfPassword() => clearField($_whichOneof(2));
 
+  UpdateUserRequest_OneOfIconUrl whichOneOfIconUrl() => _UpdateUserRequest_OneOfIconUrlByTag[$_whichOneof(3)]!;
+  void clearOneOfIconUrl() => clearField($_whichOneof(3));
+
   @$pb.TagNumber(1)
   $core.String get id => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -271,6 +303,15 @@ class UpdateUserRequest extends $pb.GeneratedMessage {
   $core.bool hasPassword() => $_has(3);
   @$pb.TagNumber(4)
   void clearPassword() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.String get iconUrl => $_getSZ(4);
+  @$pb.TagNumber(5)
+  set iconUrl($core.String v) { $_setString(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasIconUrl() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearIconUrl() => clearField(5);
 }
 
 enum UpdateUserParams_OneOfName {
@@ -288,6 +329,11 @@ enum UpdateUserParams_OneOfPassword {
   notSet
 }
 
+enum UpdateUserParams_OneOfIconUrl {
+  iconUrl, 
+  notSet
+}
+
 class UpdateUserParams extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, UpdateUserParams_OneOfName> _UpdateUserParams_OneOfNameByTag = {
     2 : UpdateUserParams_OneOfName.name,
@@ -301,14 +347,20 @@ class UpdateUserParams extends $pb.GeneratedMessage {
     4 : UpdateUserParams_OneOfPassword.password,
     0 : UpdateUserParams_OneOfPassword.notSet
   };
+  static const $core.Map<$core.int, UpdateUserParams_OneOfIconUrl> _UpdateUserParams_OneOfIconUrlByTag = {
+    5 : UpdateUserParams_OneOfIconUrl.iconUrl,
+    0 : UpdateUserParams_OneOfIconUrl.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateUserParams', createEmptyInstance: create)
     ..oo(0, [2])
     ..oo(1, [3])
     ..oo(2, [4])
+    ..oo(3, [5])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'password')
+    ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'iconUrl')
     ..hasRequiredFields = false
   ;
 
@@ -318,6 +370,7 @@ class UpdateUserParams extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? email,
     $core.String? password,
+    $core.String? iconUrl,
   }) {
     final _result = create();
     if (id != null) {
@@ -332,6 +385,9 @@ class UpdateUserParams extends $pb.GeneratedMessage {
     if (password != null) {
       _result.password = password;
     }
+    if (iconUrl != null) {
+      _result.iconUrl = iconUrl;
+    }
     return _result;
   }
   factory UpdateUserParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -364,6 +420,9 @@ class UpdateUserParams extends $pb.GeneratedMessage {
   UpdateUserParams_OneOfPassword whichOneOfPassword() => _UpdateUserParams_OneOfPasswordByTag[$_whichOneof(2)]!;
   void clearOneOfPassword() => clearField($_whichOneof(2));
 
+  UpdateUserParams_OneOfIconUrl whichOneOfIconUrl() => _UpdateUserParams_OneOfIconUrlByTag[$_whichOneof(3)]!;
+  void clearOneOfIconUrl() => clearField($_whichOneof(3));
+
   @$pb.TagNumber(1)
   $core.String get id => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -399,5 +458,263 @@ class UpdateUserParams extends $pb.GeneratedMessage {
   $core.bool hasPassword() => $_has(3);
   @$pb.TagNumber(4)
   void clearPassword() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.String get iconUrl => $_getSZ(4);
+  @$pb.TagNumber(5)
+  set iconUrl($core.String v) { $_setString(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasIconUrl() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearIconUrl() => clearField(5);
+}
+
+class ChangePasswordParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ChangePasswordParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'oldPassword')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'newPassword')
+    ..hasRequiredFields = false
+  ;
+
+  ChangePasswordParams._() : super();
+  factory ChangePasswordParams({
+    $core.String? oldPassword,
+    $core.String? newPassword,
+  }) {
+    final _result = create();
+    if (oldPassword != null) {
+      _result.oldPassword = oldPassword;
+    }
+    if (newPassword != null) {
+      _result.newPassword = newPassword;
+    }
+    return _result;
+  }
+  factory ChangePasswordParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ChangePasswordParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ChangePasswordParams clone() => ChangePasswordParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ChangePasswordParams copyWith(void Function(ChangePasswordParams) updates) => super.copyWith((message) => updates(message as ChangePasswordParams)) as ChangePasswordParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ChangePasswordParams create() => ChangePasswordParams._();
+  ChangePasswordParams createEmptyInstance() => create();
+  static $pb.PbList<ChangePasswordParams> createRepeated() => $pb.PbList<ChangePasswordParams>();
+  @$core.pragma('dart2js:noInline')
+  static ChangePasswordParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ChangePasswordParams>(create);
+  static ChangePasswordParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get oldPassword => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set oldPassword($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasOldPassword() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearOldPassword() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get newPassword => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set newPassword($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasNewPassword() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearNewPassword() => clearField(2);
+}
+
+class DeleteAccountParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DeleteAccountParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'password')
+    ..hasRequiredFields = false
+  ;
+
+  DeleteAccountParams._() : super();
+  factory DeleteAccountParams({
+    $core.String? password,
+  }) {
+    final _result = create();
+    if (password != null) {
+      _result.password = password;
+    }
+    return _result;
+  }
+  factory DeleteAccountParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DeleteAccountParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DeleteAccountParams clone() => DeleteAccountParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DeleteAccountParams copyWith(void Function(DeleteAccountParams) updates) => super.copyWith((message) => updates(message as DeleteAccountParams)) as DeleteAccountParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DeleteAccountParams create() => DeleteAccountParams._();
+  DeleteAccountParams createEmptyInstance() => create();
+  static $pb.PbList<DeleteAccountParams> createRepeated() => $pb.PbList<DeleteAccountParams>();
+  @$core.pragma('dart2js:noInline')
+  static DeleteAccountParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DeleteAccountParams>(create);
+  static DeleteAccountParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get password => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set password($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasPassword() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearPassword() => clearField(1);
+}
+
+class RequestEmailChangeParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RequestEmailChangeParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'newEmail')
+    ..hasRequiredFields = false
+  ;
+
+  RequestEmailChangeParams._() : super();
+  factory RequestEmailChangeParams({
+    $core.String? newEmail,
+  }) {
+    final _result = create();
+    if (newEmail != null) {
+      _result.newEmail = newEmail;
+    }
+    return _result;
+  }
+  factory RequestEmailChangeParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RequestEmailChangeParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RequestEmailChangeParams clone() => RequestEmailChangeParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RequestEmailChangeParams copyWith(void Function(RequestEmailChangeParams) updates) => super.copyWith((message) => updates(message as RequestEmailChangeParams)) as RequestEmailChangeParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RequestEmailChangeParams create() => RequestEmailChangeParams._();
+  RequestEmailChangeParams createEmptyInstance() => create();
+  static $pb.PbList<RequestEmailChangeParams> createRepeated() => $pb.PbList<RequestEmailChangeParams>();
+  @$core.pragma('dart2js:noInline')
+  static RequestEmailChangeParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RequestEmailChangeParams>(create);
+  static RequestEmailChangeParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get newEmail => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set newEmail($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasNewEmail() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearNewEmail() => clearField(1);
+}
+
+class ConfirmEmailChangeParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ConfirmEmailChangeParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'code')
+    ..hasRequiredFields = false
+  ;
+
+  ConfirmEmailChangeParams._() : super();
+  factory ConfirmEmailChangeParams({
+    $core.String? code,
+  }) {
+    final _result = create();
+    if (code != null) {
+      _result.code = code;
+    }
+    return _result;
+  }
+  factory ConfirmEmailChangeParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ConfirmEmailChangeParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ConfirmEmailChangeParams clone() => ConfirmEmailChangeParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ConfirmEmailChangeParams copyWith(void Function(ConfirmEmailChangeParams) updates) => super.copyWith((message) => updates(message as ConfirmEmailChangeParams)) as ConfirmEmailChangeParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ConfirmEmailChangeParams create() => ConfirmEmailChangeParams._();
+  ConfirmEmailChangeParams createEmptyInstance() => create();
+  static $pb.PbList<ConfirmEmailChangeParams> createRepeated() => $pb.PbList<ConfirmEmailChangeParams>();
+  @$core.pragma('dart2js:noInline')
+  static ConfirmEmailChangeParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ConfirmEmailChangeParams>(create);
+  static ConfirmEmailChangeParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get code => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set code($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasCode() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearCode() => clearField(1);
+}
+
+class ConfirmEmailChangeResponse extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ConfirmEmailChangeResponse', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
+    ..hasRequiredFields = false
+  ;
+
+  ConfirmEmailChangeResponse._() : super();
+  factory ConfirmEmailChangeResponse({
+    $core.String? email,
+  }) {
+    final _result = create();
+    if (email != null) {
+      _result.email = email;
+    }
+    return _result;
+  }
+  factory ConfirmEmailChangeResponse.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ConfirmEmailChangeResponse.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ConfirmEmailChangeResponse clone() => ConfirmEmailChangeResponse()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ConfirmEmailChangeResponse copyWith(void Function(ConfirmEmailChangeResponse) updates) => super.copyWith((message) => updates(message as ConfirmEmailChangeResponse)) as ConfirmEmailChangeResponse; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ConfirmEmailChangeResponse create() => ConfirmEmailChangeResponse._();
+  ConfirmEmailChangeResponse createEmptyInstance() => create();
+  static $pb.PbList<ConfirmEmailChangeResponse> createRepeated() => $pb.PbList<ConfirmEmailChangeResponse>();
+  @$core.pragma('dart2js:noInline')
+  static ConfirmEmailChangeResponse getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ConfirmEmailChangeResponse>(create);
+  static ConfirmEmailChangeResponse? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get email => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set email($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasEmail() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearEmail() => clearField(1);
 }
 
@@ -26,11 +26,12 @@ const UserProfile$json = const {
     const {'1': 'email', '3': 2, '4': 1, '5': 9, '10': 'email'},
     const {'1': 'name', '3': 3, '4': 1, '5': 9, '10': 'name'},
     const {'1': 'token', '3': 4, '4': 1, '5': 9, '10': 'token'},
+    const {'1': 'icon_url', '3': 5, '4': 1, '5': 9, '10': 'iconUrl'},
   ],
 };
 
 /// Descriptor for `UserProfile`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List userProfileDescriptor = $convert.base64Decode('CgtVc2VyUHJvZmlsZRIOCgJpZBgBIAEoCVICaWQSFAoFZW1haWwYAiABKAlSBWVtYWlsEhIKBG5hbWUYAyABKAlSBG5hbWUSFAoFdG9rZW4YBCABKAlSBXRva2Vu');
+final $typed_data.Uint8List userProfileDescriptor = $convert.base64Decode('CgtVc2VyUHJvZmlsZRIOCgJpZBgBIAEoCVICaWQSFAoFZW1haWwYAiABKAlSBWVtYWlsEhIKBG5hbWUYAyABKAlSBG5hbWUSFAoFdG9rZW4YBCABKAlSBXRva2VuEhkKCGljb25fdXJsGAUgASgJUgdpY29uVXJs');
 @$core.Deprecated('Use updateUserRequestDescriptor instead')
 const UpdateUserRequest$json = const {
   '1': 'UpdateUserRequest',
@@ -39,16 +40,18 @@ const UpdateUserRequest$json = const {
     const {'1': 'name', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'name'},
     const {'1': 'email', '3': 3, '4': 1, '5': 9, '9': 1, '10': 'email'},
     const {'1': 'password', '3': 4, '4': 1, '5': 9, '9': 2, '10': 'password'},
+    const {'1': 'icon_url', '3': 5, '4': 1, '5': 9, '9': 3, '10': 'iconUrl'},
   ],
   '8': const [
     const {'1': 'one_of_name'},
     const {'1': 'one_of_email'},
     const {'1': 'one_of_password'},
+    const {'1': 'one_of_icon_url'},
   ],
 };
 
 /// Descriptor for `UpdateUserRequest`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List updateUserRequestDescriptor = $convert.base64Decode('ChFVcGRhdGVVc2VyUmVxdWVzdBIOCgJpZBgBIAEoCVICaWQSFAoEbmFtZRgCIAEoCUgAUgRuYW1lEhYKBWVtYWlsGAMgASgJSAFSBWVtYWlsEhwKCHBhc3N3b3JkGAQgASgJSAJSCHBhc3N3b3JkQg0KC29uZV9vZl9uYW1lQg4KDG9uZV9vZl9lbWFpbEIRCg9vbmVfb2ZfcGFzc3dvcmQ=');
+final $typed_data.Uint8List updateUserRequestDescriptor = $convert.base64Decode('ChFVcGRhdGVVc2VyUmVxdWVzdBIOCgJpZBgBIAEoCVICaWQSFAoEbmFtZRgCIAEoCUgAUgRuYW1lEhYKBWVtYWlsGAMgASgJSAFSBWVtYWlsEhwKCHBhc3N3b3JkGAQgASgJSAJSCHBhc3N3b3JkEhsKCGljb25fdXJsGAUgASgJSANSB2ljb25VcmxCDQoLb25lX29mX25hbWVCDgoMb25lX29mX2VtYWlsQhEKD29uZV9vZl9wYXNzd29yZEIRCg9vbmVfb2ZfaWNvbl91cmw=');
 @$core.Deprecated('Use updateUserParamsDescriptor instead')
 const UpdateUserParams$json = const {
   '1': 'UpdateUserParams',
@@ -57,13 +60,66 @@ const UpdateUserParams$json = const {
     const {'1': 'name', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'name'},
     const {'1': 'email', '3': 3, '4': 1, '5': 9, '9': 1, '10': 'email'},
     const {'1': 'password', '3': 4, '4': 1, '5': 9, '9': 2, '10': 'password'},
+    const {'1': 'icon_url', '3': 5, '4': 1, '5': 9, '9': 3, '10': 'iconUrl'},
   ],
   '8': const [
     const {'1': 'one_of_name'},
     const {'1': 'one_of_email'},
     const {'1': 'one_of_password'},
+    const {'1': 'one_of_icon_url'},
   ],
 };
 
 /// Descriptor for `UpdateUserParams`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List updateUserParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVVc2VyUGFyYW1zEg4KAmlkGAEgASgJUgJpZBIUCgRuYW1lGAIgASgJSABSBG5hbWUSFgoFZW1haWwYAyABKAlIAVIFZW1haWwSHAoIcGFzc3dvcmQYBCABKAlIAlIIcGFzc3dvcmRCDQoLb25lX29mX25hbWVCDgoMb25lX29mX2VtYWlsQhEKD29uZV9vZl9wYXNzd29yZA==');
+final $typed_data.Uint8List updateUserParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVVc2VyUGFyYW1zEg4KAmlkGAEgASgJUgJpZBIUCgRuYW1lGAIgASgJSABSBG5hbWUSFgoFZW1haWwYAyABKAlIAVIFZW1haWwSHAoIcGFzc3dvcmQYBCABKAlIAlIIcGFzc3dvcmQSGwoIaWNvbl91cmwYBSABKAlIA1IHaWNvblVybEINCgtvbmVfb2ZfbmFtZUIOCgxvbmVfb2ZfZW1haWxCEQoPb25lX29mX3Bhc3N3b3JkQhEKD29uZV9vZl9pY29uX3VybA==');
+@$core.Deprecated('Use changePasswordParamsDescriptor instead')
+const ChangePasswordParams$json = const {
+  '1': 'ChangePasswordParams',
+  '2': const [
+    const {'1': 'old_password', '3': 1, '4': 1, '5': 9, '10': 'oldPassword'},
+    const {'1': 'new_password', '3': 2, '4': 1, '5': 9, '10': 'newPassword'},
+  ],
+};
+
+/// Descriptor for `ChangePasswordParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List changePasswordParamsDescriptor = $convert.base64Decode('ChRDaGFuZ2VQYXNzd29yZFBhcmFtcxIhCgxvbGRfcGFzc3dvcmQYASABKAlSC29sZFBhc3N3b3JkEiEKDG5ld19wYXNzd29yZBgCIAEoCVILbmV3UGFzc3dvcmQ=');
+@$core.Deprecated('Use deleteAccountParamsDescriptor instead')
+const DeleteAccountParams$json = const {
+  '1': 'DeleteAccountParams',
+  '2': const [
+    const {'1': 'password', '3': 1, '4': 1, '5': 9, '10': 'password'},
+  ],
+};
+
+/// Descriptor for `DeleteAccountParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List deleteAccountParamsDescriptor = $convert.base64Decode('ChNEZWxldGVBY2NvdW50UGFyYW1zEhoKCHBhc3N3b3JkGAEgASgJUghwYXNzd29yZA==');
+@$core.Deprecated('Use requestEmailChangeParamsDescriptor instead')
+const RequestEmailChangeParams$json = const {
+  '1': 'RequestEmailChangeParams',
+  '2': const [
+    const {'1': 'new_email', '3': 1, '4': 1, '5': 9, '10': 'newEmail'},
+  ],
+};
+
+/// Descriptor for `RequestEmailChangeParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List requestEmailChangeParamsDescriptor = $convert.base64Decode('ChhSZXF1ZXN0RW1haWxDaGFuZ2VQYXJhbXMSGwoJbmV3X2VtYWlsGAEgASgJUghuZXdFbWFpbA==');
+@$core.Deprecated('Use confirmEmailChangeParamsDescriptor instead')
+const ConfirmEmailChangeParams$json = const {
+  '1': 'ConfirmEmailChangeParams',
+  '2': const [
+    const {'1': 'code', '3': 1, '4': 1, '5': 9, '10': 'code'},
+  ],
+};
+
+/// Descriptor for `ConfirmEmailChangeParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List confirmEmailChangeParamsDescriptor = $convert.base64Decode('ChhDb25maXJtRW1haWxDaGFuZ2VQYXJhbXMSEgoEY29kZRgBIAEoCVIEY29kZQ==');
+@$core.Deprecated('Use confirmEmailChangeResponseDescriptor instead')
+const ConfirmEmailChangeResponse$json = const {
+  '1': 'ConfirmEmailChangeResponse',
+  '2': const [
+    const {'1': 'email', '3': 1, '4': 1, '5': 9, '10': 'email'},
+  ],
+};
+
+/// Descriptor for `ConfirmEmailChangeResponse`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List confirmEmailChangeResponseDescriptor = $convert.base64Decode('ChpDb25maXJtRW1haWxDaGFuZ2VSZXNwb25zZRIUCgVlbWFpbBgBIAEoCVIFZW1haWw=');
@@ -2,9 +2,11 @@ use crate::entities::SubscribeObject;
 use bytes::Bytes;
 use lazy_static::lazy_static;
 use std::{convert::TryInto, sync::RwLock};
+use tokio::sync::broadcast;
 
 lazy_static! {
     static ref DART_STREAM_SENDER: RwLock<DartStreamSender> = RwLock::new(DartStreamSender::new());
+    static ref SUBJECT_LISTENER: broadcast::Sender<SubscribeObject> = broadcast::channel(1000).0;
 }
 
 pub struct DartStreamSender {
@@ -42,14 +44,25 @@ impl DartStreamSender {
         }
     }
 
-    pub fn post(_observable_subject: SubscribeObject) -> Result<(), String> {
+    // Receives a copy of every posted subject, e.g. for the tests that can't
+    // listen on the Dart side.
+    pub fn subscribe() -> broadcast::Receiver<SubscribeObject> { SUBJECT_LISTENER.subscribe() }
+
+    pub fn post(observable_subject: SubscribeObject) -> Result<(), String> {
+        if SUBJECT_LISTENER.receiver_count() > 0 {
+            let _ = SUBJECT_LISTENER.send(observable_subject.clone());
+        }
+
         #[cfg(feature = "dart")]
         match DART_STREAM_SENDER.read() {
-            Ok(stream) => stream.inner_post(_observable_subject),
+            Ok(stream) => stream.inner_post(observable_subject),
             Err(e) => Err(format!("Get rust to flutter stream lock fail. {:?}", e)),
         }
 
         #[cfg(not(feature = "dart"))]
-        Ok(())
+        {
+            let _ = observable_subject;
+            Ok(())
+        }
     }
 }
@@ -1,15 +1,28 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use chrono::Utc;
 use lazy_static::lazy_static;
 use parking_lot::RwLock;
+use tokio::sync::broadcast;
 
-use flowy_collaboration::document::default::{initial_delta, initial_read_me};
+use flowy_collaboration::{
+    document::default::{initial_delta, initial_read_me},
+    entities::ws::CursorUpdate,
+    RichTextDelta,
+};
 use flowy_core_data_model::{entities::view::CreateViewParams, user_default};
+use flowy_database::SqliteConnection;
 use flowy_net::entities::NetworkType;
 
 use crate::{
-    entities::workspace::RepeatedWorkspace,
+    core::{DocumentLock, OfflineMode, ViewChangeEvent, ViewDataProcessor},
+    entities::{
+        view::{View, ViewId, ViewType},
+        workspace::RepeatedWorkspace,
+    },
     errors::{FlowyError, FlowyResult},
     module::{WorkspaceDatabase, WorkspaceUser},
     notify::{send_dart_notification, WorkspaceNotification},
@@ -56,12 +69,93 @@ impl CoreContext {
     }
 
     pub fn network_state_changed(&self, new_type: NetworkType) {
-        match new_type {
-            NetworkType::UnknownNetworkType => {},
-            NetworkType::Wifi => {},
-            NetworkType::Cell => {},
-            NetworkType::Ethernet => {},
-        }
+        self.view_controller.set_network_connected(new_type.is_connect());
+    }
+
+    pub fn set_offline_mode(&self, mode: OfflineMode) { self.view_controller.set_offline_mode(mode); }
+
+    // The views of the processor's ViewType are handled by it instead of the
+    // document processor.
+    pub fn register_view_data_processor(&self, processor: Arc<dyn ViewDataProcessor>) {
+        self.view_controller.register_view_data_processor(processor);
+    }
+
+    // The documents of the new views can start with the template's delta by
+    // setting the template of the CreateViewParams to its name.
+    pub fn register_template(&self, name: &str, delta: RichTextDelta) {
+        self.view_controller.register_template(name, delta);
+    }
+
+    pub fn list_templates(&self) -> Vec<String> { self.view_controller.list_templates() }
+
+    pub fn view_metrics(&self) -> ViewMetrics { self.view_controller.metrics_snapshot() }
+
+    // Reads the view without refreshing it from the server, e.g. for reading many
+    // views in a loop.
+    pub fn read_view_local(&self, view_id: &str) -> FlowyResult<View> {
+        let params = ViewId {
+            view_id: view_id.to_owned(),
+        };
+        self.view_controller.read_view_local(params)
+    }
+
+    // Reads the views in the order of the view_ids, fails if any of them doesn't
+    // exist.
+    pub fn read_views_local(&self, view_ids: &[String]) -> FlowyResult<Vec<View>> {
+        let views = self
+            .view_controller
+            .read_view_tables(view_ids.to_vec())?
+            .into_iter()
+            .map(|view_table| view_table.into())
+            .collect::<Vec<View>>();
+        Ok(views)
+    }
+
+    // Receives the change of any view, instead of the notifications that are
+    // keyed by the belong_to_id or the view id.
+    pub fn subscribe_any_view_change(&self) -> broadcast::Receiver<ViewChangeEvent> {
+        self.view_controller.subscribe_any_view_change()
+    }
+
+    // Returns the ids that are in the trash, the trash is read once for all of
+    // them.
+    pub fn filter_trashed(&self, ids: &[String]) -> FlowyResult<HashSet<String>> {
+        self.view_controller.filter_trashed(ids)
+    }
+
+    // Creates the view, or updates the one with the same view_id and keeps its
+    // document, so a creation that failed halfway can be run again.
+    pub async fn upsert_view(&self, params: CreateViewParams) -> FlowyResult<View> {
+        self.view_controller.upsert_view_from_params(params).await
+    }
+
+    // Runs the f within one immediate transaction of the current user's database,
+    // e.g. to compose the changes of the views and the trash atomically.
+    pub fn with_transaction<F, R>(&self, f: F) -> FlowyResult<R>
+    where
+        F: FnOnce(&SqliteConnection) -> FlowyResult<R>,
+    {
+        self.view_controller.with_transaction(f)
+    }
+
+    pub async fn convert_view_type(&self, view_id: &str, target: ViewType) -> FlowyResult<View> {
+        self.view_controller.convert_view_type(view_id, target).await
+    }
+
+    // The user's edits of the locked document are rejected with the ViewBusy
+    // error, e.g. while a tool is rewriting it. The document is unlocked when the
+    // returned lock is dropped.
+    pub fn lock_document(&self, doc_id: &str) -> FlowyResult<DocumentLock> {
+        self.view_controller.lock_document(doc_id)
+    }
+
+    pub fn broadcast_cursor(&self, doc_id: &str, position: usize) -> FlowyResult<()> {
+        self.view_controller.broadcast_cursor(doc_id, position)
+    }
+
+    // Receives the cursors of the other users that are editing the document.
+    pub fn subscribe_cursors(&self, doc_id: &str) -> broadcast::Receiver<CursorUpdate> {
+        self.view_controller.subscribe_cursors(doc_id)
     }
 
     pub async fn user_did_sign_in(&self, token: &str) -> FlowyResult<()> {
@@ -70,9 +164,7 @@ impl CoreContext {
         Ok(())
     }
 
-    pub async fn user_did_logout(&self) {
-        // TODO: (nathan) do something here
-    }
+    pub async fn user_did_logout(&self) { self.view_controller.close_all_views().await; }
 
     pub async fn user_session_expired(&self) {
         // TODO: (nathan) do something here
@@ -105,8 +197,12 @@ impl CoreContext {
                     view_type: view.view_type,
                     view_data,
                     view_id: view.id.clone(),
+                    insert_after: None,
+                    template: None,
+                    icon: view.icon,
                 };
-                let _ = self.view_controller.create_view_from_params(params).await?;
+                // The default views are created again if a previous sign up failed halfway.
+                let _ = self.view_controller.upsert_view_from_params(params).await?;
             }
         }
 
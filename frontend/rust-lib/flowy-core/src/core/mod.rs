@@ -2,4 +2,11 @@ mod aggregate_tasks;
 mod core_context;
 
 pub mod event_handler;
+pub use crate::services::view::{
+    locked_documents::DocumentLock,
+    metrics::ViewMetrics,
+    offline_mode::OfflineMode,
+    processor::ViewDataProcessor,
+    view_changes::{ViewChangeEvent, ViewChangeKind},
+};
 pub use core_context::*;
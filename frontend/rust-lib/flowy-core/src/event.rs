@@ -5,77 +5,170 @@ use strum_macros::Display;
 #[event_err = "FlowyError"]
 pub enum WorkspaceEvent {
     #[event(input = "CreateWorkspaceRequest", output = "Workspace")]
-    CreateWorkspace   = 0,
+    CreateWorkspace      = 0,
 
     #[event(output = "CurrentWorkspaceSetting")]
-    ReadCurWorkspace  = 1,
+    ReadCurWorkspace     = 1,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedWorkspace")]
-    ReadWorkspaces    = 2,
+    ReadWorkspaces       = 2,
 
     #[event(input = "QueryWorkspaceRequest")]
-    DeleteWorkspace   = 3,
+    DeleteWorkspace      = 3,
 
     #[event(input = "QueryWorkspaceRequest", output = "Workspace")]
-    OpenWorkspace     = 4,
+    OpenWorkspace        = 4,
 
     #[event(input = "QueryWorkspaceRequest", output = "RepeatedApp")]
-    ReadWorkspaceApps = 5,
+    ReadWorkspaceApps    = 5,
 
     #[event(input = "CreateAppRequest", output = "App")]
-    CreateApp         = 101,
+    CreateApp            = 101,
 
     #[event(input = "QueryAppRequest")]
-    DeleteApp         = 102,
+    DeleteApp            = 102,
 
     #[event(input = "QueryAppRequest", output = "App")]
-    ReadApp           = 103,
+    ReadApp              = 103,
 
     #[event(input = "UpdateAppRequest")]
-    UpdateApp         = 104,
+    UpdateApp            = 104,
 
     #[event(input = "CreateViewRequest", output = "View")]
-    CreateView        = 201,
+    CreateView           = 201,
 
     #[event(input = "QueryViewRequest", output = "View")]
-    ReadView          = 202,
+    ReadView             = 202,
 
     #[event(input = "UpdateViewRequest", output = "View")]
-    UpdateView        = 203,
+    UpdateView           = 203,
 
     #[event(input = "QueryViewRequest")]
-    DeleteView        = 204,
+    DeleteView           = 204,
 
     #[event(input = "QueryViewRequest")]
-    DuplicateView     = 205,
+    DuplicateView        = 205,
 
     #[event()]
-    CopyLink          = 206,
+    CopyLink             = 206,
 
     #[event(input = "QueryViewRequest", output = "DocumentDelta")]
-    OpenView          = 207,
+    OpenView             = 207,
 
     #[event(input = "QueryViewRequest")]
-    CloseView         = 208,
+    CloseView            = 208,
+
+    #[event(input = "QueryViewRequest", output = "DocumentDelta")]
+    OpenViewOfflineFirst = 209,
+
+    #[event(input = "QueryViewsBelongToRequest", output = "RepeatedView")]
+    ReadViewsBelongTo    = 210,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedView")]
+    PreviewDeleteView    = 211,
+
+    #[event(input = "RenameViewRequest", output = "View")]
+    RenameView           = 212,
+
+    #[event(input = "QueryViewRequest", output = "ViewSyncStatus")]
+    ReadViewSyncState    = 213,
+
+    #[event(input = "QueryViewRequest", output = "View")]
+    DuplicateViewRecursive = 214,
+
+    #[event(input = "QueryAppRequest")]
+    TrashViewsBelongTo   = 215,
+
+    #[event(output = "ConsistencyReport")]
+    VerifyViewsConsistency = 216,
+
+    #[event(input = "RepairViewsRequest", output = "RepairReport")]
+    RepairOrphanedViews  = 217,
+
+    #[event(output = "RepeatedViewId")]
+    ReadOpenDocumentIds  = 218,
+
+    #[event(output = "RepeatedDocumentDelta")]
+    RestoreOpenViews     = 219,
+
+    #[event(input = "SetViewReadonlyRequest", output = "View")]
+    SetViewReadonly      = 220,
+
+    #[event(output = "RepeatedView")]
+    ReadTrashedViews     = 221,
+
+    #[event(input = "QueryViewRequest", output = "View")]
+    RestoreView          = 222,
+
+    #[event(input = "QueryViewRequest", output = "RepeatedView")]
+    ReadViewAncestors    = 223,
+
+    #[event(input = "SetViewThumbnailRequest", output = "View")]
+    SetViewThumbnail     = 224,
+
+    #[event(input = "QueryViewRequest", output = "ViewThumbnail")]
+    ReadViewThumbnail    = 225,
+
+    #[event(input = "QueryViewsPageRequest", output = "ViewsPage")]
+    ReadViewsBelongToPaged = 226,
+
+    #[event(input = "QueryViewsCountRequest", output = "ViewCount")]
+    CountViewsBelongTo   = 227,
+
+    #[event(output = "RepeatedViewCount")]
+    CountViewsGroupedByParent = 228,
+
+    #[event(input = "MergeLocalRequest", output = "MergeReport")]
+    MergeLocalInto       = 229,
+
+    #[event(input = "ImportViewRequest", output = "View")]
+    ImportView           = 230,
+
+    #[event(input = "QueryViewsBelongToRequest", output = "RepeatedDuplicateViews")]
+    FindDuplicateViews   = 231,
+
+    #[event(input = "SetViewVisibilityRequest", output = "View")]
+    SetViewVisibility    = 232,
+
+    #[event(output = "RepeatedView")]
+    ReadAllUserViews     = 233,
+
+    #[event(input = "SetViewIconRequest", output = "View")]
+    SetViewIcon          = 234,
 
     #[event(output = "RepeatedTrash")]
-    ReadTrash         = 300,
+    ReadTrash            = 300,
 
     #[event(input = "TrashId")]
-    PutbackTrash      = 301,
+    PutbackTrash         = 301,
 
     #[event(input = "RepeatedTrashId")]
-    DeleteTrash       = 302,
+    DeleteTrash          = 302,
 
     #[event()]
-    RestoreAll        = 303,
+    RestoreAll           = 303,
 
     #[event()]
-    DeleteAll         = 304,
+    DeleteAll            = 304,
+
+    #[event(input = "DocumentDelta", output = "DocumentDeltaResult")]
+    ApplyDocDelta        = 400,
 
-    #[event(input = "DocumentDelta", output = "DocumentDelta")]
-    ApplyDocDelta     = 400,
+    #[event(input = "QueryViewRequest", output = "DocumentStats")]
+    ReadDocumentStats    = 401,
+
+    #[event(input = "QueryDocumentHistoryRequest", output = "RepeatedRevisionSummary")]
+    ReadDocumentHistory  = 402,
+
+    #[event(input = "RestoreDocumentRequest", output = "DocumentDelta")]
+    RestoreDocument      = 403,
+
+    #[event(input = "QueryViewRequest", output = "CompactReport")]
+    CompactDocument      = 404,
 
     #[event(input = "ExportRequest", output = "ExportData")]
-    ExportDocument    = 500,
+    ExportDocument       = 500,
+
+    #[event(input = "ExportAppRequest", output = "RepeatedExportData")]
+    ExportApp            = 501,
 }
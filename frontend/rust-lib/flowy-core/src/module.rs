@@ -23,16 +23,30 @@ use crate::{
     },
 };
 
+pub use crate::services::server::MOCK_UNAVAILABLE_VIEW_NAME;
+
+// The maximum number of the documents that are kept open, the least recently
+// used one will be closed if it's exceeded.
+pub const OPEN_VIEW_CAPACITY: usize = 20;
+
 pub trait WorkspaceDeps: WorkspaceUser + WorkspaceDatabase {}
 
 pub trait WorkspaceUser: Send + Sync {
     fn user_id(&self) -> Result<String, FlowyError>;
     fn token(&self) -> Result<String, FlowyError>;
+
+    // The token of the user that has a session on this device, it's not required
+    // to be the current user.
+    fn token_of(&self, user_id: &str) -> Result<String, FlowyError>;
 }
 
 pub trait WorkspaceDatabase: Send + Sync {
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
 
+    // Same as token_of, the database of the user isn't required to be the current
+    // user's.
+    fn db_pool_of(&self, user_id: &str) -> Result<Arc<ConnectionPool>, FlowyError>;
+
     fn db_connection(&self) -> Result<DBConnection, FlowyError> {
         let pool = self.db_pool()?;
         let conn = pool.get().map_err(|e| FlowyError::internal().context(e))?;
@@ -56,6 +70,7 @@ pub fn init_core(
         server.clone(),
         trash_controller.clone(),
         flowy_document,
+        OPEN_VIEW_CAPACITY,
     ));
 
     let app_controller = Arc::new(AppController::new(
@@ -113,7 +128,43 @@ pub fn create(core: Arc<CoreContext>) -> Module {
         .event(WorkspaceEvent::DuplicateView, duplicate_view_handler)
         .event(WorkspaceEvent::OpenView, open_view_handler)
         .event(WorkspaceEvent::CloseView, close_view_handler)
-        .event(WorkspaceEvent::ApplyDocDelta, document_delta_handler);
+        .event(WorkspaceEvent::OpenViewOfflineFirst, open_view_offline_first_handler)
+        .event(WorkspaceEvent::ReadViewsBelongTo, read_views_belong_to_handler)
+        .event(WorkspaceEvent::PreviewDeleteView, preview_delete_view_handler)
+        .event(WorkspaceEvent::RenameView, rename_view_handler)
+        .event(WorkspaceEvent::ReadViewSyncState, read_view_sync_state_handler)
+        .event(WorkspaceEvent::DuplicateViewRecursive, duplicate_view_recursive_handler)
+        .event(WorkspaceEvent::TrashViewsBelongTo, trash_views_belong_to_handler)
+        .event(WorkspaceEvent::VerifyViewsConsistency, verify_views_consistency_handler)
+        .event(WorkspaceEvent::RepairOrphanedViews, repair_orphaned_views_handler)
+        .event(WorkspaceEvent::ReadOpenDocumentIds, read_open_document_ids_handler)
+        .event(WorkspaceEvent::RestoreOpenViews, restore_open_views_handler)
+        .event(WorkspaceEvent::SetViewReadonly, set_view_readonly_handler)
+        .event(WorkspaceEvent::ReadTrashedViews, read_trashed_views_handler)
+        .event(WorkspaceEvent::RestoreView, restore_view_handler)
+        .event(WorkspaceEvent::ReadViewAncestors, read_view_ancestors_handler)
+        .event(WorkspaceEvent::SetViewThumbnail, set_view_thumbnail_handler)
+        .event(WorkspaceEvent::ReadViewThumbnail, read_view_thumbnail_handler)
+        .event(
+            WorkspaceEvent::ReadViewsBelongToPaged,
+            read_views_belong_to_paged_handler,
+        )
+        .event(WorkspaceEvent::CountViewsBelongTo, count_views_belong_to_handler)
+        .event(
+            WorkspaceEvent::CountViewsGroupedByParent,
+            count_views_grouped_by_parent_handler,
+        )
+        .event(WorkspaceEvent::MergeLocalInto, merge_local_into_handler)
+        .event(WorkspaceEvent::ImportView, import_view_handler)
+        .event(WorkspaceEvent::FindDuplicateViews, find_duplicate_views_handler)
+        .event(WorkspaceEvent::SetViewVisibility, set_view_visibility_handler)
+        .event(WorkspaceEvent::ReadAllUserViews, read_all_user_views_handler)
+        .event(WorkspaceEvent::SetViewIcon, set_view_icon_handler)
+        .event(WorkspaceEvent::ApplyDocDelta, document_delta_handler)
+        .event(WorkspaceEvent::ReadDocumentStats, document_stats_handler)
+        .event(WorkspaceEvent::ReadDocumentHistory, document_history_handler)
+        .event(WorkspaceEvent::RestoreDocument, restore_document_handler)
+        .event(WorkspaceEvent::CompactDocument, compact_document_handler);
 
     module = module
         .event(WorkspaceEvent::ReadTrash, read_trash_handler)
@@ -122,7 +173,9 @@ pub fn create(core: Arc<CoreContext>) -> Module {
         .event(WorkspaceEvent::RestoreAll, restore_all_handler)
         .event(WorkspaceEvent::DeleteAll, delete_all_handler);
 
-    module = module.event(WorkspaceEvent::ExportDocument, export_handler);
+    module = module
+        .event(WorkspaceEvent::ExportDocument, export_handler)
+        .event(WorkspaceEvent::ExportApp, export_app_handler);
 
     module
 }
@@ -14,9 +14,15 @@ pub(crate) enum WorkspaceNotification {
     WorkspaceAppsChanged = 14,
     AppUpdated           = 21,
     AppViewsChanged      = 24,
+    AppViewsDeltaChanged = 25,
+    AppsViewsChanged     = 26,
+    AppsViewsDeltaChanged = 27,
     ViewUpdated          = 31,
     ViewDeleted          = 32,
     ViewRestored         = 33,
+    ViewProgressChanged  = 34,
+    ViewSyncStateChanged = 35,
+    ViewContentRefreshed = 36,
     UserUnauthorized     = 100,
     TrashUpdated         = 1000,
 }
@@ -43,13 +43,44 @@ pub enum WorkspaceEvent {
     CopyLink = 206,
     OpenView = 207,
     CloseView = 208,
+    OpenViewOfflineFirst = 209,
+    ReadViewsBelongTo = 210,
+    PreviewDeleteView = 211,
+    RenameView = 212,
+    ReadViewSyncState = 213,
+    DuplicateViewRecursive = 214,
+    TrashViewsBelongTo = 215,
+    VerifyViewsConsistency = 216,
+    RepairOrphanedViews = 217,
+    ReadOpenDocumentIds = 218,
+    RestoreOpenViews = 219,
+    SetViewReadonly = 220,
+    ReadTrashedViews = 221,
+    RestoreView = 222,
+    ReadViewAncestors = 223,
+    SetViewThumbnail = 224,
+    ReadViewThumbnail = 225,
+    ReadViewsBelongToPaged = 226,
+    CountViewsBelongTo = 227,
+    CountViewsGroupedByParent = 228,
+    MergeLocalInto = 229,
+    ImportView = 230,
+    FindDuplicateViews = 231,
+    SetViewVisibility = 232,
+    ReadAllUserViews = 233,
+    SetViewIcon = 234,
     ReadTrash = 300,
     PutbackTrash = 301,
     DeleteTrash = 302,
     RestoreAll = 303,
     DeleteAll = 304,
     ApplyDocDelta = 400,
+    ReadDocumentStats = 401,
+    ReadDocumentHistory = 402,
+    RestoreDocument = 403,
+    CompactDocument = 404,
     ExportDocument = 500,
+    ExportApp = 501,
 }
 
 impl ::protobuf::ProtobufEnum for WorkspaceEvent {
@@ -77,13 +108,44 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             206 => ::std::option::Option::Some(WorkspaceEvent::CopyLink),
             207 => ::std::option::Option::Some(WorkspaceEvent::OpenView),
             208 => ::std::option::Option::Some(WorkspaceEvent::CloseView),
+            209 => ::std::option::Option::Some(WorkspaceEvent::OpenViewOfflineFirst),
+            210 => ::std::option::Option::Some(WorkspaceEvent::ReadViewsBelongTo),
+            211 => ::std::option::Option::Some(WorkspaceEvent::PreviewDeleteView),
+            212 => ::std::option::Option::Some(WorkspaceEvent::RenameView),
+            213 => ::std::option::Option::Some(WorkspaceEvent::ReadViewSyncState),
+            214 => ::std::option::Option::Some(WorkspaceEvent::DuplicateViewRecursive),
+            215 => ::std::option::Option::Some(WorkspaceEvent::TrashViewsBelongTo),
+            216 => ::std::option::Option::Some(WorkspaceEvent::VerifyViewsConsistency),
+            217 => ::std::option::Option::Some(WorkspaceEvent::RepairOrphanedViews),
+            218 => ::std::option::Option::Some(WorkspaceEvent::ReadOpenDocumentIds),
+            219 => ::std::option::Option::Some(WorkspaceEvent::RestoreOpenViews),
+            220 => ::std::option::Option::Some(WorkspaceEvent::SetViewReadonly),
+            221 => ::std::option::Option::Some(WorkspaceEvent::ReadTrashedViews),
+            222 => ::std::option::Option::Some(WorkspaceEvent::RestoreView),
+            223 => ::std::option::Option::Some(WorkspaceEvent::ReadViewAncestors),
+            224 => ::std::option::Option::Some(WorkspaceEvent::SetViewThumbnail),
+            225 => ::std::option::Option::Some(WorkspaceEvent::ReadViewThumbnail),
+            226 => ::std::option::Option::Some(WorkspaceEvent::ReadViewsBelongToPaged),
+            227 => ::std::option::Option::Some(WorkspaceEvent::CountViewsBelongTo),
+            228 => ::std::option::Option::Some(WorkspaceEvent::CountViewsGroupedByParent),
+            229 => ::std::option::Option::Some(WorkspaceEvent::MergeLocalInto),
+            230 => ::std::option::Option::Some(WorkspaceEvent::ImportView),
+            231 => ::std::option::Option::Some(WorkspaceEvent::FindDuplicateViews),
+            232 => ::std::option::Option::Some(WorkspaceEvent::SetViewVisibility),
+            233 => ::std::option::Option::Some(WorkspaceEvent::ReadAllUserViews),
+            234 => ::std::option::Option::Some(WorkspaceEvent::SetViewIcon),
             300 => ::std::option::Option::Some(WorkspaceEvent::ReadTrash),
             301 => ::std::option::Option::Some(WorkspaceEvent::PutbackTrash),
             302 => ::std::option::Option::Some(WorkspaceEvent::DeleteTrash),
             303 => ::std::option::Option::Some(WorkspaceEvent::RestoreAll),
             304 => ::std::option::Option::Some(WorkspaceEvent::DeleteAll),
             400 => ::std::option::Option::Some(WorkspaceEvent::ApplyDocDelta),
+            401 => ::std::option::Option::Some(WorkspaceEvent::ReadDocumentStats),
+            402 => ::std::option::Option::Some(WorkspaceEvent::ReadDocumentHistory),
+            403 => ::std::option::Option::Some(WorkspaceEvent::RestoreDocument),
+            404 => ::std::option::Option::Some(WorkspaceEvent::CompactDocument),
             500 => ::std::option::Option::Some(WorkspaceEvent::ExportDocument),
+            501 => ::std::option::Option::Some(WorkspaceEvent::ExportApp),
             _ => ::std::option::Option::None
         }
     }
@@ -108,13 +170,44 @@ impl ::protobuf::ProtobufEnum for WorkspaceEvent {
             WorkspaceEvent::CopyLink,
             WorkspaceEvent::OpenView,
             WorkspaceEvent::CloseView,
+            WorkspaceEvent::OpenViewOfflineFirst,
+            WorkspaceEvent::ReadViewsBelongTo,
+            WorkspaceEvent::PreviewDeleteView,
+            WorkspaceEvent::RenameView,
+            WorkspaceEvent::ReadViewSyncState,
+            WorkspaceEvent::DuplicateViewRecursive,
+            WorkspaceEvent::TrashViewsBelongTo,
+            WorkspaceEvent::VerifyViewsConsistency,
+            WorkspaceEvent::RepairOrphanedViews,
+            WorkspaceEvent::ReadOpenDocumentIds,
+            WorkspaceEvent::RestoreOpenViews,
+            WorkspaceEvent::SetViewReadonly,
+            WorkspaceEvent::ReadTrashedViews,
+            WorkspaceEvent::RestoreView,
+            WorkspaceEvent::ReadViewAncestors,
+            WorkspaceEvent::SetViewThumbnail,
+            WorkspaceEvent::ReadViewThumbnail,
+            WorkspaceEvent::ReadViewsBelongToPaged,
+            WorkspaceEvent::CountViewsBelongTo,
+            WorkspaceEvent::CountViewsGroupedByParent,
+            WorkspaceEvent::MergeLocalInto,
+            WorkspaceEvent::ImportView,
+            WorkspaceEvent::FindDuplicateViews,
+            WorkspaceEvent::SetViewVisibility,
+            WorkspaceEvent::ReadAllUserViews,
+            WorkspaceEvent::SetViewIcon,
             WorkspaceEvent::ReadTrash,
             WorkspaceEvent::PutbackTrash,
             WorkspaceEvent::DeleteTrash,
             WorkspaceEvent::RestoreAll,
             WorkspaceEvent::DeleteAll,
             WorkspaceEvent::ApplyDocDelta,
+            WorkspaceEvent::ReadDocumentStats,
+            WorkspaceEvent::ReadDocumentHistory,
+            WorkspaceEvent::RestoreDocument,
+            WorkspaceEvent::CompactDocument,
             WorkspaceEvent::ExportDocument,
+            WorkspaceEvent::ExportApp,
         ];
         values
     }
@@ -143,71 +236,147 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceEvent {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0bevent.proto*\xcb\x03\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorksp\
-    ace\x10\0\x12\x14\n\x10ReadCurWorkspace\x10\x01\x12\x12\n\x0eReadWorkspa\
-    ces\x10\x02\x12\x13\n\x0fDeleteWorkspace\x10\x03\x12\x11\n\rOpenWorkspac\
-    e\x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\r\n\tCreateApp\x10e\
+    \n\x0bevent.proto*\xa7\t\n\x0eWorkspaceEvent\x12\x13\n\x0fCreateWorkspac\
+    e\x10\0\x12\x14\n\x10ReadCurWorkspace\x10\x01\x12\x12\n\x0eReadWorkspace\
+    s\x10\x02\x12\x13\n\x0fDeleteWorkspace\x10\x03\x12\x11\n\rOpenWorkspace\
+    \x10\x04\x12\x15\n\x11ReadWorkspaceApps\x10\x05\x12\r\n\tCreateApp\x10e\
     \x12\r\n\tDeleteApp\x10f\x12\x0b\n\x07ReadApp\x10g\x12\r\n\tUpdateApp\
     \x10h\x12\x0f\n\nCreateView\x10\xc9\x01\x12\r\n\x08ReadView\x10\xca\x01\
     \x12\x0f\n\nUpdateView\x10\xcb\x01\x12\x0f\n\nDeleteView\x10\xcc\x01\x12\
     \x12\n\rDuplicateView\x10\xcd\x01\x12\r\n\x08CopyLink\x10\xce\x01\x12\r\
-    \n\x08OpenView\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\x0e\n\tR\
-    eadTrash\x10\xac\x02\x12\x11\n\x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0b\
-    DeleteTrash\x10\xae\x02\x12\x0f\n\nRestoreAll\x10\xaf\x02\x12\x0e\n\tDel\
-    eteAll\x10\xb0\x02\x12\x12\n\rApplyDocDelta\x10\x90\x03\x12\x13\n\x0eExp\
-    ortDocument\x10\xf4\x03J\xab\x08\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x1c\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x02\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x18\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x13\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x16\x17\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x19\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
-    \x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x13\n\x0c\n\x05\
-    \x05\0\x02\x03\x02\x12\x03\x06\x16\x17\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
-    \x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x14\x15\n\x0b\n\x04\x05\0\x02\x05\x12\
-    \x03\x08\x04\x1a\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x15\n\x0c\
-    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x18\x19\n\x0b\n\x04\x05\0\x02\x06\
-    \x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\r\n\x0c\n\
-    \x05\x05\0\x02\x06\x02\x12\x03\t\x10\x13\n\x0b\n\x04\x05\0\x02\x07\x12\
-    \x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\r\n\x0c\n\x05\
-    \x05\0\x02\x07\x02\x12\x03\n\x10\x13\n\x0b\n\x04\x05\0\x02\x08\x12\x03\
-    \x0b\x04\x12\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x0b\n\x0c\n\
-    \x05\x05\0\x02\x08\x02\x12\x03\x0b\x0e\x11\n\x0b\n\x04\x05\0\x02\t\x12\
-    \x03\x0c\x04\x14\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\r\n\x0c\n\
-    \x05\x05\0\x02\t\x02\x12\x03\x0c\x10\x13\n\x0b\n\x04\x05\0\x02\n\x12\x03\
-    \r\x04\x15\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x0e\n\x0c\n\x05\x05\
-    \0\x02\n\x02\x12\x03\r\x11\x14\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
-    \x13\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x0c\n\x0c\n\x05\x05\0\
-    \x02\x0b\x02\x12\x03\x0e\x0f\x12\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
-    \x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\x0c\x02\x12\x03\x0f\x11\x14\n\x0b\n\x04\x05\0\x02\r\x12\x03\
-    \x10\x04\x15\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\r\x02\x12\x03\x10\x11\x14\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\
-    \x11\x04\x18\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x11\n\x0c\n\
-    \x05\x05\0\x02\x0e\x02\x12\x03\x11\x14\x17\n\x0b\n\x04\x05\0\x02\x0f\x12\
-    \x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x0f\x12\n\x0b\n\x04\x05\0\x02\x10\
-    \x12\x03\x13\x04\x13\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x0f\x12\n\x0b\n\x04\x05\0\x02\
-    \x11\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x12\x12\x03\x15\x04\x14\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\r\
-    \n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\x10\x13\n\x0b\n\x04\x05\0\x02\
-    \x13\x12\x03\x16\x04\x17\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\
-    \x10\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\x13\x16\n\x0b\n\x04\x05\0\
-    \x02\x14\x12\x03\x17\x04\x16\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\
-    \x04\x0f\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x12\x15\n\x0b\n\x04\
-    \x05\0\x02\x15\x12\x03\x18\x04\x15\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\
-    \x18\x04\x0e\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x11\x14\n\x0b\n\
-    \x04\x05\0\x02\x16\x12\x03\x19\x04\x14\n\x0c\n\x05\x05\0\x02\x16\x01\x12\
-    \x03\x19\x04\r\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x10\x13\n\x0b\n\
-    \x04\x05\0\x02\x17\x12\x03\x1a\x04\x18\n\x0c\n\x05\x05\0\x02\x17\x01\x12\
-    \x03\x1a\x04\x11\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\x14\x17\n\x0b\
-    \n\x04\x05\0\x02\x18\x12\x03\x1b\x04\x19\n\x0c\n\x05\x05\0\x02\x18\x01\
-    \x12\x03\x1b\x04\x12\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\x1b\x15\x18b\
+    \n\x08OpenView\x10\xcf\x01\x12\x0e\n\tCloseView\x10\xd0\x01\x12\x19\n\
+    \x14OpenViewOfflineFirst\x10\xd1\x01\x12\x16\n\x11ReadViewsBelongTo\x10\
+    \xd2\x01\x12\x16\n\x11PreviewDeleteView\x10\xd3\x01\x12\x0f\n\nRenameVie\
+    w\x10\xd4\x01\x12\x16\n\x11ReadViewSyncState\x10\xd5\x01\x12\x1b\n\x16Du\
+    plicateViewRecursive\x10\xd6\x01\x12\x17\n\x12TrashViewsBelongTo\x10\xd7\
+    \x01\x12\x1b\n\x16VerifyViewsConsistency\x10\xd8\x01\x12\x18\n\x13Repair\
+    OrphanedViews\x10\xd9\x01\x12\x18\n\x13ReadOpenDocumentIds\x10\xda\x01\
+    \x12\x15\n\x10RestoreOpenViews\x10\xdb\x01\x12\x14\n\x0fSetViewReadonly\
+    \x10\xdc\x01\x12\x15\n\x10ReadTrashedViews\x10\xdd\x01\x12\x10\n\x0bRest\
+    oreView\x10\xde\x01\x12\x16\n\x11ReadViewAncestors\x10\xdf\x01\x12\x15\n\
+    \x10SetViewThumbnail\x10\xe0\x01\x12\x16\n\x11ReadViewThumbnail\x10\xe1\
+    \x01\x12\x1b\n\x16ReadViewsBelongToPaged\x10\xe2\x01\x12\x17\n\x12CountV\
+    iewsBelongTo\x10\xe3\x01\x12\x1e\n\x19CountViewsGroupedByParent\x10\xe4\
+    \x01\x12\x13\n\x0eMergeLocalInto\x10\xe5\x01\x12\x0f\n\nImportView\x10\
+    \xe6\x01\x12\x17\n\x12FindDuplicateViews\x10\xe7\x01\x12\x16\n\x11SetVie\
+    wVisibility\x10\xe8\x01\x12\x15\n\x10ReadAllUserViews\x10\xe9\x01\x12\
+    \x10\n\x0bSetViewIcon\x10\xea\x01\x12\x0e\n\tReadTrash\x10\xac\x02\x12\
+    \x11\n\x0cPutbackTrash\x10\xad\x02\x12\x10\n\x0bDeleteTrash\x10\xae\x02\
+    \x12\x0f\n\nRestoreAll\x10\xaf\x02\x12\x0e\n\tDeleteAll\x10\xb0\x02\x12\
+    \x12\n\rApplyDocDelta\x10\x90\x03\x12\x16\n\x11ReadDocumentStats\x10\x91\
+    \x03\x12\x18\n\x13ReadDocumentHistory\x10\x92\x03\x12\x14\n\x0fRestoreDo\
+    cument\x10\x93\x03\x12\x14\n\x0fCompactDocument\x10\x94\x03\x12\x13\n\
+    \x0eExportDocument\x10\xf4\x03\x12\x0e\n\tExportApp\x10\xf5\x03J\xa2\x12\
+    \n\x06\x12\x04\0\0;\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\
+    \x12\x04\x02\0;\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x13\n\x0b\n\x04\
+    \x05\0\x02\0\x12\x03\x03\x04\x18\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\
+    \x04\x13\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x16\x17\n\x0b\n\x04\x05\
+    \0\x02\x01\x12\x03\x04\x04\x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
+    \x04\x14\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\
+    \x05\0\x02\x02\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
+    \x05\x04\x12\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\
+    \x04\x05\0\x02\x03\x12\x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
+    \x03\x06\x04\x13\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x16\x17\n\x0b\
+    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x01\
+    \x12\x03\x07\x04\x11\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x14\x15\n\
+    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x1a\n\x0c\n\x05\x05\0\x02\x05\
+    \x01\x12\x03\x08\x04\x15\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x18\
+    \x19\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\
+    \x06\x01\x12\x03\t\x04\r\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x10\x13\
+    \n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x14\n\x0c\n\x05\x05\0\x02\x07\
+    \x01\x12\x03\n\x04\r\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x10\x13\n\
+    \x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x12\n\x0c\n\x05\x05\0\x02\x08\
+    \x01\x12\x03\x0b\x04\x0b\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\x0e\
+    \x11\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x14\n\x0c\n\x05\x05\0\x02\t\
+    \x01\x12\x03\x0c\x04\r\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\x10\x13\n\
+    \x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x15\n\x0c\n\x05\x05\0\x02\n\x01\x12\
+    \x03\r\x04\x0e\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x11\x14\n\x0b\n\x04\
+    \x05\0\x02\x0b\x12\x03\x0e\x04\x13\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\
+    \x0e\x04\x0c\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x0f\x12\n\x0b\n\
+    \x04\x05\0\x02\x0c\x12\x03\x0f\x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\
+    \x03\x0f\x04\x0e\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\x11\x14\n\x0b\
+    \n\x04\x05\0\x02\r\x12\x03\x10\x04\x15\n\x0c\n\x05\x05\0\x02\r\x01\x12\
+    \x03\x10\x04\x0e\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\x11\x14\n\x0b\n\
+    \x04\x05\0\x02\x0e\x12\x03\x11\x04\x18\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\
+    \x03\x11\x04\x11\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\x11\x14\x17\n\x0b\
+    \n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x01\
+    \x12\x03\x12\x04\x0c\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x0f\x12\n\
+    \x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x13\n\x0c\n\x05\x05\0\x02\x10\
+    \x01\x12\x03\x13\x04\x0c\n\x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x0f\
+    \x12\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\x02\
+    \x11\x01\x12\x03\x14\x04\r\n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x10\
+    \x13\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04\x1f\n\x0c\n\x05\x05\0\x02\
+    \x12\x01\x12\x03\x15\x04\x18\n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\
+    \x1b\x1e\n\x0b\n\x04\x05\0\x02\x13\x12\x03\x16\x04\x1c\n\x0c\n\x05\x05\0\
+    \x02\x13\x01\x12\x03\x16\x04\x15\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\
+    \x16\x18\x1b\n\x0b\n\x04\x05\0\x02\x14\x12\x03\x17\x04\x1c\n\x0c\n\x05\
+    \x05\0\x02\x14\x01\x12\x03\x17\x04\x15\n\x0c\n\x05\x05\0\x02\x14\x02\x12\
+    \x03\x17\x18\x1b\n\x0b\n\x04\x05\0\x02\x15\x12\x03\x18\x04\x15\n\x0c\n\
+    \x05\x05\0\x02\x15\x01\x12\x03\x18\x04\x0e\n\x0c\n\x05\x05\0\x02\x15\x02\
+    \x12\x03\x18\x11\x14\n\x0b\n\x04\x05\0\x02\x16\x12\x03\x19\x04\x1c\n\x0c\
+    \n\x05\x05\0\x02\x16\x01\x12\x03\x19\x04\x15\n\x0c\n\x05\x05\0\x02\x16\
+    \x02\x12\x03\x19\x18\x1b\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\x04!\n\
+    \x0c\n\x05\x05\0\x02\x17\x01\x12\x03\x1a\x04\x1a\n\x0c\n\x05\x05\0\x02\
+    \x17\x02\x12\x03\x1a\x1d\x20\n\x0b\n\x04\x05\0\x02\x18\x12\x03\x1b\x04\
+    \x1d\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03\x1b\x04\x16\n\x0c\n\x05\x05\0\
+    \x02\x18\x02\x12\x03\x1b\x19\x1c\n\x0b\n\x04\x05\0\x02\x19\x12\x03\x1c\
+    \x04!\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03\x1c\x04\x1a\n\x0c\n\x05\x05\
+    \0\x02\x19\x02\x12\x03\x1c\x1d\x20\n\x0b\n\x04\x05\0\x02\x1a\x12\x03\x1d\
+    \x04\x1e\n\x0c\n\x05\x05\0\x02\x1a\x01\x12\x03\x1d\x04\x17\n\x0c\n\x05\
+    \x05\0\x02\x1a\x02\x12\x03\x1d\x1a\x1d\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\
+    \x1e\x04\x1e\n\x0c\n\x05\x05\0\x02\x1b\x01\x12\x03\x1e\x04\x17\n\x0c\n\
+    \x05\x05\0\x02\x1b\x02\x12\x03\x1e\x1a\x1d\n\x0b\n\x04\x05\0\x02\x1c\x12\
+    \x03\x1f\x04\x1b\n\x0c\n\x05\x05\0\x02\x1c\x01\x12\x03\x1f\x04\x14\n\x0c\
+    \n\x05\x05\0\x02\x1c\x02\x12\x03\x1f\x17\x1a\n\x0b\n\x04\x05\0\x02\x1d\
+    \x12\x03\x20\x04\x1a\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03\x20\x04\x13\n\
+    \x0c\n\x05\x05\0\x02\x1d\x02\x12\x03\x20\x16\x19\n\x0b\n\x04\x05\0\x02\
+    \x1e\x12\x03!\x04\x1b\n\x0c\n\x05\x05\0\x02\x1e\x01\x12\x03!\x04\x14\n\
+    \x0c\n\x05\x05\0\x02\x1e\x02\x12\x03!\x17\x1a\n\x0b\n\x04\x05\0\x02\x1f\
+    \x12\x03\"\x04\x16\n\x0c\n\x05\x05\0\x02\x1f\x01\x12\x03\"\x04\x0f\n\x0c\
+    \n\x05\x05\0\x02\x1f\x02\x12\x03\"\x12\x15\n\x0b\n\x04\x05\0\x02\x20\x12\
+    \x03#\x04\x1c\n\x0c\n\x05\x05\0\x02\x20\x01\x12\x03#\x04\x15\n\x0c\n\x05\
+    \x05\0\x02\x20\x02\x12\x03#\x18\x1b\n\x0b\n\x04\x05\0\x02!\x12\x03$\x04\
+    \x1b\n\x0c\n\x05\x05\0\x02!\x01\x12\x03$\x04\x14\n\x0c\n\x05\x05\0\x02!\
+    \x02\x12\x03$\x17\x1a\n\x0b\n\x04\x05\0\x02\"\x12\x03%\x04\x1c\n\x0c\n\
+    \x05\x05\0\x02\"\x01\x12\x03%\x04\x15\n\x0c\n\x05\x05\0\x02\"\x02\x12\
+    \x03%\x18\x1b\n\x0b\n\x04\x05\0\x02#\x12\x03&\x04!\n\x0c\n\x05\x05\0\x02\
+    #\x01\x12\x03&\x04\x1a\n\x0c\n\x05\x05\0\x02#\x02\x12\x03&\x1d\x20\n\x0b\
+    \n\x04\x05\0\x02$\x12\x03'\x04\x1d\n\x0c\n\x05\x05\0\x02$\x01\x12\x03'\
+    \x04\x16\n\x0c\n\x05\x05\0\x02$\x02\x12\x03'\x19\x1c\n\x0b\n\x04\x05\0\
+    \x02%\x12\x03(\x04$\n\x0c\n\x05\x05\0\x02%\x01\x12\x03(\x04\x1d\n\x0c\n\
+    \x05\x05\0\x02%\x02\x12\x03(\x20#\n\x0b\n\x04\x05\0\x02&\x12\x03)\x04\
+    \x19\n\x0c\n\x05\x05\0\x02&\x01\x12\x03)\x04\x12\n\x0c\n\x05\x05\0\x02&\
+    \x02\x12\x03)\x15\x18\n\x0b\n\x04\x05\0\x02'\x12\x03*\x04\x15\n\x0c\n\
+    \x05\x05\0\x02'\x01\x12\x03*\x04\x0e\n\x0c\n\x05\x05\0\x02'\x02\x12\x03*\
+    \x11\x14\n\x0b\n\x04\x05\0\x02(\x12\x03+\x04\x1d\n\x0c\n\x05\x05\0\x02(\
+    \x01\x12\x03+\x04\x16\n\x0c\n\x05\x05\0\x02(\x02\x12\x03+\x19\x1c\n\x0b\
+    \n\x04\x05\0\x02)\x12\x03,\x04\x1c\n\x0c\n\x05\x05\0\x02)\x01\x12\x03,\
+    \x04\x15\n\x0c\n\x05\x05\0\x02)\x02\x12\x03,\x18\x1b\n\x0b\n\x04\x05\0\
+    \x02*\x12\x03-\x04\x1b\n\x0c\n\x05\x05\0\x02*\x01\x12\x03-\x04\x14\n\x0c\
+    \n\x05\x05\0\x02*\x02\x12\x03-\x17\x1a\n\x0b\n\x04\x05\0\x02+\x12\x03.\
+    \x04\x16\n\x0c\n\x05\x05\0\x02+\x01\x12\x03.\x04\x0f\n\x0c\n\x05\x05\0\
+    \x02+\x02\x12\x03.\x12\x15\n\x0b\n\x04\x05\0\x02,\x12\x03/\x04\x14\n\x0c\
+    \n\x05\x05\0\x02,\x01\x12\x03/\x04\r\n\x0c\n\x05\x05\0\x02,\x02\x12\x03/\
+    \x10\x13\n\x0b\n\x04\x05\0\x02-\x12\x030\x04\x17\n\x0c\n\x05\x05\0\x02-\
+    \x01\x12\x030\x04\x10\n\x0c\n\x05\x05\0\x02-\x02\x12\x030\x13\x16\n\x0b\
+    \n\x04\x05\0\x02.\x12\x031\x04\x16\n\x0c\n\x05\x05\0\x02.\x01\x12\x031\
+    \x04\x0f\n\x0c\n\x05\x05\0\x02.\x02\x12\x031\x12\x15\n\x0b\n\x04\x05\0\
+    \x02/\x12\x032\x04\x15\n\x0c\n\x05\x05\0\x02/\x01\x12\x032\x04\x0e\n\x0c\
+    \n\x05\x05\0\x02/\x02\x12\x032\x11\x14\n\x0b\n\x04\x05\0\x020\x12\x033\
+    \x04\x14\n\x0c\n\x05\x05\0\x020\x01\x12\x033\x04\r\n\x0c\n\x05\x05\0\x02\
+    0\x02\x12\x033\x10\x13\n\x0b\n\x04\x05\0\x021\x12\x034\x04\x18\n\x0c\n\
+    \x05\x05\0\x021\x01\x12\x034\x04\x11\n\x0c\n\x05\x05\0\x021\x02\x12\x034\
+    \x14\x17\n\x0b\n\x04\x05\0\x022\x12\x035\x04\x1c\n\x0c\n\x05\x05\0\x022\
+    \x01\x12\x035\x04\x15\n\x0c\n\x05\x05\0\x022\x02\x12\x035\x18\x1b\n\x0b\
+    \n\x04\x05\0\x023\x12\x036\x04\x1e\n\x0c\n\x05\x05\0\x023\x01\x12\x036\
+    \x04\x17\n\x0c\n\x05\x05\0\x023\x02\x12\x036\x1a\x1d\n\x0b\n\x04\x05\0\
+    \x024\x12\x037\x04\x1a\n\x0c\n\x05\x05\0\x024\x01\x12\x037\x04\x13\n\x0c\
+    \n\x05\x05\0\x024\x02\x12\x037\x16\x19\n\x0b\n\x04\x05\0\x025\x12\x038\
+    \x04\x1a\n\x0c\n\x05\x05\0\x025\x01\x12\x038\x04\x13\n\x0c\n\x05\x05\0\
+    \x025\x02\x12\x038\x16\x19\n\x0b\n\x04\x05\0\x026\x12\x039\x04\x19\n\x0c\
+    \n\x05\x05\0\x026\x01\x12\x039\x04\x12\n\x0c\n\x05\x05\0\x026\x02\x12\
+    \x039\x15\x18\n\x0b\n\x04\x05\0\x027\x12\x03:\x04\x14\n\x0c\n\x05\x05\0\
+    \x027\x01\x12\x03:\x04\r\n\x0c\n\x05\x05\0\x027\x02\x12\x03:\x10\x13b\
     \x06proto3\
 ";
 
@@ -33,9 +33,15 @@ pub enum WorkspaceNotification {
     WorkspaceAppsChanged = 14,
     AppUpdated = 21,
     AppViewsChanged = 24,
+    AppViewsDeltaChanged = 25,
+    AppsViewsChanged = 26,
+    AppsViewsDeltaChanged = 27,
     ViewUpdated = 31,
     ViewDeleted = 32,
     ViewRestored = 33,
+    ViewProgressChanged = 34,
+    ViewSyncStateChanged = 35,
+    ViewContentRefreshed = 36,
     UserUnauthorized = 100,
     TrashUpdated = 1000,
 }
@@ -55,9 +61,15 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             14 => ::std::option::Option::Some(WorkspaceNotification::WorkspaceAppsChanged),
             21 => ::std::option::Option::Some(WorkspaceNotification::AppUpdated),
             24 => ::std::option::Option::Some(WorkspaceNotification::AppViewsChanged),
+            25 => ::std::option::Option::Some(WorkspaceNotification::AppViewsDeltaChanged),
+            26 => ::std::option::Option::Some(WorkspaceNotification::AppsViewsChanged),
+            27 => ::std::option::Option::Some(WorkspaceNotification::AppsViewsDeltaChanged),
             31 => ::std::option::Option::Some(WorkspaceNotification::ViewUpdated),
             32 => ::std::option::Option::Some(WorkspaceNotification::ViewDeleted),
             33 => ::std::option::Option::Some(WorkspaceNotification::ViewRestored),
+            34 => ::std::option::Option::Some(WorkspaceNotification::ViewProgressChanged),
+            35 => ::std::option::Option::Some(WorkspaceNotification::ViewSyncStateChanged),
+            36 => ::std::option::Option::Some(WorkspaceNotification::ViewContentRefreshed),
             100 => ::std::option::Option::Some(WorkspaceNotification::UserUnauthorized),
             1000 => ::std::option::Option::Some(WorkspaceNotification::TrashUpdated),
             _ => ::std::option::Option::None
@@ -74,9 +86,15 @@ impl ::protobuf::ProtobufEnum for WorkspaceNotification {
             WorkspaceNotification::WorkspaceAppsChanged,
             WorkspaceNotification::AppUpdated,
             WorkspaceNotification::AppViewsChanged,
+            WorkspaceNotification::AppViewsDeltaChanged,
+            WorkspaceNotification::AppsViewsChanged,
+            WorkspaceNotification::AppsViewsDeltaChanged,
             WorkspaceNotification::ViewUpdated,
             WorkspaceNotification::ViewDeleted,
             WorkspaceNotification::ViewRestored,
+            WorkspaceNotification::ViewProgressChanged,
+            WorkspaceNotification::ViewSyncStateChanged,
+            WorkspaceNotification::ViewContentRefreshed,
             WorkspaceNotification::UserUnauthorized,
             WorkspaceNotification::TrashUpdated,
         ];
@@ -107,42 +125,58 @@ impl ::protobuf::reflect::ProtobufValue for WorkspaceNotification {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\xa2\x02\n\x15WorkspaceNotification\x12\x0b\n\x07\
+    \n\x10observable.proto*\xba\x03\n\x15WorkspaceNotification\x12\x0b\n\x07\
     Unknown\x10\0\x12\x17\n\x13UserCreateWorkspace\x10\n\x12\x17\n\x13UserDe\
     leteWorkspace\x10\x0b\x12\x14\n\x10WorkspaceUpdated\x10\x0c\x12\x18\n\
     \x14WorkspaceListUpdated\x10\r\x12\x18\n\x14WorkspaceAppsChanged\x10\x0e\
     \x12\x0e\n\nAppUpdated\x10\x15\x12\x13\n\x0fAppViewsChanged\x10\x18\x12\
-    \x0f\n\x0bViewUpdated\x10\x1f\x12\x0f\n\x0bViewDeleted\x10\x20\x12\x10\n\
-    \x0cViewRestored\x10!\x12\x14\n\x10UserUnauthorized\x10d\x12\x11\n\x0cTr\
-    ashUpdated\x10\xe8\x07J\xbf\x04\n\x06\x12\x04\0\0\x10\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x10\x01\n\n\n\x03\x05\
-    \0\x01\x12\x03\x02\x05\x1a\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x10\n\
-    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\x0c\n\x05\x05\0\x02\0\
-    \x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\x1d\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x01\x02\x12\x03\x04\x1a\x1c\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\x04\
-    \x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\
-    \x02\x02\x02\x12\x03\x05\x1a\x1c\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x06\
-    \x04\x1a\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x14\n\x0c\n\x05\
-    \x05\0\x02\x03\x02\x12\x03\x06\x17\x19\n\x0b\n\x04\x05\0\x02\x04\x12\x03\
-    \x07\x04\x1e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x18\n\x0c\n\
-    \x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1d\n\x0b\n\x04\x05\0\x02\x05\x12\
-    \x03\x08\x04\x1e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x18\n\x0c\
-    \n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\x1d\n\x0b\n\x04\x05\0\x02\x06\
-    \x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\x0e\n\x0c\
-    \n\x05\x05\0\x02\x06\x02\x12\x03\t\x11\x13\n\x0b\n\x04\x05\0\x02\x07\x12\
-    \x03\n\x04\x19\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x07\x02\x12\x03\n\x16\x18\n\x0b\n\x04\x05\0\x02\x08\x12\
-    \x03\x0b\x04\x15\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x0f\n\x0c\
-    \n\x05\x05\0\x02\x08\x02\x12\x03\x0b\x12\x14\n\x0b\n\x04\x05\0\x02\t\x12\
-    \x03\x0c\x04\x15\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x0f\n\x0c\n\
-    \x05\x05\0\x02\t\x02\x12\x03\x0c\x12\x14\n\x0b\n\x04\x05\0\x02\n\x12\x03\
-    \r\x04\x16\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x10\n\x0c\n\x05\x05\
-    \0\x02\n\x02\x12\x03\r\x13\x15\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
-    \x1b\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x05\0\
-    \x02\x0b\x02\x12\x03\x0e\x17\x1a\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
-    \x04\x18\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x10\n\x0c\n\x05\
-    \x05\0\x02\x0c\x02\x12\x03\x0f\x13\x17b\x06proto3\
+    \x18\n\x14AppViewsDeltaChanged\x10\x19\x12\x14\n\x10AppsViewsChanged\x10\
+    \x1a\x12\x19\n\x15AppsViewsDeltaChanged\x10\x1b\x12\x0f\n\x0bViewUpdated\
+    \x10\x1f\x12\x0f\n\x0bViewDeleted\x10\x20\x12\x10\n\x0cViewRestored\x10!\
+    \x12\x17\n\x13ViewProgressChanged\x10\"\x12\x18\n\x14ViewSyncStateChange\
+    d\x10#\x12\x18\n\x14ViewContentRefreshed\x10$\x12\x14\n\x10UserUnauthori\
+    zed\x10d\x12\x11\n\x0cTrashUpdated\x10\xe8\x07J\xb5\x06\n\x06\x12\x04\0\
+    \0\x16\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\
+    \x16\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x1a\n\x0b\n\x04\x05\0\x02\0\
+    \x12\x03\x03\x04\x10\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0b\n\
+    \x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\
+    \x12\x03\x04\x04\x1d\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\
+    \x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x1a\x1c\n\x0b\n\x04\x05\0\x02\
+    \x02\x12\x03\x05\x04\x1d\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\
+    \x17\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x1a\x1c\n\x0b\n\x04\x05\0\
+    \x02\x03\x12\x03\x06\x04\x1a\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\
+    \x04\x14\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x17\x19\n\x0b\n\x04\
+    \x05\0\x02\x04\x12\x03\x07\x04\x1e\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\
+    \x07\x04\x18\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1d\n\x0b\n\
+    \x04\x05\0\x02\x05\x12\x03\x08\x04\x1e\n\x0c\n\x05\x05\0\x02\x05\x01\x12\
+    \x03\x08\x04\x18\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\x1d\n\x0b\
+    \n\x04\x05\0\x02\x06\x12\x03\t\x04\x14\n\x0c\n\x05\x05\0\x02\x06\x01\x12\
+    \x03\t\x04\x0e\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x11\x13\n\x0b\n\
+    \x04\x05\0\x02\x07\x12\x03\n\x04\x19\n\x0c\n\x05\x05\0\x02\x07\x01\x12\
+    \x03\n\x04\x13\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x16\x18\n\x0b\n\
+    \x04\x05\0\x02\x08\x12\x03\x0b\x04\x1e\n\x0c\n\x05\x05\0\x02\x08\x01\x12\
+    \x03\x0b\x04\x18\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\x1b\x1d\n\x0b\
+    \n\x04\x05\0\x02\t\x12\x03\x0c\x04\x1a\n\x0c\n\x05\x05\0\x02\t\x01\x12\
+    \x03\x0c\x04\x14\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\x17\x19\n\x0b\n\
+    \x04\x05\0\x02\n\x12\x03\r\x04\x1f\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\
+    \x04\x19\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x1c\x1e\n\x0b\n\x04\x05\0\
+    \x02\x0b\x12\x03\x0e\x04\x15\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\
+    \x04\x0f\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x12\x14\n\x0b\n\x04\
+    \x05\0\x02\x0c\x12\x03\x0f\x04\x15\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\
+    \x0f\x04\x0f\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\x12\x14\n\x0b\n\
+    \x04\x05\0\x02\r\x12\x03\x10\x04\x16\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\
+    \x10\x04\x10\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\x13\x15\n\x0b\n\x04\
+    \x05\0\x02\x0e\x12\x03\x11\x04\x1d\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\
+    \x11\x04\x17\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\x11\x1a\x1c\n\x0b\n\
+    \x04\x05\0\x02\x0f\x12\x03\x12\x04\x1e\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\
+    \x03\x12\x04\x18\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x1b\x1d\n\x0b\
+    \n\x04\x05\0\x02\x10\x12\x03\x13\x04\x1e\n\x0c\n\x05\x05\0\x02\x10\x01\
+    \x12\x03\x13\x04\x18\n\x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x1b\x1d\n\
+    \x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04\x1b\n\x0c\n\x05\x05\0\x02\x11\
+    \x01\x12\x03\x14\x04\x14\n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x17\
+    \x1a\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04\x18\n\x0c\n\x05\x05\0\x02\
+    \x12\x01\x12\x03\x15\x04\x10\n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\
+    \x13\x17b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
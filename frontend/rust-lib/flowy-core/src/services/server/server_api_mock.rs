@@ -2,7 +2,7 @@ use crate::{
     entities::{
         app::{App, AppId, CreateAppParams, RepeatedApp, UpdateAppParams},
         trash::{RepeatedTrash, RepeatedTrashId},
-        view::{CreateViewParams, RepeatedView, RepeatedViewId, UpdateViewParams, View, ViewId},
+        view::{CreateViewParams, RepeatedView, RepeatedViewId, UpdateViewParams, View, ViewId, ViewVisibility},
         workspace::{CreateWorkspaceParams, RepeatedWorkspace, UpdateWorkspaceParams, Workspace, WorkspaceId},
     },
     errors::FlowyError,
@@ -10,6 +10,9 @@ use crate::{
 };
 use lib_infra::{future::FutureResult, timestamp, uuid_string};
 
+// Creating the view of this name fails as if the server is unavailable.
+pub const MOCK_UNAVAILABLE_VIEW_NAME: &str = "mock unavailable view";
+
 pub struct WorkspaceServerMock {}
 
 impl WorkspaceServerAPI for WorkspaceServerMock {
@@ -45,6 +48,9 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
     }
 
     fn create_view(&self, _token: &str, params: CreateViewParams) -> FutureResult<View, FlowyError> {
+        if params.name == MOCK_UNAVAILABLE_VIEW_NAME {
+            return FutureResult::new(async { Err(FlowyError::server_unavailable()) });
+        }
         let time = timestamp();
         let view = View {
             id: params.view_id,
@@ -56,6 +62,10 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             belongings: RepeatedView::default(),
             modified_time: time,
             create_time: time,
+            owner_id: "".to_owned(),
+            visibility: ViewVisibility::default(),
+            created_by: "".to_owned(),
+            icon: params.icon,
         };
         FutureResult::new(async { Ok(view) })
     }
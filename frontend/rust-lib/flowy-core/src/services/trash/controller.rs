@@ -187,6 +187,16 @@ impl TrashController {
             .collect::<Vec<String>>();
         Ok(ids)
     }
+
+    pub fn read_trash_ids_with_type(&self, ty: TrashType, conn: &SqliteConnection) -> Result<Vec<String>, FlowyError> {
+        let ids = TrashTableSql::read_all(&*conn)?
+            .into_inner()
+            .into_iter()
+            .filter(|item| item.ty == ty)
+            .map(|item| item.id)
+            .collect::<Vec<String>>();
+        Ok(ids)
+    }
 }
 
 impl TrashController {
@@ -314,4 +324,20 @@ impl TrashEvent {
             },
         }
     }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TrashEvent::NewTrash(_, _) => "new_trash",
+            TrashEvent::Putback(_, _) => "putback",
+            TrashEvent::Delete(_, _) => "delete",
+        }
+    }
+
+    pub fn identifier_count(&self) -> usize {
+        match self {
+            TrashEvent::NewTrash(identifiers, _) => identifiers.items.len(),
+            TrashEvent::Putback(identifiers, _) => identifiers.items.len(),
+            TrashEvent::Delete(identifiers, _) => identifiers.items.len(),
+        }
+    }
 }
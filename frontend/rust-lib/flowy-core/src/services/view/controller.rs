@@ -1,33 +1,101 @@
 use bytes::Bytes;
 use flowy_collaboration::entities::{
-    doc::{DocumentDelta, DocumentId},
-    revision::{RepeatedRevision, Revision},
+    doc::{DocumentDelta, DocumentDeltaResult, DocumentId, RepeatedDocumentDelta},
+    revision::{CompactReport, RepeatedRevision, Revision, RevisionSummary},
+    ws::CursorUpdate,
 };
 use flowy_database::SqliteConnection;
 use futures::{FutureExt, StreamExt};
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
+use tokio::sync::{broadcast, mpsc};
+use tracing::Instrument;
 
 use crate::{
     entities::{
-        trash::{RepeatedTrashId, TrashType},
-        view::{CreateViewParams, RepeatedView, UpdateViewParams, View, ViewId},
+        app::{ColorStyle, CreateAppParams},
+        trash::{RepeatedTrashId, Trash, TrashType},
+        view::{
+            AppViews,
+            AppViewsDelta,
+            ConsistencyReport,
+            CreateViewParams,
+            CreateViewParamsBuilder,
+            CreateViewResult,
+            DocumentStats,
+            MergeReport,
+            RepairReport,
+            RepairStrategy,
+            RepeatedAppViews,
+            RepeatedAppViewsDelta,
+            RepeatedView,
+            UpdateViewParams,
+            View,
+            ViewId,
+            ViewProgress,
+            ViewSyncState,
+            ViewSyncStatus,
+            ViewType,
+            ViewVisibility,
+        },
     },
-    errors::{FlowyError, FlowyResult},
+    errors::{internal_error, ErrorCode, FlowyError, FlowyResult},
     module::{WorkspaceDatabase, WorkspaceUser},
-    notify::{send_dart_notification, WorkspaceNotification},
+    notify::{send_anonymous_dart_notification, send_dart_notification, WorkspaceNotification},
     services::{
+        app::sql::{AppTable, AppTableSql},
         server::Server,
-        view::sql::{ViewTable, ViewTableChangeset, ViewTableSql},
+        view::{
+            in_flight_reads::InFlightReads,
+            locked_documents::{DocumentLock, LockedDocuments},
+            metrics::{ViewMetrics, ViewMetricsCounter},
+            offline_mode::{OfflineMode, OfflineState},
+            open_views::OpenViewLRU,
+            processor::{DocumentViewDataProcessor, ViewDataProcessor, ViewDataProcessorMap},
+            server_fetches::ServerFetches,
+            sql::{
+                ViewTable,
+                ViewTableChangeset,
+                ViewTableSql,
+                ViewTableType,
+                ViewThumbnailTable,
+                ViewThumbnailTableSql,
+            },
+            sync_states::ViewSyncStates,
+            templates::ViewTemplates,
+            view_changes::{ViewChangeEvent, ViewChangeKind, ViewChangeNotifier},
+        },
+        workspace::sql::WorkspaceTableSql,
         TrashController,
         TrashEvent,
     },
+    util::RetryAction,
 };
-use flowy_core_data_model::entities::share::{ExportData, ExportParams};
+use flowy_collaboration::{document::default::initial_delta_string, util::md5};
+use flowy_core_data_model::entities::share::{ExportData, ExportParams, ExportType};
 use flowy_database::kv::KV;
 use flowy_document::context::DocumentContext;
-use lib_infra::uuid_string;
+use lib_infra::{
+    retry::{FixedInterval, Retry},
+    timestamp,
+};
+use lib_ot::{
+    core::{validate, Operation},
+    rich_text::RichTextDelta,
+};
 
 const LATEST_VIEW_ID: &str = "latest_view_id";
+const OPEN_VIEW_IDS: &str = "open_view_ids";
+
+// Called with (processed, total) by the operations that handle a view tree.
+pub(crate) type ProgressCallback = Box<dyn Fn(usize, usize) + Send + Sync>;
+
+const VIEW_NAME_MAX_LEN: usize = 256;
+
+const CREATE_VIEW_RETRY_INTERVAL: u64 = 5000;
+const CREATE_VIEW_RETRY_COUNT: usize = 3;
 
 pub(crate) struct ViewController {
     user: Arc<dyn WorkspaceUser>,
@@ -35,6 +103,16 @@ pub(crate) struct ViewController {
     database: Arc<dyn WorkspaceDatabase>,
     trash_controller: Arc<TrashController>,
     document_ctx: Arc<DocumentContext>,
+    processors: ViewDataProcessorMap,
+    open_views: Arc<OpenViewLRU>,
+    sync_states: Arc<ViewSyncStates>,
+    in_flight_reads: Arc<InFlightReads>,
+    server_fetches: ServerFetches,
+    offline: OfflineState,
+    metrics: Arc<ViewMetricsCounter>,
+    view_changes: ViewChangeNotifier,
+    locked_documents: LockedDocuments,
+    templates: ViewTemplates,
 }
 
 impl ViewController {
@@ -44,62 +122,251 @@ impl ViewController {
         server: Server,
         trash_can: Arc<TrashController>,
         document_ctx: Arc<DocumentContext>,
+        open_view_capacity: usize,
     ) -> Self {
+        let processors = ViewDataProcessorMap::new(Arc::new(DocumentViewDataProcessor::new(document_ctx.clone())));
         Self {
             user,
             server,
             database,
             trash_controller: trash_can,
             document_ctx,
+            processors,
+            open_views: Arc::new(OpenViewLRU::new(open_view_capacity)),
+            sync_states: Arc::new(ViewSyncStates::default()),
+            in_flight_reads: Arc::new(InFlightReads::default()),
+            server_fetches: ServerFetches::default(),
+            offline: OfflineState::default(),
+            metrics: Arc::new(ViewMetricsCounter::default()),
+            view_changes: ViewChangeNotifier::new(),
+            locked_documents: LockedDocuments::default(),
+            templates: ViewTemplates::new(),
         }
     }
 
+    pub(crate) fn set_offline_mode(&self, mode: OfflineMode) { self.offline.set_mode(mode); }
+
+    pub(crate) fn set_network_connected(&self, is_connected: bool) { self.offline.set_connected(is_connected); }
+
+    pub(crate) fn metrics_snapshot(&self) -> ViewMetrics { self.metrics.snapshot() }
+
+    pub(crate) fn register_template(&self, name: &str, delta: RichTextDelta) { self.templates.register(name, delta); }
+
+    pub(crate) fn list_templates(&self) -> Vec<String> { self.templates.names() }
+
+    pub(crate) fn subscribe_any_view_change(&self) -> broadcast::Receiver<ViewChangeEvent> {
+        self.view_changes.subscribe()
+    }
+
+    pub(crate) fn register_view_data_processor(&self, processor: Arc<dyn ViewDataProcessor>) {
+        self.processors.register(processor);
+    }
+
+    // The view that can't be read, e.g. it's not saved yet, is handled by the
+    // default processor.
+    fn processor_of_view(&self, view_id: &str) -> Arc<dyn ViewDataProcessor> {
+        let view_type = self
+            .database
+            .db_connection()
+            .and_then(|conn| ViewTableSql::read_view(view_id, &*conn))
+            .map(|view_table| View::from(view_table).view_type)
+            .unwrap_or_default();
+        self.processors.get(&view_type)
+    }
+
     pub(crate) fn init(&self) -> Result<(), FlowyError> {
         let _ = self.document_ctx.init()?;
         self.listen_trash_can_event();
+        let _ = self.reconcile_trash();
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
-    pub(crate) async fn create_view_from_params(&self, params: CreateViewParams) -> Result<View, FlowyError> {
+    pub(crate) async fn create_view_from_params(
+        &self,
+        mut params: CreateViewParams,
+    ) -> Result<CreateViewResult, FlowyError> {
+        let _ = check_view_name(&params.name)?;
+        let _ = self.offline.check_online()?;
+        if let Some(template) = &params.template {
+            match self.templates.get(template) {
+                None => {
+                    return Err(
+                        FlowyError::record_not_found().context(format!("The template:{} is not registered", template))
+                    )
+                },
+                Some(delta) => params.view_data = delta.to_json(),
+            }
+        }
         let delta_data = Bytes::from(params.view_data.clone());
         let user_id = self.user.user_id()?;
+        let insert_after = params.insert_after.clone();
         let repeated_revision: RepeatedRevision =
             Revision::initial_revision(&user_id, &params.view_id, delta_data).into();
-        let _ = self
-            .document_ctx
-            .controller
-            .save_document(&params.view_id, repeated_revision)
+        let initial_rev_id = self
+            .processors
+            .get(&params.view_type)
+            .create_container(&params.view_id, repeated_revision)
             .await?;
-        let view = self.create_view_on_server(params).await?;
-        let _ = self.create_view_on_local(view.clone()).await?;
+        let view = self.create_view_on_server_or_retry(params).await?;
+        let _ = self.create_view_on_local(view.clone(), insert_after).await?;
+
+        Ok(CreateViewResult { view, initial_rev_id })
+    }
 
+    // The view is kept locally even if the server fails, it's retried in the
+    // background and the ViewSyncState tells whether it's synced. The server isn't
+    // requested in the forced offline mode, the view stays pending then.
+    async fn create_view_on_server_or_retry(&self, params: CreateViewParams) -> Result<View, FlowyError> {
+        let user_id = self.user.user_id()?;
+        let mut view = if !self.offline.allows_server_task() {
+            let view = view_from_params(params.clone());
+            let _ = self.retry_create_view_on_server(params)?;
+            view
+        } else {
+            match self.create_view_on_server(params.clone()).await {
+                Ok(view) => view,
+                Err(e) => {
+                    tracing::error!("Create view on server failed: {:?}, retry in the background", e);
+                    let view = view_from_params(params.clone());
+                    let _ = self.retry_create_view_on_server(params)?;
+                    view
+                },
+            }
+        };
+        if view.created_by.is_empty() {
+            view.created_by = user_id.clone();
+        }
+        view.owner_id = user_id;
         Ok(view)
     }
 
-    pub(crate) async fn create_view_on_local(&self, view: View) -> Result<(), FlowyError> {
-        let conn = &*self.database.db_connection()?;
-        let trash_can = self.trash_controller.clone();
+    // Same as create_view_from_params, but the delta_json is parsed and validated
+    // before anything is saved, so a malformed import fails with a clear error
+    // instead of a failure when the document is opened.
+    #[tracing::instrument(level = "debug", skip(self, delta_json), err)]
+    pub(crate) async fn create_view_from_delta_json(
+        &self,
+        belong_to_id: &str,
+        name: &str,
+        delta_json: &str,
+    ) -> Result<View, FlowyError> {
+        let delta = RichTextDelta::from_json(delta_json)
+            .map_err(|e| FlowyError::serde().context(format!("Parse the delta json failed: {}", e)))?;
+        let _ = validate(&delta).map_err(|e| FlowyError::serde().context(format!("The delta is invalid: {}", e)))?;
+
+        let params = CreateViewParamsBuilder::new(belong_to_id, name)
+            .view_data(delta.to_json())
+            .build();
+        let result = self.create_view_from_params(params).await?;
+        Ok(result.view)
+    }
 
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
-            let belong_to_id = view.belong_to_id.clone();
-            let _ = self.save_view(view, conn)?;
-            let _ = notify_views_changed(&belong_to_id, trash_can, &conn)?;
+    // Same as create_view_from_params, but the view that already exists is
+    // updated instead, and the document is only saved if it has no revisions
+    // yet. So an import can be re-run after it failed halfway.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+    pub(crate) async fn upsert_view_from_params(&self, params: CreateViewParams) -> Result<View, FlowyError> {
+        let view_id = params.view_id.clone();
+        let _lock = self.lock_document(&view_id)?;
+        self.upsert_view_from_params_locked(params).await
+    }
+
+    async fn upsert_view_from_params_locked(&self, params: CreateViewParams) -> Result<View, FlowyError> {
+        let _ = check_view_name(&params.name)?;
+        let _ = self.offline.check_online()?;
+        if !self.document_ctx.controller.has_document(&params.view_id)? {
+            let delta_data = Bytes::from(params.view_data.clone());
+            let user_id = self.user.user_id()?;
+            let repeated_revision: RepeatedRevision =
+                Revision::initial_revision(&user_id, &params.view_id, delta_data).into();
+            let _ = self
+                .processors
+                .get(&params.view_type)
+                .create_container(&params.view_id, repeated_revision)
+                .await?;
+        }
+
+        let is_exist = {
+            let conn = &*self.database.db_connection()?;
+            ViewTableSql::read_view(&params.view_id, conn).is_ok()
+        };
+        if is_exist {
+            let update_params = UpdateViewParams::new(&params.view_id)
+                .name(&params.name)
+                .desc(&params.desc)
+                .thumbnail(&params.thumbnail);
+            return self.update_view(update_params).await;
+        }
+
+        let insert_after = params.insert_after.clone();
+        let view = self.create_view_on_server_or_retry(params).await?;
+        let _ = self.create_view_on_local(view.clone(), insert_after).await?;
+        Ok(view)
+    }
+
+    // The view is appended to its siblings, or placed right after the
+    // insert_after sibling and the siblings after it are shifted in the same
+    // transaction.
+    pub(crate) async fn create_view_on_local(
+        &self,
+        view: View,
+        insert_after: Option<String>,
+    ) -> Result<(), FlowyError> {
+        self.with_transaction(|conn| {
+            let view_index = match &insert_after {
+                None => ViewTableSql::next_view_index(&view.belong_to_id, conn)?,
+                Some(sibling_id) => make_room_after_sibling(&view.belong_to_id, sibling_id, conn)?,
+            };
+            let view_id = view.id.clone();
+            let mut delta = AppViewsDelta::new(&view.belong_to_id);
+            delta.added.push(view.clone());
+            let _ = self.save_view(view.clone(), conn)?;
+            let _ = ViewTableSql::update_view_index(&view_id, view_index, conn)?;
+            notify_views_delta(delta);
 
             Ok(())
         })?;
-
+        self.metrics.view_created();
+        self.view_changes
+            .notify(&view.id, &view.belong_to_id, ViewChangeKind::Created);
         Ok(())
     }
 
+    // Runs the f within one immediate transaction, so that the changes made by
+    // f, e.g. save_view and the changeset updates, are committed atomically.
+    pub(crate) fn with_transaction<F, R>(&self, f: F) -> Result<R, FlowyError>
+    where
+        F: FnOnce(&SqliteConnection) -> Result<R, FlowyError>,
+    {
+        let conn = &*self.database.db_connection()?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| f(conn))
+    }
+
+    // The view is owned by the current user. It's created by the current user too,
+    // unless the server says otherwise.
     pub(crate) fn save_view(&self, view: View, conn: &SqliteConnection) -> Result<(), FlowyError> {
-        let view_table = ViewTable::new(view);
+        let user_id = self.user.user_id()?;
+        let mut view_table = ViewTable::new(view);
+        if view_table.created_by.is_empty() {
+            view_table.created_by = user_id.clone();
+        }
+        view_table.owner_id = user_id;
         let _ = ViewTableSql::create_view(view_table, conn)?;
         Ok(())
     }
 
     #[tracing::instrument(skip(self, params), fields(view_id = %params.view_id), err)]
     pub(crate) async fn read_view(&self, params: ViewId) -> Result<View, FlowyError> {
+        let _ = self.offline.check_online()?;
+        let view = self.read_view_local(params.clone())?;
+        let _ = self.read_view_on_server(params);
+        Ok(view)
+    }
+
+    // Same as read_view, but doesn't refresh the view from the server. Use it when
+    // reading many views, e.g. building the view tree.
+    pub(crate) fn read_view_local(&self, params: ViewId) -> Result<View, FlowyError> {
         let conn = self.database.db_connection()?;
         let view_table = ViewTableSql::read_view(&params.view_id, &*conn)?;
 
@@ -108,120 +375,964 @@ impl ViewController {
             return Err(FlowyError::record_not_found());
         }
 
-        let view: View = view_table.into();
-        let _ = self.read_view_on_server(params);
-        Ok(view)
+        Ok(view_table.into())
     }
 
-    pub(crate) fn read_view_tables(&self, ids: Vec<String>) -> Result<Vec<ViewTable>, FlowyError> {
+    // Returns the ids that are in the trash. The trash is read once, so a batch of
+    // ids doesn't need to read it for each id.
+    pub(crate) fn filter_trashed(&self, ids: &[String]) -> Result<HashSet<String>, FlowyError> {
         let conn = &*self.database.db_connection()?;
-        let mut view_tables = vec![];
-        conn.immediate_transaction::<_, FlowyError, _>(|| {
-            for view_id in ids {
-                view_tables.push(ViewTableSql::read_view(&view_id, conn)?);
-            }
-            Ok(())
-        })?;
+        let trash_ids = self
+            .trash_controller
+            .read_trash_ids(conn)?
+            .into_iter()
+            .collect::<HashSet<String>>();
+        let trashed_ids = ids
+            .iter()
+            .filter(|id| trash_ids.contains(*id))
+            .cloned()
+            .collect::<HashSet<String>>();
+        Ok(trashed_ids)
+    }
 
-        Ok(view_tables)
+    pub(crate) fn read_view_tables(&self, ids: Vec<String>) -> Result<Vec<ViewTable>, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        ViewTableSql::read_views_with_ids(&ids, conn)
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn open_view(&self, params: DocumentId) -> Result<DocumentDelta, FlowyError> {
+        self.open_view_with(params, false).await
+    }
+
+    // Returns the locally persisted document immediately and reconciles with the
+    // server in the background, a WorkspaceNotification::ViewContentRefreshed with
+    // the new document will be sent if the document was changed. Falls back to
+    // open_view if the document doesn't exist locally.
+    #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
+    pub(crate) async fn open_view_offline_first(&self, params: DocumentId) -> Result<DocumentDelta, FlowyError> {
+        self.open_view_with(params, true).await
+    }
+
+    async fn open_view_with(&self, params: DocumentId, offline_first: bool) -> Result<DocumentDelta, FlowyError> {
+        let _ = self.offline.check_online()?;
         let doc_id = params.doc_id.clone();
-        let editor = self.document_ctx.controller.open(&params.doc_id).await?;
+        self.touch_open_view(&doc_id).await;
+        if self.server_fetches.switch_to(&doc_id) {
+            self.metrics.server_fetches_cancelled(1);
+        }
+        if offline_first {
+            if let Some(document_json) = self.document_ctx.controller.read_local_document(&doc_id).await? {
+                self.metrics.document_opened();
+                self.save_latest_view(doc_id.clone());
+                let _ = self.reconcile_view_on_server(&doc_id, document_json.clone());
+                return Ok(DocumentDelta::with_content_hash(&doc_id, document_json));
+            }
+        }
 
-        KV::set_str(LATEST_VIEW_ID, doc_id.clone());
-        let document_json = editor.document_json().await?;
-        Ok(DocumentDelta {
-            doc_id,
-            delta_json: document_json,
-        })
+        let document_json = self.processor_of_view(&doc_id).open_view(&doc_id).await?;
+        self.metrics.document_opened();
+        // Re-opening the latest view doesn't need to write the KV again
+        self.save_latest_view(doc_id.clone());
+        Ok(DocumentDelta::with_content_hash(&doc_id, document_json))
     }
 
     #[tracing::instrument(level = "debug", skip(self,params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn close_view(&self, params: DocumentId) -> Result<(), FlowyError> {
-        let _ = self.document_ctx.controller.close(&params.doc_id)?;
+        self.open_views.remove(&params.doc_id);
+        if self.server_fetches.cancel(&params.doc_id) {
+            self.metrics.server_fetches_cancelled(1);
+        }
+        self.save_open_views();
+        // The last edits that are still waiting for the debounce window are
+        // flushed by the close
+        let _ = self.document_ctx.controller.close(&params.doc_id).await?;
+        self.metrics.documents_closed(1);
         Ok(())
     }
 
+    // The cursor is relayed by the server to the other users that opened the
+    // document, it's dropped for the local-only sessions.
+    pub(crate) fn broadcast_cursor(&self, doc_id: &str, position: usize) -> Result<(), FlowyError> {
+        self.document_ctx.controller.broadcast_cursor(doc_id, position)
+    }
+
+    pub(crate) fn subscribe_cursors(&self, doc_id: &str) -> broadcast::Receiver<CursorUpdate> {
+        self.document_ctx.controller.subscribe_cursors(doc_id)
+    }
+
+    // Close all the opened documents, they are bound to the database of the current
+    // user.
+    pub(crate) async fn close_all_views(&self) {
+        self.metrics.documents_closed(self.open_views.doc_ids().len());
+        self.open_views.clear();
+        self.metrics.server_fetches_cancelled(self.server_fetches.cancel_all());
+        self.save_open_views();
+        self.document_ctx.controller.close_all().await;
+    }
+
+    // Reopens the views that were open when the app was closed, e.g. to rebuild the
+    // tabs on startup. The views that were trashed or deleted since then are
+    // skipped.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn restore_open_views(&self) -> Result<RepeatedDocumentDelta, FlowyError> {
+        let key = open_view_ids_key(&self.user.user_id()?);
+        let doc_ids = match KV::get_str(&key) {
+            None => vec![],
+            Some(s) => s
+                .split(',')
+                .filter(|doc_id| !doc_id.is_empty())
+                .map(|doc_id| doc_id.to_owned())
+                .collect::<Vec<String>>(),
+        };
+
+        let live_doc_ids = {
+            let trashed_ids = self.filter_trashed(&doc_ids)?;
+            let conn = &*self.database.db_connection()?;
+            doc_ids
+                .into_iter()
+                .filter(|doc_id| !trashed_ids.contains(doc_id) && ViewTableSql::read_view(doc_id, conn).is_ok())
+                .collect::<Vec<String>>()
+        };
+
+        // Open the least recently used one first to keep the order of the opened views
+        let mut items = vec![];
+        for doc_id in live_doc_ids.into_iter().rev() {
+            match self.open_view((&doc_id).into()).await {
+                Ok(delta) => items.push(delta),
+                Err(e) => log::error!("Restore the opened view:{} failed: {:?}", doc_id, e),
+            }
+        }
+        items.reverse();
+        Ok(RepeatedDocumentDelta { items })
+    }
+
+    fn save_open_views(&self) {
+        match self.user.user_id() {
+            Ok(user_id) => self.set_kv_str(&open_view_ids_key(&user_id), self.open_views.doc_ids().join(",")),
+            Err(e) => log::error!("Save the opened views failed: {:?}", e),
+        }
+    }
+
+    fn save_latest_view(&self, view_id: String) {
+        match self.user.user_id() {
+            Ok(user_id) => self.set_kv_str(&latest_view_id_key(&user_id), view_id),
+            Err(e) => log::error!("Save the latest view failed: {:?}", e),
+        }
+    }
+
+    fn set_kv_str(&self, key: &str, value: String) {
+        if KV::set_str_if_changed(key, value) {
+            self.metrics.kv_written();
+        }
+    }
+
+    // Closes the least recently used views if there are more opened views than the
+    // capacity.
+    async fn touch_open_view(&self, doc_id: &str) {
+        for evicted_doc_id in self.open_views.touch(doc_id) {
+            match self.document_ctx.controller.close(&evicted_doc_id).await {
+                Ok(_) => self.metrics.documents_closed(1),
+                Err(e) => log::error!("Close the least recently used view:{} failed: {:?}", evicted_doc_id, e),
+            }
+        }
+        self.save_open_views();
+    }
+
+    // Reads the document with an editor that is closed afterwards unless the view
+    // is opened, so the opened documents stay bounded by the OpenViewLRU.
+    async fn read_document_json(&self, doc_id: &str) -> Result<String, FlowyError> {
+        let result = match self.document_ctx.controller.open(doc_id).await {
+            Ok(editor) => editor.document_json().await,
+            Err(e) => Err(e),
+        };
+        self.close_unless_opened(doc_id).await;
+        result
+    }
+
+    async fn close_unless_opened(&self, doc_id: &str) {
+        if self.open_views.contains(doc_id) {
+            return;
+        }
+        if let Err(e) = self.document_ctx.controller.close(doc_id).await {
+            log::error!("Close the document:{} failed: {:?}", doc_id, e);
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self,params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn delete_view(&self, params: DocumentId) -> Result<(), FlowyError> {
-        if let Some(view_id) = KV::get_str(LATEST_VIEW_ID) {
+        let latest_view_key = latest_view_id_key(&self.user.user_id()?);
+        if let Some(view_id) = KV::get_str(&latest_view_key) {
             if view_id == params.doc_id {
-                let _ = KV::remove(LATEST_VIEW_ID);
+                let _ = KV::remove(&latest_view_key);
+            }
+        }
+        self.open_views.remove(&params.doc_id);
+        self.save_open_views();
+        let _ = self.document_ctx.controller.close(&params.doc_id).await?;
+        self.metrics.view_deleted();
+        Ok(())
+    }
+
+    // Moves all the live views that belong to the belong_to_id to the trash in one
+    // batch, so the belonging views changed notification is sent once.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn trash_views_belong_to(&self, belong_to_id: &str) -> Result<(), FlowyError> {
+        let view_tables = {
+            let conn = &*self.database.db_connection()?;
+            let mut view_tables = ViewTableSql::read_views(belong_to_id, conn)?;
+            let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+            view_tables.retain(|view_table| !trash_ids.contains(&view_table.id));
+            view_tables
+        };
+        if view_tables.is_empty() {
+            return Ok(());
+        }
+
+        // The views are only moved to the trash, so their editors are closed
+        // without counting them as deleted.
+        for view_table in &view_tables {
+            self.open_views.remove(&view_table.id);
+            if self.server_fetches.cancel(&view_table.id) {
+                self.metrics.server_fetches_cancelled(1);
+            }
+            if let Err(e) = self.document_ctx.controller.close(&view_table.id).await {
+                log::error!("Close the document:{} failed: {:?}", view_table.id, e);
             }
         }
-        let _ = self.document_ctx.controller.close(&params.doc_id)?;
+        self.save_open_views();
+        let trash = view_tables
+            .into_iter()
+            .map(|view_table| view_table.into())
+            .collect::<Vec<Trash>>();
+        let _ = self.trash_controller.add(trash).await?;
         Ok(())
     }
 
+    // Cross-checks the views against the documents on disk, nothing is changed. The
+    // views that were read from the server but never opened have no document on
+    // disk yet, so they are reported as orphaned too.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn verify_views_consistency(&self) -> Result<ConsistencyReport, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let view_ids = ViewTableSql::read_view_ids(conn)?;
+        let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+        let doc_ids = self.document_ctx.controller.document_ids()?;
+        Ok(check_views_consistency(view_ids, trash_ids, doc_ids))
+    }
+
+    // Repairs the orphaned views that verify_views_consistency reports. Deleting
+    // only removes the local rows, the views that still exist on the server come
+    // back when they are read from the server again.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn repair_orphaned_views(&self, strategy: RepairStrategy) -> Result<RepairReport, FlowyError> {
+        let orphaned_views = self.verify_views_consistency()?.orphaned_views;
+        let mut report = RepairReport::default();
+        if orphaned_views.is_empty() {
+            return Ok(report);
+        }
+
+        match strategy {
+            RepairStrategy::CreateEmptyDocument => {
+                let user_id = self.user.user_id()?;
+                let revisions = orphaned_views
+                    .iter()
+                    .map(|view_id| Revision::initial_revision(&user_id, view_id, Bytes::from(initial_delta_string())))
+                    .collect::<Vec<Revision>>();
+                let _ = self.document_ctx.controller.save_documents(revisions)?;
+                report.created_documents = orphaned_views.len() as i64;
+            },
+            RepairStrategy::DeleteDanglingView => {
+                let conn = &*self.database.db_connection()?;
+                let removed = conn.immediate_transaction::<_, FlowyError, _>(|| {
+                    let mut removed = vec![];
+                    for view_id in &orphaned_views {
+                        let view_table = ViewTableSql::read_view(view_id, conn)?;
+                        let _ = ViewTableSql::delete_view(view_id, conn)?;
+                        removed.push((view_id.clone(), view_table.belong_to_id));
+                    }
+                    Ok(removed)
+                })?;
+                self.metrics.views_deleted(removed.len());
+                let deltas = notify_views_removed(removed, &self.view_changes);
+                notify_views_deltas(deltas);
+                report.deleted_views = orphaned_views.len() as i64;
+            },
+        }
+        Ok(report)
+    }
+
+    // Returns the view and all of its descendant views that deleting it would
+    // remove, without mutating anything.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn preview_delete(&self, view_id: &str) -> Result<Vec<View>, FlowyError> { self.read_view_tree(view_id) }
+
+    // Returns the view and its descendant views, the parents always come before
+    // their children. The trashed descendants are skipped because they are
+    // already deleted.
+    fn read_view_tree(&self, view_id: &str) -> Result<Vec<View>, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+        let mut visited_ids = HashSet::new();
+        visited_ids.insert(view_id.to_owned());
+
+        let mut view_tables = vec![ViewTableSql::read_view(view_id, conn)?];
+        let mut index = 0;
+        while index < view_tables.len() {
+            let child_view_tables = ViewTableSql::read_views(&view_tables[index].id, conn)?
+                .into_iter()
+                .filter(|view_table| !trash_ids.contains(&view_table.id));
+            for view_table in child_view_tables {
+                if !visited_ids.insert(view_table.id.clone()) {
+                    return Err(
+                        FlowyError::internal().context(format!("Detect the cycle in the descendants of {}", view_id))
+                    );
+                }
+                view_tables.push(view_table);
+            }
+            index += 1;
+        }
+
+        let views = view_tables
+            .into_iter()
+            .map(|view_table| view_table.into())
+            .collect::<Vec<View>>();
+        Ok(views)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, params), fields(doc_id = %params.doc_id), err)]
     pub(crate) async fn duplicate_view(&self, params: DocumentId) -> Result<(), FlowyError> {
         let view: View = ViewTableSql::read_view(&params.doc_id, &*self.database.db_connection()?)?.into();
-        let editor = self.document_ctx.controller.open(&params.doc_id).await?;
-        let document_json = editor.document_json().await?;
-        let duplicate_params = CreateViewParams {
-            belong_to_id: view.belong_to_id.clone(),
-            name: format!("{} (copy)", &view.name),
-            desc: view.desc.clone(),
-            thumbnail: "".to_owned(),
-            view_type: view.view_type.clone(),
-            view_data: document_json,
-            view_id: uuid_string(),
-        };
+        let document_json = self.read_document_json(&params.doc_id).await?;
+        let duplicate_params = CreateViewParamsBuilder::new(&view.belong_to_id, &format!("{} (copy)", &view.name))
+            .desc(&view.desc)
+            .icon(&view.icon)
+            .view_type(view.view_type.clone())
+            .view_data(document_json)
+            .build();
 
         let _ = self.create_view_from_params(duplicate_params).await?;
         Ok(())
     }
 
+    // Duplicates the view and all of its descendant views, the duplicated tree
+    // keeps the same structure. The progress will be called with (processed,
+    // total) after each view is duplicated.
+    #[tracing::instrument(level = "debug", skip(self, progress), err)]
+    pub(crate) async fn duplicate_view_recursive(
+        &self,
+        view_id: &str,
+        progress: Option<ProgressCallback>,
+    ) -> Result<View, FlowyError> {
+        // Parents always come before their children and the trashed views are
+        // not duplicated
+        let views = self.read_view_tree(view_id)?;
+        let total = views.len();
+        let mut duplicated_ids: HashMap<String, String> = HashMap::new();
+        let mut duplicated_root: Option<View> = None;
+        for (index, view) in views.into_iter().enumerate() {
+            let document_json = self.read_document_json(&view.id).await?;
+            let (belong_to_id, name) = match duplicated_ids.get(&view.belong_to_id) {
+                Some(duplicated_parent_id) => (duplicated_parent_id.clone(), view.name.clone()),
+                None => (view.belong_to_id.clone(), format!("{} (copy)", &view.name)),
+            };
+            let duplicate_params = CreateViewParamsBuilder::new(&belong_to_id, &name)
+                .desc(&view.desc)
+                .icon(&view.icon)
+                .view_type(view.view_type.clone())
+                .view_data(document_json)
+                .build();
+
+            let duplicated_view = self.create_view_from_params(duplicate_params).await?.view;
+            duplicated_ids.insert(view.id.clone(), duplicated_view.id.clone());
+            if duplicated_root.is_none() {
+                duplicated_root = Some(duplicated_view);
+            }
+
+            if let Some(progress) = &progress {
+                progress(index + 1, total);
+            }
+        }
+
+        duplicated_root.ok_or_else(FlowyError::record_not_found)
+    }
+
+    // Copies the apps and the views of the current user, e.g. the one that signed
+    // up with sign_up_local, into the first workspace of the target user. The
+    // copies get fresh ids so they never collide with the target user's data, and
+    // the documents are created on the server with the target user's token. The
+    // data of the current user is kept.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn merge_local_into(&self, target_user_id: &str) -> Result<MergeReport, FlowyError> {
+        let user_id = self.user.user_id()?;
+        if user_id == target_user_id {
+            return Err(FlowyError::user_id().context("Can't merge the user into itself"));
+        }
+        let _ = self.offline.check_online()?;
+        let target_token = self.user.token_of(target_user_id)?;
+        let target_pool = self.database.db_pool_of(target_user_id)?;
+        let target_workspace_id = {
+            let target_conn = target_pool.get().map_err(internal_error)?;
+            match WorkspaceTableSql::read_workspaces(None, target_user_id, &*target_conn)?
+                .into_iter()
+                .next()
+            {
+                None => {
+                    return Err(
+                        FlowyError::record_not_found().context(format!("The user:{} has no workspace", target_user_id))
+                    )
+                },
+                Some(workspace_table) => workspace_table.id,
+            }
+        };
+
+        let app_tables = {
+            let conn = &*self.database.db_connection()?;
+            let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+            let mut app_tables = vec![];
+            for workspace_table in WorkspaceTableSql::read_workspaces(None, &user_id, conn)? {
+                let workspace_apps = AppTableSql::read_workspace_apps(&workspace_table.id, false, conn)?;
+                app_tables.extend(
+                    workspace_apps
+                        .into_iter()
+                        .filter(|app_table| !trash_ids.contains(&app_table.id)),
+                );
+            }
+            app_tables
+        };
+
+        let mut report = MergeReport::default();
+        for app_table in app_tables {
+            let params = CreateAppParams {
+                workspace_id: target_workspace_id.clone(),
+                name: app_table.name.clone(),
+                desc: app_table.desc.clone(),
+                color_style: ColorStyle {
+                    theme_color: app_table.color_style.theme_color.clone(),
+                },
+            };
+            let merged_app = self.server.create_app(&target_token, params).await?;
+            let merged_app_id = merged_app.id.clone();
+            let _ = AppTableSql::create_app(AppTable::new(merged_app), &*target_pool.get().map_err(internal_error)?)?;
+            report.merged_apps += 1;
+
+            // Parents always come before their children
+            let mut belong_to_ids = VecDeque::from(vec![(app_table.id, merged_app_id)]);
+            while let Some((belong_to_id, merged_belong_to_id)) = belong_to_ids.pop_front() {
+                let views = {
+                    let conn = self.database.db_connection()?;
+                    read_belonging_views_on_local(&belong_to_id, None, self.trash_controller.clone(), &conn)?.items
+                };
+                for view in views {
+                    let document_json = self.read_document_json(&view.id).await?;
+                    let params = CreateViewParamsBuilder::new(&merged_belong_to_id, &view.name)
+                        .desc(&view.desc)
+                        .icon(&view.icon)
+                        .view_type(view.view_type.clone())
+                        .view_data(document_json.clone())
+                        .build();
+                    let merged_view = self.server.create_view(&target_token, params).await?;
+                    let merged_view_id = merged_view.id.clone();
+                    let revision =
+                        Revision::initial_revision(target_user_id, &merged_view_id, Bytes::from(document_json));
+                    let _ =
+                        self.document_ctx
+                            .controller
+                            .save_document_of(target_user_id, target_pool.clone(), revision)?;
+                    let target_conn = target_pool.get().map_err(internal_error)?;
+                    let mut view_table = ViewTable::new(merged_view);
+                    view_table.owner_id = target_user_id.to_owned();
+                    let _ = ViewTableSql::create_view(view_table, &*target_conn)?;
+                    report.merged_views += 1;
+                    belong_to_ids.push_back((view.id, merged_view_id));
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, params), err)]
     pub(crate) async fn export_doc(&self, params: ExportParams) -> Result<ExportData, FlowyError> {
-        let editor = self.document_ctx.controller.open(&params.doc_id).await?;
-        let delta_json = editor.document_json().await?;
+        let data = self
+            .processor_of_view(&params.doc_id)
+            .export_view(&params.doc_id, params.export_type.clone())
+            .await?;
+        let view_name = {
+            let conn = self.database.db_connection()?;
+            ViewTableSql::read_view(&params.doc_id, &*conn)?.name
+        };
+        let file_name = format!(
+            "{}.{}",
+            sanitize_file_name(&view_name),
+            params.export_type.file_extension()
+        );
         Ok(ExportData {
-            data: delta_json,
+            size_in_bytes: data.len() as i64,
+            data,
             export_type: params.export_type,
+            file_name,
         })
     }
 
+    // Exports all the views of the app that are not in the trash, the parents come
+    // before their children. The views that have the same name get the file names
+    // like "name (1).md", so they can be saved into the same directory.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn export_app(
+        &self,
+        app_id: &str,
+        export_type: ExportType,
+    ) -> Result<Vec<ExportData>, FlowyError> {
+        let mut view_ids = vec![];
+        {
+            let conn = self.database.db_connection()?;
+            let _ = AppTableSql::read_app(app_id, &*conn)?;
+            let mut belong_to_ids = VecDeque::from(vec![app_id.to_owned()]);
+            while let Some(belong_to_id) = belong_to_ids.pop_front() {
+                let views = read_belonging_views_on_local(&belong_to_id, None, self.trash_controller.clone(), &conn)?;
+                for view in views.items {
+                    belong_to_ids.push_back(view.id.clone());
+                    view_ids.push(view.id);
+                }
+            }
+        }
+
+        let extension = format!(".{}", export_type.file_extension());
+        let mut file_name_counts: HashMap<String, usize> = HashMap::new();
+        let mut items = vec![];
+        for view_id in view_ids {
+            let params = ExportParams {
+                doc_id: view_id,
+                export_type: export_type.clone(),
+            };
+            let mut data = self.export_doc(params).await?;
+            let count = file_name_counts.entry(data.file_name.clone()).or_insert(0);
+            if *count > 0 {
+                let stem = data.file_name.strip_suffix(&extension).unwrap_or(&data.file_name);
+                data.file_name = format!("{} ({}){}", stem, count, extension);
+            }
+            *count += 1;
+            items.push(data);
+        }
+        Ok(items)
+    }
+
+    // Groups the child views of the belong_to_id by the md5 of their exported
+    // text, only the groups that have more than one view are returned. The views
+    // of each group are ordered like read_views_belong_to.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn find_duplicate_views(&self, belong_to_id: &str) -> Result<Vec<Vec<String>>, FlowyError> {
+        let views = {
+            let conn = self.database.db_connection()?;
+            read_belonging_views_on_local(belong_to_id, None, self.trash_controller.clone(), &conn)?
+        };
+
+        let mut content_hashes = vec![];
+        let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+        for view in views.items {
+            let params = ExportParams {
+                doc_id: view.id.clone(),
+                export_type: ExportType::Text,
+            };
+            let data = self.export_doc(params).await?;
+            let content_hash = md5(&data.data);
+            let group = groups.entry(content_hash.clone()).or_insert_with(|| {
+                content_hashes.push(content_hash);
+                vec![]
+            });
+            group.push(view.id);
+        }
+
+        let duplicate_views = content_hashes
+            .into_iter()
+            .flat_map(|content_hash| groups.remove(&content_hash))
+            .filter(|view_ids| view_ids.len() > 1)
+            .collect::<Vec<Vec<String>>>();
+        Ok(duplicate_views)
+    }
+
+    // Counts the words, characters and lines of the document, the opened editor
+    // will be reused if the document is already open.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn document_stats(&self, doc_id: &str) -> Result<DocumentStats, FlowyError> {
+        let document_json = self.read_document_json(doc_id).await?;
+        let delta = RichTextDelta::from_json(&document_json).map_err(internal_error)?;
+        Ok(calculate_document_stats(&delta))
+    }
+
+    // Returns at most limit revisions of the document, newest first.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn read_document_history(&self, doc_id: &str, limit: usize) -> Result<Vec<RevisionSummary>, FlowyError> {
+        self.document_ctx.controller.read_document_history(doc_id, limit)
+    }
+
+    // The acked revisions of the document are squashed into one, the document is
+    // locked meanwhile, the user's edits are rejected until the compaction is done.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn compact_document(&self, doc_id: &str) -> Result<CompactReport, FlowyError> {
+        let _lock = self.lock_document(doc_id)?;
+        self.document_ctx.controller.compact_document(doc_id).await
+    }
+
     // belong_to_id will be the app_id or view_id.
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub(crate) async fn read_views_belong_to(&self, belong_to_id: &str) -> Result<RepeatedView, FlowyError> {
         // TODO: read from server
+        let _ = self.offline.check_online()?;
         let conn = self.database.db_connection()?;
-        let repeated_view = read_belonging_views_on_local(belong_to_id, self.trash_controller.clone(), &conn)?;
+        let repeated_view = read_belonging_views_on_local(belong_to_id, None, self.trash_controller.clone(), &conn)?;
+        Ok(repeated_view)
+    }
+
+    // Same as read_views_belong_to, but only returns the page of the views in
+    // [offset, offset + limit) together with the number of all the views.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_views_belong_to_paged(
+        &self,
+        belong_to_id: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(RepeatedView, usize), FlowyError> {
+        let _ = self.offline.check_online()?;
+        let conn = self.database.db_connection()?;
+        read_belonging_views_page_on_local(belong_to_id, offset, limit, &conn)
+    }
+
+    // Same as read_views_belong_to(belong_to_id).len(), but the views are counted
+    // by the database instead of being read.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn count_views_belong_to(&self, belong_to_id: &str) -> Result<usize, FlowyError> {
+        let conn = self.database.db_connection()?;
+        let count = ViewTableSql::count_views(belong_to_id, &conn)?;
+        Ok(count as usize)
+    }
+
+    // Returns the number of the views of each belong_to_id, the belong_to_id that
+    // has no views is not included.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn count_views_grouped_by_parent(&self) -> Result<HashMap<String, usize>, FlowyError> {
+        let conn = self.database.db_connection()?;
+        let counts = ViewTableSql::count_views_grouped_by_parent(&conn)?
+            .into_iter()
+            .map(|(belong_to_id, count)| (belong_to_id, count as usize))
+            .collect::<HashMap<String, usize>>();
+        Ok(counts)
+    }
+
+    // Same as read_views_belong_to, but only returns the views of the view_type.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn read_views_belong_to_filtered(
+        &self,
+        belong_to_id: &str,
+        view_type: ViewType,
+    ) -> Result<RepeatedView, FlowyError> {
+        let _ = self.offline.check_online()?;
+        let conn = self.database.db_connection()?;
+        let view_type = Some(ViewTableType::from(view_type));
+        let repeated_view =
+            read_belonging_views_on_local(belong_to_id, view_type, self.trash_controller.clone(), &conn)?;
         Ok(repeated_view)
     }
 
     #[tracing::instrument(level = "debug", skip(self, params), err)]
     pub(crate) async fn update_view(&self, params: UpdateViewParams) -> Result<View, FlowyError> {
-        let conn = &*self.database.db_connection()?;
+        if let Some(name) = &params.name {
+            let _ = check_view_name(name)?;
+        }
+        let _ = self.offline.check_online()?;
         let changeset = ViewTableChangeset::new(params.clone());
         let view_id = changeset.id.clone();
 
-        let updated_view = conn.immediate_transaction::<_, FlowyError, _>(|| {
+        let updated_view = self.with_transaction(|conn| {
             let _ = ViewTableSql::update_view(changeset, conn)?;
             let view: View = ViewTableSql::read_view(&view_id, conn)?.into();
             Ok(view)
         })?;
+        self.metrics.view_updated();
+        self.view_changes
+            .notify(&view_id, &updated_view.belong_to_id, ViewChangeKind::Updated);
         send_dart_notification(&view_id, WorkspaceNotification::ViewUpdated)
             .payload(updated_view.clone())
             .send();
 
         //
-        let _ = notify_views_changed(&updated_view.belong_to_id, self.trash_controller.clone(), conn)?;
+        let mut delta = AppViewsDelta::new(&updated_view.belong_to_id);
+        delta.updated.push(updated_view.clone());
+        notify_views_delta(delta);
         let _ = self.update_view_on_server(params);
         Ok(updated_view)
     }
 
-    pub(crate) async fn receive_document_delta(&self, params: DocumentDelta) -> Result<DocumentDelta, FlowyError> {
-        let doc = self.document_ctx.controller.apply_document_delta(params).await?;
-        Ok(doc)
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn rename_view(&self, view_id: &str, new_name: &str) -> Result<View, FlowyError> {
+        // The name is validated by update_view
+        let params = UpdateViewParams::new(view_id).name(new_name);
+        self.update_view(params).await
+    }
+
+    // The read-only view can still be opened, but the document deltas applied to
+    // it are rejected.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn set_view_readonly(&self, view_id: &str, readonly: bool) -> Result<View, FlowyError> {
+        let params = UpdateViewParams::new(view_id).readonly(readonly);
+        self.update_view(params).await
+    }
+
+    // The view_type is saved, then the view's data is transformed by the processor
+    // of the target type. The view_type is rolled back if the transform fails. The
+    // document is locked meanwhile, so the user's edits can't interleave with it,
+    // and the view is never left in a type that doesn't match its data. The server
+    // doesn't know the view_type changes yet, the view is only converted
+    // locally.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn convert_view_type(&self, view_id: &str, target: ViewType) -> Result<View, FlowyError> {
+        let view: View = {
+            let conn = &*self.database.db_connection()?;
+            ViewTableSql::read_view(view_id, conn)?.into()
+        };
+        if view.view_type == target {
+            return Ok(view);
+        }
+
+        let processor = self.processors.get_registered(&target).ok_or_else(|| {
+            FlowyError::unsupported_view_conversion().context(format!("No processor of {:?} is registered", target))
+        })?;
+        let _lock = self.lock_document(view_id)?;
+        let view_type = ViewTableType::from(target);
+        let converted_view = self.with_transaction(|conn| {
+            let _ = ViewTableSql::update_view_type(view_id, view_type, conn)?;
+            let view: View = ViewTableSql::read_view(view_id, conn)?.into();
+            Ok(view)
+        })?;
+        if let Err(e) = processor.convert_view_data(view_id, view.view_type.clone()).await {
+            let view_type = ViewTableType::from(view.view_type);
+            let _ = self.with_transaction(|conn| ViewTableSql::update_view_type(view_id, view_type, conn))?;
+            return Err(e);
+        }
+        self.metrics.view_updated();
+        self.view_changes
+            .notify(view_id, &converted_view.belong_to_id, ViewChangeKind::Updated);
+        send_dart_notification(view_id, WorkspaceNotification::ViewUpdated)
+            .payload(converted_view.clone())
+            .send();
+
+        let mut delta = AppViewsDelta::new(&converted_view.belong_to_id);
+        delta.updated.push(converted_view.clone());
+        notify_views_delta(delta);
+        Ok(converted_view)
+    }
+
+    // The blob is kept in its own table, so reading the views doesn't load it. The
+    // string thumbnail of the view, e.g. an emoji or a url, is kept as it is.
+    #[tracing::instrument(level = "debug", skip(self, bytes), err)]
+    pub(crate) fn set_view_thumbnail_blob(&self, view_id: &str, bytes: Bytes, mime: &str) -> Result<View, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let view = conn.immediate_transaction::<_, FlowyError, _>(|| {
+            let view: View = ViewTableSql::read_view(view_id, conn)?.into();
+            let thumbnail_table = ViewThumbnailTable {
+                view_id: view_id.to_owned(),
+                data: bytes.to_vec(),
+                mime: mime.to_owned(),
+            };
+            let _ = ViewThumbnailTableSql::create_thumbnail(thumbnail_table, conn)?;
+            Ok(view)
+        })?;
+        self.metrics.view_updated();
+        self.view_changes
+            .notify(view_id, &view.belong_to_id, ViewChangeKind::Updated);
+        send_dart_notification(view_id, WorkspaceNotification::ViewUpdated)
+            .payload(view.clone())
+            .send();
+        Ok(view)
+    }
+
+    // Returns the blob and its mime type, or None if the view has no thumbnail
+    // blob.
+    pub(crate) fn read_view_thumbnail(&self, view_id: &str) -> Result<Option<(Bytes, String)>, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let thumbnail = ViewThumbnailTableSql::read_thumbnail(view_id, conn)?
+            .map(|thumbnail_table| (Bytes::from(thumbnail_table.data), thumbnail_table.mime));
+        Ok(thumbnail)
+    }
+
+    // Returns the views of the current user in all the workspaces except the
+    // trashed ones, e.g. to build the search index or to export everything.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn read_all_user_views(&self) -> Result<Vec<View>, FlowyError> {
+        let user_id = self.user.user_id()?;
+        let conn = &*self.database.db_connection()?;
+        let views = ViewTableSql::read_views_of_owner(&user_id, conn)?
+            .into_iter()
+            .map(|view_table| view_table.into())
+            .collect::<Vec<View>>();
+        Ok(views)
+    }
+
+    // Returns the views in the trash, e.g. to show them in the trash folder.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn read_trashed_views(&self) -> Result<RepeatedView, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let trash_ids = self.trash_controller.read_trash_ids_with_type(TrashType::View, conn)?;
+        let mut views = vec![];
+        for trash_id in trash_ids {
+            match ViewTableSql::read_view(&trash_id, conn) {
+                Ok(view_table) => views.push(view_table.into()),
+                Err(e) => log::error!("Read the trashed view:{} failed: {:?}", trash_id, e),
+            }
+        }
+        Ok(RepeatedView { items: views })
+    }
+
+    // Returns the parent views of the view, ordered from the root to the direct
+    // parent, e.g. to build the breadcrumbs. The walk stops at the first parent
+    // that isn't a view, which is the app.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) fn read_view_ancestors(&self, view_id: &str) -> Result<RepeatedView, FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let mut visited_ids = HashSet::new();
+        visited_ids.insert(view_id.to_owned());
+
+        let mut ancestors = vec![];
+        let mut belong_to_id = ViewTableSql::read_view(view_id, conn)?.belong_to_id;
+        while let Ok(view_table) = ViewTableSql::read_view(&belong_to_id, conn) {
+            if !visited_ids.insert(view_table.id.clone()) {
+                return Err(FlowyError::internal().context(format!("Detect the cycle in the ancestors of {}", view_id)));
+            }
+            belong_to_id = view_table.belong_to_id.clone();
+            ancestors.push(view_table.into());
+        }
+        ancestors.reverse();
+        Ok(RepeatedView { items: ancestors })
+    }
+
+    // Puts the view back from the trash. The notifications are sent by the
+    // TrashEvent::Putback handler.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn restore_view(&self, view_id: &str) -> Result<View, FlowyError> {
+        let _ = self.trash_controller.putback(view_id).await?;
+        let conn = self.database.db_connection()?;
+        let view: View = ViewTableSql::read_view(view_id, &*conn)?.into();
+        Ok(view)
+    }
+
+    // Only the visibility is synced to the server, it decides who can read the
+    // view.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn set_view_visibility(
+        &self,
+        view_id: &str,
+        visibility: ViewVisibility,
+    ) -> Result<View, FlowyError> {
+        let params = UpdateViewParams::new(view_id).visibility(visibility);
+        self.update_view(params).await
+    }
+
+    // The icon is an emoji or the identifier of an icon, it's removed by setting
+    // it to empty.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn set_view_icon(&self, view_id: &str, icon: &str) -> Result<View, FlowyError> {
+        let params = UpdateViewParams::new(view_id).icon(icon);
+        self.update_view(params).await
+    }
+
+    pub(crate) fn open_document_ids(&self) -> Vec<String> { self.document_ctx.controller.open_document_ids() }
+
+    // Whether the last create or update of the view was sent to the server
+    // successfully.
+    pub(crate) fn view_sync_state(&self, view_id: &str) -> ViewSyncState { self.sync_states.state(view_id) }
+
+    // Fails with the ViewBusy error if the document is already locked. The lock is
+    // held until the returned DocumentLock is dropped.
+    pub(crate) fn lock_document(&self, doc_id: &str) -> Result<DocumentLock, FlowyError> {
+        self.locked_documents.lock(doc_id)
+    }
+
+    pub(crate) async fn receive_document_delta(
+        &self,
+        params: DocumentDelta,
+    ) -> Result<DocumentDeltaResult, FlowyError> {
+        if self.locked_documents.is_locked(&params.doc_id) {
+            return Err(FlowyError::view_busy().context(format!("The view:{} is locked", params.doc_id)));
+        }
+        self.apply_document_delta(params).await
+    }
+
+    // Same as receive_document_delta, but the lock of the document is ignored. It's
+    // used by the one that holds the lock.
+    async fn apply_document_delta(&self, params: DocumentDelta) -> Result<DocumentDeltaResult, FlowyError> {
+        let is_readonly = {
+            let conn = &*self.database.db_connection()?;
+            ViewTableSql::read_view(&params.doc_id, conn)
+                .map(|view_table| view_table.is_readonly)
+                .unwrap_or(false)
+        };
+        if is_readonly {
+            return Err(FlowyError::view_readonly().context(format!("The view:{} is read-only", params.doc_id)));
+        }
+
+        let params = match self.document_ctx.delta_interceptor() {
+            None => params,
+            Some(interceptor) => {
+                let delta = RichTextDelta::from_json(&params.delta_json).map_err(internal_error)?;
+                DocumentDelta {
+                    doc_id: params.doc_id,
+                    delta_json: interceptor(delta).to_json(),
+                    content_hash: None,
+                }
+            },
+        };
+        let result = self.document_ctx.controller.apply_document_delta(params).await?;
+        Ok(result)
+    }
+
+    // Rolls the document back to the state at rev_id. The document isn't rewritten,
+    // the changes are applied as a new revision, so the restore can be undone by
+    // restoring to a later revision.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn restore_document_to_revision(
+        &self,
+        doc_id: &str,
+        rev_id: i64,
+    ) -> Result<DocumentDelta, FlowyError> {
+        let lock = self.lock_document(doc_id)?;
+        let result = self.restore_document_to_revision_locked(doc_id, rev_id).await;
+        drop(lock);
+        self.close_unless_opened(doc_id).await;
+        result
+    }
+
+    async fn restore_document_to_revision_locked(
+        &self,
+        doc_id: &str,
+        rev_id: i64,
+    ) -> Result<DocumentDelta, FlowyError> {
+        let target = self.document_ctx.controller.read_document_at_revision(doc_id, rev_id)?;
+        let document_json = self.read_document_json(doc_id).await?;
+        let current = RichTextDelta::from_json(&document_json).map_err(internal_error)?;
+        if current == target {
+            return Ok(DocumentDelta::with_content_hash(doc_id, document_json));
+        }
+
+        let mut delta = RichTextDelta::new();
+        delta.delete(current.target_len);
+        delta.extend(target);
+        let result = self
+            .apply_document_delta(DocumentDelta {
+                doc_id: doc_id.to_owned(),
+                delta_json: delta.to_json(),
+                content_hash: None,
+            })
+            .await?;
+
+        let conn = self.database.db_connection()?;
+        let view_table = ViewTableSql::read_view(doc_id, &*conn)?;
+        notify_dart(view_table, WorkspaceNotification::ViewUpdated);
+        Ok(result.delta)
     }
 
     pub(crate) fn latest_visit_view(&self) -> FlowyResult<Option<View>> {
-        match KV::get_str(LATEST_VIEW_ID) {
+        match KV::get_str(&latest_view_id_key(&self.user.user_id()?)) {
             None => Ok(None),
             Some(view_id) => {
                 let conn = self.database.db_connection()?;
@@ -231,28 +1342,82 @@ impl ViewController {
         }
     }
 
-    pub(crate) fn set_latest_view(&self, view: &View) { KV::set_str(LATEST_VIEW_ID, view.id.clone()); }
+    pub(crate) fn set_latest_view(&self, view: &View) { self.save_latest_view(view.id.clone()); }
 }
 
 impl ViewController {
     #[tracing::instrument(skip(self), err)]
     async fn create_view_on_server(&self, params: CreateViewParams) -> Result<View, FlowyError> {
         let token = self.user.token()?;
-        let view = self.server.create_view(&token, params).await?;
+        let view_id = params.view_id.clone();
+        let seq = self.sync_states.begin(&view_id);
+        let result = self.server.create_view(&token, params).await;
+        self.sync_states.finish(&view_id, seq, result.is_ok());
+        self.metrics.server_synced(result.is_ok());
+        let view = result?;
         Ok(view)
     }
 
+    #[tracing::instrument(skip(self), err)]
+    fn retry_create_view_on_server(&self, params: CreateViewParams) -> Result<(), FlowyError> {
+        let view_id = params.view_id.clone();
+        let sync_states = self.sync_states.clone();
+        let metrics = self.metrics.clone();
+        let seq = sync_states.begin(&view_id);
+        notify_view_sync_state(&view_id, ViewSyncState::Pending);
+        // The view stays pending, nothing is sent to the server in the forced offline
+        // mode.
+        if !self.offline.allows_server_task() {
+            return Ok(());
+        }
+
+        let action = RetryAction::new(
+            self.server.clone(),
+            self.user.clone(),
+            move |token: String, server: Server| server.create_view(&token, params.clone()),
+        );
+        let strategy = FixedInterval::from_millis(CREATE_VIEW_RETRY_INTERVAL).take(CREATE_VIEW_RETRY_COUNT);
+        tokio::spawn(async move {
+            let is_success = match Retry::spawn(strategy, action).await {
+                Ok(_) => true,
+                Err(e) => {
+                    log::error!("Retry creating view:{} failed: {:?}", view_id, e);
+                    false
+                },
+            };
+            metrics.server_synced(is_success);
+            if let Some(state) = sync_states.finish(&view_id, seq, is_success) {
+                notify_view_sync_state(&view_id, state);
+            }
+        });
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self), err)]
     fn update_view_on_server(&self, params: UpdateViewParams) -> Result<(), FlowyError> {
+        if !self.offline.allows_server_task() {
+            return Ok(());
+        }
         let token = self.user.token()?;
         let server = self.server.clone();
+        let sync_states = self.sync_states.clone();
+        let metrics = self.metrics.clone();
+        let view_id = params.view_id.clone();
+        let seq = sync_states.begin(&view_id);
+        // The UI can show the view is saving until the follow-up notification arrives
+        notify_view_sync_state(&view_id, ViewSyncState::Pending);
         tokio::spawn(async move {
-            match server.update_view(&token, params).await {
-                Ok(_) => {},
+            let is_success = match server.update_view(&token, params).await {
+                Ok(_) => true,
                 Err(e) => {
                     // TODO: retry?
                     log::error!("Update view failed: {:?}", e);
+                    false
                 },
+            };
+            metrics.server_synced(is_success);
+            if let Some(state) = sync_states.finish(&view_id, seq, is_success) {
+                notify_view_sync_state(&view_id, state);
             }
         });
         Ok(())
@@ -260,20 +1425,39 @@ impl ViewController {
 
     #[tracing::instrument(skip(self), err)]
     fn read_view_on_server(&self, params: ViewId) -> Result<(), FlowyError> {
+        if !self.offline.allows_server_task() {
+            return Ok(());
+        }
         let token = self.user.token()?;
         let server = self.server.clone();
         let pool = self.database.db_pool()?;
+        let view_id = params.view_id.clone();
+        if !self.in_flight_reads.begin(&view_id) {
+            tracing::trace!("The view:{} is being read from the server", view_id);
+            return Ok(());
+        }
+        self.metrics.server_read();
+
+        let in_flight_reads = self.in_flight_reads.clone();
+        let cancellation = self.server_fetches.token(&view_id);
         // TODO: Retry with RetryAction?
         tokio::spawn(async move {
-            match server.read_view(&token, params).await {
-                Ok(Some(view)) => match pool.get() {
+            let result = tokio::select! {
+                _ = cancellation.cancelled() => None,
+                result = server.read_view(&token, params) => Some(result),
+            };
+            match result {
+                None => tracing::trace!("Read view:{} on server is cancelled", view_id),
+                Some(Ok(Some(view))) => match pool.get() {
                     Ok(conn) => {
                         let view_table = ViewTable::new(view.clone());
-                        let result = ViewTableSql::create_view(view_table, &conn);
+                        // The owner_id and the visibility are only stored locally
+                        let result = ViewTableSql::create_view(view_table, &conn)
+                            .and_then(|_| ViewTableSql::read_view(&view.id, &conn));
                         match result {
-                            Ok(_) => {
+                            Ok(view_table) => {
                                 send_dart_notification(&view.id, WorkspaceNotification::ViewUpdated)
-                                    .payload(view.clone())
+                                    .payload(View::from(view_table))
                                     .send();
                             },
                             Err(e) => log::error!("Save view failed: {:?}", e),
@@ -281,40 +1465,149 @@ impl ViewController {
                     },
                     Err(e) => log::error!("Require db connection failed: {:?}", e),
                 },
-                Ok(None) => {},
-                Err(e) => log::error!("Read view failed: {:?}", e),
+                Some(Ok(None)) => {},
+                Some(Err(e)) => log::error!("Read view failed: {:?}", e),
+            }
+            in_flight_reads.finish(&view_id);
+        });
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, local_document_json), err)]
+    fn reconcile_view_on_server(&self, doc_id: &str, local_document_json: String) -> Result<(), FlowyError> {
+        if !self.offline.allows_server_task() {
+            return Ok(());
+        }
+        let controller = self.document_ctx.controller.clone();
+        let open_views = self.open_views.clone();
+        let pool = self.database.db_pool()?;
+        let doc_id = doc_id.to_owned();
+        let cancellation = self.server_fetches.token(&doc_id);
+        tokio::spawn(async move {
+            // Opening the editor starts syncing the document with the server.
+            let result = match controller.open(&doc_id).await {
+                Ok(editor) => editor.document_json().await,
+                Err(e) => Err(e),
+            };
+
+            // The refresh of the document that the user switched from is dropped,
+            // its editor is only kept if the view is still opened.
+            if cancellation.is_cancelled() {
+                tracing::trace!("Reconcile view:{} on server is cancelled", doc_id);
+                if !open_views.contains(&doc_id) {
+                    let _ = controller.close(&doc_id).await;
+                }
+                return;
+            }
+            match result {
+                Ok(document_json) if document_json != local_document_json => {
+                    send_dart_notification(&doc_id, WorkspaceNotification::ViewContentRefreshed)
+                        .payload(DocumentDelta::with_content_hash(&doc_id, document_json))
+                        .send();
+                    match pool.get() {
+                        Ok(conn) => match ViewTableSql::read_view(&doc_id, &conn) {
+                            Ok(view_table) => notify_dart(view_table, WorkspaceNotification::ViewUpdated),
+                            Err(e) => log::error!("Read view failed: {:?}", e),
+                        },
+                        Err(e) => log::error!("Require db connection failed: {:?}", e),
+                    }
+                },
+                Ok(_) => {},
+                Err(e) => log::error!("Reconcile view failed: {:?}", e),
             }
         });
         Ok(())
     }
 
+    // The views may be trashed while the app was not running or on another device,
+    // so notify their parents to refresh on startup.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    fn reconcile_trash(&self) -> Result<(), FlowyError> {
+        let conn = &*self.database.db_connection()?;
+        let trash_ids = self.trash_controller.read_trash_ids(conn)?;
+        let mut notify_ids = HashSet::new();
+        for trash_id in trash_ids {
+            // The trash may be an app, only the views are reconciled here.
+            if let Ok(view_table) = ViewTableSql::read_view(&trash_id, conn) {
+                notify_ids.insert(view_table.belong_to_id);
+            }
+        }
+
+        self.notify_parents_changed(&notify_ids, conn)
+    }
+
+    // Sends all the views of each parent. Use it only if the changes are unknown,
+    // e.g. on startup, otherwise send the AppViewsDelta by notify_views_deltas. The
+    // parents are sent by one AppsViewsChanged notification, so the UI refreshes
+    // them at once instead of once per parent.
+    #[tracing::instrument(level = "debug", skip(self, conn), err)]
+    fn notify_parents_changed(&self, parent_ids: &HashSet<String>, conn: &SqliteConnection) -> FlowyResult<()> {
+        if parent_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut parent_ids = parent_ids.iter().collect::<Vec<&String>>();
+        parent_ids.sort();
+        let mut items = vec![];
+        for parent_id in parent_ids {
+            let views = read_belonging_views_on_local(parent_id, None, self.trash_controller.clone(), conn)?;
+            items.push(AppViews {
+                belong_to_id: parent_id.clone(),
+                views,
+            });
+        }
+        send_anonymous_dart_notification(WorkspaceNotification::AppsViewsChanged)
+            .payload(RepeatedAppViews { items })
+            .send();
+        Ok(())
+    }
+
     fn listen_trash_can_event(&self) {
         let mut rx = self.trash_controller.subscribe();
         let database = self.database.clone();
         let document = self.document_ctx.clone();
-        let trash_can = self.trash_controller.clone();
+        let view_changes = self.view_changes.clone();
+        let metrics = self.metrics.clone();
         let _ = tokio::spawn(async move {
             loop {
                 let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
                     match result {
                         Ok(event) => event.select(TrashType::View),
-                        Err(_e) => None,
+                        Err(e) => {
+                            // The receiver lagged behind and the skipped events are lost
+                            tracing::warn!("Receive trash event failed: {:?}", e);
+                            None
+                        },
                     }
                 }));
 
                 if let Some(event) = stream.next().await {
-                    handle_trash_event(database.clone(), document.clone(), trash_can.clone(), event).await
+                    let span = tracing::debug_span!(
+                        "trash_event",
+                        kind = event.kind(),
+                        identifier_count = event.identifier_count()
+                    );
+                    handle_trash_event(
+                        database.clone(),
+                        document.clone(),
+                        view_changes.clone(),
+                        metrics.clone(),
+                        event,
+                    )
+                    .instrument(span)
+                    .await
                 }
             }
         });
     }
 }
 
-#[tracing::instrument(level = "trace", skip(database, context, trash_can))]
+#[tracing::instrument(level = "trace", skip(database, context, view_changes, metrics))]
 async fn handle_trash_event(
     database: Arc<dyn WorkspaceDatabase>,
     context: Arc<DocumentContext>,
-    trash_can: Arc<TrashController>,
+    view_changes: ViewChangeNotifier,
+    metrics: Arc<ViewMetricsCounter>,
     event: TrashEvent,
 ) {
     let db_result = database.db_connection();
@@ -324,61 +1617,219 @@ async fn handle_trash_event(
             let result = || {
                 let conn = &*db_result?;
                 let view_tables = read_view_tables(identifiers, conn)?;
+                let mut deltas = HashMap::new();
                 for view_table in view_tables {
-                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
+                    removed_view_delta(&mut deltas, view_table.belong_to_id.clone(), view_table.id.clone());
+                    view_changes.notify(&view_table.id, &view_table.belong_to_id, ViewChangeKind::Deleted);
                     notify_dart(view_table, WorkspaceNotification::ViewDeleted);
                 }
+                notify_views_deltas(deltas);
                 Ok::<(), FlowyError>(())
             };
-            let _ = ret.send(result()).await;
+            send_trash_result(ret, result()).await;
         },
         TrashEvent::Putback(identifiers, ret) => {
             let result = || {
                 let conn = &*db_result?;
                 let view_tables = read_view_tables(identifiers, conn)?;
+                let mut deltas = HashMap::new();
                 for view_table in view_tables {
-                    let _ = notify_views_changed(&view_table.belong_to_id, trash_can.clone(), conn)?;
+                    added_view_delta(&mut deltas, view_table.belong_to_id.clone(), view_table.clone().into());
+                    view_changes.notify(&view_table.id, &view_table.belong_to_id, ViewChangeKind::Restored);
                     notify_dart(view_table, WorkspaceNotification::ViewRestored);
                 }
+                notify_views_deltas(deltas);
                 Ok::<(), FlowyError>(())
             };
-            let _ = ret.send(result()).await;
+            send_trash_result(ret, result()).await;
         },
         TrashEvent::Delete(identifiers, ret) => {
             let result = || {
                 let conn = &*db_result?;
-                let _ = conn.immediate_transaction::<_, FlowyError, _>(|| {
-                    let mut notify_ids = HashSet::new();
-                    for identifier in identifiers.items {
+                let total = identifiers.items.len();
+                let removed = conn.immediate_transaction::<_, FlowyError, _>(|| {
+                    let mut removed = vec![];
+                    for (index, identifier) in identifiers.items.into_iter().enumerate() {
                         let view_table = ViewTableSql::read_view(&identifier.id, conn)?;
                         let _ = ViewTableSql::delete_view(&identifier.id, conn)?;
                         let _ = context.controller.delete(&identifier.id)?;
-                        notify_ids.insert(view_table.belong_to_id);
+                        removed.push((identifier.id.clone(), view_table.belong_to_id));
+                        send_anonymous_dart_notification(WorkspaceNotification::ViewProgressChanged)
+                            .payload(ViewProgress {
+                                view_id: identifier.id,
+                                processed: (index + 1) as i64,
+                                total: total as i64,
+                            })
+                            .send();
                     }
-
-                    for notify_id in notify_ids {
-                        let _ = notify_views_changed(&notify_id, trash_can.clone(), conn)?;
-                    }
-
-                    Ok(())
+                    Ok(removed)
                 })?;
+                metrics.views_deleted(removed.len());
+                let deltas = notify_views_removed(removed, &view_changes);
+                notify_views_deltas(deltas);
                 Ok::<(), FlowyError>(())
             };
-            let _ = ret.send(result()).await;
+            send_trash_result(ret, result()).await;
         },
     }
 }
 
-fn read_view_tables(identifiers: RepeatedTrashId, conn: &SqliteConnection) -> Result<Vec<ViewTable>, FlowyError> {
-    let mut view_tables = vec![];
-    let _ = conn.immediate_transaction::<_, FlowyError, _>(|| {
-        for identifier in identifiers.items {
-            let view_table = ViewTableSql::read_view(&identifier.id, conn)?;
-            view_tables.push(view_table);
+// Takes the (view_id, belong_to_id) pairs of the permanently deleted views and
+// returns their deltas grouped by the parent.
+fn notify_views_removed(
+    removed: Vec<(String, String)>,
+    view_changes: &ViewChangeNotifier,
+) -> HashMap<String, AppViewsDelta> {
+    let mut deltas = HashMap::new();
+    for (view_id, belong_to_id) in removed {
+        view_changes.notify(&view_id, &belong_to_id, ViewChangeKind::Removed);
+        removed_view_delta(&mut deltas, belong_to_id, view_id);
+    }
+    deltas
+}
+
+// The trash controller waits for the result, so a failed send means the caller
+// hung up before the event was handled.
+async fn send_trash_result(ret: mpsc::Sender<FlowyResult<()>>, result: FlowyResult<()>) {
+    if let Err(e) = ret.send(result).await {
+        tracing::warn!("Send the result of the trash event failed: {:?}", e);
+    }
+}
+
+// Replaces the characters that are illegal in file names on Windows, macOS or
+// Linux.
+fn sanitize_file_name(name: &str) -> String {
+    let file_name = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect::<String>();
+
+    // Windows doesn't allow a file name ends with a dot or a space.
+    let file_name = file_name.trim().trim_end_matches('.').to_owned();
+    if file_name.is_empty() {
+        "Untitled".to_owned()
+    } else {
+        file_name
+    }
+}
+
+// Renumbers the siblings in their current order with a gap right after the
+// sibling_id and returns the view_index of the gap. The siblings that were
+// created before the view_index was added share the same view_index, so all
+// of them are renumbered instead of only shifting the ones after the sibling.
+fn make_room_after_sibling(belong_to_id: &str, sibling_id: &str, conn: &SqliteConnection) -> Result<i32, FlowyError> {
+    let siblings = ViewTableSql::read_views(belong_to_id, conn)?;
+    let position = match siblings.iter().position(|sibling| sibling.id == sibling_id) {
+        Some(position) => position,
+        None => {
+            log::warn!(
+                "The view:{} doesn't belong to {}, append the new view",
+                sibling_id,
+                belong_to_id
+            );
+            return ViewTableSql::next_view_index(belong_to_id, conn);
+        },
+    };
+
+    for (index, sibling) in siblings.iter().enumerate() {
+        let view_index = if index <= position {
+            index as i32
+        } else {
+            index as i32 + 1
+        };
+        if sibling.view_index != view_index {
+            let _ = ViewTableSql::update_view_index(&sibling.id, view_index, conn)?;
         }
-        Ok(())
-    })?;
-    Ok(view_tables)
+    }
+    Ok(position as i32 + 1)
+}
+
+// Builds the view locally when the server failed to create it, the fields are
+// filled in the same way the server does.
+fn view_from_params(params: CreateViewParams) -> View {
+    let time = timestamp();
+    View {
+        id: params.view_id,
+        belong_to_id: params.belong_to_id,
+        name: params.name,
+        desc: params.desc,
+        view_type: params.view_type,
+        version: 0,
+        belongings: RepeatedView::default(),
+        modified_time: time,
+        create_time: time,
+        owner_id: "".to_owned(),
+        visibility: ViewVisibility::default(),
+        created_by: "".to_owned(),
+        icon: params.icon,
+    }
+}
+
+fn check_view_name(name: &str) -> Result<(), FlowyError> {
+    if name.trim().is_empty() {
+        return Err(FlowyError::new(
+            ErrorCode::ViewNameInvalid,
+            "View name should not be empty",
+        ));
+    }
+
+    if name.chars().count() > VIEW_NAME_MAX_LEN {
+        return Err(FlowyError::new(
+            ErrorCode::ViewNameTooLong,
+            &format!("View name should not be longer than {} characters", VIEW_NAME_MAX_LEN),
+        ));
+    }
+    Ok(())
+}
+
+fn read_view_tables(identifiers: RepeatedTrashId, conn: &SqliteConnection) -> Result<Vec<ViewTable>, FlowyError> {
+    let view_ids = identifiers
+        .items
+        .into_iter()
+        .map(|identifier| identifier.id)
+        .collect::<Vec<String>>();
+    ViewTableSql::read_views_with_ids(&view_ids, conn)
+}
+
+// The opened views are saved per user, the KV is shared by all the users.
+fn open_view_ids_key(user_id: &str) -> String { format!("{}_{}", OPEN_VIEW_IDS, user_id) }
+
+fn latest_view_id_key(user_id: &str) -> String { format!("{}_{}", LATEST_VIEW_ID, user_id) }
+
+fn check_views_consistency(view_ids: Vec<String>, trash_ids: Vec<String>, doc_ids: Vec<String>) -> ConsistencyReport {
+    let trash_ids = trash_ids.into_iter().collect::<HashSet<String>>();
+    let doc_ids = doc_ids.into_iter().collect::<HashSet<String>>();
+    let mut orphaned_views = view_ids
+        .iter()
+        .filter(|view_id| !trash_ids.contains(*view_id) && !doc_ids.contains(*view_id))
+        .cloned()
+        .collect::<Vec<String>>();
+    orphaned_views.sort();
+
+    let view_ids = view_ids.into_iter().collect::<HashSet<String>>();
+    let mut orphaned_documents = doc_ids
+        .into_iter()
+        .filter(|doc_id| !view_ids.contains(doc_id))
+        .collect::<Vec<String>>();
+    orphaned_documents.sort();
+
+    ConsistencyReport {
+        orphaned_views,
+        orphaned_documents,
+    }
+}
+
+fn notify_view_sync_state(view_id: &str, state: ViewSyncState) {
+    send_dart_notification(view_id, WorkspaceNotification::ViewSyncStateChanged)
+        .payload(ViewSyncStatus {
+            view_id: view_id.to_owned(),
+            state,
+        })
+        .send();
 }
 
 fn notify_dart(view_table: ViewTable, notification: WorkspaceNotification) {
@@ -386,26 +1837,63 @@ fn notify_dart(view_table: ViewTable, notification: WorkspaceNotification) {
     send_dart_notification(&view.id, notification).payload(view).send();
 }
 
-#[tracing::instrument(skip(belong_to_id, trash_controller, conn), fields(view_count), err)]
-fn notify_views_changed(
-    belong_to_id: &str,
-    trash_controller: Arc<TrashController>,
-    conn: &SqliteConnection,
-) -> FlowyResult<()> {
-    let repeated_view = read_belonging_views_on_local(belong_to_id, trash_controller.clone(), conn)?;
-    tracing::Span::current().record("view_count", &format!("{}", repeated_view.len()).as_str());
-    send_dart_notification(&belong_to_id, WorkspaceNotification::AppViewsChanged)
-        .payload(repeated_view)
+fn notify_views_delta(delta: AppViewsDelta) {
+    if delta.is_empty() {
+        return;
+    }
+
+    let belong_to_id = delta.belong_to_id.clone();
+    send_dart_notification(&belong_to_id, WorkspaceNotification::AppViewsDeltaChanged)
+        .payload(delta)
         .send();
-    Ok(())
+}
+
+// The deltas of one parent are sent as they are, the ones of several parents
+// are sent by one AppsViewsDeltaChanged notification, so the UI refreshes them
+// at once instead of once per parent.
+fn notify_views_deltas(deltas: HashMap<String, AppViewsDelta>) {
+    if deltas.len() <= 1 {
+        deltas.into_iter().for_each(|(_, delta)| notify_views_delta(delta));
+        return;
+    }
+
+    let mut items = deltas
+        .into_iter()
+        .map(|(_, delta)| delta)
+        .filter(|delta| !delta.is_empty())
+        .collect::<Vec<AppViewsDelta>>();
+    items.sort_by(|a, b| a.belong_to_id.cmp(&b.belong_to_id));
+    send_anonymous_dart_notification(WorkspaceNotification::AppsViewsDeltaChanged)
+        .payload(RepeatedAppViewsDelta { items })
+        .send();
+}
+
+fn added_view_delta(deltas: &mut HashMap<String, AppViewsDelta>, belong_to_id: String, view: View) {
+    deltas
+        .entry(belong_to_id)
+        .or_insert_with_key(|belong_to_id| AppViewsDelta::new(belong_to_id))
+        .added
+        .push(view);
+}
+
+fn removed_view_delta(deltas: &mut HashMap<String, AppViewsDelta>, belong_to_id: String, view_id: String) {
+    deltas
+        .entry(belong_to_id)
+        .or_insert_with_key(|belong_to_id| AppViewsDelta::new(belong_to_id))
+        .removed
+        .push(view_id);
 }
 
 fn read_belonging_views_on_local(
     belong_to_id: &str,
+    view_type: Option<ViewTableType>,
     trash_controller: Arc<TrashController>,
     conn: &SqliteConnection,
 ) -> FlowyResult<RepeatedView> {
-    let mut view_tables = ViewTableSql::read_views(belong_to_id, conn)?;
+    let mut view_tables = match view_type {
+        None => ViewTableSql::read_views(belong_to_id, conn)?,
+        Some(view_type) => ViewTableSql::read_views_with_type(belong_to_id, view_type, conn)?,
+    };
     let trash_ids = trash_controller.read_trash_ids(conn)?;
     view_tables.retain(|view_table| !trash_ids.contains(&view_table.id));
 
@@ -416,3 +1904,51 @@ fn read_belonging_views_on_local(
 
     Ok(RepeatedView { items: views })
 }
+
+// The trashed views are excluded in the query, so they are neither in the page
+// nor counted in the total.
+fn read_belonging_views_page_on_local(
+    belong_to_id: &str,
+    offset: usize,
+    limit: usize,
+    conn: &SqliteConnection,
+) -> FlowyResult<(RepeatedView, usize)> {
+    let (view_tables, total) = ViewTableSql::read_views_page(belong_to_id, offset as i64, limit as i64, conn)?;
+    let views = view_tables
+        .into_iter()
+        .map(|view_table| view_table.into())
+        .collect::<Vec<View>>();
+
+    Ok((RepeatedView { items: views }, total as usize))
+}
+
+// Only the text inserts are counted, the embeds are skipped and each '\n' is a
+// line break.
+fn calculate_document_stats(delta: &RichTextDelta) -> DocumentStats {
+    let mut stats = DocumentStats::default();
+    let mut in_word = false;
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            if insert.is_embed() {
+                in_word = false;
+                continue;
+            }
+
+            for c in insert.s.chars() {
+                if c == '\n' {
+                    stats.line_count += 1;
+                } else {
+                    stats.char_count += 1;
+                }
+
+                if c.is_whitespace() {
+                    in_word = false;
+                } else if !in_word {
+                    in_word = true;
+                    stats.word_count += 1;
+                }
+            }
+        }
+    }
+    stats
+}
@@ -1,22 +1,64 @@
 use crate::{
     entities::{
+        app::{AppId, QueryAppRequest},
         trash::Trash,
         view::{
+            ConsistencyReport,
             CreateViewParams,
             CreateViewRequest,
+            DocumentHistoryParams,
+            DocumentStats,
+            DuplicateViews,
+            ImportViewParams,
+            ImportViewRequest,
+            MergeLocalRequest,
+            MergeReport,
+            QueryDocumentHistoryRequest,
             QueryViewRequest,
+            QueryViewsBelongToRequest,
+            QueryViewsCountRequest,
+            QueryViewsPageRequest,
+            RenameViewRequest,
+            RepairReport,
+            RepairViewsRequest,
+            RepeatedDuplicateViews,
+            RepeatedView,
+            RepeatedViewCount,
             RepeatedViewId,
+            RestoreDocumentParams,
+            RestoreDocumentRequest,
+            SetViewIconRequest,
+            SetViewReadonlyRequest,
+            SetViewThumbnailRequest,
+            SetViewVisibilityRequest,
             UpdateViewParams,
             UpdateViewRequest,
             View,
+            ViewCount,
             ViewId,
+            ViewProgress,
+            ViewSyncStatus,
+            ViewThumbnail,
+            ViewsPage,
+            ViewsPageParams,
         },
     },
     errors::FlowyError,
-    services::{TrashController, ViewController},
+    notify::{send_dart_notification, WorkspaceNotification},
+    services::{ProgressCallback, TrashController, ViewController},
+};
+use bytes::Bytes;
+use flowy_collaboration::entities::{
+    doc::{DocumentDelta, DocumentDeltaResult, RepeatedDocumentDelta},
+    revision::{CompactReport, RepeatedRevisionSummary},
+};
+use flowy_core_data_model::entities::share::{
+    ExportAppRequest,
+    ExportData,
+    ExportParams,
+    ExportRequest,
+    RepeatedExportData,
 };
-use flowy_collaboration::entities::doc::DocumentDelta;
-use flowy_core_data_model::entities::share::{ExportData, ExportParams, ExportRequest};
 use lib_dispatch::prelude::{data_result, Data, DataResult, Unit};
 use std::{convert::TryInto, sync::Arc};
 
@@ -25,7 +67,18 @@ pub(crate) async fn create_view_handler(
     controller: Unit<Arc<ViewController>>,
 ) -> DataResult<View, FlowyError> {
     let params: CreateViewParams = data.into_inner().try_into()?;
-    let view = controller.create_view_from_params(params).await?;
+    let result = controller.create_view_from_params(params).await?;
+    data_result(result.view)
+}
+
+pub(crate) async fn import_view_handler(
+    data: Data<ImportViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let params: ImportViewParams = data.into_inner().try_into()?;
+    let view = controller
+        .create_view_from_delta_json(&params.belong_to_id, &params.name, &params.delta_json)
+        .await?;
     data_result(view)
 }
 
@@ -54,9 +107,9 @@ pub(crate) async fn update_view_handler(
 pub(crate) async fn document_delta_handler(
     data: Data<DocumentDelta>,
     controller: Unit<Arc<ViewController>>,
-) -> DataResult<DocumentDelta, FlowyError> {
-    let doc = controller.receive_document_delta(data.into_inner()).await?;
-    data_result(doc)
+) -> DataResult<DocumentDeltaResult, FlowyError> {
+    let result = controller.receive_document_delta(data.into_inner()).await?;
+    data_result(result)
 }
 
 pub(crate) async fn delete_view_handler(
@@ -79,6 +132,234 @@ pub(crate) async fn delete_view_handler(
     Ok(())
 }
 
+pub(crate) async fn trash_views_belong_to_handler(
+    data: Data<QueryAppRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+    let params: AppId = data.into_inner().try_into()?;
+    let _ = controller.trash_views_belong_to(&params.app_id).await?;
+    Ok(())
+}
+
+pub(crate) async fn verify_views_consistency_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ConsistencyReport, FlowyError> {
+    let report = controller.verify_views_consistency()?;
+    data_result(report)
+}
+
+pub(crate) async fn repair_orphaned_views_handler(
+    data: Data<RepairViewsRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepairReport, FlowyError> {
+    let report = controller.repair_orphaned_views(data.into_inner().strategy)?;
+    data_result(report)
+}
+
+pub(crate) async fn read_trashed_views_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, FlowyError> {
+    let repeated_view = controller.read_trashed_views()?;
+    data_result(repeated_view)
+}
+
+pub(crate) async fn read_all_user_views_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, FlowyError> {
+    let items = controller.read_all_user_views()?;
+    data_result(RepeatedView { items })
+}
+
+pub(crate) async fn read_views_belong_to_paged_handler(
+    data: Data<QueryViewsPageRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewsPage, FlowyError> {
+    let params: ViewsPageParams = data.into_inner().try_into()?;
+    let (items, total) = controller
+        .read_views_belong_to_paged(&params.belong_to_id, params.offset, params.limit)
+        .await?;
+    data_result(ViewsPage {
+        items,
+        total: total as i64,
+    })
+}
+
+pub(crate) async fn count_views_belong_to_handler(
+    data: Data<QueryViewsCountRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewCount, FlowyError> {
+    let belong_to_id = data.into_inner().belong_to_id;
+    let count = controller.count_views_belong_to(&belong_to_id)?;
+    data_result(ViewCount {
+        belong_to_id,
+        count: count as i64,
+    })
+}
+
+pub(crate) async fn count_views_grouped_by_parent_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedViewCount, FlowyError> {
+    let items = controller
+        .count_views_grouped_by_parent()?
+        .into_iter()
+        .map(|(belong_to_id, count)| ViewCount {
+            belong_to_id,
+            count: count as i64,
+        })
+        .collect::<Vec<ViewCount>>();
+    data_result(RepeatedViewCount { items })
+}
+
+pub(crate) async fn merge_local_into_handler(
+    data: Data<MergeLocalRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<MergeReport, FlowyError> {
+    let request = data.into_inner();
+    let report = controller.merge_local_into(&request.target_user_id).await?;
+    data_result(report)
+}
+
+pub(crate) async fn find_duplicate_views_handler(
+    data: Data<QueryViewsBelongToRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedDuplicateViews, FlowyError> {
+    let belong_to_id = data.into_inner().belong_to_id;
+    let items = controller
+        .find_duplicate_views(&belong_to_id)
+        .await?
+        .into_iter()
+        .map(|view_ids| DuplicateViews { view_ids })
+        .collect::<Vec<DuplicateViews>>();
+    data_result(RepeatedDuplicateViews { items })
+}
+
+pub(crate) async fn read_view_ancestors_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let repeated_view = controller.read_view_ancestors(&params.view_id)?;
+    data_result(repeated_view)
+}
+
+pub(crate) async fn set_view_thumbnail_handler(
+    data: Data<SetViewThumbnailRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let request = data.into_inner();
+    let view = controller.set_view_thumbnail_blob(&request.view_id, Bytes::from(request.data), &request.mime)?;
+    data_result(view)
+}
+
+pub(crate) async fn read_view_thumbnail_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewThumbnail, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    match controller.read_view_thumbnail(&params.view_id)? {
+        None => Err(FlowyError::record_not_found().context(format!("The view:{} has no thumbnail", params.view_id))),
+        Some((data, mime)) => data_result(ViewThumbnail {
+            data: data.to_vec(),
+            mime,
+        }),
+    }
+}
+
+pub(crate) async fn restore_view_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let view = controller.restore_view(&params.view_id).await?;
+    data_result(view)
+}
+
+pub(crate) async fn read_open_document_ids_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedViewId, FlowyError> {
+    let items = controller.open_document_ids();
+    data_result(RepeatedViewId { items })
+}
+
+pub(crate) async fn restore_open_views_handler(
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedDocumentDelta, FlowyError> {
+    let deltas = controller.restore_open_views().await?;
+    data_result(deltas)
+}
+
+pub(crate) async fn read_views_belong_to_handler(
+    data: Data<QueryViewsBelongToRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, FlowyError> {
+    let request = data.into_inner();
+    let repeated_view = controller
+        .read_views_belong_to_filtered(&request.belong_to_id, request.view_type)
+        .await?;
+    data_result(repeated_view)
+}
+
+pub(crate) async fn preview_delete_view_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedView, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let views = controller.preview_delete(&params.view_id)?;
+    data_result(RepeatedView { items: views })
+}
+
+pub(crate) async fn rename_view_handler(
+    data: Data<RenameViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let request = data.into_inner();
+    let view = controller.rename_view(&request.view_id, &request.name).await?;
+    data_result(view)
+}
+
+pub(crate) async fn set_view_visibility_handler(
+    data: Data<SetViewVisibilityRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let request = data.into_inner();
+    let view = controller
+        .set_view_visibility(&request.view_id, request.visibility)
+        .await?;
+    data_result(view)
+}
+
+pub(crate) async fn set_view_icon_handler(
+    data: Data<SetViewIconRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let request = data.into_inner();
+    let view = controller.set_view_icon(&request.view_id, &request.icon).await?;
+    data_result(view)
+}
+
+pub(crate) async fn set_view_readonly_handler(
+    data: Data<SetViewReadonlyRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let request = data.into_inner();
+    let view = controller
+        .set_view_readonly(&request.view_id, request.is_readonly)
+        .await?;
+    data_result(view)
+}
+
+pub(crate) async fn read_view_sync_state_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<ViewSyncStatus, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let state = controller.view_sync_state(&params.view_id);
+    data_result(ViewSyncStatus {
+        view_id: params.view_id,
+        state,
+    })
+}
+
 pub(crate) async fn open_view_handler(
     data: Data<QueryViewRequest>,
     controller: Unit<Arc<ViewController>>,
@@ -88,6 +369,53 @@ pub(crate) async fn open_view_handler(
     data_result(doc)
 }
 
+pub(crate) async fn open_view_offline_first_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentDelta, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let doc = controller.open_view_offline_first(params.into()).await?;
+    data_result(doc)
+}
+
+pub(crate) async fn document_stats_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentStats, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let stats = controller.document_stats(&params.view_id).await?;
+    data_result(stats)
+}
+
+pub(crate) async fn document_history_handler(
+    data: Data<QueryDocumentHistoryRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedRevisionSummary, FlowyError> {
+    let params: DocumentHistoryParams = data.into_inner().try_into()?;
+    let items = controller.read_document_history(&params.doc_id, params.limit)?;
+    data_result(RepeatedRevisionSummary { items })
+}
+
+pub(crate) async fn compact_document_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<CompactReport, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let report = controller.compact_document(&params.view_id).await?;
+    data_result(report)
+}
+
+pub(crate) async fn restore_document_handler(
+    data: Data<RestoreDocumentRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<DocumentDelta, FlowyError> {
+    let params: RestoreDocumentParams = data.into_inner().try_into()?;
+    let delta = controller
+        .restore_document_to_revision(&params.doc_id, params.rev_id)
+        .await?;
+    data_result(delta)
+}
+
 pub(crate) async fn close_view_handler(
     data: Data<QueryViewRequest>,
     controller: Unit<Arc<ViewController>>,
@@ -107,6 +435,28 @@ pub(crate) async fn duplicate_view_handler(
     Ok(())
 }
 
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn duplicate_view_recursive_handler(
+    data: Data<QueryViewRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<View, FlowyError> {
+    let params: ViewId = data.into_inner().try_into()?;
+    let view_id = params.view_id.clone();
+    let progress: ProgressCallback = Box::new(move |processed, total| {
+        send_dart_notification(&view_id, WorkspaceNotification::ViewProgressChanged)
+            .payload(ViewProgress {
+                view_id: view_id.clone(),
+                processed: processed as i64,
+                total: total as i64,
+            })
+            .send();
+    });
+    let view = controller
+        .duplicate_view_recursive(&params.view_id, Some(progress))
+        .await?;
+    data_result(view)
+}
+
 #[tracing::instrument(skip(data, controller), err)]
 pub(crate) async fn export_handler(
     data: Data<ExportRequest>,
@@ -116,3 +466,13 @@ pub(crate) async fn export_handler(
     let data = controller.export_doc(params).await?;
     data_result(data)
 }
+
+#[tracing::instrument(skip(data, controller), err)]
+pub(crate) async fn export_app_handler(
+    data: Data<ExportAppRequest>,
+    controller: Unit<Arc<ViewController>>,
+) -> DataResult<RepeatedExportData, FlowyError> {
+    let request = data.into_inner();
+    let items = controller.export_app(&request.app_id, request.export_type).await?;
+    data_result(RepeatedExportData { items })
+}
@@ -0,0 +1,99 @@
+use lib_ot::{
+    core::Operation,
+    rich_text::{RichTextAttributeKey, RichTextAttributes, RichTextDelta},
+};
+
+// Renders the delta to a minimal html fragment. Like the markdown converter,
+// the block attributes, e.g. header and list, are read from the attributes of
+// the '\n' that ends the line.
+pub(crate) fn delta_to_html(delta: &RichTextDelta) -> String {
+    let mut html = String::new();
+    let mut line = String::new();
+    let mut list_tag: Option<&'static str> = None;
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            if insert.is_embed() {
+                continue;
+            }
+
+            let mut segments = insert.s.split('\n').peekable();
+            while let Some(segment) = segments.next() {
+                push_inline(&mut line, segment, &insert.attributes);
+                if segments.peek().is_some() {
+                    push_line(&mut html, &mut list_tag, &line, &insert.attributes);
+                    line.clear();
+                }
+            }
+        }
+    }
+
+    if !line.is_empty() {
+        push_line(&mut html, &mut list_tag, &line, &RichTextAttributes::default());
+    }
+    close_list(&mut html, &mut list_tag);
+    html
+}
+
+fn push_inline(line: &mut String, text: &str, attributes: &RichTextAttributes) {
+    if text.is_empty() {
+        return;
+    }
+
+    let bold = is_enabled(attributes, RichTextAttributeKey::Bold);
+    let italic = is_enabled(attributes, RichTextAttributeKey::Italic);
+    if bold {
+        line.push_str("<strong>");
+    }
+    if italic {
+        line.push_str("<em>");
+    }
+    line.push_str(&escape_html(text));
+    if italic {
+        line.push_str("</em>");
+    }
+    if bold {
+        line.push_str("</strong>");
+    }
+}
+
+fn push_line(html: &mut String, list_tag: &mut Option<&'static str>, line: &str, attributes: &RichTextAttributes) {
+    let content = if line.is_empty() { "<br>" } else { line };
+    let new_list_tag = match attribute_value(attributes, RichTextAttributeKey::List) {
+        None => None,
+        Some("ordered") => Some("ol"),
+        Some(_) => Some("ul"),
+    };
+    if *list_tag != new_list_tag {
+        close_list(html, list_tag);
+        if let Some(tag) = new_list_tag {
+            html.push_str(&format!("<{}>", tag));
+        }
+        *list_tag = new_list_tag;
+    }
+
+    if list_tag.is_some() {
+        html.push_str(&format!("<li>{}</li>", content));
+        return;
+    }
+
+    match attribute_value(attributes, RichTextAttributeKey::Header).and_then(|level| level.parse::<usize>().ok()) {
+        Some(level) if (1..=6).contains(&level) => html.push_str(&format!("<h{0}>{1}</h{0}>", level, content)),
+        _ => html.push_str(&format!("<p>{}</p>", content)),
+    }
+}
+
+fn close_list(html: &mut String, list_tag: &mut Option<&'static str>) {
+    if let Some(tag) = list_tag.take() {
+        html.push_str(&format!("</{}>", tag));
+    }
+}
+
+fn attribute_value(attributes: &RichTextAttributes, key: RichTextAttributeKey) -> Option<&str> {
+    attributes.get(&key).and_then(|value| value.0.as_deref())
+}
+
+fn is_enabled(attributes: &RichTextAttributes, key: RichTextAttributeKey) -> bool {
+    matches!(attribute_value(attributes, key), Some("true"))
+}
+
+fn escape_html(s: &str) -> String { s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;") }
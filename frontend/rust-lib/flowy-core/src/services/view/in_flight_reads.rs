@@ -0,0 +1,44 @@
+use parking_lot::Mutex;
+use std::collections::HashSet;
+
+// Records the views that are being read from the server. The overlapping reads
+// of the same view are skipped while one is in flight, they don't wait for its
+// result either. The ViewUpdated notification of the read in flight refreshes
+// all of the callers.
+#[derive(Default)]
+pub(crate) struct InFlightReads {
+    view_ids: Mutex<HashSet<String>>,
+}
+
+impl InFlightReads {
+    // Returns false if the view is being read already.
+    pub(crate) fn begin(&self, view_id: &str) -> bool { self.view_ids.lock().insert(view_id.to_owned()) }
+
+    pub(crate) fn finish(&self, view_id: &str) { self.view_ids.lock().remove(view_id); }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InFlightReads;
+    use std::{sync::Arc, thread};
+
+    #[test]
+    fn in_flight_reads_begin_concurrently_test() {
+        let in_flight_reads = Arc::new(InFlightReads::default());
+        let handles = (0..8)
+            .map(|_| {
+                let in_flight_reads = in_flight_reads.clone();
+                thread::spawn(move || in_flight_reads.begin("view"))
+            })
+            .collect::<Vec<_>>();
+        let begun = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .filter(|is_begun| *is_begun)
+            .count();
+        assert_eq!(begun, 1);
+
+        in_flight_reads.finish("view");
+        assert!(in_flight_reads.begin("view"));
+    }
+}
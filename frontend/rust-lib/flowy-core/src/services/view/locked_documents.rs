@@ -0,0 +1,36 @@
+use flowy_error::FlowyError;
+use parking_lot::Mutex;
+use std::{collections::HashSet, sync::Arc};
+
+// The documents that are being rewritten, e.g. by a restore, reject the user's
+// edits.
+#[derive(Default)]
+pub(crate) struct LockedDocuments {
+    doc_ids: Arc<Mutex<HashSet<String>>>,
+}
+
+impl LockedDocuments {
+    // Fails with the ViewBusy error if the document is already locked.
+    pub(crate) fn lock(&self, doc_id: &str) -> Result<DocumentLock, FlowyError> {
+        if !self.doc_ids.lock().insert(doc_id.to_owned()) {
+            return Err(FlowyError::view_busy().context(format!("The view:{} is locked", doc_id)));
+        }
+        Ok(DocumentLock {
+            doc_id: doc_id.to_owned(),
+            doc_ids: self.doc_ids.clone(),
+        })
+    }
+
+    pub(crate) fn is_locked(&self, doc_id: &str) -> bool { self.doc_ids.lock().contains(doc_id) }
+}
+
+// The document is unlocked when the lock is dropped, so an early return or a
+// dropped future doesn't leave the document locked.
+pub struct DocumentLock {
+    doc_id: String,
+    doc_ids: Arc<Mutex<HashSet<String>>>,
+}
+
+impl Drop for DocumentLock {
+    fn drop(&mut self) { self.doc_ids.lock().remove(&self.doc_id); }
+}
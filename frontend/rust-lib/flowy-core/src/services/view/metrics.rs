@@ -0,0 +1,80 @@
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+// The counters of the view operations since the ViewController was created. The
+// counters are independent of each other, so the relaxed ordering is enough.
+#[derive(Default)]
+pub(crate) struct ViewMetricsCounter {
+    views_created: AtomicU64,
+    views_updated: AtomicU64,
+    views_deleted: AtomicU64,
+    server_sync_successes: AtomicU64,
+    server_sync_failures: AtomicU64,
+    server_reads: AtomicU64,
+    kv_writes: AtomicU64,
+    documents_opened: AtomicU64,
+    documents_closed: AtomicU64,
+    server_fetches_cancelled: AtomicU64,
+}
+
+impl ViewMetricsCounter {
+    pub(crate) fn view_created(&self) { self.views_created.fetch_add(1, Relaxed); }
+
+    pub(crate) fn view_updated(&self) { self.views_updated.fetch_add(1, Relaxed); }
+
+    pub(crate) fn view_deleted(&self) { self.views_deleted.fetch_add(1, Relaxed); }
+
+    pub(crate) fn views_deleted(&self, n: usize) { self.views_deleted.fetch_add(n as u64, Relaxed); }
+
+    pub(crate) fn server_synced(&self, is_success: bool) {
+        if is_success {
+            self.server_sync_successes.fetch_add(1, Relaxed);
+        } else {
+            self.server_sync_failures.fetch_add(1, Relaxed);
+        }
+    }
+
+    pub(crate) fn server_read(&self) { self.server_reads.fetch_add(1, Relaxed); }
+
+    pub(crate) fn kv_written(&self) { self.kv_writes.fetch_add(1, Relaxed); }
+
+    pub(crate) fn document_opened(&self) { self.documents_opened.fetch_add(1, Relaxed); }
+
+    pub(crate) fn documents_closed(&self, n: usize) { self.documents_closed.fetch_add(n as u64, Relaxed); }
+
+    // Counted per document, not per fetch of the document.
+    pub(crate) fn server_fetches_cancelled(&self, n: usize) {
+        self.server_fetches_cancelled.fetch_add(n as u64, Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> ViewMetrics {
+        ViewMetrics {
+            views_created: self.views_created.load(Relaxed),
+            views_updated: self.views_updated.load(Relaxed),
+            views_deleted: self.views_deleted.load(Relaxed),
+            server_sync_successes: self.server_sync_successes.load(Relaxed),
+            server_sync_failures: self.server_sync_failures.load(Relaxed),
+            server_reads: self.server_reads.load(Relaxed),
+            kv_writes: self.kv_writes.load(Relaxed),
+            documents_opened: self.documents_opened.load(Relaxed),
+            documents_closed: self.documents_closed.load(Relaxed),
+            server_fetches_cancelled: self.server_fetches_cancelled.load(Relaxed),
+        }
+    }
+}
+
+// The counters are read one by one, so the snapshot taken while the views are
+// being changed may be slightly inconsistent, e.g. for scraping by the
+// monitoring.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ViewMetrics {
+    pub views_created: u64,
+    pub views_updated: u64,
+    pub views_deleted: u64,
+    pub server_sync_successes: u64,
+    pub server_sync_failures: u64,
+    pub server_reads: u64,
+    pub kv_writes: u64,
+    pub documents_opened: u64,
+    pub documents_closed: u64,
+    pub server_fetches_cancelled: u64,
+}
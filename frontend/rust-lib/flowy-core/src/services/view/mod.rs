@@ -1,3 +1,14 @@
 pub mod controller;
 pub mod event_handler;
+mod html;
+mod in_flight_reads;
+pub(crate) mod locked_documents;
+pub(crate) mod metrics;
+pub(crate) mod offline_mode;
+mod open_views;
+pub(crate) mod processor;
+mod server_fetches;
 mod sql;
+mod sync_states;
+mod templates;
+pub(crate) mod view_changes;
@@ -0,0 +1,49 @@
+use crate::errors::{FlowyError, FlowyResult};
+use parking_lot::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering::SeqCst};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OfflineMode {
+    // Reads from the local and refreshes from the server in the background.
+    Auto,
+    // Never requests the server in the background, e.g. in tests or in the
+    // airplane mode.
+    ForceOffline,
+    // The reads and the changes that request the server fail with
+    // ErrorCode::ServerUnavailable if the network is down.
+    RequireOnline,
+}
+
+impl std::default::Default for OfflineMode {
+    fn default() -> Self { OfflineMode::Auto }
+}
+
+// The network is considered connected until it's reported otherwise.
+pub(crate) struct OfflineState {
+    mode: RwLock<OfflineMode>,
+    is_connected: AtomicBool,
+}
+
+impl std::default::Default for OfflineState {
+    fn default() -> Self {
+        Self {
+            mode: RwLock::new(OfflineMode::default()),
+            is_connected: AtomicBool::new(true),
+        }
+    }
+}
+
+impl OfflineState {
+    pub(crate) fn set_mode(&self, mode: OfflineMode) { *self.mode.write() = mode; }
+
+    pub(crate) fn set_connected(&self, is_connected: bool) { self.is_connected.store(is_connected, SeqCst); }
+
+    pub(crate) fn allows_server_task(&self) -> bool { *self.mode.read() != OfflineMode::ForceOffline }
+
+    pub(crate) fn check_online(&self) -> FlowyResult<()> {
+        if *self.mode.read() == OfflineMode::RequireOnline && !self.is_connected.load(SeqCst) {
+            return Err(FlowyError::server_unavailable());
+        }
+        Ok(())
+    }
+}
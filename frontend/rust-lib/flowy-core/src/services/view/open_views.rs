@@ -0,0 +1,45 @@
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+
+// Records the opened views from the most to the least recently used one, so
+// that the number of the opened documents is bounded by the capacity.
+pub(crate) struct OpenViewLRU {
+    capacity: usize,
+    doc_ids: Mutex<VecDeque<String>>,
+}
+
+impl OpenViewLRU {
+    pub(crate) fn new(capacity: usize) -> Self {
+        // Keep at least the view that was just opened
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            doc_ids: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    // Marks the doc_id as the most recently used one. Returns the doc_ids that
+    // exceed the capacity, they should be closed by the caller.
+    pub(crate) fn touch(&self, doc_id: &str) -> Vec<String> {
+        let mut doc_ids = self.doc_ids.lock();
+        doc_ids.retain(|id| id != doc_id);
+        doc_ids.push_front(doc_id.to_owned());
+
+        let mut evicted = vec![];
+        while doc_ids.len() > self.capacity {
+            if let Some(doc_id) = doc_ids.pop_back() {
+                evicted.push(doc_id);
+            }
+        }
+        evicted
+    }
+
+    // From the most to the least recently used one
+    pub(crate) fn doc_ids(&self) -> Vec<String> { self.doc_ids.lock().iter().cloned().collect() }
+
+    pub(crate) fn contains(&self, doc_id: &str) -> bool { self.doc_ids.lock().iter().any(|id| id == doc_id) }
+
+    pub(crate) fn remove(&self, doc_id: &str) { self.doc_ids.lock().retain(|id| id != doc_id); }
+
+    pub(crate) fn clear(&self) { self.doc_ids.lock().clear(); }
+}
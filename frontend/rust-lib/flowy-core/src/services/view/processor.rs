@@ -0,0 +1,132 @@
+use flowy_collaboration::entities::revision::RepeatedRevision;
+use flowy_core_data_model::entities::share::ExportType;
+use flowy_document::context::DocumentContext;
+use lib_infra::future::FutureResultSend;
+use lib_ot::rich_text::RichTextDelta;
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    entities::view::ViewType,
+    errors::{internal_error, FlowyError},
+    services::view::html::delta_to_html,
+};
+
+// Handles the data of the views of one ViewType, e.g. the document of the
+// ViewType::Doc. The ViewController routes the operations on the view's data to
+// the processor that is registered for the view's type.
+pub trait ViewDataProcessor: Send + Sync {
+    fn view_type(&self) -> ViewType;
+
+    // Saves the initial revisions of the view's data. Returns the rev_id of the
+    // last revision.
+    fn create_container(&self, view_id: &str, repeated_revision: RepeatedRevision)
+        -> FutureResultSend<i64, FlowyError>;
+
+    // Opens the view's data and returns it as json, the view is kept open until
+    // it's closed by the ViewController.
+    fn open_view(&self, view_id: &str) -> FutureResultSend<String, FlowyError>;
+
+    fn export_view(&self, view_id: &str, export_type: ExportType) -> FutureResultSend<String, FlowyError>;
+
+    // Transforms the data of the view, that was of the `from` type, to the data
+    // of this processor's type. The conversion isn't supported by default.
+    fn convert_view_data(&self, view_id: &str, from: ViewType) -> FutureResultSend<(), FlowyError> {
+        let msg = format!(
+            "The view:{} can't be converted from {:?} to {:?}",
+            view_id,
+            from,
+            self.view_type()
+        );
+        FutureResultSend::new(async move { Err(FlowyError::unsupported_view_conversion().context(msg)) })
+    }
+}
+
+// The views whose type has no registered processor are handled by the
+// default one.
+pub(crate) struct ViewDataProcessorMap {
+    default_processor: Arc<dyn ViewDataProcessor>,
+    processors: RwLock<HashMap<ViewType, Arc<dyn ViewDataProcessor>>>,
+}
+
+impl ViewDataProcessorMap {
+    pub(crate) fn new(default_processor: Arc<dyn ViewDataProcessor>) -> Self {
+        Self {
+            default_processor,
+            processors: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Replaces the processor that was registered for the same ViewType.
+    pub(crate) fn register(&self, processor: Arc<dyn ViewDataProcessor>) {
+        self.processors.write().insert(processor.view_type(), processor);
+    }
+
+    // Unlike get, the default processor is only returned for its own ViewType.
+    pub(crate) fn get_registered(&self, view_type: &ViewType) -> Option<Arc<dyn ViewDataProcessor>> {
+        match self.processors.read().get(view_type) {
+            Some(processor) => Some(processor.clone()),
+            None if &self.default_processor.view_type() == view_type => Some(self.default_processor.clone()),
+            None => None,
+        }
+    }
+
+    pub(crate) fn get(&self, view_type: &ViewType) -> Arc<dyn ViewDataProcessor> {
+        match self.processors.read().get(view_type) {
+            None => self.default_processor.clone(),
+            Some(processor) => processor.clone(),
+        }
+    }
+}
+
+pub(crate) struct DocumentViewDataProcessor {
+    document_ctx: Arc<DocumentContext>,
+}
+
+impl DocumentViewDataProcessor {
+    pub(crate) fn new(document_ctx: Arc<DocumentContext>) -> Self { Self { document_ctx } }
+}
+
+impl ViewDataProcessor for DocumentViewDataProcessor {
+    fn view_type(&self) -> ViewType { ViewType::Doc }
+
+    fn create_container(
+        &self,
+        view_id: &str,
+        repeated_revision: RepeatedRevision,
+    ) -> FutureResultSend<i64, FlowyError> {
+        let controller = self.document_ctx.controller.clone();
+        let view_id = view_id.to_owned();
+        FutureResultSend::new(async move { controller.save_document(&view_id, repeated_revision).await })
+    }
+
+    fn open_view(&self, view_id: &str) -> FutureResultSend<String, FlowyError> {
+        let controller = self.document_ctx.controller.clone();
+        let view_id = view_id.to_owned();
+        FutureResultSend::new(async move {
+            let editor = controller.open(&view_id).await?;
+            editor.document_json().await
+        })
+    }
+
+    // The data of the blank view is saved as a document too, nothing to transform.
+    fn convert_view_data(&self, _view_id: &str, _from: ViewType) -> FutureResultSend<(), FlowyError> {
+        FutureResultSend::new(async { Ok(()) })
+    }
+
+    fn export_view(&self, view_id: &str, export_type: ExportType) -> FutureResultSend<String, FlowyError> {
+        let controller = self.document_ctx.controller.clone();
+        let view_id = view_id.to_owned();
+        FutureResultSend::new(async move {
+            let editor = controller.open(&view_id).await?;
+            let delta_json = editor.document_json().await?;
+            match export_type {
+                ExportType::Html => {
+                    let delta = RichTextDelta::from_json(&delta_json).map_err(internal_error)?;
+                    Ok(delta_to_html(&delta))
+                },
+                _ => Ok(delta_json),
+            }
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use tokio::sync::watch;
+
+// Shared by the background server fetches of one document. It's cancelled when
+// the user switches to another document or closes it, so that the late results
+// of the fetches don't overwrite the newer state.
+#[derive(Clone)]
+pub(crate) struct CancellationToken(watch::Receiver<bool>);
+
+impl CancellationToken {
+    pub(crate) fn is_cancelled(&self) -> bool { *self.0.borrow() }
+
+    // Never resolves if the token isn't cancelled.
+    pub(crate) async fn cancelled(mut self) {
+        while !self.is_cancelled() {
+            if self.0.changed().await.is_err() {
+                futures::future::pending::<()>().await;
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct ServerFetches {
+    tokens: Mutex<HashMap<String, (watch::Sender<bool>, watch::Receiver<bool>)>>,
+    current_doc_id: Mutex<Option<String>>,
+}
+
+impl ServerFetches {
+    // The fetches that start after the document's fetches were cancelled receive
+    // a new token.
+    pub(crate) fn token(&self, doc_id: &str) -> CancellationToken {
+        let mut tokens = self.tokens.lock();
+        let (_, receiver) = tokens.entry(doc_id.to_owned()).or_insert_with(|| watch::channel(false));
+        CancellationToken(receiver.clone())
+    }
+
+    // Returns true if the document had a token to cancel.
+    pub(crate) fn cancel(&self, doc_id: &str) -> bool {
+        match self.tokens.lock().remove(doc_id) {
+            None => false,
+            Some((sender, _)) => {
+                let _ = sender.send(true);
+                true
+            },
+        }
+    }
+
+    // Cancels the fetches of the document that was opened before the doc_id.
+    pub(crate) fn switch_to(&self, doc_id: &str) -> bool {
+        let previous_doc_id = self.current_doc_id.lock().replace(doc_id.to_owned());
+        match previous_doc_id {
+            Some(previous_doc_id) if previous_doc_id != doc_id => self.cancel(&previous_doc_id),
+            _ => false,
+        }
+    }
+
+    // Returns the number of the documents whose tokens were cancelled.
+    pub(crate) fn cancel_all(&self) -> usize {
+        *self.current_doc_id.lock() = None;
+        let mut tokens = self.tokens.lock();
+        let n = tokens.len();
+        for (_, (sender, _)) in tokens.drain() {
+            let _ = sender.send(true);
+        }
+        n
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     entities::{
         trash::{Trash, TrashType},
-        view::{RepeatedView, UpdateViewParams, View, ViewType},
+        view::{RepeatedView, UpdateViewParams, View, ViewType, ViewVisibility},
     },
     errors::FlowyError,
     services::app::sql::AppTable,
@@ -9,10 +9,15 @@ use crate::{
 use diesel::sql_types::Integer;
 use flowy_database::{
     prelude::*,
-    schema::{view_table, view_table::dsl},
+    schema::{trash_table, view_table, view_table::dsl, view_thumbnail_table},
     SqliteConnection,
 };
 use lib_infra::timestamp;
+use std::collections::HashMap;
+
+// The ids of one IN (...) are bound as the variables of the statement, which
+// are limited to 999 by the older versions of SQLite.
+const MAX_BOUND_IDS: usize = 999;
 
 pub struct ViewTableSql {}
 
@@ -43,17 +48,153 @@ impl ViewTableSql {
         Ok(view_table)
     }
 
-    // belong_to_id will be the app_id or view_id.
+    // Reads the views with a query per MAX_BOUND_IDS ids instead of one per id.
+    // The views are returned in the order of the view_ids, it fails if any of
+    // them doesn't exist.
+    pub(crate) fn read_views_with_ids(
+        view_ids: &[String],
+        conn: &SqliteConnection,
+    ) -> Result<Vec<ViewTable>, FlowyError> {
+        let mut view_table_map = HashMap::with_capacity(view_ids.len());
+        for chunk in view_ids.chunks(MAX_BOUND_IDS) {
+            let view_tables = dsl::view_table
+                .filter(view_table::id.eq_any(chunk))
+                .load::<ViewTable>(conn)?;
+            for view_table in view_tables {
+                view_table_map.insert(view_table.id.clone(), view_table);
+            }
+        }
+
+        view_ids
+            .iter()
+            .map(|view_id| match view_table_map.get(view_id) {
+                None => Err(FlowyError::record_not_found().context(format!("The view:{} is not found", view_id))),
+                Some(view_table) => Ok(view_table.clone()),
+            })
+            .collect()
+    }
+
+    pub(crate) fn read_view_ids(conn: &SqliteConnection) -> Result<Vec<String>, FlowyError> {
+        let view_ids = dsl::view_table.select(view_table::id).load::<String>(conn)?;
+        Ok(view_ids)
+    }
+
+    // belong_to_id will be the app_id or view_id. The views are ordered by the
+    // view_index, the ones with the same view_index, e.g. the views created before
+    // the view_index was added, are ordered by the create_time.
     pub(crate) fn read_views(belong_to_id: &str, conn: &SqliteConnection) -> Result<Vec<ViewTable>, FlowyError> {
         let view_tables = dsl::view_table
             .filter(view_table::belong_to_id.eq(belong_to_id))
-            .order(view_table::create_time.asc())
+            .order((view_table::view_index.asc(), view_table::create_time.asc()))
             .into_boxed()
             .load::<ViewTable>(conn)?;
 
         Ok(view_tables)
     }
 
+    pub(crate) fn read_views_with_type(
+        belong_to_id: &str,
+        view_type: ViewTableType,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<ViewTable>, FlowyError> {
+        let view_tables = dsl::view_table
+            .filter(view_table::belong_to_id.eq(belong_to_id))
+            .filter(view_table::view_type.eq(view_type))
+            .order((view_table::view_index.asc(), view_table::create_time.asc()))
+            .into_boxed()
+            .load::<ViewTable>(conn)?;
+
+        Ok(view_tables)
+    }
+
+    // Reads the views in [offset, offset + limit) of the views that belong to the
+    // belong_to_id, the trashed views are skipped before the page is taken.
+    // Returns the page and the number of all the views that are not trashed. The
+    // trash is excluded by a subquery, so its ids aren't bound as variables.
+    pub(crate) fn read_views_page(
+        belong_to_id: &str,
+        offset: i64,
+        limit: i64,
+        conn: &SqliteConnection,
+    ) -> Result<(Vec<ViewTable>, i64), FlowyError> {
+        let total = Self::count_views(belong_to_id, conn)?;
+        let view_tables = dsl::view_table
+            .filter(view_table::belong_to_id.eq(belong_to_id))
+            .filter(view_table::id.ne_all(trash_table::table.select(trash_table::id)))
+            .order((view_table::view_index.asc(), view_table::create_time.asc()))
+            .limit(limit)
+            .offset(offset)
+            .load::<ViewTable>(conn)?;
+
+        Ok((view_tables, total))
+    }
+
+    // Counts the views that belong to the belong_to_id without loading them, the
+    // trashed views are not counted.
+    pub(crate) fn count_views(belong_to_id: &str, conn: &SqliteConnection) -> Result<i64, FlowyError> {
+        let count = dsl::view_table
+            .filter(view_table::belong_to_id.eq(belong_to_id))
+            .filter(view_table::id.ne_all(trash_table::table.select(trash_table::id)))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    // Returns the (belong_to_id, count) pairs of all the views except the trashed
+    // ones.
+    pub(crate) fn count_views_grouped_by_parent(conn: &SqliteConnection) -> Result<Vec<(String, i64)>, FlowyError> {
+        let counts = dsl::view_table
+            .filter(view_table::id.ne_all(trash_table::table.select(trash_table::id)))
+            .group_by(view_table::belong_to_id)
+            .select((view_table::belong_to_id, diesel::dsl::count(view_table::id)))
+            .load::<(String, i64)>(conn)?;
+        Ok(counts)
+    }
+
+    // Reads the views of the owner across all the parents, the views that were
+    // saved before the owner was recorded have an empty owner_id and are included
+    // too. The trashed views are skipped.
+    pub(crate) fn read_views_of_owner(owner_id: &str, conn: &SqliteConnection) -> Result<Vec<ViewTable>, FlowyError> {
+        let view_tables = dsl::view_table
+            .filter(view_table::owner_id.eq(owner_id).or(view_table::owner_id.eq("")))
+            .filter(view_table::id.ne_all(trash_table::table.select(trash_table::id)))
+            .order(view_table::create_time.asc())
+            .load::<ViewTable>(conn)?;
+        Ok(view_tables)
+    }
+
+    // The view_index that places the view after all the views of the belong_to_id.
+    pub(crate) fn next_view_index(belong_to_id: &str, conn: &SqliteConnection) -> Result<i32, FlowyError> {
+        let max_index = dsl::view_table
+            .filter(view_table::belong_to_id.eq(belong_to_id))
+            .select(diesel::dsl::max(view_table::view_index))
+            .first::<Option<i32>>(conn)?;
+        Ok(max_index.map(|index| index + 1).unwrap_or(0))
+    }
+
+    pub(crate) fn update_view_index(view_id: &str, view_index: i32, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = dsl::view_table.filter(view_table::id.eq(view_id));
+        let _ = diesel::update(filter)
+            .set(view_table::view_index.eq(view_index))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn update_view_type(
+        view_id: &str,
+        view_type: ViewTableType,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        let filter = dsl::view_table.filter(view_table::id.eq(view_id));
+        let _ = diesel::update(filter)
+            .set((
+                view_table::view_type.eq(view_type),
+                view_table::modified_time.eq(timestamp()),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
     pub(crate) fn update_view(changeset: ViewTableChangeset, conn: &SqliteConnection) -> Result<(), FlowyError> {
         diesel_update_table!(view_table, changeset, conn);
         Ok(())
@@ -61,6 +202,39 @@ impl ViewTableSql {
 
     pub(crate) fn delete_view(view_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
         diesel_delete_table!(view_table, view_id, conn);
+        let _ = ViewThumbnailTableSql::delete_thumbnail(view_id, conn)?;
+        Ok(())
+    }
+}
+
+pub struct ViewThumbnailTableSql {}
+
+impl ViewThumbnailTableSql {
+    pub(crate) fn create_thumbnail(
+        thumbnail_table: ViewThumbnailTable,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        let _ = diesel::replace_into(view_thumbnail_table::table)
+            .values(&thumbnail_table)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub(crate) fn read_thumbnail(
+        view_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Option<ViewThumbnailTable>, FlowyError> {
+        let thumbnail_table = view_thumbnail_table::dsl::view_thumbnail_table
+            .filter(view_thumbnail_table::view_id.eq(view_id))
+            .load::<ViewThumbnailTable>(conn)?
+            .pop();
+        Ok(thumbnail_table)
+    }
+
+    // The view may have no thumbnail, so no row being deleted is fine.
+    pub(crate) fn delete_thumbnail(view_id: &str, conn: &SqliteConnection) -> Result<(), FlowyError> {
+        let filter = view_thumbnail_table::dsl::view_thumbnail_table.filter(view_thumbnail_table::view_id.eq(view_id));
+        let _ = diesel::delete(filter).execute(conn)?;
         Ok(())
     }
 }
@@ -108,6 +282,14 @@ impl ViewTableSql {
 //     Ok(RepeatedView { items: views })
 // }
 
+#[derive(PartialEq, Clone, Debug, Queryable, Insertable)]
+#[table_name = "view_thumbnail_table"]
+pub(crate) struct ViewThumbnailTable {
+    pub view_id: String,
+    pub data: Vec<u8>,
+    pub mime: String,
+}
+
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
 #[belongs_to(AppTable, foreign_key = "belong_to_id")]
 #[table_name = "view_table"]
@@ -122,15 +304,18 @@ pub(crate) struct ViewTable {
     pub view_type: ViewTableType,
     pub version: i64,
     pub is_trash: bool,
+    pub is_readonly: bool,
+    pub owner_id: String,
+    pub visibility: ViewTableVisibility,
+    pub view_index: i32,
+    pub created_by: String,
+    pub icon: String,
 }
 
 impl ViewTable {
     pub fn new(view: View) -> Self {
-        let view_type = match view.view_type {
-            ViewType::Blank => ViewTableType::Docs,
-            ViewType::Doc => ViewTableType::Docs,
-        };
-
+        let view_type = ViewTableType::from(view.view_type);
+        let visibility = ViewTableVisibility::from(view.visibility);
         ViewTable {
             id: view.id,
             belong_to_id: view.belong_to_id,
@@ -143,6 +328,12 @@ impl ViewTable {
             view_type,
             version: 0,
             is_trash: false,
+            is_readonly: false,
+            owner_id: view.owner_id,
+            visibility,
+            view_index: 0,
+            created_by: view.created_by,
+            icon: view.icon,
         }
     }
 }
@@ -151,6 +342,7 @@ impl std::convert::From<ViewTable> for View {
     fn from(table: ViewTable) -> Self {
         let view_type = match table.view_type {
             ViewTableType::Docs => ViewType::Doc,
+            ViewTableType::Blank => ViewType::Blank,
         };
 
         View {
@@ -163,6 +355,10 @@ impl std::convert::From<ViewTable> for View {
             modified_time: table.modified_time,
             version: table.version,
             create_time: table.create_time,
+            owner_id: table.owner_id,
+            visibility: table.visibility.into(),
+            created_by: table.created_by,
+            icon: table.icon,
         }
     }
 }
@@ -187,6 +383,10 @@ pub(crate) struct ViewTableChangeset {
     pub desc: Option<String>,
     pub thumbnail: Option<String>,
     pub modified_time: i64,
+    pub is_readonly: Option<bool>,
+    pub visibility: Option<ViewTableVisibility>,
+    pub icon: Option<String>,
+    pub created_by: Option<String>,
 }
 
 impl ViewTableChangeset {
@@ -197,6 +397,10 @@ impl ViewTableChangeset {
             desc: params.desc,
             thumbnail: params.thumbnail,
             modified_time: timestamp(),
+            is_readonly: params.is_readonly,
+            visibility: params.visibility.map(ViewTableVisibility::from),
+            icon: params.icon,
+            created_by: None,
         }
     }
 
@@ -207,6 +411,12 @@ impl ViewTableChangeset {
             desc: Some(table.desc),
             thumbnail: Some(table.thumbnail),
             modified_time: table.modified_time,
+            // The server doesn't know the flag and the visibility, keep the local ones.
+            // The creator of the view never changes, so the local one is kept too.
+            is_readonly: None,
+            visibility: None,
+            icon: Some(table.icon),
+            created_by: None,
         }
     }
 }
@@ -215,7 +425,8 @@ impl ViewTableChangeset {
 #[repr(i32)]
 #[sql_type = "Integer"]
 pub enum ViewTableType {
-    Docs = 0,
+    Docs  = 0,
+    Blank = 1,
 }
 
 impl std::default::Default for ViewTableType {
@@ -226,6 +437,7 @@ impl std::convert::From<i32> for ViewTableType {
     fn from(value: i32) -> Self {
         match value {
             0 => ViewTableType::Docs,
+            1 => ViewTableType::Blank,
             o => {
                 log::error!("Unsupported view type {}, fallback to ViewType::Docs", o);
                 ViewTableType::Docs
@@ -234,8 +446,73 @@ impl std::convert::From<i32> for ViewTableType {
     }
 }
 
+impl std::convert::From<ViewType> for ViewTableType {
+    fn from(view_type: ViewType) -> Self {
+        match view_type {
+            ViewType::Blank => ViewTableType::Blank,
+            ViewType::Doc => ViewTableType::Docs,
+        }
+    }
+}
+
 impl ViewTableType {
     pub fn value(&self) -> i32 { *self as i32 }
 }
 
 impl_sql_integer_expression!(ViewTableType);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
+#[repr(i32)]
+#[sql_type = "Integer"]
+pub enum ViewTableVisibility {
+    Private   = 0,
+    Workspace = 1,
+    Public    = 2,
+}
+
+impl std::default::Default for ViewTableVisibility {
+    fn default() -> Self { ViewTableVisibility::Private }
+}
+
+impl std::convert::From<i32> for ViewTableVisibility {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => ViewTableVisibility::Private,
+            1 => ViewTableVisibility::Workspace,
+            2 => ViewTableVisibility::Public,
+            o => {
+                log::error!(
+                    "Unsupported view visibility {}, fallback to ViewTableVisibility::Private",
+                    o
+                );
+                ViewTableVisibility::Private
+            },
+        }
+    }
+}
+
+impl std::convert::From<ViewVisibility> for ViewTableVisibility {
+    fn from(visibility: ViewVisibility) -> Self {
+        match visibility {
+            ViewVisibility::Private => ViewTableVisibility::Private,
+            ViewVisibility::Workspace => ViewTableVisibility::Workspace,
+            ViewVisibility::Public => ViewTableVisibility::Public,
+        }
+    }
+}
+
+impl std::convert::From<ViewTableVisibility> for ViewVisibility {
+    fn from(visibility: ViewTableVisibility) -> Self {
+        match visibility {
+            ViewTableVisibility::Private => ViewVisibility::Private,
+            ViewTableVisibility::Workspace => ViewVisibility::Workspace,
+            ViewTableVisibility::Public => ViewVisibility::Public,
+        }
+    }
+}
+
+impl ViewTableVisibility {
+    pub fn value(&self) -> i32 { *self as i32 }
+}
+
+impl_sql_integer_expression!(ViewTableVisibility);
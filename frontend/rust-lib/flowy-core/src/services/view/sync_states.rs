@@ -0,0 +1,49 @@
+use crate::entities::view::ViewSyncState;
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering::SeqCst},
+};
+
+// Records the state of the last server request of each view. Every request gets
+// a sequence number, so a request that finishes after a newer one was made
+// doesn't override the state of the newer one.
+#[derive(Default)]
+pub(crate) struct ViewSyncStates {
+    states: RwLock<HashMap<String, (u64, ViewSyncState)>>,
+    seq: AtomicU64,
+}
+
+impl ViewSyncStates {
+    pub(crate) fn begin(&self, view_id: &str) -> u64 {
+        let seq = self.seq.fetch_add(1, SeqCst) + 1;
+        self.states
+            .write()
+            .insert(view_id.to_owned(), (seq, ViewSyncState::Pending));
+        seq
+    }
+
+    // Returns the new state, or None if a newer request was made after this one.
+    pub(crate) fn finish(&self, view_id: &str, seq: u64, is_success: bool) -> Option<ViewSyncState> {
+        match self.states.write().get_mut(view_id) {
+            Some((last_seq, state)) if *last_seq == seq => {
+                *state = if is_success {
+                    ViewSyncState::Synced
+                } else {
+                    ViewSyncState::Failed
+                };
+                Some(state.clone())
+            },
+            _ => None,
+        }
+    }
+
+    // The view that was never sent to the server in this session is considered
+    // synced.
+    pub(crate) fn state(&self, view_id: &str) -> ViewSyncState {
+        match self.states.read().get(view_id) {
+            None => ViewSyncState::Synced,
+            Some((_, state)) => state.clone(),
+        }
+    }
+}
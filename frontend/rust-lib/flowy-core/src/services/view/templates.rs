@@ -0,0 +1,39 @@
+use flowy_collaboration::document::default::initial_delta;
+use lib_ot::rich_text::RichTextDelta;
+use parking_lot::RwLock;
+use std::collections::BTreeMap;
+
+pub(crate) const EMPTY_TEMPLATE: &str = "empty";
+pub(crate) const MEETING_NOTES_TEMPLATE: &str = "meeting_notes";
+
+const MEETING_NOTES_JSON: &str = r#"[{"insert":"Meeting notes"},{"insert":"\n","attributes":{"header":1}},{"insert":"Date: \nAttendees: \n"},{"insert":"Agenda"},{"insert":"\n","attributes":{"header":2}},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"Action items"},{"insert":"\n","attributes":{"header":2}},{"insert":"\n","attributes":{"list":"unchecked"}}]"#;
+
+// The deltas that the document of a new view can start with, keyed by the
+// template's name. The built-in templates are registered when it's created.
+pub(crate) struct ViewTemplates {
+    templates: RwLock<BTreeMap<String, RichTextDelta>>,
+}
+
+impl ViewTemplates {
+    pub(crate) fn new() -> Self {
+        let templates = Self {
+            templates: RwLock::new(BTreeMap::new()),
+        };
+        templates.register(EMPTY_TEMPLATE, initial_delta());
+        templates.register(
+            MEETING_NOTES_TEMPLATE,
+            RichTextDelta::from_json(MEETING_NOTES_JSON).unwrap(),
+        );
+        templates
+    }
+
+    // Replaces the template that was registered with the same name.
+    pub(crate) fn register(&self, name: &str, delta: RichTextDelta) {
+        self.templates.write().insert(name.to_owned(), delta);
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<RichTextDelta> { self.templates.read().get(name).cloned() }
+
+    // Sorted by the name
+    pub(crate) fn names(&self) -> Vec<String> { self.templates.read().keys().cloned().collect() }
+}
@@ -0,0 +1,45 @@
+use tokio::sync::broadcast;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViewChangeKind {
+    Created,
+    Updated,
+    // Moved to the trash
+    Deleted,
+    // Put back from the trash
+    Restored,
+    // Deleted permanently
+    Removed,
+}
+
+// The lightweight event of any view's change, e.g. to refresh the sync badge
+// without subscribing to the notifications of every belong_to_id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewChangeEvent {
+    pub view_id: String,
+    pub belong_to_id: String,
+    pub kind: ViewChangeKind,
+}
+
+#[derive(Clone)]
+pub(crate) struct ViewChangeNotifier {
+    sender: broadcast::Sender<ViewChangeEvent>,
+}
+
+impl ViewChangeNotifier {
+    pub(crate) fn new() -> Self {
+        let (sender, _) = broadcast::channel(100);
+        Self { sender }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<ViewChangeEvent> { self.sender.subscribe() }
+
+    // Sending fails if there is no subscriber, which is fine.
+    pub(crate) fn notify(&self, view_id: &str, belong_to_id: &str, kind: ViewChangeKind) {
+        let _ = self.sender.send(ViewChangeEvent {
+            view_id: view_id.to_owned(),
+            belong_to_id: belong_to_id.to_owned(),
+            kind,
+        });
+    }
+}
@@ -22,7 +22,6 @@ pub(crate) struct RetryAction<Fut, T, E> {
 }
 
 impl<Fut, T, E> RetryAction<Fut, T, E> {
-    #[allow(dead_code)]
     pub(crate) fn new<F>(server: Server, user: Arc<dyn WorkspaceUser>, builder: F) -> Self
     where
         Fut: Future<Output = Result<T, E>> + Send + Sync + 'static,
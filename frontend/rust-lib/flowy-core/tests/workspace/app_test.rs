@@ -55,6 +55,9 @@ async fn app_create_with_view() {
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
         view_type: ViewType::Doc,
+        insert_after: None,
+        template: None,
+        icon: None,
     };
 
     let request_b = CreateViewRequest {
@@ -63,6 +66,9 @@ async fn app_create_with_view() {
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
         view_type: ViewType::Doc,
+        insert_after: None,
+        template: None,
+        icon: None,
     };
 
     let view_a = create_view_with_request(&test.sdk, request_a).await;
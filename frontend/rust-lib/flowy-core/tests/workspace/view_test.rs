@@ -1,9 +1,36 @@
-use flowy_core::entities::{
-    app::QueryAppRequest,
-    trash::{TrashId, TrashType},
-    view::*,
+use flowy_collaboration::{
+    entities::{doc::DocumentDelta, revision::RepeatedRevision},
+    RichTextDelta,
 };
-use flowy_test::{helper::*, FlowySDKTest};
+use flowy_core::{
+    core::{OfflineMode, ViewChangeEvent, ViewChangeKind, ViewDataProcessor, ViewMetrics},
+    entities::{
+        app::QueryAppRequest,
+        share::{ExportAppRequest, ExportRequest, ExportType},
+        trash::{TrashId, TrashType},
+        view::*,
+    },
+    errors::{internal_error, ErrorCode, FlowyError},
+    event::WorkspaceEvent::{
+        ApplyDocDelta,
+        CloseView,
+        CreateView,
+        ImportView,
+        MergeLocalInto,
+        ReadView,
+        ReadViewsBelongTo,
+        ReadViewsBelongToPaged,
+        RenameView,
+    },
+    module::{MOCK_UNAVAILABLE_VIEW_NAME, OPEN_VIEW_CAPACITY},
+    protobuf::WorkspaceNotification,
+};
+use flowy_database::{prelude::RunQueryDsl, sql_query};
+use flowy_net::entities::NetworkType;
+use flowy_test::{event_builder::*, helper::*, FlowySDKTest};
+use lib_infra::future::FutureResultSend;
+use std::sync::Arc;
+use tokio::time::{sleep, timeout, Duration};
 
 #[tokio::test]
 #[should_panic]
@@ -19,6 +46,527 @@ async fn view_delete() {
     let _ = read_view(&test.sdk, query).await;
 }
 
+#[tokio::test]
+async fn view_read_trashed_views() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    assert!(read_trashed_views(&test.sdk).await.items.is_empty());
+
+    test.delete_views(vec![test.view.id.clone()]).await;
+    let trashed_views = read_trashed_views(&test.sdk).await.items;
+    assert_eq!(trashed_views.len(), 1);
+    assert_eq!(trashed_views[0].id, test.view.id);
+    assert_eq!(trashed_views[0].name, test.view.name);
+}
+
+#[tokio::test]
+async fn view_read_all_user_views() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let views = read_all_user_views(&test.sdk).await.items;
+    assert!(views.iter().any(|view| view.id == test.view.id));
+
+    test.delete_views(vec![test.view.id.clone()]).await;
+    let views = read_all_user_views(&test.sdk).await.items;
+    assert!(views.iter().all(|view| view.id != test.view.id));
+}
+
+#[tokio::test]
+async fn view_trash_reconciled_on_restart() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let kept_view = create_view(&test.sdk, &test.app.id).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+
+    let mut rx = subscribe_notifications();
+    let _restarted = test.sdk.restart();
+    let app_id = test.app.id.clone();
+    let subject = wait_for_notification(&mut rx, WorkspaceNotification::AppsViewsChanged as i32, |subject| {
+        parse_notification::<RepeatedAppViews>(subject)
+            .items
+            .iter()
+            .any(|app_views| app_views.belong_to_id == app_id)
+    })
+    .await
+    .expect("The parent of the trashed view should be refreshed on startup");
+
+    let app_views = parse_notification::<RepeatedAppViews>(&subject)
+        .items
+        .into_iter()
+        .find(|app_views| app_views.belong_to_id == test.app.id)
+        .unwrap();
+    assert!(app_views.views.items.iter().any(|view| view.id == kept_view.id));
+    assert!(app_views.views.items.iter().all(|view| view.id != test.view.id));
+}
+
+#[tokio::test]
+async fn view_trash_of_several_parents_notified_at_once() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view_b = create_view(&test.sdk, &test.app.id).await;
+    let child_view = create_view(&test.sdk, &test.view.id).await;
+
+    let mut rx = subscribe_notifications();
+    test.delete_views(vec![view_b.id.clone(), child_view.id.clone()]).await;
+    let parent_ids = vec![test.app.id.clone(), test.view.id.clone()];
+    let subject = wait_for_notification(
+        &mut rx,
+        WorkspaceNotification::AppsViewsDeltaChanged as i32,
+        |subject| {
+            let items = parse_notification::<RepeatedAppViewsDelta>(subject).items;
+            parent_ids
+                .iter()
+                .all(|parent_id| items.iter().any(|delta| &delta.belong_to_id == parent_id))
+        },
+    )
+    .await
+    .expect("The deltas of the trashed views' parents should be sent by one notification");
+
+    let items = parse_notification::<RepeatedAppViewsDelta>(&subject).items;
+    let removed_of = |parent_id: &str| {
+        items
+            .iter()
+            .find(|delta| delta.belong_to_id == parent_id)
+            .unwrap()
+            .removed
+            .clone()
+    };
+    assert_eq!(removed_of(&test.app.id), vec![view_b.id.clone()]);
+    assert_eq!(removed_of(&test.view.id), vec![child_view.id.clone()]);
+}
+
+#[tokio::test]
+async fn view_filter_trashed() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let live_view = create_view(&test.sdk, &test.app.id).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+
+    let ids = vec![test.view.id.clone(), live_view.id.clone(), "unknown".to_owned()];
+    let trashed_ids = test.sdk.core.filter_trashed(&ids).unwrap();
+    assert_eq!(trashed_ids.len(), 1);
+    assert!(trashed_ids.contains(&test.view.id));
+}
+
+#[tokio::test]
+async fn view_restore_from_trash() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let view = restore_view(&test.sdk, request).await;
+    assert_eq!(view.id, test.view.id);
+    assert!(read_trashed_views(&test.sdk).await.items.is_empty());
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, request).await, test.view);
+}
+
+#[tokio::test]
+async fn view_read_ancestors() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child_view = create_view(&test.sdk, &test.view.id).await;
+    let grandchild_view = create_view(&test.sdk, &child_view.id).await;
+
+    let request = QueryViewRequest {
+        view_ids: vec![grandchild_view.id.clone()],
+    };
+    let ancestor_ids = read_view_ancestors(&test.sdk, request)
+        .await
+        .items
+        .into_iter()
+        .map(|view| view.id)
+        .collect::<Vec<String>>();
+    assert_eq!(ancestor_ids, vec![test.view.id.clone(), child_view.id.clone()]);
+
+    // The view that belongs to the app has no ancestor views
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert!(read_view_ancestors(&test.sdk, request).await.items.is_empty());
+}
+
+#[tokio::test]
+async fn view_set_thumbnail_blob() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = SetViewThumbnailRequest {
+        view_id: test.view.id.clone(),
+        data: vec![1, 2, 3],
+        mime: "image/png".to_owned(),
+    };
+    let view = set_view_thumbnail(&test.sdk, request).await;
+    assert_eq!(view.id, test.view.id);
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let thumbnail = read_view_thumbnail(&test.sdk, request).await;
+    assert_eq!(thumbnail.data, vec![1, 2, 3]);
+    assert_eq!(thumbnail.mime, "image/png");
+}
+
+#[tokio::test]
+async fn view_read_belong_to_paged() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut child_views = vec![];
+    for _ in 0..3 {
+        child_views.push(create_view(&test.sdk, &test.view.id).await);
+    }
+    let request = QueryViewRequest {
+        view_ids: vec![child_views[0].id.clone()],
+    };
+    delete_view(&test.sdk, request).await;
+
+    let request = QueryViewsPageRequest {
+        belong_to_id: test.view.id.clone(),
+        offset: 1,
+        limit: 5,
+    };
+    let page = read_views_belong_to_paged(&test.sdk, request).await;
+    assert_eq!(page.total, 2);
+    assert_eq!(page.items.len(), 1);
+    assert!(page.items.iter().all(|view| view.id != child_views[0].id));
+}
+
+#[tokio::test]
+async fn view_count_belong_to() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut child_views = vec![];
+    for _ in 0..3 {
+        child_views.push(create_view(&test.sdk, &test.view.id).await);
+    }
+    let request = QueryViewRequest {
+        view_ids: vec![child_views[0].id.clone()],
+    };
+    delete_view(&test.sdk, request).await;
+
+    let request = QueryViewsCountRequest {
+        belong_to_id: test.view.id.clone(),
+    };
+    let view_count = count_views_belong_to(&test.sdk, request).await;
+    assert_eq!(view_count.count, 2);
+
+    let view_counts = count_views_grouped_by_parent(&test.sdk).await;
+    let view_count = view_counts
+        .items
+        .iter()
+        .find(|view_count| view_count.belong_to_id == test.view.id)
+        .unwrap();
+    assert_eq!(view_count.count, 2);
+}
+
+#[tokio::test]
+async fn view_merge_local_into_other_user() {
+    let test = FlowySDKTest::setup();
+    let target = test.init_user().await;
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = RenameViewRequest {
+        view_id: test.view.id.clone(),
+        name: "merged view".to_owned(),
+    };
+    let _ = rename_view(&test.sdk, request).await;
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"merged text"}]"#.to_owned(),
+        content_hash: None,
+    };
+    let _ = apply_doc_delta(&test.sdk, request).await;
+
+    let request = MergeLocalRequest {
+        target_user_id: target.id.clone(),
+    };
+    let report = merge_local_into(&test.sdk, request).await;
+    assert!(report.merged_apps > 0);
+    assert!(report.merged_views > 0);
+
+    let _ = test.sdk.user_session.switch_session(&target.id).await.unwrap();
+    let merged_view = read_all_user_views(&test.sdk)
+        .await
+        .items
+        .into_iter()
+        .find(|view| view.name == "merged view")
+        .unwrap();
+    assert_ne!(merged_view.id, test.view.id);
+    let request = QueryViewRequest {
+        view_ids: vec![merged_view.id.clone()],
+    };
+    let document_info = open_view(&test.sdk, request).await;
+    assert!(document_info.text.contains("merged text"));
+}
+
+#[tokio::test]
+async fn view_merge_local_into_self() {
+    let test = FlowySDKTest::setup();
+    let user = test.init_user().await;
+
+    let request = MergeLocalRequest {
+        target_user_id: user.id.clone(),
+    };
+    let error = CoreModuleEventBuilder::new(test.clone())
+        .event(MergeLocalInto)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::UserIdInvalid.value());
+}
+
+#[tokio::test]
+async fn view_read_require_online() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.sdk.core.set_offline_mode(OfflineMode::RequireOnline);
+    test.sdk.core.network_state_changed(NetworkType::UnknownNetworkType);
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ServerUnavailable.value());
+
+    let request = QueryViewsPageRequest {
+        belong_to_id: test.app.id.clone(),
+        offset: 0,
+        limit: 5,
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadViewsBelongToPaged)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ServerUnavailable.value());
+
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: test.app.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ReadViewsBelongTo)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ServerUnavailable.value());
+
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "View B".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        insert_after: None,
+        template: None,
+        icon: None,
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ServerUnavailable.value());
+
+    test.sdk.core.network_state_changed(NetworkType::Wifi);
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, request).await.id, test.view.id);
+}
+
+#[tokio::test]
+async fn view_create_skips_server_in_force_offline() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.sdk.core.set_offline_mode(OfflineMode::ForceOffline);
+    let before = test.sdk.core.view_metrics();
+    let view = create_view(&test.sdk, &test.app.id).await;
+    let after = test.sdk.core.view_metrics();
+    assert_eq!(after.server_sync_successes, before.server_sync_successes);
+    assert_eq!(after.server_sync_failures, before.server_sync_failures);
+
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(
+        read_view_sync_state(&test.sdk, request).await.state,
+        ViewSyncState::Pending
+    );
+}
+
+#[tokio::test]
+async fn view_import_from_delta_json() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let delta_json = r#"[{"insert":"123\n"}]"#;
+    let request = ImportViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "imported".to_owned(),
+        delta_json: delta_json.to_owned(),
+    };
+    let view = import_view(&test.sdk, request).await;
+    assert_eq!(view.name, "imported");
+
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(open_view(&test.sdk, request).await.text, delta_json);
+}
+
+#[tokio::test]
+async fn view_upsert_is_idempotent() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let delta_json = r#"[{"insert":"123\n"}]"#;
+    let params = CreateViewParamsBuilder::new(&test.app.id, "upserted")
+        .view_data(delta_json.to_owned())
+        .build();
+    let view = test.sdk.core.upsert_view(params.clone()).await.unwrap();
+
+    let mut params = params;
+    params.name = "upserted again".to_owned();
+    params.view_data = r#"[{"insert":"456\n"}]"#.to_owned();
+    let upserted_view = test.sdk.core.upsert_view(params).await.unwrap();
+    assert_eq!(upserted_view.id, view.id);
+    assert_eq!(upserted_view.name, "upserted again");
+
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: test.app.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let views = read_views_belong_to(&test.sdk, request).await;
+    assert_eq!(views.items.iter().filter(|item| item.id == view.id).count(), 1);
+
+    // The document isn't saved again
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(open_view(&test.sdk, request).await.text, delta_json);
+}
+
+#[tokio::test]
+async fn view_upsert_keeps_created_by() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let params = CreateViewParamsBuilder::new(&test.app.id, "upserted").build();
+    let view = test.sdk.core.upsert_view(params.clone()).await.unwrap();
+    let set_created_by_sql = format!(
+        "UPDATE view_table SET created_by = 'another user' WHERE id = '{}'",
+        view.id
+    );
+    test.sdk
+        .core
+        .with_transaction(|conn| {
+            let _ = sql_query(&set_created_by_sql).execute(conn).map_err(internal_error)?;
+            Ok(())
+        })
+        .unwrap();
+
+    let _ = test.sdk.core.upsert_view(params).await.unwrap();
+    assert_eq!(
+        test.sdk.core.read_view_local(&view.id).unwrap().created_by,
+        "another user"
+    );
+}
+
+#[tokio::test]
+async fn view_import_from_invalid_delta_json() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = ImportViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "imported".to_owned(),
+        delta_json: r#"[{"insert":"#.to_owned(),
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ImportView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::SerdeError.value());
+}
+
+#[tokio::test]
+async fn view_find_duplicate_views() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut view_ids = vec![];
+    for delta_json in &[
+        r#"[{"insert":"123\n"}]"#,
+        r#"[{"insert":"456\n"}]"#,
+        r#"[{"insert":"123\n"}]"#,
+    ] {
+        let request = ImportViewRequest {
+            belong_to_id: test.view.id.clone(),
+            name: "imported".to_owned(),
+            delta_json: (*delta_json).to_owned(),
+        };
+        view_ids.push(import_view(&test.sdk, request).await.id);
+    }
+
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: test.view.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let duplicate_views = find_duplicate_views(&test.sdk, request).await;
+    assert_eq!(duplicate_views.items.len(), 1);
+    let mut duplicate_view_ids = duplicate_views.items[0].view_ids.clone();
+    duplicate_view_ids.sort();
+    let mut expected_view_ids = vec![view_ids[0].clone(), view_ids[2].clone()];
+    expected_view_ids.sort();
+    assert_eq!(duplicate_view_ids, expected_view_ids);
+}
+
 #[tokio::test]
 async fn view_delete_then_putback() {
     let test = FlowySDKTest::setup();
@@ -94,3 +642,1423 @@ async fn view_open_doc() {
     };
     let _ = open_view(&test.sdk, request).await;
 }
+
+#[tokio::test]
+async fn view_open_doc_offline_first() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let document = open_view_offline_first(&test.sdk, request).await;
+    assert_eq!(document.doc_id, test.view.id);
+    assert!(document.content_hash.is_some());
+    assert!(document.verify_content_hash());
+
+    let mut document = document;
+    document.delta_json = r#"[{"insert":"123\n"}]"#.to_owned();
+    assert!(!document.verify_content_hash());
+}
+
+#[tokio::test]
+async fn view_read_belong_to_with_view_type() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: test.app.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let repeated_view = read_views_belong_to(&test.sdk, request).await;
+    assert_eq!(repeated_view.len(), 1);
+    assert_eq!(repeated_view.items[0].id, test.view.id);
+}
+
+#[tokio::test]
+async fn view_read_document_stats() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let stats = read_document_stats(&test.sdk, request).await;
+    assert_eq!(stats.word_count, 0);
+    assert_eq!(stats.char_count, 0);
+    assert_eq!(stats.line_count, 1);
+}
+
+#[tokio::test]
+async fn view_read_document_stats_closes_editor() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = read_document_stats(&test.sdk, request).await;
+    assert!(!read_open_document_ids(&test.sdk).await.items.contains(&test.view.id));
+}
+
+#[tokio::test]
+async fn view_open_evicts_least_recently_used_at_capacity() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut view_ids = vec![test.view.id.clone()];
+    for _ in 0..OPEN_VIEW_CAPACITY {
+        view_ids.push(create_view(&test.sdk, &test.app.id).await.id);
+    }
+    for view_id in &view_ids {
+        let request = QueryViewRequest {
+            view_ids: vec![view_id.clone()],
+        };
+        let _ = open_view(&test.sdk, request).await;
+    }
+
+    let open_document_ids = read_open_document_ids(&test.sdk).await.items;
+    assert_eq!(open_document_ids.len(), OPEN_VIEW_CAPACITY);
+    assert!(!open_document_ids.contains(&view_ids[0]));
+    assert!(view_ids[1..].iter().all(|view_id| open_document_ids.contains(view_id)));
+}
+
+#[tokio::test]
+async fn view_reopen_skips_kv_write() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    let before = test.sdk.core.view_metrics();
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    assert_eq!(test.sdk.core.view_metrics().kv_writes, before.kv_writes);
+
+    let other_view = create_view(&test.sdk, &test.app.id).await;
+    let request = QueryViewRequest {
+        view_ids: vec![other_view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    assert!(test.sdk.core.view_metrics().kv_writes > before.kv_writes);
+}
+
+#[tokio::test]
+async fn view_apply_doc_delta() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"123"},{"retain":1}]"#.to_owned(),
+        content_hash: None,
+    };
+    let result = apply_doc_delta(&test.sdk, request).await;
+    assert_eq!(result.delta.doc_id, test.view.id);
+    assert_eq!(result.delta.delta_json, r#"[{"insert":"123\n"}]"#);
+    assert!(!result.was_transformed);
+    assert!(result.applied_rev_id > 0);
+}
+
+#[tokio::test]
+async fn view_apply_doc_delta_with_interceptor() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+    test.document_ctx
+        .set_delta_interceptor(Some(Arc::new(|_: RichTextDelta| {
+            RichTextDelta::from_json(r#"[{"insert":"456"}]"#).unwrap()
+        })));
+
+    let test = ViewTest::new(&test).await;
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"123"}]"#.to_owned(),
+        content_hash: None,
+    };
+    let result = apply_doc_delta(&test.sdk, request).await;
+    assert_eq!(result.delta.delta_json, r#"[{"insert":"456\n"}]"#);
+}
+
+#[tokio::test]
+async fn view_read_document_history() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut applied_rev_id = 0;
+    for s in &["1", "2"] {
+        let request = DocumentDelta {
+            doc_id: test.view.id.clone(),
+            delta_json: format!(r#"[{{"insert":"{}"}}]"#, s),
+            content_hash: None,
+        };
+        applied_rev_id = apply_doc_delta(&test.sdk, request).await.applied_rev_id;
+    }
+    // Wait for the revisions to be written to disk
+    sleep(Duration::from_millis(500)).await;
+
+    let request = QueryDocumentHistoryRequest {
+        doc_id: test.view.id.clone(),
+        limit: 10,
+    };
+    let history = read_document_history(&test.sdk, request).await.items;
+    assert!(history.len() >= 2);
+    assert_eq!(history[0].rev_id, applied_rev_id);
+    assert!(history.windows(2).all(|pair| pair[0].rev_id > pair[1].rev_id));
+
+    let request = QueryDocumentHistoryRequest {
+        doc_id: test.view.id.clone(),
+        limit: 1,
+    };
+    let history = read_document_history(&test.sdk, request).await.items;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].rev_id, applied_rev_id);
+}
+
+#[tokio::test]
+async fn view_compact_document() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut applied_rev_id = 0;
+    for s in &["1", "2", "3"] {
+        let request = DocumentDelta {
+            doc_id: test.view.id.clone(),
+            delta_json: format!(r#"[{{"insert":"{}"}}]"#, s),
+            content_hash: None,
+        };
+        applied_rev_id = apply_doc_delta(&test.sdk, request).await.applied_rev_id;
+    }
+    let query = || QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let text = open_view(&test.sdk, query()).await.text;
+    // Wait for the revisions to be acked, only the acked revisions are compacted
+    sleep(Duration::from_millis(2000)).await;
+
+    let report = compact_document(&test.sdk, query()).await;
+    assert_eq!(report.rev_id, applied_rev_id);
+    assert!(report.revisions_reclaimed > 0);
+    assert_eq!(open_view(&test.sdk, query()).await.text, text);
+
+    let request = QueryDocumentHistoryRequest {
+        doc_id: test.view.id.clone(),
+        limit: 10,
+    };
+    let history = read_document_history(&test.sdk, request).await.items;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].rev_id, applied_rev_id);
+}
+
+#[tokio::test]
+async fn view_apply_doc_delta_with_debounce() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut applied_rev_ids = vec![];
+    for s in &["1", "2", "3"] {
+        let request = DocumentDelta {
+            doc_id: test.view.id.clone(),
+            delta_json: format!(r#"[{{"insert":"{}"}}]"#, s),
+            content_hash: None,
+        };
+        let result = apply_doc_delta(&test.sdk, request).await;
+        // The document is updated immediately
+        assert!(result.delta.delta_json.contains(*s));
+        applied_rev_ids.push(result.applied_rev_id);
+    }
+    // Wait for the debounce window and the revisions to be written to disk
+    sleep(Duration::from_millis(1000)).await;
+
+    let request = QueryDocumentHistoryRequest {
+        doc_id: test.view.id.clone(),
+        limit: 10,
+    };
+    let history = read_document_history(&test.sdk, request).await.items;
+    assert_eq!(history[0].rev_id, applied_rev_ids[2]);
+    assert!(history
+        .iter()
+        .all(|summary| summary.rev_id != applied_rev_ids[0] && summary.rev_id != applied_rev_ids[1]));
+}
+
+#[tokio::test]
+async fn view_close_flushes_debounced_delta() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"123"}]"#.to_owned(),
+        content_hash: None,
+    };
+    let applied_rev_id = apply_doc_delta(&test.sdk, request).await.applied_rev_id;
+
+    // Close the view within the debounce window
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CloseView)
+        .request(request)
+        .async_send()
+        .await;
+
+    let request = QueryDocumentHistoryRequest {
+        doc_id: test.view.id.clone(),
+        limit: 1,
+    };
+    let history = read_document_history(&test.sdk, request).await.items;
+    assert_eq!(history[0].rev_id, applied_rev_id);
+}
+
+#[tokio::test]
+async fn view_close_all_flushes_debounced_delta() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"123"}]"#.to_owned(),
+        content_hash: None,
+    };
+    let applied_rev_id = apply_doc_delta(&test.sdk, request).await.applied_rev_id;
+
+    // Close the documents within the debounce window, e.g. on logout
+    test.sdk.document_ctx.controller.close_all().await;
+
+    let request = QueryDocumentHistoryRequest {
+        doc_id: test.view.id.clone(),
+        limit: 1,
+    };
+    let history = read_document_history(&test.sdk, request).await.items;
+    assert_eq!(history[0].rev_id, applied_rev_id);
+}
+
+#[tokio::test]
+async fn view_restore_document_to_revision() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut applied_rev_ids = vec![];
+    for s in &["1", "2"] {
+        let request = DocumentDelta {
+            doc_id: test.view.id.clone(),
+            delta_json: format!(r#"[{{"insert":"{}"}}]"#, s),
+            content_hash: None,
+        };
+        applied_rev_ids.push(apply_doc_delta(&test.sdk, request).await.applied_rev_id);
+        // Wait for the debounce window, otherwise the deltas are composed into one
+        // revision
+        sleep(Duration::from_millis(500)).await;
+    }
+    // Wait for the revisions to be written to disk
+    sleep(Duration::from_millis(500)).await;
+
+    let request = RestoreDocumentRequest {
+        doc_id: test.view.id.clone(),
+        rev_id: applied_rev_ids[0],
+    };
+    let delta = restore_document(&test.sdk, request).await;
+    assert_eq!(delta.delta_json, r#"[{"insert":"1\n"}]"#);
+
+    // The restore is a new revision, the history is kept
+    sleep(Duration::from_millis(500)).await;
+    let request = QueryDocumentHistoryRequest {
+        doc_id: test.view.id.clone(),
+        limit: 10,
+    };
+    let history = read_document_history(&test.sdk, request).await.items;
+    assert!(history[0].rev_id > applied_rev_ids[1]);
+    assert!(history.iter().any(|summary| summary.rev_id == applied_rev_ids[1]));
+}
+
+#[tokio::test]
+async fn view_document_encrypted_at_rest() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let controller = test.sdk.document_ctx.controller.clone();
+    controller.close_all().await;
+    assert!(controller.read_local_document(&test.view.id).await.unwrap().is_some());
+
+    let key = test.sdk.user_session.encryption_key();
+    assert!(key.is_some());
+    test.sdk.user_session.set_encryption_key(None);
+    assert!(controller.read_local_document(&test.view.id).await.is_err());
+
+    test.sdk.user_session.set_encryption_key(key);
+    assert!(controller.read_local_document(&test.view.id).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn view_trash_views_belong_to_app_closes_views() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    let before = test.sdk.core.view_metrics();
+
+    let request = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    trash_views_belong_to(&test.sdk, request).await;
+    assert!(!read_open_document_ids(&test.sdk).await.items.contains(&test.view.id));
+    assert_eq!(test.sdk.core.view_metrics().views_deleted, before.views_deleted);
+}
+
+#[tokio::test]
+async fn view_document_readable_after_restart() {
+    let test = FlowySDKTest::setup();
+    let user = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let query = || QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, query()).await;
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"123"}]"#.to_owned(),
+        content_hash: None,
+    };
+    let _ = apply_doc_delta(&test.sdk, request).await;
+    let text = open_view(&test.sdk, query()).await.text;
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CloseView)
+        .request(query())
+        .async_send()
+        .await;
+    let key = test.sdk.user_session.encryption_key();
+
+    // The session is restored after the restart, but the key isn't stored on
+    // disk, so it's unlocked by signing in again.
+    let restarted = test.sdk.restart();
+    assert!(restarted.user_session.has_active_session());
+    assert!(restarted.user_session.encryption_key().is_none());
+    let _ = async_sign_in(restarted.dispatcher(), &user.email, &login_password()).await;
+    assert_eq!(restarted.user_session.encryption_key(), key);
+    assert_eq!(open_view(&restarted, query()).await.text, text);
+}
+
+#[tokio::test]
+async fn view_trash_views_belong_to_app() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let _ = create_view(&test.sdk, &test.app.id).await;
+    let request = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    trash_views_belong_to(&test.sdk, request).await;
+
+    let request = QueryAppRequest {
+        app_ids: vec![test.app.id.clone()],
+    };
+    let app = read_app(&test.sdk, request).await;
+    assert!(app.belongings.items.is_empty());
+
+    let trash_ids = read_trash(&test.sdk)
+        .await
+        .items
+        .into_iter()
+        .map(|trash| trash.id)
+        .collect::<Vec<String>>();
+    assert_eq!(trash_ids.len(), 2);
+    assert!(trash_ids.contains(&test.view.id));
+}
+
+#[tokio::test]
+async fn view_verify_consistency() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let report = verify_views_consistency(&test.sdk).await;
+    assert!(!report.orphaned_views.contains(&test.view.id));
+    assert!(!report.orphaned_documents.contains(&test.view.id));
+
+    // Deleting the view permanently keeps its revisions on disk
+    test.delete_views_permanent(vec![test.view.id.clone()]).await;
+    let report = verify_views_consistency(&test.sdk).await;
+    assert!(report.orphaned_documents.contains(&test.view.id));
+}
+
+#[tokio::test]
+async fn view_repair_without_orphaned_views() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    assert!(verify_views_consistency(&test.sdk).await.orphaned_views.is_empty());
+
+    let request = RepairViewsRequest {
+        strategy: RepairStrategy::DeleteDanglingView,
+    };
+    let report = repair_orphaned_views(&test.sdk, request).await;
+    assert_eq!(report.created_documents, 0);
+    assert_eq!(report.deleted_views, 0);
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, request).await.id, test.view.id);
+}
+
+#[tokio::test]
+async fn view_read_open_document_ids() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    assert!(read_open_document_ids(&test.sdk).await.items.contains(&test.view.id));
+
+    test.sdk.document_ctx.controller.close_all().await;
+    assert!(read_open_document_ids(&test.sdk).await.items.is_empty());
+}
+
+#[tokio::test]
+async fn view_restore_open_views() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view_b = create_view(&test.sdk, &test.app.id).await;
+    for view_id in &[&test.view.id, &view_b.id] {
+        let request = QueryViewRequest {
+            view_ids: vec![view_id.to_string()],
+        };
+        let _ = open_view(&test.sdk, request).await;
+    }
+
+    let doc_ids = restore_open_views(&test.sdk)
+        .await
+        .items
+        .into_iter()
+        .map(|delta| delta.doc_id)
+        .collect::<Vec<String>>();
+    assert_eq!(doc_ids, vec![view_b.id.clone(), test.view.id.clone()]);
+
+    // The trashed view is skipped
+    test.delete_views(vec![view_b.id.clone()]).await;
+    let doc_ids = restore_open_views(&test.sdk)
+        .await
+        .items
+        .into_iter()
+        .map(|delta| delta.doc_id)
+        .collect::<Vec<String>>();
+    assert_eq!(doc_ids, vec![test.view.id.clone()]);
+}
+
+#[tokio::test]
+async fn view_set_readonly() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = SetViewReadonlyRequest {
+        view_id: test.view.id.clone(),
+        is_readonly: true,
+    };
+    let _ = set_view_readonly(&test.sdk, request).await;
+
+    // The read-only view can still be opened
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let document = open_view_offline_first(&test.sdk, request).await;
+    assert_eq!(document.doc_id, test.view.id);
+
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"123"}]"#.to_owned(),
+        content_hash: None,
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ApplyDocDelta)
+        .request(request.clone())
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewReadOnly.value());
+
+    let request_readonly = SetViewReadonlyRequest {
+        view_id: test.view.id.clone(),
+        is_readonly: false,
+    };
+    let _ = set_view_readonly(&test.sdk, request_readonly).await;
+    let result = apply_doc_delta(&test.sdk, request).await;
+    assert_eq!(result.delta.delta_json, r#"[{"insert":"123\n"}]"#);
+}
+
+#[tokio::test]
+async fn view_set_visibility() {
+    let test = FlowySDKTest::setup();
+    let user_id = test.init_user().await.id;
+
+    let test = ViewTest::new(&test).await;
+    assert_eq!(test.view.owner_id, user_id);
+    assert_eq!(test.view.visibility, ViewVisibility::Private);
+
+    let request = SetViewVisibilityRequest {
+        view_id: test.view.id.clone(),
+        visibility: ViewVisibility::Public,
+    };
+    let view = set_view_visibility(&test.sdk, request).await;
+    assert_eq!(view.visibility, ViewVisibility::Public);
+
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: test.app.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let views = read_views_belong_to(&test.sdk, request).await;
+    let view = views.iter().find(|view| view.id == test.view.id).unwrap();
+    assert_eq!(view.owner_id, user_id);
+    assert_eq!(view.visibility, ViewVisibility::Public);
+}
+
+#[tokio::test]
+async fn view_set_icon() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    assert_eq!(test.view.icon, "");
+
+    let request = SetViewIconRequest {
+        view_id: test.view.id.clone(),
+        icon: "🚀".to_owned(),
+    };
+    let view = set_view_icon(&test.sdk, request).await;
+    assert_eq!(view.icon, "🚀");
+
+    let query = || QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, query()).await.icon, "🚀");
+
+    // The empty icon removes the icon
+    let request = SetViewIconRequest {
+        view_id: test.view.id.clone(),
+        icon: "".to_owned(),
+    };
+    let _ = set_view_icon(&test.sdk, request).await;
+    assert_eq!(read_view(&test.sdk, query()).await.icon, "");
+}
+
+#[tokio::test]
+async fn view_create_with_icon() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Launch".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        insert_after: None,
+        template: None,
+        icon: Some("🚀".to_owned()),
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    assert_eq!(view.icon, "🚀");
+
+    let query = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, query).await.icon, "🚀");
+}
+
+#[tokio::test]
+async fn view_rename() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = RenameViewRequest {
+        view_id: test.view.id.clone(),
+        name: "renamed view".to_owned(),
+    };
+    let view = rename_view(&test.sdk, request).await;
+    assert_eq!(view.name, "renamed view");
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, request).await.name, "renamed view");
+}
+
+#[tokio::test]
+async fn view_rename_with_empty_name() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = RenameViewRequest {
+        view_id: test.view.id.clone(),
+        name: "   ".to_owned(),
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(RenameView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewNameInvalid.value());
+}
+
+#[tokio::test]
+async fn view_rename_with_too_long_name() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = RenameViewRequest {
+        view_id: test.view.id.clone(),
+        name: "a".repeat(257),
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(RenameView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewNameTooLong.value());
+}
+
+#[tokio::test]
+async fn view_export_document_metadata() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = ExportRequest {
+        doc_id: test.view.id.clone(),
+        export_type: ExportType::Markdown,
+    };
+    let data = export_document(&test.sdk, request).await;
+    assert_eq!(data.file_name, format!("{}.md", test.view.name));
+    assert_eq!(data.size_in_bytes, data.data.len() as i64);
+}
+
+#[tokio::test]
+async fn view_export_app() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child_view = create_view(&test.sdk, &test.view.id).await;
+    let trashed_view = create_view(&test.sdk, &test.app.id).await;
+    test.delete_views(vec![trashed_view.id.clone()]).await;
+
+    let request = ExportAppRequest {
+        app_id: test.app.id.clone(),
+        export_type: ExportType::Markdown,
+    };
+    let items = export_app(&test.sdk, request).await.items;
+    let file_names = items.iter().map(|data| data.file_name.clone()).collect::<Vec<String>>();
+    assert_eq!(
+        file_names,
+        vec![format!("{}.md", test.view.name), format!("{} (1).md", child_view.name)]
+    );
+}
+
+#[tokio::test]
+async fn view_export_document_sanitize_file_name() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = RenameViewRequest {
+        view_id: test.view.id.clone(),
+        name: "a/b:c?.".to_owned(),
+    };
+    let _ = rename_view(&test.sdk, request).await;
+
+    let request = ExportRequest {
+        doc_id: test.view.id.clone(),
+        export_type: ExportType::Text,
+    };
+    let data = export_document(&test.sdk, request).await;
+    assert_eq!(data.file_name, "a_b_c_.txt");
+}
+
+#[tokio::test]
+async fn view_export_document_to_html() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"a<b>&","attributes":{"bold":"true"}},{"insert":"\nitem"},{"retain":1,"attributes":{"header":"1"}},{"insert":"\n","attributes":{"list":"bullet"}}]"#.to_owned(),
+        content_hash: None,
+    };
+    let _ = apply_doc_delta(&test.sdk, request).await;
+
+    let request = ExportRequest {
+        doc_id: test.view.id.clone(),
+        export_type: ExportType::Html,
+    };
+    let data = export_document(&test.sdk, request).await;
+    assert_eq!(data.file_name, format!("{}.html", test.view.name));
+    assert_eq!(
+        data.data,
+        "<p><strong>a&lt;b&gt;&amp;</strong></p><h1>item</h1><ul><li><br></li></ul>"
+    );
+}
+
+#[tokio::test]
+async fn view_sync_state_after_create() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let status = read_view_sync_state(&test.sdk, request).await;
+    assert_eq!(status.view_id, test.view.id);
+    assert_eq!(status.state, ViewSyncState::Synced);
+}
+
+#[tokio::test]
+async fn view_preview_delete_skips_trashed_descendants() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child_view = create_view(&test.sdk, &test.view.id).await;
+    let trashed_view = create_view(&test.sdk, &test.view.id).await;
+    let _ = create_view(&test.sdk, &trashed_view.id).await;
+    test.delete_views(vec![trashed_view.id.clone()]).await;
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let view_ids = preview_delete_view(&test.sdk, request)
+        .await
+        .items
+        .into_iter()
+        .map(|view| view.id)
+        .collect::<Vec<String>>();
+    assert_eq!(view_ids, vec![test.view.id.clone(), child_view.id.clone()]);
+}
+
+#[tokio::test]
+async fn view_duplicate_recursive() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child_view = create_view(&test.sdk, &test.view.id).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let duplicated_view = duplicate_view_recursive(&test.sdk, request).await;
+    assert_eq!(duplicated_view.belong_to_id, test.view.belong_to_id);
+    assert_eq!(duplicated_view.name, format!("{} (copy)", test.view.name));
+
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: duplicated_view.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let duplicated_children = read_views_belong_to(&test.sdk, request).await;
+    assert_eq!(duplicated_children.len(), 1);
+    assert_eq!(duplicated_children[0].name, child_view.name);
+    assert_ne!(duplicated_children[0].id, child_view.id);
+}
+
+#[tokio::test]
+async fn view_duplicate_recursive_skips_trashed_views() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let child_view = create_view(&test.sdk, &test.view.id).await;
+    let trashed_view = create_view(&test.sdk, &test.view.id).await;
+    test.delete_views(vec![trashed_view.id.clone()]).await;
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let duplicated_view = duplicate_view_recursive(&test.sdk, request).await;
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: duplicated_view.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let duplicated_children = read_views_belong_to(&test.sdk, request).await;
+    assert_eq!(duplicated_children.len(), 1);
+    assert_eq!(duplicated_children[0].name, child_view.name);
+}
+
+#[tokio::test]
+async fn view_open_with_registered_processor() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.sdk
+        .core
+        .register_view_data_processor(Arc::new(BlankViewDataProcessor));
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "blank".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Blank,
+        insert_after: None,
+        template: None,
+        icon: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(open_view(&test.sdk, request).await.text, BLANK_VIEW_DATA);
+
+    // The views of the other types are still handled by the document processor
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_ne!(open_view(&test.sdk, request).await.text, BLANK_VIEW_DATA);
+}
+
+const BLANK_VIEW_DATA: &str = "blank";
+
+struct BlankViewDataProcessor;
+
+impl ViewDataProcessor for BlankViewDataProcessor {
+    fn view_type(&self) -> ViewType { ViewType::Blank }
+
+    fn create_container(
+        &self,
+        _view_id: &str,
+        _repeated_revision: RepeatedRevision,
+    ) -> FutureResultSend<i64, FlowyError> {
+        FutureResultSend::new(async { Ok(0) })
+    }
+
+    fn open_view(&self, _view_id: &str) -> FutureResultSend<String, FlowyError> {
+        FutureResultSend::new(async { Ok(BLANK_VIEW_DATA.to_owned()) })
+    }
+
+    fn export_view(&self, _view_id: &str, _export_type: ExportType) -> FutureResultSend<String, FlowyError> {
+        FutureResultSend::new(async { Ok(BLANK_VIEW_DATA.to_owned()) })
+    }
+}
+
+#[tokio::test]
+async fn view_metrics_snapshot() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let before = test.sdk.core.view_metrics();
+    assert!(before.views_created >= 1);
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    let request = RenameViewRequest {
+        view_id: test.view.id.clone(),
+        name: "renamed view".to_owned(),
+    };
+    let _ = rename_view(&test.sdk, request).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+
+    let after = test.sdk.core.view_metrics();
+    assert_eq!(
+        after,
+        ViewMetrics {
+            views_updated: before.views_updated + 1,
+            views_deleted: before.views_deleted + 1,
+            documents_opened: before.documents_opened + 1,
+            ..after.clone()
+        }
+    );
+}
+
+#[tokio::test]
+async fn view_metrics_count_permanent_delete_and_thumbnail() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let before = test.sdk.core.view_metrics();
+    let request = SetViewThumbnailRequest {
+        view_id: test.view.id.clone(),
+        data: vec![1, 2, 3],
+        mime: "image/png".to_owned(),
+    };
+    let _ = set_view_thumbnail(&test.sdk, request).await;
+    assert_eq!(test.sdk.core.view_metrics().views_updated, before.views_updated + 1);
+
+    // Moving the view to the trash and deleting it permanently are both counted.
+    test.delete_views_permanent(vec![test.view.id.clone()]).await;
+    assert_eq!(test.sdk.core.view_metrics().views_deleted, before.views_deleted + 2);
+}
+
+#[tokio::test]
+async fn view_read_local_skips_server() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let before = test.sdk.core.view_metrics();
+    let view = test.sdk.core.read_view_local(&test.view.id).unwrap();
+    assert_eq!(view, test.view);
+    assert_eq!(test.sdk.core.view_metrics().server_reads, before.server_reads);
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = read_view(&test.sdk, request).await;
+    assert_eq!(test.sdk.core.view_metrics().server_reads, before.server_reads + 1);
+
+    test.delete_views(vec![test.view.id.clone()]).await;
+    assert!(test.sdk.core.read_view_local(&test.view.id).is_err());
+}
+
+#[tokio::test]
+async fn view_create_after_sibling() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view_b = create_view(&test.sdk, &test.app.id).await;
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "View C".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        insert_after: Some(test.view.id.clone()),
+        template: None,
+        icon: None,
+    };
+    let view_c = create_view_with_request(&test.sdk, request).await;
+    let view_d = create_view(&test.sdk, &test.app.id).await;
+
+    let request = QueryViewsBelongToRequest {
+        belong_to_id: test.app.id.clone(),
+        view_type: ViewType::Doc,
+    };
+    let view_ids = read_views_belong_to(&test.sdk, request)
+        .await
+        .items
+        .into_iter()
+        .map(|view| view.id)
+        .collect::<Vec<String>>();
+    assert_eq!(view_ids, vec![test.view.id.clone(), view_c.id, view_b.id, view_d.id]);
+}
+
+#[tokio::test]
+async fn view_subscribe_any_view_change() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut receiver = test.sdk.core.subscribe_any_view_change();
+    let request = RenameViewRequest {
+        view_id: test.view.id.clone(),
+        name: "renamed view".to_owned(),
+    };
+    let _ = rename_view(&test.sdk, request).await;
+    test.delete_views(vec![test.view.id.clone()]).await;
+
+    let mut events = vec![];
+    while let Ok(Ok(event)) = timeout(Duration::from_millis(500), receiver.recv()).await {
+        events.push(event);
+    }
+    let expected = |kind: ViewChangeKind| ViewChangeEvent {
+        view_id: test.view.id.clone(),
+        belong_to_id: test.app.id.clone(),
+        kind,
+    };
+    assert_eq!(
+        events,
+        vec![expected(ViewChangeKind::Updated), expected(ViewChangeKind::Deleted)]
+    );
+}
+
+#[tokio::test]
+async fn view_subscribe_any_view_change_of_permanent_delete() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut receiver = test.sdk.core.subscribe_any_view_change();
+    test.delete_views_permanent(vec![test.view.id.clone()]).await;
+
+    let mut kinds = vec![];
+    while let Ok(Ok(event)) = timeout(Duration::from_millis(500), receiver.recv()).await {
+        assert_eq!(event.view_id, test.view.id);
+        assert_eq!(event.belong_to_id, test.app.id);
+        kinds.push(event.kind);
+    }
+    assert_eq!(kinds, vec![ViewChangeKind::Deleted, ViewChangeKind::Removed]);
+}
+
+#[tokio::test]
+async fn view_broadcast_cursor_without_server() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let mut receiver = test.sdk.core.subscribe_cursors(&test.view.id);
+    let _ = test.sdk.core.broadcast_cursor(&test.view.id, 3).unwrap();
+
+    // No other user receives the cursor of the local-only session
+    let result = timeout(Duration::from_millis(200), receiver.recv()).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn view_locked_document_rejects_edits() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let _ = open_view(&test.sdk, request).await;
+    let lock = test.sdk.core.lock_document(&test.view.id).unwrap();
+    assert!(test.sdk.core.lock_document(&test.view.id).is_err());
+
+    let request = DocumentDelta {
+        doc_id: test.view.id.clone(),
+        delta_json: r#"[{"insert":"123"}]"#.to_owned(),
+        content_hash: None,
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(ApplyDocDelta)
+        .request(request.clone())
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::ViewBusy.value());
+
+    drop(lock);
+    let result = apply_doc_delta(&test.sdk, request).await;
+    assert_eq!(result.delta.doc_id, test.view.id);
+}
+
+#[tokio::test]
+async fn view_convert_view_type() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "blank".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Blank,
+        insert_after: None,
+        template: None,
+        icon: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    let converted_view = test.sdk.core.convert_view_type(&view.id, ViewType::Doc).await.unwrap();
+    assert_eq!(converted_view.view_type, ViewType::Doc);
+
+    // No processor of the blank view is registered
+    let error = test
+        .sdk
+        .core
+        .convert_view_type(&test.view.id, ViewType::Blank)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::UnsupportedViewConversion.value());
+
+    // The processor doesn't support converting the document
+    test.sdk
+        .core
+        .register_view_data_processor(Arc::new(BlankViewDataProcessor));
+    let error = test
+        .sdk
+        .core
+        .convert_view_type(&test.view.id, ViewType::Blank)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::UnsupportedViewConversion.value());
+
+    // The view_type is rolled back
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    assert_eq!(read_view(&test.sdk, request).await.view_type, ViewType::Doc);
+}
+
+#[tokio::test]
+async fn view_created_by_current_user() {
+    let test = FlowySDKTest::setup();
+    let user_id = test.init_user().await.id;
+
+    let test = ViewTest::new(&test).await;
+    assert_eq!(test.view.created_by, user_id);
+
+    let request = QueryViewRequest {
+        view_ids: vec![test.view.id.clone()],
+    };
+    let view = read_view(&test.sdk, request).await;
+    assert_eq!(view.created_by, user_id);
+}
+
+#[tokio::test]
+async fn view_create_with_template() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let templates = test.sdk.core.list_templates();
+    assert!(templates.contains(&"empty".to_owned()));
+    assert!(templates.contains(&"meeting_notes".to_owned()));
+
+    let delta = RichTextDelta::from_json(r#"[{"insert":"Weekly report\n"}]"#).unwrap();
+    test.sdk.core.register_template("weekly_report", delta.clone());
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Report".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        insert_after: None,
+        template: Some("weekly_report".to_owned()),
+        icon: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    let query = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(open_view(&test.sdk, query).await.delta().unwrap(), delta);
+}
+
+#[tokio::test]
+async fn view_create_with_unknown_template() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: "Report".to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        insert_after: None,
+        template: Some("not_registered".to_owned()),
+        icon: None,
+    };
+    let error = CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CreateView)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+#[tokio::test]
+async fn view_switch_and_close_cancel_server_fetches() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view_a = test.view.clone();
+    let view_b = create_view(&test.sdk, &test.app.id).await;
+    let query = |view_id: &str| QueryViewRequest {
+        view_ids: vec![view_id.to_owned()],
+    };
+
+    let text = open_view(&test.sdk, query(&view_a.id)).await.text;
+    let _ = open_view_offline_first(&test.sdk, query(&view_a.id)).await;
+    let _ = read_view(&test.sdk, query(&view_a.id)).await;
+    let before = test.sdk.core.view_metrics();
+    // Switching to another view cancels the fetches of the previous one
+    let _ = open_view(&test.sdk, query(&view_b.id)).await;
+    assert_eq!(
+        test.sdk.core.view_metrics().server_fetches_cancelled,
+        before.server_fetches_cancelled + 1
+    );
+
+    // Re-opening the current view doesn't cancel its own fetches, closing it does
+    let _ = open_view_offline_first(&test.sdk, query(&view_b.id)).await;
+    assert_eq!(
+        test.sdk.core.view_metrics().server_fetches_cancelled,
+        before.server_fetches_cancelled + 1
+    );
+    CoreModuleEventBuilder::new(test.sdk.clone())
+        .event(CloseView)
+        .request(query(&view_b.id))
+        .async_send()
+        .await;
+    assert_eq!(
+        test.sdk.core.view_metrics().server_fetches_cancelled,
+        before.server_fetches_cancelled + 2
+    );
+    sleep(Duration::from_millis(500)).await;
+
+    // The views can be read and opened again after their fetches were cancelled
+    assert_eq!(read_view(&test.sdk, query(&view_a.id)).await, view_a);
+    assert_eq!(open_view(&test.sdk, query(&view_a.id)).await.text, text);
+}
+
+#[tokio::test]
+async fn view_read_many_keeps_the_order_of_ids() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let view_b = create_view(&test.sdk, &test.app.id).await;
+    let view_c = create_view(&test.sdk, &test.app.id).await;
+    let view_ids = vec![view_c.id.clone(), test.view.id.clone(), view_b.id.clone()];
+
+    // More ids than one query binds, so the views are read in several chunks.
+    let repeated_ids = view_ids.repeat(400);
+    let views = test.sdk.core.read_views_local(&repeated_ids).unwrap();
+    assert_eq!(
+        views.iter().map(|view| view.id.clone()).collect::<Vec<String>>(),
+        repeated_ids
+    );
+    assert_eq!(views[0], view_c);
+    assert_eq!(views[1], test.view);
+    assert_eq!(views[2], view_b);
+
+    let mut missing_ids = view_ids.clone();
+    missing_ids.insert(1, "not exist view id".to_owned());
+    let error = test.sdk.core.read_views_local(&missing_ids).unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+#[tokio::test]
+async fn view_with_transaction_rolls_back_on_error() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let rename_sql = format!(
+        "UPDATE view_table SET name = 'renamed in transaction' WHERE id = '{}'",
+        test.view.id
+    );
+    let result = test.sdk.core.with_transaction(|conn| {
+        let _ = sql_query(&rename_sql).execute(conn).map_err(internal_error)?;
+        Err::<(), _>(FlowyError::internal().context("Abort the transaction"))
+    });
+    assert!(result.is_err());
+    assert_eq!(
+        test.sdk.core.read_view_local(&test.view.id).unwrap().name,
+        test.view.name
+    );
+
+    test.sdk
+        .core
+        .with_transaction(|conn| {
+            let _ = sql_query(&rename_sql).execute(conn).map_err(internal_error)?;
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(
+        test.sdk.core.read_view_local(&test.view.id).unwrap().name,
+        "renamed in transaction"
+    );
+}
+
+#[tokio::test]
+async fn view_create_kept_locally_if_server_fails() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    let before = test.sdk.core.view_metrics();
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: MOCK_UNAVAILABLE_VIEW_NAME.to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        insert_after: None,
+        template: None,
+        icon: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    assert_eq!(
+        test.sdk.core.read_view_local(&view.id).unwrap().name,
+        MOCK_UNAVAILABLE_VIEW_NAME
+    );
+    assert_eq!(
+        test.sdk.core.view_metrics().server_sync_failures,
+        before.server_sync_failures + 1
+    );
+
+    // The creation is retried in the background, the failed state is replaced by
+    // the pending one
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(
+        read_view_sync_state(&test.sdk, request).await.state,
+        ViewSyncState::Pending
+    );
+}
+
+#[tokio::test]
+async fn view_create_stays_pending_in_force_offline_if_server_fails() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+
+    let test = ViewTest::new(&test).await;
+    test.sdk.core.set_offline_mode(OfflineMode::ForceOffline);
+    let request = CreateViewRequest {
+        belong_to_id: test.app.id.clone(),
+        name: MOCK_UNAVAILABLE_VIEW_NAME.to_owned(),
+        desc: "".to_owned(),
+        thumbnail: None,
+        view_type: ViewType::Doc,
+        insert_after: None,
+        template: None,
+        icon: None,
+    };
+    let view = create_view_with_request(&test.sdk, request).await;
+    assert!(test.sdk.core.read_view_local(&view.id).is_ok());
+
+    let request = QueryViewRequest {
+        view_ids: vec![view.id.clone()],
+    };
+    assert_eq!(
+        read_view_sync_state(&test.sdk, request).await.state,
+        ViewSyncState::Pending
+    );
+}
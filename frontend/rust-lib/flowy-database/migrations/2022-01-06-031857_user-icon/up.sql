@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE user_table ADD COLUMN icon_url TEXT NOT NULL DEFAULT '';
\ No newline at end of file
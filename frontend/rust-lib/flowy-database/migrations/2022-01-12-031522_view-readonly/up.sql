@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE view_table ADD COLUMN is_readonly BOOLEAN NOT NULL DEFAULT FALSE;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE view_thumbnail_table;
\ No newline at end of file
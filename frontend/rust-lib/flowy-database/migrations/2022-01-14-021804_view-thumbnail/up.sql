@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE view_thumbnail_table (
+    view_id TEXT NOT NULL PRIMARY KEY,
+    data BLOB NOT NULL DEFAULT (x''),
+    mime TEXT NOT NULL DEFAULT ''
+);
\ No newline at end of file
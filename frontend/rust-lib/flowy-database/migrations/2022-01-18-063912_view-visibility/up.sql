@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE view_table ADD COLUMN owner_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE view_table ADD COLUMN visibility INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE view_table ADD COLUMN view_index INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
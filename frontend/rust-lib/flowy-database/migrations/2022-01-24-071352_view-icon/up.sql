@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE view_table ADD COLUMN icon TEXT NOT NULL DEFAULT '';
\ No newline at end of file
@@ -152,6 +152,18 @@ impl_set_func!(set_float, float_value, f64);
 
 impl_get_func!(get_str,str_value=>String);
 
+impl KV {
+    // Same as set_str, but skips the write if the stored value is equal to the
+    // value. Returns true if the value was written.
+    pub fn set_str_if_changed(key: &str, value: String) -> bool {
+        if KV::get_str(key).as_ref() == Some(&value) {
+            return false;
+        }
+        KV::set_str(key, value);
+        true
+    }
+}
+
 impl_get_func!(get_int,int_value=>i64);
 
 impl_get_func!(get_float,float_value=>f64);
@@ -220,4 +232,20 @@ mod tests {
 
         assert_eq!(KV::get_bool("2"), None);
     }
+
+    #[test]
+    fn kv_set_str_if_changed_test() {
+        let dir = "./temp/";
+        if !std::path::Path::new(dir).exists() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+
+        KV::init(dir).unwrap();
+
+        let _ = KV::remove("latest");
+        assert!(KV::set_str_if_changed("latest", "a".to_string()));
+        assert!(!KV::set_str_if_changed("latest", "a".to_string()));
+        assert!(KV::set_str_if_changed("latest", "b".to_string()));
+        assert_eq!(KV::get_str("latest").unwrap(), "b");
+    }
 }
@@ -30,6 +30,7 @@ table! {
         data -> Binary,
         state -> Integer,
         ty -> Integer,
+        timestamp -> BigInt,
     }
 }
 
@@ -51,6 +52,7 @@ table! {
         token -> Text,
         email -> Text,
         workspace -> Text,
+        icon_url -> Text,
     }
 }
 
@@ -66,6 +68,20 @@ table! {
         view_type -> Integer,
         version -> BigInt,
         is_trash -> Bool,
+        is_readonly -> Bool,
+        owner_id -> Text,
+        visibility -> Integer,
+        view_index -> Integer,
+        created_by -> Text,
+        icon -> Text,
+    }
+}
+
+table! {
+    view_thumbnail_table (view_id) {
+        view_id -> Text,
+        data -> Binary,
+        mime -> Text,
     }
 }
 
@@ -88,5 +104,6 @@ allow_tables_to_appear_in_same_query!(
     trash_table,
     user_table,
     view_table,
+    view_thumbnail_table,
     workspace_table,
 );
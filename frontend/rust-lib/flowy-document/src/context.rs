@@ -9,18 +9,28 @@ use crate::{
 use backend_service::configuration::ClientServerConfiguration;
 
 use flowy_database::ConnectionPool;
-use std::sync::Arc;
+use lib_ot::rich_text::RichTextDelta;
+use parking_lot::RwLock;
+use std::{sync::Arc, time::Duration};
 
 pub trait DocumentUser: Send + Sync {
     fn user_dir(&self) -> Result<String, FlowyError>;
     fn user_id(&self) -> Result<String, FlowyError>;
     fn token(&self) -> Result<String, FlowyError>;
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
+    // The revisions are encrypted before they are written to disk if the key
+    // exists.
+    fn encryption_key(&self) -> Option<Vec<u8>>;
 }
 
+// Transforms the deltas that are received from the UI before they are applied,
+// e.g. to strip the embeds that are not allowed.
+pub type DeltaInterceptor = Arc<dyn Fn(RichTextDelta) -> RichTextDelta + Send + Sync>;
+
 pub struct DocumentContext {
     pub controller: Arc<DocumentController>,
     pub user: Arc<dyn DocumentUser>,
+    delta_interceptor: RwLock<Option<DeltaInterceptor>>,
 }
 
 impl DocumentContext {
@@ -29,12 +39,20 @@ impl DocumentContext {
         ws_receivers: Arc<DocumentWSReceivers>,
         ws_sender: Arc<dyn DocumentWebSocket>,
         server_config: &ClientServerConfiguration,
+        delta_debounce: Duration,
     ) -> DocumentContext {
         let server = construct_doc_server(server_config);
-        let doc_ctrl = Arc::new(DocumentController::new(server, user.clone(), ws_receivers, ws_sender));
+        let doc_ctrl = Arc::new(DocumentController::new(
+            server,
+            user.clone(),
+            ws_receivers,
+            ws_sender,
+            delta_debounce,
+        ));
         Self {
             controller: doc_ctrl,
             user,
+            delta_interceptor: RwLock::new(None),
         }
     }
 
@@ -42,4 +60,10 @@ impl DocumentContext {
         let _ = self.controller.init()?;
         Ok(())
     }
+
+    pub fn set_delta_interceptor(&self, interceptor: Option<DeltaInterceptor>) {
+        *self.delta_interceptor.write() = interceptor;
+    }
+
+    pub fn delta_interceptor(&self) -> Option<DeltaInterceptor> { self.delta_interceptor.read().clone() }
 }
@@ -4,24 +4,36 @@ use crate::{
     services::{
         doc::{
             edit::ClientDocumentEditor,
-            revision::{RevisionCache, RevisionManager, RevisionServer},
+            revision::{
+                Persistence,
+                RevisionCache,
+                RevisionCipher,
+                RevisionDiskCache,
+                RevisionManager,
+                RevisionServer,
+            },
             DocumentWSReceivers,
             DocumentWebSocket,
+            RevisionRecord,
             WSStateReceiver,
         },
         server::Server,
     },
+    sql_tables::RevisionTableSql,
 };
 use bytes::Bytes;
 use dashmap::DashMap;
 use flowy_collaboration::entities::{
-    doc::{DocumentDelta, DocumentId, DocumentInfo},
-    revision::RepeatedRevision,
+    doc::{DocumentDelta, DocumentDeltaResult, DocumentId, DocumentInfo},
+    revision::{CompactReport, RepeatedRevision, Revision, RevisionState, RevisionSummary},
+    ws::{CursorUpdate, DocumentClientWSData},
 };
 use flowy_database::ConnectionPool;
-use flowy_error::FlowyResult;
+use flowy_error::{internal_error, FlowyResult};
 use lib_infra::future::FutureResult;
-use std::sync::Arc;
+use lib_ot::rich_text::RichTextDelta;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast;
 
 pub struct DocumentController {
     server: Server,
@@ -29,6 +41,7 @@ pub struct DocumentController {
     ws_sender: Arc<dyn DocumentWebSocket>,
     open_cache: Arc<OpenDocCache>,
     user: Arc<dyn DocumentUser>,
+    delta_debounce: Duration,
 }
 
 impl DocumentController {
@@ -37,6 +50,7 @@ impl DocumentController {
         user: Arc<dyn DocumentUser>,
         ws_receivers: Arc<DocumentWSReceivers>,
         ws_sender: Arc<dyn DocumentWebSocket>,
+        delta_debounce: Duration,
     ) -> Self {
         let open_cache = Arc::new(OpenDocCache::new());
         Self {
@@ -45,6 +59,7 @@ impl DocumentController {
             ws_sender,
             open_cache,
             user,
+            delta_debounce,
         }
     }
 
@@ -62,15 +77,76 @@ impl DocumentController {
         self.get_editor(doc_id).await
     }
 
+    // The debounced edits are flushed before the document is closed, so nothing
+    // is written to the database of the user after it's closed. The document is
+    // closed even if the flush fails.
     #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
-    pub fn close<T: AsRef<str>>(&self, doc_id: T) -> Result<(), FlowyError> {
+    pub async fn close<T: AsRef<str>>(&self, doc_id: T) -> Result<(), FlowyError> {
         let doc_id = doc_id.as_ref();
         tracing::Span::current().record("doc_id", &doc_id);
+        let result = self.flush(doc_id).await;
         self.open_cache.remove(doc_id);
         self.ws_receivers.remove(doc_id);
+        result
+    }
+
+    // Writes the debounced edits of the opened document to the disk. Does nothing
+    // if the document isn't opened.
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub async fn flush<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<()> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        if let Some(editor) = self.open_cache.get(doc_id) {
+            let _ = editor.rev_manager().flush().await?;
+        }
         Ok(())
     }
 
+    // Squashes the revisions of the document to reclaim the space. It's safe to
+    // compact the opened document, its edits are flushed first.
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub async fn compact_document<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<CompactReport> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        let rev_manager = match self.open_cache.get(doc_id) {
+            Some(editor) => editor.rev_manager(),
+            None => {
+                let db_pool = self.user.db_pool()?;
+                Arc::new(self.make_rev_manager(doc_id, db_pool)?)
+            },
+        };
+        rev_manager.compact().await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn close_all(&self) {
+        for doc_id in self.open_cache.doc_ids() {
+            if let Err(e) = self.flush(&doc_id).await {
+                log::error!("Flush the document:{} failed: {:?}", doc_id, e);
+            }
+            self.open_cache.remove(&doc_id);
+            self.ws_receivers.remove(&doc_id);
+        }
+    }
+
+    // Sends the cursor of the current user to the other users of the document.
+    // Does nothing without the server, there is no other user.
+    pub fn broadcast_cursor(&self, doc_id: &str, position: usize) -> FlowyResult<()> {
+        if !cfg!(feature = "http_server") {
+            return Ok(());
+        }
+        self.ws_sender
+            .send(DocumentClientWSData::cursor(doc_id, position as i64))
+    }
+
+    // Receives the cursors of the other users until the document is closed.
+    pub fn subscribe_cursors(&self, doc_id: &str) -> broadcast::Receiver<CursorUpdate> {
+        self.ws_receivers.subscribe_cursors(doc_id)
+    }
+
+    // Returns the ids of the documents that have an opened editor.
+    pub fn open_document_ids(&self) -> Vec<String> { self.open_cache.doc_ids() }
+
     #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
     pub fn delete<T: AsRef<str>>(&self, doc_id: T) -> Result<(), FlowyError> {
         let doc_id = doc_id.as_ref();
@@ -81,22 +157,106 @@ impl DocumentController {
     }
 
     #[tracing::instrument(level = "debug", skip(self, delta), fields(doc_id = %delta.doc_id), err)]
-    pub async fn apply_document_delta(&self, delta: DocumentDelta) -> Result<DocumentDelta, FlowyError> {
+    pub async fn apply_document_delta(&self, delta: DocumentDelta) -> Result<DocumentDeltaResult, FlowyError> {
         let editor = self.get_editor(&delta.doc_id).await?;
-        let _ = editor.compose_local_delta(Bytes::from(delta.delta_json)).await?;
+        let (rev_id, was_transformed) = editor.compose_local_delta(Bytes::from(delta.delta_json)).await?;
         let document_json = editor.document_json().await?;
-        Ok(DocumentDelta {
-            doc_id: delta.doc_id.clone(),
-            delta_json: document_json,
+        Ok(DocumentDeltaResult {
+            delta: DocumentDelta::with_content_hash(&delta.doc_id, document_json),
+            was_transformed,
+            applied_rev_id: rev_id.into(),
         })
     }
 
-    pub async fn save_document<T: AsRef<str>>(&self, doc_id: T, revisions: RepeatedRevision) -> FlowyResult<()> {
+    // Returns the document json of the opened editor, or the one composed from the
+    // locally persisted revisions. The server is never touched.
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub async fn read_local_document<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<Option<String>> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        if let Some(editor) = self.open_cache.get(doc_id) {
+            let document_json = editor.document_json().await?;
+            return Ok(Some(document_json));
+        }
+
+        let db_pool = self.user.db_pool()?;
+        let rev_manager = self.make_rev_manager(doc_id, db_pool)?;
+        let delta = rev_manager.read_local_document()?;
+        Ok(delta.map(|delta| delta.to_json()))
+    }
+
+    // Returns the summaries of the locally persisted revisions, newest first.
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub fn read_document_history<T: AsRef<str>>(&self, doc_id: T, limit: usize) -> FlowyResult<Vec<RevisionSummary>> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        let db_pool = self.user.db_pool()?;
+        let rev_manager = self.make_rev_manager(doc_id, db_pool)?;
+        rev_manager.read_revision_summaries(limit)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, doc_id), fields(doc_id), err)]
+    pub fn read_document_at_revision<T: AsRef<str>>(&self, doc_id: T, rev_id: i64) -> FlowyResult<RichTextDelta> {
+        let doc_id = doc_id.as_ref();
+        tracing::Span::current().record("doc_id", &doc_id);
+        let db_pool = self.user.db_pool()?;
+        let rev_manager = self.make_rev_manager(doc_id, db_pool)?;
+        rev_manager.read_document_at(rev_id)
+    }
+
+    // Returns the ids of all the documents that have revisions on disk.
+    pub fn document_ids(&self) -> FlowyResult<Vec<String>> {
+        let db_pool = self.user.db_pool()?;
+        let conn = db_pool.get().map_err(internal_error)?;
+        RevisionTableSql::read_doc_ids(&*conn)
+    }
+
+    // Same as document_ids, but only checks the one document.
+    pub fn has_document<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<bool> {
+        let db_pool = self.user.db_pool()?;
+        let conn = db_pool.get().map_err(internal_error)?;
+        RevisionTableSql::has_revisions(doc_id.as_ref(), &*conn)
+    }
+
+    // Returns the rev_id of the last saved revision.
+    pub async fn save_document<T: AsRef<str>>(&self, doc_id: T, revisions: RepeatedRevision) -> FlowyResult<i64> {
         let doc_id = doc_id.as_ref().to_owned();
         let db_pool = self.user.db_pool()?;
         let rev_manager = self.make_rev_manager(&doc_id, db_pool)?;
-        let _ = rev_manager.reset_document(revisions).await?;
-        Ok(())
+        rev_manager.reset_document(revisions).await
+    }
+
+    // Writes the revisions of many documents in one transaction, e.g. to seed the
+    // documents that are missing.
+    pub fn save_documents(&self, revisions: Vec<Revision>) -> FlowyResult<()> {
+        let user_id = self.user.user_id()?;
+        let db_pool = self.user.db_pool()?;
+        let persistence = Persistence::new(&user_id, db_pool.clone(), self.make_cipher()?);
+        let records = revisions
+            .into_iter()
+            .map(|revision| RevisionRecord {
+                revision,
+                state: RevisionState::Local,
+                write_to_disk: false,
+            })
+            .collect::<Vec<_>>();
+
+        let conn = db_pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| persistence.write_revision_records(records, &*conn))
+    }
+
+    // Writes the revision into the database of another user, e.g. to merge the
+    // local documents into the user that signs in. The key of the other user is
+    // locked, so the revision is written without encryption.
+    pub fn save_document_of(&self, user_id: &str, db_pool: Arc<ConnectionPool>, revision: Revision) -> FlowyResult<()> {
+        let persistence = Persistence::new(user_id, db_pool.clone(), None);
+        let record = RevisionRecord {
+            revision,
+            state: RevisionState::Local,
+            write_to_disk: false,
+        };
+        let conn = db_pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| persistence.write_revision_records(vec![record], &*conn))
     }
 
     async fn get_editor(&self, doc_id: &str) -> FlowyResult<Arc<ClientDocumentEditor>> {
@@ -132,8 +292,15 @@ impl DocumentController {
 
     fn make_rev_manager(&self, doc_id: &str, pool: Arc<ConnectionPool>) -> Result<RevisionManager, FlowyError> {
         let user_id = self.user.user_id()?;
-        let cache = Arc::new(RevisionCache::new(&user_id, doc_id, pool));
-        Ok(RevisionManager::new(&user_id, doc_id, cache))
+        let cache = Arc::new(RevisionCache::new(&user_id, doc_id, pool, self.make_cipher()?));
+        Ok(RevisionManager::new(&user_id, doc_id, cache).with_debounce(self.delta_debounce))
+    }
+
+    fn make_cipher(&self) -> Result<Option<RevisionCipher>, FlowyError> {
+        match self.user.encryption_key() {
+            None => Ok(None),
+            Some(key) => Ok(Some(RevisionCipher::new(&key)?)),
+        }
     }
 }
 
@@ -174,6 +341,8 @@ impl OpenDocCache {
         self.inner.insert(doc_id.to_string(), doc.clone());
     }
 
+    pub(crate) fn doc_ids(&self) -> Vec<String> { self.inner.iter().map(|item| item.key().clone()).collect() }
+
     pub(crate) fn contains(&self, doc_id: &str) -> bool { self.inner.get(doc_id).is_some() }
 
     pub(crate) fn get(&self, doc_id: &str) -> Option<Arc<ClientDocumentEditor>> {
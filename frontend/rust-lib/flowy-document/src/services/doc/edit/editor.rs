@@ -149,27 +149,41 @@ impl ClientDocumentEditor {
     }
 
     async fn save_local_delta(&self, delta: RichTextDelta, md5: String) -> Result<RevId, FlowyError> {
+        let revision = self.make_local_revision(delta, md5)?;
+        let _ = self.rev_manager.add_local_revision(&revision).await?;
+        Ok(revision.rev_id.into())
+    }
+
+    fn make_local_revision(&self, delta: RichTextDelta, md5: String) -> Result<Revision, FlowyError> {
         let delta_data = delta.to_bytes();
         let (base_rev_id, rev_id) = self.rev_manager.next_rev_id_pair();
         let user_id = self.user.user_id()?;
-        let revision = Revision::new(&self.doc_id, base_rev_id, rev_id, delta_data, &user_id, md5);
-        let _ = self.rev_manager.add_local_revision(&revision).await?;
-        Ok(rev_id.into())
+        Ok(Revision::new(
+            &self.doc_id,
+            base_rev_id,
+            rev_id,
+            delta_data,
+            &user_id,
+            md5,
+        ))
     }
 
     #[tracing::instrument(level = "debug", skip(self, data), err)]
-    pub(crate) async fn compose_local_delta(&self, data: Bytes) -> Result<(), FlowyError> {
+    pub(crate) async fn compose_local_delta(&self, data: Bytes) -> Result<(RevId, bool), FlowyError> {
         let delta = RichTextDelta::from_bytes(&data)?;
-        let (ret, rx) = oneshot::channel::<CollaborateResult<DocumentMD5>>();
-        let msg = EditorCommand::ComposeDelta {
+        let (ret, rx) = oneshot::channel::<CollaborateResult<ComposedDelta>>();
+        let msg = EditorCommand::ComposeLocalDelta {
             delta: delta.clone(),
             ret,
         };
         let _ = self.edit_queue.send(msg);
-        let md5 = rx.await.map_err(internal_error)??;
+        let (md5, was_transformed) = rx.await.map_err(internal_error)??;
 
-        let _ = self.save_local_delta(delta, md5).await?;
-        Ok(())
+        // The document is updated already, only sending the revision to the server is
+        // debounced.
+        let revision = self.make_local_revision(delta, md5)?;
+        let _ = self.rev_manager.add_local_revision_with_debounce(&revision).await?;
+        Ok((revision.rev_id.into(), was_transformed))
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
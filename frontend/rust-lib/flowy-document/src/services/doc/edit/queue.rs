@@ -12,13 +12,19 @@ use lib_ot::{
     core::{Interval, OperationTransformable},
     rich_text::{RichTextAttribute, RichTextDelta},
 };
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering::SeqCst},
+    Arc,
+};
 use tokio::sync::{mpsc, oneshot, RwLock};
 
 pub(crate) struct EditorCommandQueue {
     #[allow(dead_code)]
     doc_id: String,
     document: Arc<RwLock<Document>>,
+    // Set when pushed server revisions were transformed against the local document, cleared by
+    // the next ComposeLocalDelta.
+    transformed: AtomicBool,
     receiver: Option<mpsc::UnboundedReceiver<EditorCommand>>,
 }
 
@@ -28,6 +34,7 @@ impl EditorCommandQueue {
         Self {
             doc_id: doc_id.to_owned(),
             document,
+            transformed: AtomicBool::new(false),
             receiver: Some(receiver),
         }
     }
@@ -66,6 +73,19 @@ impl EditorCommandQueue {
 
                 let _ = ret.send(fut().await);
             },
+            EditorCommand::ComposeLocalDelta { delta, ret } => {
+                let fut = || async {
+                    let mut document = self.document.write().await;
+                    let _ = document.compose_delta(delta)?;
+                    let md5 = document.md5();
+                    drop(document);
+
+                    let was_transformed = self.transformed.swap(false, SeqCst);
+                    Ok::<ComposedDelta, CollaborateError>((md5, was_transformed))
+                };
+
+                let _ = ret.send(fut().await);
+            },
             EditorCommand::OverrideDelta { delta, ret } => {
                 let fut = || async {
                     let mut document = self.document.write().await;
@@ -90,6 +110,7 @@ impl EditorCommandQueue {
                         let (s_prime, c_prime) = read_guard.delta().transform(&new_delta)?;
                         client_prime = c_prime;
                         server_prime = Some(s_prime);
+                        self.transformed.store(true, SeqCst);
                     }
 
                     drop(read_guard);
@@ -158,6 +179,7 @@ impl EditorCommandQueue {
 pub(crate) type Ret<T> = oneshot::Sender<Result<T, CollaborateError>>;
 pub(crate) type NewDelta = (RichTextDelta, String);
 pub(crate) type DocumentMD5 = String;
+pub(crate) type ComposedDelta = (DocumentMD5, bool);
 
 #[allow(dead_code)]
 pub(crate) enum EditorCommand {
@@ -165,6 +187,10 @@ pub(crate) enum EditorCommand {
         delta: RichTextDelta,
         ret: Ret<DocumentMD5>,
     },
+    ComposeLocalDelta {
+        delta: RichTextDelta,
+        ret: Ret<ComposedDelta>,
+    },
     OverrideDelta {
         delta: RichTextDelta,
         ret: Ret<DocumentMD5>,
@@ -1,6 +1,7 @@
 use crate::{
     errors::FlowyError,
     services::doc::revision::cache::{
+        cipher::RevisionCipher,
         disk::{Persistence, RevisionDiskCache},
         memory::{RevisionMemoryCache, RevisionMemoryCacheDelegate},
     },
@@ -8,7 +9,7 @@ use crate::{
 };
 use std::borrow::Cow;
 
-use flowy_collaboration::entities::revision::{Revision, RevisionRange, RevisionState};
+use flowy_collaboration::entities::revision::{Revision, RevisionRange, RevisionState, RevisionSummary};
 use flowy_database::ConnectionPool;
 use flowy_error::{internal_error, FlowyResult};
 
@@ -26,8 +27,13 @@ pub struct RevisionCache {
 }
 
 impl RevisionCache {
-    pub fn new(user_id: &str, doc_id: &str, pool: Arc<ConnectionPool>) -> RevisionCache {
-        let disk_cache = Arc::new(Persistence::new(user_id, pool));
+    pub(crate) fn new(
+        user_id: &str,
+        doc_id: &str,
+        pool: Arc<ConnectionPool>,
+        cipher: Option<RevisionCipher>,
+    ) -> RevisionCache {
+        let disk_cache = Arc::new(Persistence::new(user_id, pool, cipher));
         let memory_cache = Arc::new(RevisionMemoryCache::new(doc_id, Arc::new(disk_cache.clone())));
         let doc_id = doc_id.to_owned();
         Self {
@@ -61,6 +67,8 @@ impl RevisionCache {
 
     pub async fn ack(&self, rev_id: i64) { self.memory_cache.ack(&rev_id).await; }
 
+    pub async fn flush(&self) -> FlowyResult<()> { self.memory_cache.flush().await }
+
     pub async fn get(&self, rev_id: i64) -> Option<RevisionRecord> {
         match self.memory_cache.get(&rev_id).await {
             None => match self.disk_cache.read_revision_records(&self.doc_id, Some(vec![rev_id])) {
@@ -83,6 +91,10 @@ impl RevisionCache {
         self.disk_cache.read_revision_records(doc_id, None)
     }
 
+    pub fn revision_summaries(&self, limit: usize) -> FlowyResult<Vec<RevisionSummary>> {
+        self.disk_cache.read_revision_summaries(&self.doc_id, limit)
+    }
+
     pub async fn latest_revision(&self) -> Revision {
         let rev_id = self.latest_rev_id.load(SeqCst);
         self.get(rev_id).await.unwrap().revision
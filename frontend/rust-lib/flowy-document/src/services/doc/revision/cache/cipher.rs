@@ -0,0 +1,63 @@
+use aes_gcm::{
+    aead::{Aead, NewAead},
+    Aes256Gcm,
+    Key,
+    Nonce,
+};
+use flowy_error::{FlowyError, FlowyResult};
+
+const ENCRYPTED_PREFIX: &[u8] = b"flowy-enc-v1:";
+const ENCRYPTION_KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+// Encrypts the revision data with AES-256-GCM before it's written to disk. The
+// encrypted data starts with the ENCRYPTED_PREFIX followed by the nonce, so
+// that it can be told apart from the data that was written without a key.
+pub(crate) struct RevisionCipher {
+    cipher: Aes256Gcm,
+}
+
+impl RevisionCipher {
+    pub(crate) fn new(key: &[u8]) -> FlowyResult<Self> {
+        if key.len() != ENCRYPTION_KEY_LEN {
+            return Err(FlowyError::internal().context(format!(
+                "The encryption key should be {} bytes, but receive {}",
+                ENCRYPTION_KEY_LEN,
+                key.len()
+            )));
+        }
+        let cipher = Aes256Gcm::new(Key::from_slice(key));
+        Ok(Self { cipher })
+    }
+
+    pub(crate) fn encrypt(&self, data: &[u8]) -> FlowyResult<Vec<u8>> {
+        let nonce = rand::random::<[u8; NONCE_LEN]>();
+        let encrypted = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), data)
+            .map_err(|e| FlowyError::internal().context(format!("Encrypt revision failed: {}", e)))?;
+
+        let mut bytes = Vec::with_capacity(ENCRYPTED_PREFIX.len() + NONCE_LEN + encrypted.len());
+        bytes.extend_from_slice(ENCRYPTED_PREFIX);
+        bytes.extend_from_slice(&nonce);
+        bytes.extend(encrypted);
+        Ok(bytes)
+    }
+
+    pub(crate) fn decrypt(&self, data: &[u8]) -> FlowyResult<Vec<u8>> {
+        if !is_encrypted(data) {
+            return Ok(data.to_vec());
+        }
+
+        let data = &data[ENCRYPTED_PREFIX.len()..];
+        if data.len() < NONCE_LEN {
+            return Err(FlowyError::internal().context("The encrypted revision is corrupted"));
+        }
+        let (nonce, encrypted) = data.split_at(NONCE_LEN);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), encrypted)
+            .map_err(|e| FlowyError::internal().context(format!("Decrypt revision failed: {}", e)))
+    }
+}
+
+pub(crate) fn is_encrypted(data: &[u8]) -> bool { data.starts_with(ENCRYPTED_PREFIX) }
@@ -1,8 +1,14 @@
-use crate::services::doc::revision::RevisionRecord;
+use crate::services::doc::revision::{
+    cache::cipher::{is_encrypted, RevisionCipher},
+    RevisionRecord,
+};
 
 use crate::sql_tables::{RevisionChangeset, RevisionTableSql};
 use diesel::SqliteConnection;
-use flowy_collaboration::entities::revision::RevisionRange;
+use flowy_collaboration::{
+    entities::revision::{RevisionRange, RevisionSummary},
+    util::md5,
+};
 use flowy_database::ConnectionPool;
 use flowy_error::{internal_error, FlowyError, FlowyResult};
 use std::{fmt::Debug, sync::Arc};
@@ -28,6 +34,8 @@ pub trait RevisionDiskCache: Sync + Send {
         range: &RevisionRange,
     ) -> Result<Vec<RevisionRecord>, Self::Error>;
 
+    fn read_revision_summaries(&self, doc_id: &str, limit: usize) -> Result<Vec<RevisionSummary>, Self::Error>;
+
     fn update_revision_record(&self, changesets: Vec<RevisionChangeset>) -> FlowyResult<()>;
 
     // Delete all the records if the rev_ids is None
@@ -46,6 +54,7 @@ pub trait RevisionDiskCache: Sync + Send {
 pub(crate) struct Persistence {
     user_id: String,
     pub(crate) pool: Arc<ConnectionPool>,
+    cipher: Option<RevisionCipher>,
 }
 
 impl RevisionDiskCache for Persistence {
@@ -56,6 +65,7 @@ impl RevisionDiskCache for Persistence {
         revisions: Vec<RevisionRecord>,
         conn: &SqliteConnection,
     ) -> Result<(), Self::Error> {
+        let revisions = self.encrypt_records(revisions)?;
         let _ = RevisionTableSql::create(revisions, conn)?;
         Ok(())
     }
@@ -67,7 +77,7 @@ impl RevisionDiskCache for Persistence {
     ) -> Result<Vec<RevisionRecord>, Self::Error> {
         let conn = self.pool.get().map_err(internal_error)?;
         let records = RevisionTableSql::read(&self.user_id, doc_id, rev_ids, &*conn)?;
-        Ok(records)
+        self.decrypt_records(records)
     }
 
     fn read_revision_records_with_range(
@@ -77,7 +87,13 @@ impl RevisionDiskCache for Persistence {
     ) -> Result<Vec<RevisionRecord>, Self::Error> {
         let conn = &*self.pool.get().map_err(internal_error)?;
         let revisions = RevisionTableSql::read_with_range(&self.user_id, doc_id, range.clone(), conn)?;
-        Ok(revisions)
+        self.decrypt_records(revisions)
+    }
+
+    fn read_revision_summaries(&self, doc_id: &str, limit: usize) -> Result<Vec<RevisionSummary>, Self::Error> {
+        let conn = &*self.pool.get().map_err(internal_error)?;
+        let summaries = RevisionTableSql::read_summaries(&self.user_id, doc_id, limit, conn)?;
+        Ok(summaries)
     }
 
     fn update_revision_record(&self, changesets: Vec<RevisionChangeset>) -> FlowyResult<()> {
@@ -114,10 +130,42 @@ impl RevisionDiskCache for Persistence {
 }
 
 impl Persistence {
-    pub(crate) fn new(user_id: &str, pool: Arc<ConnectionPool>) -> Self {
+    pub(crate) fn new(user_id: &str, pool: Arc<ConnectionPool>, cipher: Option<RevisionCipher>) -> Self {
         Self {
             user_id: user_id.to_owned(),
             pool,
+            cipher,
+        }
+    }
+
+    fn encrypt_records(&self, mut records: Vec<RevisionRecord>) -> FlowyResult<Vec<RevisionRecord>> {
+        if let Some(cipher) = &self.cipher {
+            for record in records.iter_mut() {
+                record.revision.delta_data = cipher.encrypt(&record.revision.delta_data)?;
+            }
         }
+        Ok(records)
+    }
+
+    // The revisions that were written without a key are returned as they are, but
+    // the encrypted ones can't be read without the key.
+    fn decrypt_records(&self, mut records: Vec<RevisionRecord>) -> FlowyResult<Vec<RevisionRecord>> {
+        for record in records.iter_mut() {
+            if !is_encrypted(&record.revision.delta_data) {
+                continue;
+            }
+
+            match &self.cipher {
+                None => {
+                    return Err(FlowyError::internal()
+                        .context(format!("The revisions of {} are encrypted", record.revision.doc_id)))
+                },
+                Some(cipher) => {
+                    record.revision.delta_data = cipher.decrypt(&record.revision.delta_data)?;
+                    record.revision.md5 = md5(&record.revision.delta_data);
+                },
+            }
+        }
+        Ok(records)
     }
 }
@@ -92,6 +92,25 @@ impl RevisionMemoryCache {
         Ok(())
     }
 
+    // Writes the revisions that are waiting for the next checkpoint to the disk
+    // without waiting for it.
+    pub(crate) async fn flush(&self) -> FlowyResult<()> {
+        if let Some(handler) = self.defer_save.write().await.take() {
+            handler.abort();
+        }
+
+        let mut revs_write_guard = self.pending_write_revs.write().await;
+        let save_records = revs_write_guard
+            .iter()
+            .flat_map(|rev_id| self.revs_map.get(rev_id).map(|record| record.value().clone()))
+            .collect::<Vec<RevisionRecord>>();
+        if !save_records.is_empty() {
+            let _ = self.delegate.checkpoint_tick(save_records)?;
+        }
+        revs_write_guard.clear();
+        Ok(())
+    }
+
     async fn make_checkpoint(&self) {
         // https://github.com/async-graphql/async-graphql/blob/ed8449beec3d9c54b94da39bab33cec809903953/src/dataloader/mod.rs#L362
         if let Some(handler) = self.defer_save.write().await.take() {
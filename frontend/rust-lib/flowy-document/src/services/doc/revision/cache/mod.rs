@@ -1,6 +1,9 @@
 #![allow(clippy::module_inception)]
 mod cache;
+mod cipher;
 mod disk;
 mod memory;
 
 pub use cache::*;
+pub(crate) use cipher::RevisionCipher;
+pub(crate) use disk::{Persistence, RevisionDiskCache};
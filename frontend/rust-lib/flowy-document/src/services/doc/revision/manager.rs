@@ -7,7 +7,7 @@ use dashmap::DashMap;
 use flowy_collaboration::{
     entities::{
         doc::DocumentInfo,
-        revision::{RepeatedRevision, Revision, RevisionRange, RevisionState},
+        revision::{CompactReport, RepeatedRevision, Revision, RevisionRange, RevisionState, RevisionSummary},
     },
     util::{md5, pair_rev_id_from_revisions, RevIdCounter},
 };
@@ -19,7 +19,7 @@ use lib_ot::{
     errors::OTError,
     rich_text::RichTextDelta,
 };
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 
 pub trait RevisionServer: Send + Sync {
@@ -32,6 +32,10 @@ pub struct RevisionManager {
     rev_id_counter: RevIdCounter,
     cache: Arc<RevisionCache>,
     sync_seq: Arc<RevisionSyncSequence>,
+    // The local revision that is waiting for the debounce window to elapse. The
+    // local revisions added within the window are composed into it.
+    pending: Arc<RwLock<Option<Revision>>>,
+    debounce: Duration,
 }
 
 impl RevisionManager {
@@ -44,9 +48,16 @@ impl RevisionManager {
             rev_id_counter,
             cache,
             sync_seq,
+            pending: Arc::new(RwLock::new(None)),
+            debounce: Duration::from_millis(0),
         }
     }
 
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
     pub async fn load_document(&mut self, server: Arc<dyn RevisionServer>) -> FlowyResult<RichTextDelta> {
         let revisions = RevisionLoader {
             doc_id: self.doc_id.clone(),
@@ -61,12 +72,56 @@ impl RevisionManager {
         Ok(doc.delta()?)
     }
 
+    // Compose the document from the revisions persisted on disk without fetching
+    // it from the server. Returns None if the document was never persisted.
+    pub fn read_local_document(&self) -> FlowyResult<Option<RichTextDelta>> {
+        let records = self.cache.batch_get(&self.doc_id)?;
+        if records.is_empty() {
+            return Ok(None);
+        }
+
+        let revisions = records
+            .into_iter()
+            .map(|record| record.revision)
+            .collect::<Vec<Revision>>();
+        let doc = mk_doc_from_revisions(&self.doc_id, revisions)?;
+        Ok(Some(doc.delta()?))
+    }
+
+    // Compose the document from the persisted revisions up to and including the
+    // rev_id.
+    pub fn read_document_at(&self, rev_id: i64) -> FlowyResult<RichTextDelta> {
+        let revisions = self
+            .cache
+            .batch_get(&self.doc_id)?
+            .into_iter()
+            .map(|record| record.revision)
+            .filter(|revision| revision.rev_id <= rev_id)
+            .collect::<Vec<Revision>>();
+        if revisions.last().map(|revision| revision.rev_id) != Some(rev_id) {
+            return Err(FlowyError::record_not_found()
+                .context(format!("Can't find the revision {} of {}", rev_id, self.doc_id)));
+        }
+
+        let doc = mk_doc_from_revisions(&self.doc_id, revisions)?;
+        Ok(doc.delta()?)
+    }
+
+    // Newest first. The revisions that are still waiting in the memory cache for
+    // the next checkpoint are not included.
+    pub fn read_revision_summaries(&self, limit: usize) -> FlowyResult<Vec<RevisionSummary>> {
+        self.cache.revision_summaries(limit)
+    }
+
     #[tracing::instrument(level = "debug", skip(self, revisions), err)]
-    pub async fn reset_document(&self, revisions: RepeatedRevision) -> FlowyResult<()> {
+    // Returns the rev_id of the last revision, the next local revision is counted
+    // from it.
+    pub async fn reset_document(&self, revisions: RepeatedRevision) -> FlowyResult<i64> {
+        let _ = self.flush_pending_revision().await?;
         let rev_id = pair_rev_id_from_revisions(&revisions).1;
         let _ = self.cache.reset_document(&self.doc_id, revisions.into_inner()).await?;
         self.rev_id_counter.set(rev_id);
-        Ok(())
+        Ok(rev_id)
     }
 
     #[tracing::instrument(level = "debug", skip(self, revision), err)]
@@ -74,6 +129,7 @@ impl RevisionManager {
         if revision.delta_data.is_empty() {
             return Err(FlowyError::internal().context("Delta data should be empty"));
         }
+        let _ = self.flush_pending_revision().await?;
         self.rev_id_counter.set(revision.rev_id);
         let _ = self.cache.add(revision.clone(), RevisionState::Ack, true).await?;
         Ok(())
@@ -85,11 +141,110 @@ impl RevisionManager {
             return Err(FlowyError::internal().context("Delta data should be empty"));
         }
 
+        // The pending revision's rev_id is less than the new one's
+        let _ = self.flush_pending_revision().await?;
         let record = self.cache.add(revision.clone(), RevisionState::Local, true).await?;
         self.sync_seq.add_revision(record).await?;
         Ok(())
     }
 
+    // Same as add_local_revision, but the revisions added within the debounce
+    // window are composed into one revision that keeps the base_rev_id of the
+    // first one and the rev_id of the last one. So they are sent to the server
+    // as one revision.
+    #[tracing::instrument(level = "debug", skip(self, revision), err)]
+    pub async fn add_local_revision_with_debounce(&self, revision: &Revision) -> Result<(), FlowyError> {
+        if self.debounce.as_millis() == 0 {
+            return self.add_local_revision(revision).await;
+        }
+
+        if revision.delta_data.is_empty() {
+            return Err(FlowyError::internal().context("Delta data should be empty"));
+        }
+
+        let mut pending = self.pending.write().await;
+        if let Some(pending_revision) = pending.as_ref() {
+            let composed_revision = compose_revisions(pending_revision, revision)?;
+            *pending = Some(composed_revision);
+            return Ok(());
+        }
+
+        *pending = Some(revision.clone());
+        let debounce = self.debounce;
+        let pending = self.pending.clone();
+        let cache = self.cache.clone();
+        let sync_seq = self.sync_seq.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(debounce).await;
+            if let Err(e) = flush_pending_revision(&pending, &cache, &sync_seq).await {
+                tracing::error!("Flush the pending revision failed: {}", e);
+            }
+        });
+        Ok(())
+    }
+
+    // Adds the pending revision to the cache without waiting for the debounce
+    // window to elapse.
+    pub async fn flush_pending_revision(&self) -> FlowyResult<()> {
+        flush_pending_revision(&self.pending, &self.cache, &self.sync_seq).await
+    }
+
+    // Adds the pending revision to the cache and writes the cached revisions to
+    // the disk, so that nothing is lost if the document is closed afterwards. The
+    // revisions are still sent to the server by the sync sequence.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn flush(&self) -> FlowyResult<()> {
+        let _ = self.flush_pending_revision().await?;
+        self.cache.flush().await
+    }
+
+    // Squashes the acked revisions into one acked revision that holds their
+    // document and keeps the rev_id of the last of them. The unacked revisions are
+    // kept as they are, so the server only receives the changes it doesn't have.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn compact(&self) -> FlowyResult<CompactReport> {
+        let _ = self.flush().await?;
+        let mut records = self.cache.batch_get(&self.doc_id)?;
+        let rev_id = records.last().map(|record| record.revision.rev_id).unwrap_or(0);
+        let acked_len = records
+            .iter()
+            .take_while(|record| record.state == RevisionState::Ack)
+            .count();
+        if acked_len <= 1 {
+            return Ok(CompactReport {
+                rev_id,
+                ..CompactReport::default()
+            });
+        }
+
+        let unacked_revisions = records
+            .split_off(acked_len)
+            .into_iter()
+            .map(|record| record.revision)
+            .collect::<Vec<Revision>>();
+        let acked_rev_id = records[acked_len - 1].revision.rev_id;
+        let bytes_len: usize = records.iter().map(|record| record.revision.delta_data.len()).sum();
+        let revisions = records
+            .into_iter()
+            .map(|record| record.revision)
+            .collect::<Vec<Revision>>();
+        let doc = mk_doc_from_revisions(&self.doc_id, revisions)?;
+        let delta_data = doc.delta()?.to_bytes();
+        let md5 = md5(&delta_data);
+        let revision = Revision::new(&self.doc_id, 0, acked_rev_id, delta_data, &self.user_id, md5);
+        let compacted_len = revision.delta_data.len();
+
+        let mut revisions = vec![revision];
+        revisions.extend(unacked_revisions);
+        let _ = self.cache.reset_document(&self.doc_id, revisions).await?;
+        self.cache.ack(acked_rev_id).await;
+        Ok(CompactReport {
+            rev_id,
+            revisions_reclaimed: (acked_len - 1) as i64,
+            bytes_reclaimed: bytes_len.saturating_sub(compacted_len) as i64,
+        })
+    }
+
     #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn ack_revision(&self, rev_id: i64) -> Result<(), FlowyError> {
         if self.sync_seq.ack(&rev_id).await.is_ok() {
@@ -237,6 +392,40 @@ impl RevisionLoader {
     }
 }
 
+async fn flush_pending_revision(
+    pending: &RwLock<Option<Revision>>,
+    cache: &Arc<RevisionCache>,
+    sync_seq: &Arc<RevisionSyncSequence>,
+) -> FlowyResult<()> {
+    // Hold the lock until the revision is added, so that the following revisions
+    // can't be added before it.
+    let mut pending = pending.write().await;
+    if let Some(revision) = pending.take() {
+        let record = cache.add(revision, RevisionState::Local, true).await?;
+        sync_seq.add_revision(record).await?;
+    }
+    Ok(())
+}
+
+// The rev_ids between the first and the second revision are skipped, the
+// composed revision is never persisted or sent with them. The server accepts
+// the gap because the composed revision keeps the base_rev_id of the first one,
+// which is the server's rev_id when the revision is sent.
+fn compose_revisions(first: &Revision, second: &Revision) -> FlowyResult<Revision> {
+    let delta = RichTextDelta::from_bytes(&first.delta_data)?;
+    let delta = delta.compose(&RichTextDelta::from_bytes(&second.delta_data)?)?;
+    let delta_data = delta.to_bytes();
+    let md5 = md5(&delta_data);
+    Ok(Revision::new(
+        &second.doc_id,
+        first.base_rev_id,
+        second.rev_id,
+        delta_data,
+        &second.user_id,
+        md5,
+    ))
+}
+
 fn mk_doc_from_revisions(doc_id: &str, revisions: Vec<Revision>) -> FlowyResult<DocumentInfo> {
     let (base_rev_id, rev_id) = revisions.last().unwrap().pair_rev_id();
     let mut delta = RichTextDelta::new();
@@ -199,6 +199,9 @@ impl DocumentWSStream {
                 let _ = self.consumer.receive_new_user_connect(new_user).await;
                 // Notify the user that someone has connected to this document
             },
+            DocumentServerWSDataType::UserCursor => {
+                // The cursors are relayed by the DocumentWSReceivers
+            },
         }
 
         Ok(())
@@ -238,6 +238,10 @@ pub(crate) async fn handle_push_rev(
         return Ok(None);
     }
 
+    // The pushed revisions are compared with the local ones, the pending one should
+    // be one of them.
+    let _ = rev_manager.flush_pending_revision().await?;
+
     let first_revision = revisions.first().unwrap();
     if let Some(local_revision) = rev_manager.get_revision(first_revision.rev_id).await {
         if local_revision.md5 == first_revision.md5 {
@@ -1,9 +1,18 @@
 use crate::errors::FlowyError;
 use bytes::Bytes;
 use dashmap::DashMap;
-use flowy_collaboration::entities::ws::{DocumentClientWSData, DocumentServerWSData};
+use flowy_collaboration::entities::ws::{
+    CursorUpdate,
+    DocumentClientWSData,
+    DocumentServerWSData,
+    DocumentServerWSDataType,
+};
 use lib_ws::WSConnectState;
-use std::{convert::TryInto, sync::Arc};
+use std::{
+    convert::{TryFrom, TryInto},
+    sync::Arc,
+};
+use tokio::sync::broadcast;
 
 pub(crate) trait DocumentWSReceiver: Send + Sync {
     fn receive_ws_data(&self, data: DocumentServerWSData);
@@ -19,12 +28,16 @@ pub trait DocumentWebSocket: Send + Sync {
 pub struct DocumentWSReceivers {
     // key: the document id
     receivers: Arc<DashMap<String, Arc<dyn DocumentWSReceiver>>>,
+    // key: the document id. The cursors of the other users are relayed to the
+    // subscribers instead of the document's receiver.
+    cursors: Arc<DashMap<String, broadcast::Sender<CursorUpdate>>>,
 }
 
 impl std::default::Default for DocumentWSReceivers {
     fn default() -> Self {
         let receivers: Arc<DashMap<String, Arc<dyn DocumentWSReceiver>>> = Arc::new(DashMap::new());
-        DocumentWSReceivers { receivers }
+        let cursors = Arc::new(DashMap::new());
+        DocumentWSReceivers { receivers, cursors }
     }
 }
 
@@ -38,10 +51,25 @@ impl DocumentWSReceivers {
         self.receivers.insert(doc_id.to_string(), receiver);
     }
 
-    pub(crate) fn remove(&self, id: &str) { self.receivers.remove(id); }
+    pub(crate) fn remove(&self, id: &str) {
+        self.receivers.remove(id);
+        self.cursors.remove(id);
+    }
+
+    pub(crate) fn subscribe_cursors(&self, doc_id: &str) -> broadcast::Receiver<CursorUpdate> {
+        self.cursors
+            .entry(doc_id.to_owned())
+            .or_insert_with(|| broadcast::channel(100).0)
+            .subscribe()
+    }
 
     pub fn did_receive_data(&self, data: Bytes) {
         let data: DocumentServerWSData = data.try_into().unwrap();
+        if data.ty == DocumentServerWSDataType::UserCursor {
+            self.did_receive_cursor(data);
+            return;
+        }
+
         match self.receivers.get(&data.doc_id) {
             None => {
                 log::error!("Can't find any source handler for {:?}", data.doc_id);
@@ -52,6 +80,18 @@ impl DocumentWSReceivers {
         }
     }
 
+    fn did_receive_cursor(&self, data: DocumentServerWSData) {
+        match CursorUpdate::try_from(Bytes::from(data.data)) {
+            Ok(cursor) => {
+                if let Some(sender) = self.cursors.get(&data.doc_id) {
+                    // Sending fails if there is no subscriber, which is fine.
+                    let _ = sender.send(cursor);
+                }
+            },
+            Err(e) => log::error!("Parse the cursor of {:?} failed: {:?}", data.doc_id, e),
+        }
+    }
+
     pub fn ws_connect_state_changed(&self, state: &WSConnectState) {
         self.receivers.iter().for_each(|receiver| {
             receiver.value().connect_state_changed(&state);
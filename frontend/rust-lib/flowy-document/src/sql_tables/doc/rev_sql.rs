@@ -10,14 +10,16 @@ use crate::{
     },
 };
 use diesel::update;
-use flowy_collaboration::entities::revision::RevisionRange;
+use flowy_collaboration::entities::revision::{RevisionRange, RevisionSummary};
 use flowy_database::{insert_or_ignore_into, prelude::*, schema::rev_table::dsl, SqliteConnection};
+use lib_infra::timestamp;
 
 pub struct RevisionTableSql {}
 
 impl RevisionTableSql {
     pub(crate) fn create(revision_records: Vec<RevisionRecord>, conn: &SqliteConnection) -> Result<(), FlowyError> {
         // Batch insert: https://diesel.rs/guides/all-about-inserts.html
+        let timestamp = timestamp();
         let records = revision_records
             .into_iter()
             .map(|record| {
@@ -29,6 +31,7 @@ impl RevisionTableSql {
                     dsl::data.eq(record.revision.delta_data),
                     dsl::state.eq(rev_state),
                     dsl::ty.eq(RevTableType::Local),
+                    dsl::timestamp.eq(timestamp),
                 )
             })
             .collect::<Vec<_>>();
@@ -85,6 +88,43 @@ impl RevisionTableSql {
         Ok(revisions)
     }
 
+    // Newest first. Only the rev_id and timestamp columns are loaded, so the data
+    // never needs to be decrypted.
+    pub(crate) fn read_summaries(
+        user_id: &str,
+        doc_id: &str,
+        limit: usize,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<RevisionSummary>, FlowyError> {
+        let rows = dsl::rev_table
+            .select((dsl::rev_id, dsl::timestamp))
+            .filter(dsl::doc_id.eq(doc_id))
+            .order(dsl::rev_id.desc())
+            .limit(limit as i64)
+            .load::<(i64, i64)>(conn)?;
+
+        let summaries = rows
+            .into_iter()
+            .map(|(rev_id, timestamp)| RevisionSummary {
+                rev_id,
+                user_id: user_id.to_owned(),
+                timestamp,
+            })
+            .collect::<Vec<_>>();
+        Ok(summaries)
+    }
+
+    pub(crate) fn read_doc_ids(conn: &SqliteConnection) -> Result<Vec<String>, FlowyError> {
+        let doc_ids = dsl::rev_table.select(dsl::doc_id).distinct().load::<String>(conn)?;
+        Ok(doc_ids)
+    }
+
+    pub(crate) fn has_revisions(doc_id: &str, conn: &SqliteConnection) -> Result<bool, FlowyError> {
+        let filter = dsl::rev_table.filter(dsl::doc_id.eq(doc_id));
+        let is_exist = diesel::select(diesel::dsl::exists(filter)).get_result::<bool>(conn)?;
+        Ok(is_exist)
+    }
+
     pub(crate) fn delete(doc_id: &str, rev_ids: Option<Vec<i64>>, conn: &SqliteConnection) -> Result<(), FlowyError> {
         let mut sql = dsl::rev_table.filter(dsl::doc_id.eq(doc_id)).into_boxed();
         if let Some(rev_ids) = rev_ids {
@@ -17,6 +17,7 @@ pub(crate) struct RevisionTable {
     pub(crate) data: Vec<u8>,
     pub(crate) state: RevisionTableState,
     pub(crate) ty: RevTableType, // Deprecated
+    pub(crate) timestamp: i64,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
@@ -3,7 +3,8 @@ use crate::editor::{Rng, TestBuilder, TestOp::*};
 use flowy_collaboration::document::{NewlineDoc, PlainDoc};
 use lib_ot::{
     core::*,
-    rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributes, RichTextDelta},
+    errors::OTErrorCode,
+    rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributes, RichTextDelta, RichTextDeltaBuilder},
 };
 
 #[test]
@@ -350,6 +351,52 @@ fn apply() {
     assert_eq!("hello world,appflowy", &after_b);
 }
 
+#[test]
+fn apply_checked_keeps_remaining_base() {
+    let mut delta = RichTextDelta::default();
+    delta.retain(5, RichTextAttributes::default());
+    delta.insert(" appflowy", RichTextAttributes::default());
+    delta.delete(1);
+
+    let s = delta.apply_checked("hello, world").unwrap();
+    assert_eq!("hello appflowy world", &s);
+}
+
+#[test]
+fn apply_checked_length_mismatch() {
+    let mut delta = RichTextDelta::default();
+    delta.retain(5, RichTextAttributes::default());
+    delta.delete(3);
+
+    let error = delta.apply_checked("hello").unwrap_err();
+    match error.code {
+        OTErrorCode::LengthMismatch { expected, actual } => {
+            assert_eq!(expected, 8);
+            assert_eq!(actual, 5);
+        },
+        _ => panic!("Expect the LengthMismatch error, but receive {:?}", error.code),
+    }
+}
+
+#[test]
+fn collect_attributes() {
+    let bold_italic = AttributeBuilder::new()
+        .add_attr(RichTextAttribute::Bold(true))
+        .add_attr(RichTextAttribute::Italic(true))
+        .build();
+    let header = AttributeBuilder::new().add_attr(RichTextAttribute::Header(1)).build();
+    let delta = RichTextDeltaBuilder::new()
+        .insert_with_attributes("hello", bold_italic)
+        .retain_with_attributes(1, header)
+        .delete(2)
+        .insert("world")
+        .build();
+
+    let mut keys = delta.collect_attributes().into_iter().collect::<Vec<String>>();
+    keys.sort();
+    assert_eq!(keys, vec!["bold", "header", "italic"]);
+}
+
 #[test]
 fn base_len_test() {
     let mut delta_a = RichTextDelta::default();
@@ -474,6 +521,22 @@ fn transform_random_delta() {
     }
 }
 
+#[test]
+fn transform_fn_random_delta_converges() {
+    for _ in 0..1000 {
+        let mut rng = Rng::default();
+        let s = rng.gen_string(20);
+        let a = rng.gen_delta(&s);
+        let b = rng.gen_delta(&s);
+        let (a_prime, b_prime) = transform(&a, &b).unwrap();
+        assert_eq!((a_prime.clone(), b_prime.clone()), a.transform(&b).unwrap());
+
+        let after_ab_prime = b_prime.apply(&a.apply(&s).unwrap()).unwrap();
+        let after_ba_prime = a_prime.apply(&b.apply(&s).unwrap()).unwrap();
+        assert_eq!(after_ab_prime, after_ba_prime);
+    }
+}
+
 #[test]
 fn transform_with_two_delta_test() {
     let mut a = RichTextDelta::default();
@@ -733,3 +796,243 @@ fn delta_compose_with_missing_delta() {
     ];
     TestBuilder::new().run_scripts::<NewlineDoc>(ops);
 }
+
+#[test]
+fn delta_normalize_test() {
+    let mut delta = RichTextDelta::default();
+    delta.ops.push(OpBuilder::retain(3).build());
+    delta.ops.push(OpBuilder::retain(2).build());
+    delta.ops.push(OpBuilder::insert("abc").build());
+    delta.ops.push(OpBuilder::insert("def").build());
+    normalize(&mut delta);
+    assert_eq!(delta.ops.len(), 2);
+    assert_eq!(delta.ops[0], OpBuilder::retain(5).build());
+    assert_eq!(delta.ops[1], OpBuilder::insert("abcdef").build());
+    assert_eq!(delta.base_len, 5);
+    assert_eq!(delta.target_len, 11);
+}
+
+#[test]
+fn delta_validate_test() {
+    let delta = RichTextDeltaBuilder::new()
+        .retain(3)
+        .insert("abc")
+        .delete(1)
+        .try_build()
+        .unwrap();
+    assert_eq!(delta.base_len, 4);
+    assert_eq!(delta.target_len, 6);
+
+    let mut delta = RichTextDelta::default();
+    delta.ops.push(OpBuilder::retain(0).build());
+    assert!(validate(&delta).is_err());
+
+    let mut delta = RichTextDelta::default();
+    delta.ops.push(OpBuilder::insert("abc").build());
+    assert!(validate(&delta).is_err());
+}
+
+#[test]
+fn delta_builder_compose_test() {
+    let edit = RichTextDeltaBuilder::new().retain(3).insert("456").build();
+    let delta = RichTextDeltaBuilder::new()
+        .insert("123")
+        .compose(edit)
+        .unwrap()
+        .insert("789")
+        .build();
+    assert_eq!(delta.to_json(), r#"[{"insert":"123456789"}]"#);
+}
+
+#[test]
+fn delta_builder_invert_insert_test() {
+    let base = RichTextDeltaBuilder::new().insert("123456").build();
+    let builder = RichTextDeltaBuilder::new().retain(3).insert("abc");
+    let inverted = builder.invert(&base);
+    let delta = builder.build();
+    let undo = base.compose(&delta).unwrap().compose(&inverted).unwrap();
+    assert_eq!(undo, base);
+}
+
+#[test]
+fn delta_builder_invert_delete_test() {
+    let base = RichTextDeltaBuilder::new().insert("123456").build();
+    let builder = RichTextDeltaBuilder::new().retain(2).delete(3);
+    let inverted = builder.invert(&base);
+    let delta = builder.build();
+    let undo = base.compose(&delta).unwrap().compose(&inverted).unwrap();
+    assert_eq!(undo, base);
+}
+
+#[test]
+fn delta_builder_delete_with_attributes_test() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let base = RichTextDeltaBuilder::new()
+        .insert("12")
+        .insert_with_attributes("345", bold.clone())
+        .insert("6")
+        .build();
+    let builder = RichTextDeltaBuilder::new()
+        .retain(2)
+        .delete_with_attributes(3, bold.clone())
+        .insert("abc");
+    assert_eq!(builder.current_position(), 5);
+    let delta = builder.build();
+    assert_eq!(delta.base_len, 5);
+    assert_eq!(delta.target_len, 5);
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"retain":2},{"insert":"abc"},{"delete":3,"attributes":{"bold":"true"}}]"#
+    );
+
+    let composed = base.compose(&delta).unwrap();
+    assert_eq!(composed.to_json(), r#"[{"insert":"12abc6"}]"#);
+
+    // The deletes with different attributes are not merged
+    let delta = RichTextDeltaBuilder::new()
+        .delete(1)
+        .delete_with_attributes(2, bold)
+        .try_build()
+        .unwrap();
+    assert_eq!(delta.ops.len(), 2);
+    assert_eq!(delta.base_len, 3);
+}
+
+#[test]
+fn delta_builder_transform_delete_with_attributes_test() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let a = RichTextDeltaBuilder::new()
+        .delete_with_attributes(3, bold.clone())
+        .retain(3)
+        .build();
+    let b = RichTextDeltaBuilder::new().retain(6).insert("abc").build();
+    let (a_prime, b_prime) = transform(&a, &b).unwrap();
+    assert_eq!(a_prime.ops[0], RichTextOpBuilder::delete(3).attributes(bold).build());
+    assert_eq!(a_prime.base_len, 9);
+
+    let base = RichTextDeltaBuilder::new().insert("123456").build();
+    let ab = base.compose(&a).unwrap().compose(&b_prime).unwrap();
+    let ba = base.compose(&b).unwrap().compose(&a_prime).unwrap();
+    assert_eq!(ab, ba);
+}
+
+#[test]
+fn delta_builder_delete_attributes_test() {
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let mut op = RichTextOpBuilder::delete(3).build();
+    op.set_attributes(bold.clone());
+    assert_eq!(op.get_attributes(), bold);
+
+    // The invert reinserts the attributes of the delete
+    let delta = RichTextDeltaBuilder::new()
+        .retain(1)
+        .delete_with_attributes(3, bold.clone())
+        .build();
+    let inverted = delta.invert_str("1234");
+    assert_eq!(
+        inverted.ops[1],
+        RichTextOpBuilder::insert("234").attributes(bold.clone()).build()
+    );
+    assert_eq!(inverted.ops[1].get_attributes(), delta.ops[1].get_attributes());
+
+    // The attributes of the delete aren't applied to the transformed insert
+    let a = RichTextDeltaBuilder::new().delete_with_attributes(3, bold).build();
+    let b = RichTextDeltaBuilder::new().insert("abc").retain(3).build();
+    let (_, b_prime) = transform(&a, &b).unwrap();
+    assert_eq!(b_prime.ops[0], RichTextOpBuilder::insert("abc").build());
+}
+
+#[test]
+fn delta_builder_invert_retain_with_attributes_test() {
+    let base = RichTextDeltaBuilder::new().insert("123456").build();
+    let bold = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let builder = RichTextDeltaBuilder::new().retain_with_attributes(3, bold);
+    let inverted = builder.invert(&base);
+    let delta = builder.build();
+    let undo = base.compose(&delta).unwrap().compose(&inverted).unwrap();
+    assert_eq!(undo, base);
+}
+
+#[test]
+fn delta_builder_insert_with_test() {
+    let delta = RichTextDeltaBuilder::new()
+        .insert_with("123", |mut attrs| {
+            attrs.add(RichTextAttribute::Bold(true));
+            attrs
+        })
+        .insert_with_attributes(
+            "456",
+            AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build(),
+        )
+        .build();
+    assert_eq!(delta.to_json(), r#"[{"insert":"123456","attributes":{"bold":"true"}}]"#);
+
+    let plain = RichTextDeltaBuilder::new().insert_with("123", |attrs| attrs).build();
+    assert_eq!(plain, RichTextDeltaBuilder::new().insert("123").build());
+}
+
+#[test]
+fn delta_builder_from_plain_text_test() {
+    let delta = RichTextDeltaBuilder::from_plain_text("123\n\n456\n");
+    let expected = RichTextDeltaBuilder::new()
+        .insert("123")
+        .insert_newline()
+        .insert_newline()
+        .insert("456")
+        .insert_newline()
+        .build();
+    assert_eq!(delta, expected);
+    assert_eq!(delta.to_json(), r#"[{"insert":"123\n\n456\n"}]"#);
+
+    assert!(RichTextDeltaBuilder::from_plain_text("").is_empty());
+}
+
+#[test]
+fn delta_builder_current_position_test() {
+    let builder = RichTextDeltaBuilder::new();
+    assert_eq!(builder.current_position(), 0);
+
+    let builder = builder.retain(3).insert("abc");
+    assert_eq!(builder.current_position(), 3);
+
+    let builder = builder.delete(2).retain(1);
+    assert_eq!(builder.current_position(), 6);
+    assert_eq!(builder.build().base_len, 6);
+}
+
+#[test]
+fn delta_slice_test() {
+    let delta = RichTextDeltaBuilder::new()
+        .insert("123")
+        .insert_with("456", |mut attrs| {
+            attrs.add(RichTextAttribute::Bold(true));
+            attrs
+        })
+        .build();
+    assert_eq!(
+        delta.slice(2, 3).to_json(),
+        r#"[{"insert":"3"},{"insert":"45","attributes":{"bold":"true"}}]"#
+    );
+    assert_eq!(delta.slice(0, 6), delta);
+    assert_eq!(
+        delta.slice(4, 100).to_json(),
+        r#"[{"insert":"56","attributes":{"bold":"true"}}]"#
+    );
+    assert!(delta.slice(6, 3).is_empty());
+}
+
+#[test]
+fn delta_attributes_at_test() {
+    let delta = RichTextDeltaBuilder::new()
+        .insert("123")
+        .insert_with("456", |mut attrs| {
+            attrs.add(RichTextAttribute::Bold(true));
+            attrs
+        })
+        .build();
+    assert!(delta.attributes_at(0).is_empty());
+    assert!(delta.attributes_at(2).is_empty());
+    assert_eq!(delta.attributes_at(3), delta.ops[1].get_attributes());
+    assert_eq!(delta.attributes_at(5), delta.ops[1].get_attributes());
+    assert!(delta.attributes_at(6).is_empty());
+}
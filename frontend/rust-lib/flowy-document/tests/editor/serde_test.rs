@@ -1,7 +1,7 @@
 use flowy_collaboration::document::{Document, PlainDoc};
 use lib_ot::{
     core::*,
-    rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributeValue, RichTextDelta},
+    rich_text::{AttributeBuilder, RichTextAttribute, RichTextAttributeValue, RichTextAttributes, RichTextDelta},
 };
 
 #[test]
@@ -29,12 +29,22 @@ fn operation_retain_serialize_test() {
 
 #[test]
 fn operation_delete_serialize_test() {
-    let operation = RichTextOperation::Delete(2);
+    let operation = RichTextOperation::Delete(2.into());
     let json = serde_json::to_string(&operation).unwrap();
     let insert_op: RichTextOperation = serde_json::from_str(&json).unwrap();
     assert_eq!(insert_op, operation);
 }
 
+#[test]
+fn operation_delete_with_attributes_serialize_test() {
+    let attributes = AttributeBuilder::new().add_attr(RichTextAttribute::Bold(true)).build();
+    let operation = RichTextOpBuilder::delete(2).attributes(attributes).build();
+    let json = serde_json::to_string(&operation).unwrap();
+    assert_eq!(json, r#"{"delete":2,"attributes":{"bold":"true"}}"#);
+    let delete_op: RichTextOperation = serde_json::from_str(&json).unwrap();
+    assert_eq!(delete_op, operation);
+}
+
 #[test]
 fn attributes_serialize_test() {
     let attributes = AttributeBuilder::new()
@@ -59,7 +69,7 @@ fn delta_serialize_multi_attribute_test() {
 
     delta.add(retain);
     delta.add(Operation::Retain(5.into()));
-    delta.add(Operation::Delete(3));
+    delta.add(Operation::Delete(3.into()));
 
     let json = serde_json::to_string(&delta).unwrap();
     eprintln!("{}", json);
@@ -111,3 +121,40 @@ fn document_insert_serde_test() {
     assert_eq!(r#"[{"insert":"123\n"}]"#, json);
     assert_eq!(r#"[{"insert":"123\n"}]"#, Document::from_json(&json).unwrap().to_json());
 }
+
+#[test]
+fn delta_embed_serde_test() {
+    let embed = serde_json::json!({"image": "https://appflowy.io/logo.png"});
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("123")
+        .insert_embed(embed.clone(), RichTextAttributes::default())
+        .insert("\n")
+        .trim()
+        .build();
+    assert_eq!(delta.ops.len(), 3);
+    assert_eq!(delta.target_len, 5);
+
+    let json = delta.to_json();
+    assert_eq!(
+        json,
+        r#"[{"insert":"123"},{"insert":{"image":"https://appflowy.io/logo.png"}},{"insert":"\n"}]"#
+    );
+    let delta_from_json = RichTextDelta::from_json(&json).unwrap();
+    assert_eq!(delta_from_json, delta);
+    assert!(delta_from_json.ops[1].is_embed());
+}
+
+#[test]
+fn delta_insert_newline_with_attributes_test() {
+    let header = AttributeBuilder::new().add_attr(RichTextAttribute::Header(1)).build();
+    let delta: RichTextDelta = DeltaBuilder::new()
+        .insert("AppFlowy")
+        .insert_newline_with_attributes(header)
+        .insert("123")
+        .insert_newline()
+        .build();
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"insert":"AppFlowy"},{"insert":"\n","attributes":{"header":1}},{"insert":"123\n"}]"#
+    );
+}
@@ -36,6 +36,7 @@ impl FlowyError {
 
     static_flowy_error!(internal, ErrorCode::Internal);
     static_flowy_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_flowy_error!(serde, ErrorCode::SerdeError);
     static_flowy_error!(workspace_name, ErrorCode::WorkspaceNameInvalid);
     static_flowy_error!(workspace_id, ErrorCode::WorkspaceIdInvalid);
     static_flowy_error!(color_style, ErrorCode::AppColorStyleInvalid);
@@ -43,12 +44,17 @@ impl FlowyError {
     static_flowy_error!(app_name, ErrorCode::AppNameInvalid);
     static_flowy_error!(invalid_app_id, ErrorCode::AppIdInvalid);
     static_flowy_error!(view_name, ErrorCode::ViewNameInvalid);
+    static_flowy_error!(view_name_too_long, ErrorCode::ViewNameTooLong);
+    static_flowy_error!(view_readonly, ErrorCode::ViewReadOnly);
+    static_flowy_error!(view_busy, ErrorCode::ViewBusy);
+    static_flowy_error!(unsupported_view_conversion, ErrorCode::UnsupportedViewConversion);
     static_flowy_error!(view_thumbnail, ErrorCode::ViewThumbnailInvalid);
     static_flowy_error!(invalid_view_id, ErrorCode::ViewIdInvalid);
     static_flowy_error!(view_desc, ErrorCode::ViewDescTooLong);
     static_flowy_error!(view_data, ErrorCode::ViewDataInvalid);
     static_flowy_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_flowy_error!(connection, ErrorCode::ConnectError);
+    static_flowy_error!(server_unavailable, ErrorCode::ServerUnavailable);
     static_flowy_error!(email_empty, ErrorCode::EmailIsEmpty);
     static_flowy_error!(email_format, ErrorCode::EmailFormatInvalid);
     static_flowy_error!(email_exist, ErrorCode::EmailAlreadyExists);
@@ -62,6 +68,16 @@ impl FlowyError {
     static_flowy_error!(name_empty, ErrorCode::UserNameIsEmpty);
     static_flowy_error!(user_id, ErrorCode::UserIdInvalid);
     static_flowy_error!(user_not_exist, ErrorCode::UserNotExist);
+    static_flowy_error!(email_change_code_invalid, ErrorCode::EmailChangeCodeInvalid);
+    static_flowy_error!(email_change_code_expired, ErrorCode::EmailChangeCodeExpired);
+    static_flowy_error!(
+        password_change_requires_old_password,
+        ErrorCode::PasswordChangeRequiresOldPassword
+    );
+    static_flowy_error!(
+        email_change_requires_verification,
+        ErrorCode::EmailChangeRequiresVerification
+    );
 }
 
 impl std::convert::From<ErrorCode> for FlowyError {
@@ -13,6 +13,8 @@ fn server_error_to_flowy_error(code: ServerErrorCode) -> ErrorCode {
     match code {
         ServerErrorCode::UserUnauthorized => ErrorCode::UserUnauthorized,
         ServerErrorCode::PasswordNotMatch => ErrorCode::PasswordNotMatch,
+        ServerErrorCode::EmailChangeCodeInvalid => ErrorCode::EmailChangeCodeInvalid,
+        ServerErrorCode::EmailChangeCodeExpired => ErrorCode::EmailChangeCodeExpired,
         ServerErrorCode::RecordNotFound => ErrorCode::RecordNotFound,
         ServerErrorCode::ConnectRefused | ServerErrorCode::ConnectTimeout | ServerErrorCode::ConnectClose => {
             ErrorCode::ConnectError
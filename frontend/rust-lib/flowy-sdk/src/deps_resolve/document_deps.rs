@@ -58,6 +58,8 @@ impl DocumentUser for DocumentUserImpl {
     fn token(&self) -> Result<String, FlowyError> { self.user.token() }
 
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError> { self.user.db_pool() }
+
+    fn encryption_key(&self) -> Option<Vec<u8>> { self.user.encryption_key() }
 }
 
 struct DocumentWebSocketAdapter {
@@ -34,6 +34,12 @@ impl WorkspaceDatabase for Resolver {
             .db_pool()
             .map_err(|e| FlowyError::internal().context(e))
     }
+
+    fn db_pool_of(&self, user_id: &str) -> Result<Arc<ConnectionPool>, FlowyError> {
+        self.user_session
+            .db_pool_of(user_id)
+            .map_err(|e| FlowyError::internal().context(e))
+    }
 }
 
 impl WorkspaceUser for Resolver {
@@ -46,4 +52,10 @@ impl WorkspaceUser for Resolver {
     fn token(&self) -> Result<String, FlowyError> {
         self.user_session.token().map_err(|e| FlowyError::internal().context(e))
     }
+
+    fn token_of(&self, user_id: &str) -> Result<String, FlowyError> {
+        self.user_session
+            .token_of(user_id)
+            .map_err(|e| FlowyError::internal().context(e))
+    }
 }
@@ -17,13 +17,17 @@ use lib_dispatch::prelude::*;
 use lib_ws::WSController;
 use module::mk_modules;
 pub use module::*;
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
 use tokio::sync::broadcast;
 
 static INIT_LOG: AtomicBool = AtomicBool::new(false);
+const DEFAULT_DOC_DELTA_DEBOUNCE: Duration = Duration::from_millis(300);
 
 #[derive(Debug, Clone)]
 pub struct FlowySDKConfig {
@@ -31,6 +35,7 @@ pub struct FlowySDKConfig {
     root: String,
     log_filter: String,
     server_config: ClientServerConfiguration,
+    doc_delta_debounce: Duration,
 }
 
 impl FlowySDKConfig {
@@ -40,6 +45,7 @@ impl FlowySDKConfig {
             root: root.to_owned(),
             log_filter: crate_log_filter(None),
             server_config,
+            doc_delta_debounce: DEFAULT_DOC_DELTA_DEBOUNCE,
         }
     }
 
@@ -47,6 +53,16 @@ impl FlowySDKConfig {
         self.log_filter = crate_log_filter(Some(filter.to_owned()));
         self
     }
+
+    // The document deltas received within the debounce window are sent to the
+    // server as one revision. Zero disables it.
+    pub fn doc_delta_debounce(mut self, debounce: Duration) -> Self {
+        self.doc_delta_debounce = debounce;
+        self
+    }
+
+    // The prefix of the KV keys that the sessions of the users are stored under.
+    pub fn session_cache_key(&self) -> String { format!("{}_session_cache", &self.name) }
 }
 
 fn crate_log_filter(level: Option<String>) -> String {
@@ -67,7 +83,6 @@ fn crate_log_filter(level: Option<String>) -> String {
 
 #[derive(Clone)]
 pub struct FlowySDK {
-    #[allow(dead_code)]
     config: FlowySDKConfig,
     pub user_session: Arc<UserSession>,
     pub document_ctx: Arc<DocumentContext>,
@@ -90,7 +105,12 @@ impl FlowySDK {
 
         let ws_manager = Arc::new(FlowyWSConnect::new(config.server_config.ws_addr(), ws));
         let user_session = mk_user_session(&config);
-        let flowy_document = mk_document(ws_manager.clone(), user_session.clone(), &config.server_config);
+        let flowy_document = mk_document(
+            ws_manager.clone(),
+            user_session.clone(),
+            &config.server_config,
+            config.doc_delta_debounce,
+        );
         let core_ctx = mk_core_context(user_session.clone(), flowy_document.clone(), &config.server_config);
 
         //
@@ -109,6 +129,8 @@ impl FlowySDK {
     }
 
     pub fn dispatcher(&self) -> Arc<EventDispatcher> { self.dispatcher.clone() }
+
+    pub fn config(&self) -> FlowySDKConfig { self.config.clone() }
 }
 
 fn _init(
@@ -123,6 +145,7 @@ fn _init(
 
     dispatch.spawn(async move {
         user_session.init();
+        user_session.flush_pending_sign_outs().await;
         listen_on_websocket(ws_manager.clone());
         _listen_user_status(ws_manager.clone(), subscribe_user_status, core.clone()).await;
     });
@@ -192,8 +215,7 @@ fn init_log(config: &FlowySDKConfig) {
 }
 
 fn mk_user_session(config: &FlowySDKConfig) -> Arc<UserSession> {
-    let session_cache_key = format!("{}_session_cache", &config.name);
-    let user_config = UserSessionConfig::new(&config.root, &config.server_config, &session_cache_key);
+    let user_config = UserSessionConfig::new(&config.root, &config.server_config, &config.session_cache_key());
     Arc::new(UserSession::new(user_config))
 }
 
@@ -211,7 +233,14 @@ pub fn mk_document(
     ws_manager: Arc<FlowyWSConnect>,
     user_session: Arc<UserSession>,
     server_config: &ClientServerConfiguration,
+    doc_delta_debounce: Duration,
 ) -> Arc<DocumentContext> {
     let (user, ws_receivers, ws_sender) = DocumentDepsResolver::resolve(ws_manager, user_session);
-    Arc::new(DocumentContext::new(user, ws_receivers, ws_sender, server_config))
+    Arc::new(DocumentContext::new(
+        user,
+        ws_receivers,
+        ws_sender,
+        server_config,
+        doc_delta_debounce,
+    ))
 }
@@ -18,6 +18,7 @@ pub enum EditorScript {
     AssertJson(&'static str),
 
     WaitSyncFinished,
+    Compact,
 }
 
 pub struct EditorTest {
@@ -95,6 +96,9 @@ impl EditorTest {
                 // Workaround: just wait two seconds
                 sleep(Duration::from_millis(2000)).await;
             },
+            EditorScript::Compact => {
+                let _ = rev_manager.compact().await.unwrap();
+            },
         }
         sleep(Duration::from_millis(wait_millis)).await;
     }
@@ -1,9 +1,16 @@
-use std::{fs, path::PathBuf, sync::Arc};
+use std::{fs, path::PathBuf, sync::Arc, time::Duration};
 
-use flowy_collaboration::entities::doc::DocumentInfo;
+use bytes::Bytes;
+use dart_notify::{dart::DartStreamSender, entities::SubscribeObject};
+
+use flowy_collaboration::entities::{
+    doc::{DocumentDelta, DocumentDeltaResult, DocumentInfo, RepeatedDocumentDelta},
+    revision::{CompactReport, RepeatedRevisionSummary},
+};
 use flowy_core::{
     entities::{
         app::*,
+        share::{ExportAppRequest, ExportData, ExportRequest, RepeatedExportData},
         trash::{RepeatedTrash, TrashId},
         view::*,
         workspace::{CreateWorkspaceRequest, QueryWorkspaceRequest, Workspace, *},
@@ -16,8 +23,9 @@ use flowy_user::{
     errors::FlowyError,
     event::UserEvent::{InitUser, SignIn, SignOut, SignUp},
 };
-use lib_dispatch::prelude::{EventDispatcher, ModuleRequest, ToBytes};
+use lib_dispatch::prelude::{EventDispatcher, FromBytes, ModuleRequest, ToBytes};
 use lib_infra::uuid_string;
+use tokio::sync::broadcast::{error::RecvError, Receiver};
 
 use crate::prelude::*;
 
@@ -223,6 +231,9 @@ pub async fn create_view(sdk: &FlowySDKTest, app_id: &str) -> View {
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
         view_type: ViewType::Doc,
+        insert_after: None,
+        template: None,
+        icon: None,
     };
 
     create_view_with_request(sdk, request).await
@@ -253,6 +264,162 @@ pub async fn delete_view(sdk: &FlowySDKTest, request: QueryViewRequest) {
         .await;
 }
 
+pub async fn rename_view(sdk: &FlowySDKTest, request: RenameViewRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(RenameView)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn set_view_visibility(sdk: &FlowySDKTest, request: SetViewVisibilityRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(SetViewVisibility)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn set_view_icon(sdk: &FlowySDKTest, request: SetViewIconRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(SetViewIcon)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn set_view_readonly(sdk: &FlowySDKTest, request: SetViewReadonlyRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(SetViewReadonly)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn read_view_sync_state(sdk: &FlowySDKTest, request: QueryViewRequest) -> ViewSyncStatus {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadViewSyncState)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ViewSyncStatus>()
+}
+
+pub async fn preview_delete_view(sdk: &FlowySDKTest, request: QueryViewRequest) -> RepeatedView {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(PreviewDeleteView)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+}
+
+pub async fn duplicate_view_recursive(sdk: &FlowySDKTest, request: QueryViewRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(DuplicateViewRecursive)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn trash_views_belong_to(sdk: &FlowySDKTest, request: QueryAppRequest) {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(TrashViewsBelongTo)
+        .request(request)
+        .async_send()
+        .await;
+}
+
+pub async fn verify_views_consistency(sdk: &FlowySDKTest) -> ConsistencyReport {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(VerifyViewsConsistency)
+        .async_send()
+        .await
+        .parse::<ConsistencyReport>()
+}
+
+pub async fn repair_orphaned_views(sdk: &FlowySDKTest, request: RepairViewsRequest) -> RepairReport {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(RepairOrphanedViews)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepairReport>()
+}
+
+pub async fn read_trashed_views(sdk: &FlowySDKTest) -> RepeatedView {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadTrashedViews)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+}
+
+pub async fn read_all_user_views(sdk: &FlowySDKTest) -> RepeatedView {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadAllUserViews)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+}
+
+pub async fn set_view_thumbnail(sdk: &FlowySDKTest, request: SetViewThumbnailRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(SetViewThumbnail)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn read_view_thumbnail(sdk: &FlowySDKTest, request: QueryViewRequest) -> ViewThumbnail {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadViewThumbnail)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ViewThumbnail>()
+}
+
+pub async fn read_view_ancestors(sdk: &FlowySDKTest, request: QueryViewRequest) -> RepeatedView {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadViewAncestors)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+}
+
+pub async fn restore_view(sdk: &FlowySDKTest, request: QueryViewRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(RestoreView)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn read_open_document_ids(sdk: &FlowySDKTest) -> RepeatedViewId {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadOpenDocumentIds)
+        .async_send()
+        .await
+        .parse::<RepeatedViewId>()
+}
+
+pub async fn restore_open_views(sdk: &FlowySDKTest) -> RepeatedDocumentDelta {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(RestoreOpenViews)
+        .async_send()
+        .await
+        .parse::<RepeatedDocumentDelta>()
+}
+
 pub async fn read_trash(sdk: &FlowySDKTest) -> RepeatedTrash {
     CoreModuleEventBuilder::new(sdk.clone())
         .event(ReadTrash)
@@ -278,6 +445,176 @@ pub async fn open_view(sdk: &FlowySDKTest, request: QueryViewRequest) -> Documen
         .parse::<DocumentInfo>()
 }
 
+pub async fn open_view_offline_first(sdk: &FlowySDKTest, request: QueryViewRequest) -> DocumentDelta {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(OpenViewOfflineFirst)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<DocumentDelta>()
+}
+
+pub async fn read_views_belong_to(sdk: &FlowySDKTest, request: QueryViewsBelongToRequest) -> RepeatedView {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadViewsBelongTo)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedView>()
+}
+
+pub async fn read_views_belong_to_paged(sdk: &FlowySDKTest, request: QueryViewsPageRequest) -> ViewsPage {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadViewsBelongToPaged)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ViewsPage>()
+}
+
+pub async fn count_views_belong_to(sdk: &FlowySDKTest, request: QueryViewsCountRequest) -> ViewCount {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(CountViewsBelongTo)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ViewCount>()
+}
+
+pub async fn count_views_grouped_by_parent(sdk: &FlowySDKTest) -> RepeatedViewCount {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(CountViewsGroupedByParent)
+        .async_send()
+        .await
+        .parse::<RepeatedViewCount>()
+}
+
+pub async fn merge_local_into(sdk: &FlowySDKTest, request: MergeLocalRequest) -> MergeReport {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(MergeLocalInto)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<MergeReport>()
+}
+
+pub async fn import_view(sdk: &FlowySDKTest, request: ImportViewRequest) -> View {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ImportView)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<View>()
+}
+
+pub async fn find_duplicate_views(sdk: &FlowySDKTest, request: QueryViewsBelongToRequest) -> RepeatedDuplicateViews {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(FindDuplicateViews)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedDuplicateViews>()
+}
+
+pub async fn read_document_stats(sdk: &FlowySDKTest, request: QueryViewRequest) -> DocumentStats {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadDocumentStats)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<DocumentStats>()
+}
+
+pub async fn read_document_history(
+    sdk: &FlowySDKTest,
+    request: QueryDocumentHistoryRequest,
+) -> RepeatedRevisionSummary {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ReadDocumentHistory)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedRevisionSummary>()
+}
+
+pub async fn restore_document(sdk: &FlowySDKTest, request: RestoreDocumentRequest) -> DocumentDelta {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(RestoreDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<DocumentDelta>()
+}
+
+pub async fn compact_document(sdk: &FlowySDKTest, request: QueryViewRequest) -> CompactReport {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(CompactDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<CompactReport>()
+}
+
+pub async fn apply_doc_delta(sdk: &FlowySDKTest, request: DocumentDelta) -> DocumentDeltaResult {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ApplyDocDelta)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<DocumentDeltaResult>()
+}
+
+pub async fn export_document(sdk: &FlowySDKTest, request: ExportRequest) -> ExportData {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ExportDocument)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<ExportData>()
+}
+
+pub async fn export_app(sdk: &FlowySDKTest, request: ExportAppRequest) -> RepeatedExportData {
+    CoreModuleEventBuilder::new(sdk.clone())
+        .event(ExportApp)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<RepeatedExportData>()
+}
+
+pub fn subscribe_notifications() -> Receiver<SubscribeObject> { DartStreamSender::subscribe() }
+
+// Waits for the notification of the ty that matches the predicate. The tests
+// run in parallel, so the receiver also gets the notifications of the other
+// tests.
+pub async fn wait_for_notification<F>(
+    rx: &mut Receiver<SubscribeObject>,
+    ty: i32,
+    predicate: F,
+) -> Option<SubscribeObject>
+where
+    F: Fn(&SubscribeObject) -> bool,
+{
+    let wait = async {
+        loop {
+            match rx.recv().await {
+                Ok(subject) if subject.ty == ty && predicate(&subject) => return Some(subject),
+                Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(3), wait).await.ok().flatten()
+}
+
+pub fn parse_notification<T: FromBytes>(subject: &SubscribeObject) -> T {
+    let payload = subject.payload.clone().unwrap_or_default();
+    match T::parse_from_bytes(Bytes::from(payload)) {
+        Ok(data) => data,
+        Err(e) => panic!("Parse notification failed: {:?}", e),
+    }
+}
+
 pub fn root_dir() -> String {
     // https://doc.rust-lang.org/cargo/reference/environment-variables.html
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| "./".to_owned());
@@ -344,6 +681,25 @@ pub async fn async_sign_up(dispatch: Arc<EventDispatcher>) -> SignUpContext {
     SignUpContext { user_profile, password }
 }
 
+// Signs in the user that signed up before, e.g. to unlock the encryption key
+// again after a restart.
+pub async fn async_sign_in(dispatch: Arc<EventDispatcher>, email: &str, password: &str) -> UserProfile {
+    let payload = SignInRequest {
+        email: email.to_owned(),
+        password: password.to_owned(),
+        name: "".to_owned(),
+    }
+    .into_bytes()
+    .unwrap();
+
+    let request = ModuleRequest::new(SignIn).payload(payload);
+    EventDispatcher::async_send(dispatch, request)
+        .await
+        .parse::<UserProfile, FlowyError>()
+        .unwrap()
+        .unwrap()
+}
+
 pub async fn init_user_setting(dispatch: Arc<EventDispatcher>) {
     let request = ModuleRequest::new(InitUser);
     let _ = EventDispatcher::async_send(dispatch.clone(), request).await;
@@ -36,6 +36,14 @@ impl FlowySDKTest {
         Self(sdk)
     }
 
+    // Builds a new sdk with the same root and session cache, as if the app was
+    // relaunched, so the session of the signed-in user is restored.
+    pub fn restart(&self) -> Self {
+        let sdk = Self(FlowySDK::new(self.0.config()));
+        std::mem::forget(sdk.dispatcher());
+        sdk
+    }
+
     pub async fn sign_up(&self) -> SignUpContext {
         let context = async_sign_up(self.0.dispatcher()).await;
         context
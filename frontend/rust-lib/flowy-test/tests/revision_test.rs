@@ -29,3 +29,26 @@ async fn doc_sync_retry_ws_conn() {
     ];
     EditorTest::new().await.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn doc_compact_keeps_unacked_revisions() {
+    let scripts = vec![
+        InsertText("1", 0),
+        WaitSyncFinished,
+        AssertRevisionState(1, RevisionState::Ack),
+        StopWs,
+        InsertText("2", 1),
+        InsertText("3", 2),
+        Compact,
+        AssertRevisionState(1, RevisionState::Ack),
+        AssertRevisionState(2, RevisionState::Local),
+        AssertRevisionState(3, RevisionState::Local),
+        AssertJson(r#"[{"insert":"123\n"}]"#),
+        StartWs,
+        WaitSyncFinished,
+        AssertRevisionState(3, RevisionState::Ack),
+        AssertNextRevId(None),
+        AssertJson(r#"[{"insert":"123\n"}]"#),
+    ];
+    EditorTest::new().await.run_scripts(scripts).await;
+}
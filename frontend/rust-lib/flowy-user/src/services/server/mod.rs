@@ -7,7 +7,19 @@ pub use server_api_mock::*;
 use std::sync::Arc;
 pub(crate) type Server = Arc<dyn UserServerAPI + Send + Sync>;
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ChangePasswordParams,
+        ConfirmEmailChangeParams,
+        ConfirmEmailChangeResponse,
+        DeleteAccountParams,
+        RequestEmailChangeParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::FlowyError,
 };
 use backend_service::configuration::ClientServerConfiguration;
@@ -19,6 +31,16 @@ pub trait UserServerAPI {
     fn sign_out(&self, token: &str) -> FutureResult<(), FlowyError>;
     fn update_user(&self, token: &str, params: UpdateUserParams) -> FutureResult<(), FlowyError>;
     fn get_user(&self, token: &str) -> FutureResult<UserProfile, FlowyError>;
+    fn change_password(&self, token: &str, params: ChangePasswordParams) -> FutureResult<(), FlowyError>;
+    fn delete_account(&self, token: &str, params: DeleteAccountParams) -> FutureResult<(), FlowyError>;
+    // Sends the verification code to the new email, the user's email is kept
+    // until the code is confirmed.
+    fn request_email_change(&self, token: &str, params: RequestEmailChangeParams) -> FutureResult<(), FlowyError>;
+    fn confirm_email_change(
+        &self,
+        token: &str,
+        params: ConfirmEmailChangeParams,
+    ) -> FutureResult<ConfirmEmailChangeResponse, FlowyError>;
     fn ws_addr(&self) -> String;
 }
 
@@ -26,6 +48,6 @@ pub(crate) fn construct_user_server(config: &ClientServerConfiguration) -> Arc<d
     if cfg!(feature = "http_server") {
         Arc::new(UserHttpServer::new(config.clone()))
     } else {
-        Arc::new(UserServerMock {})
+        Arc::new(UserServerMock::default())
     }
 }
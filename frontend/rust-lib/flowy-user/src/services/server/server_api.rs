@@ -1,5 +1,17 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ChangePasswordParams,
+        ConfirmEmailChangeParams,
+        ConfirmEmailChangeResponse,
+        DeleteAccountParams,
+        RequestEmailChangeParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::FlowyError,
     services::server::UserServerAPI,
 };
@@ -57,6 +69,46 @@ impl UserServerAPI for UserHttpServer {
         })
     }
 
+    fn change_password(&self, token: &str, params: ChangePasswordParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.change_password_url();
+        FutureResult::new(async move {
+            let _ = change_password_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn delete_account(&self, token: &str, params: DeleteAccountParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.user_profile_url();
+        FutureResult::new(async move {
+            let _ = delete_account_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn request_email_change(&self, token: &str, params: RequestEmailChangeParams) -> FutureResult<(), FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.email_change_url();
+        FutureResult::new(async move {
+            let _ = request_email_change_request(&token, params, &url).await?;
+            Ok(())
+        })
+    }
+
+    fn confirm_email_change(
+        &self,
+        token: &str,
+        params: ConfirmEmailChangeParams,
+    ) -> FutureResult<ConfirmEmailChangeResponse, FlowyError> {
+        let token = token.to_owned();
+        let url = self.config.confirm_email_change_url();
+        FutureResult::new(async move {
+            let response = confirm_email_change_request(&token, params, &url).await?;
+            Ok(response)
+        })
+    }
+
     fn ws_addr(&self) -> String { self.config.ws_addr() }
 }
 
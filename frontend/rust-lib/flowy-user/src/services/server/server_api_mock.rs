@@ -1,12 +1,37 @@
 use crate::{
-    entities::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    entities::{
+        ChangePasswordParams,
+        ConfirmEmailChangeParams,
+        ConfirmEmailChangeResponse,
+        DeleteAccountParams,
+        RequestEmailChangeParams,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
     errors::FlowyError,
 };
 
 use crate::services::server::UserServerAPI;
 use lib_infra::{future::FutureResult, uuid_string};
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+// The mock server "sends" this code for every email change.
+pub const MOCK_EMAIL_CHANGE_CODE: &str = "123456";
+const EMAIL_CHANGE_CODE_EXPIRATION: Duration = Duration::from_secs(10 * 60);
 
-pub struct UserServerMock {}
+#[derive(Default)]
+pub struct UserServerMock {
+    // The requested email and the time of the request, keyed by the token
+    email_changes: RwLock<HashMap<String, (String, Instant)>>,
+}
 
 impl UserServerMock {}
 
@@ -15,10 +40,11 @@ impl UserServerAPI for UserServerMock {
         let uid = uuid_string();
         FutureResult::new(async move {
             Ok(SignUpResponse {
-                user_id: uid.clone(),
+                user_id: uid,
                 name: params.name,
                 email: params.email,
-                token: uid,
+                token: uuid_string(),
+                expires_at: 0,
             })
         })
     }
@@ -27,10 +53,11 @@ impl UserServerAPI for UserServerMock {
         let user_id = uuid_string();
         FutureResult::new(async {
             Ok(SignInResponse {
-                user_id: user_id.clone(),
+                user_id,
                 name: params.name,
                 email: params.email,
-                token: user_id,
+                token: uuid_string(),
+                expires_at: 0,
             })
         })
     }
@@ -45,5 +72,40 @@ impl UserServerAPI for UserServerMock {
         FutureResult::new(async { Ok(UserProfile::default()) })
     }
 
+    fn change_password(&self, _token: &str, _params: ChangePasswordParams) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn delete_account(&self, _token: &str, _params: DeleteAccountParams) -> FutureResult<(), FlowyError> {
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn request_email_change(&self, token: &str, params: RequestEmailChangeParams) -> FutureResult<(), FlowyError> {
+        self.email_changes
+            .write()
+            .insert(token.to_owned(), (params.new_email, Instant::now()));
+        FutureResult::new(async { Ok(()) })
+    }
+
+    fn confirm_email_change(
+        &self,
+        token: &str,
+        params: ConfirmEmailChangeParams,
+    ) -> FutureResult<ConfirmEmailChangeResponse, FlowyError> {
+        let result = if params.code != MOCK_EMAIL_CHANGE_CODE {
+            Err(FlowyError::email_change_code_invalid())
+        } else {
+            // The code can only be confirmed once
+            match self.email_changes.write().remove(token) {
+                None => Err(FlowyError::email_change_code_invalid()),
+                Some((_, requested_at)) if requested_at.elapsed() > EMAIL_CHANGE_CODE_EXPIRATION => {
+                    Err(FlowyError::email_change_code_expired())
+                },
+                Some((email, _)) => Ok(ConfirmEmailChangeResponse { email }),
+            }
+        };
+        FutureResult::new(async { result })
+    }
+
     fn ws_addr(&self) -> String { "ws://localhost:8000/ws/".to_owned() }
 }
@@ -4,29 +4,32 @@ use lazy_static::lazy_static;
 use lib_sqlite::ConnectionPool;
 use once_cell::sync::Lazy;
 use parking_lot::{Mutex, RwLock};
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{collections::HashMap, fs, path::Path, sync::Arc, time::Duration};
 lazy_static! {
     static ref DB: RwLock<Option<Database>> = RwLock::new(None);
 }
 
 pub(crate) struct UserDB {
-    db_dir: String,
+    db_dir: RwLock<String>,
 }
 
 impl UserDB {
     pub(crate) fn new(db_dir: &str) -> Self {
         Self {
-            db_dir: db_dir.to_owned(),
+            db_dir: RwLock::new(db_dir.to_owned()),
         }
     }
 
+    // The opened databases should be closed before changing the directory.
+    pub(crate) fn set_db_dir(&self, db_dir: &str) { *self.db_dir.write() = db_dir.to_owned(); }
+
     fn open_user_db(&self, user_id: &str) -> Result<(), FlowyError> {
         if user_id.is_empty() {
             return Err(FlowyError::internal().context("user id is empty"));
         }
 
         tracing::info!("open user db {}", user_id);
-        let dir = format!("{}/{}", self.db_dir, user_id);
+        let dir = format!("{}/{}", self.db_dir.read(), user_id);
         let db = flowy_database::init(&dir).map_err(|e| {
             log::error!("init user db failed, {:?}, user_id: {}", e, user_id);
             FlowyError::internal().context(e)
@@ -52,6 +55,17 @@ impl UserDB {
         }
     }
 
+    // Close the database and remove the user's directory. It's fine to call it
+    // again if the directory was already removed.
+    pub(crate) fn delete_user_db(&self, user_id: &str) -> Result<(), FlowyError> {
+        let _ = self.close_user_db(user_id)?;
+        let dir = format!("{}/{}", self.db_dir.read(), user_id);
+        if Path::new(&dir).exists() {
+            let _ = fs::remove_dir_all(&dir)?;
+        }
+        Ok(())
+    }
+
     pub(crate) fn get_connection(&self, user_id: &str) -> Result<DBConnection, FlowyError> {
         let conn = self.get_pool(user_id)?.get()?;
         Ok(conn)
@@ -1,8 +1,25 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use parking_lot::RwLock;
+use aes_gcm::{
+    aead::{Aead, NewAead},
+    Aes256Gcm,
+    Key,
+    Nonce,
+};
+use hmac::Hmac;
+use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
-use tokio::sync::mpsc;
+use sha2::Sha256;
+use tokio::sync::{broadcast, mpsc};
+use tracing::Instrument;
 
 use backend_service::configuration::ClientServerConfiguration;
 use flowy_database::{
@@ -13,12 +30,25 @@ use flowy_database::{
     ExpressionMethods,
     UserDatabaseConnection,
 };
-use flowy_user_data_model::entities::{SignInResponse, SignUpResponse};
+use flowy_user_data_model::{
+    entities::{SignInResponse, SignUpResponse},
+    parser::UserPassword,
+};
+use lib_infra::{timestamp, uuid_string};
 use lib_sqlite::ConnectionPool;
 
 use crate::{
-    entities::{SignInParams, SignUpParams, UpdateUserParams, UserProfile},
-    errors::{ErrorCode, FlowyError},
+    entities::{
+        ChangePasswordParams,
+        ConfirmEmailChangeParams,
+        DeleteAccountParams,
+        RequestEmailChangeParams,
+        SignInParams,
+        SignUpParams,
+        UpdateUserParams,
+        UserProfile,
+    },
+    errors::{internal_error, ErrorCode, FlowyError},
     notify::*,
     services::{
         server::{construct_user_server, Server},
@@ -27,18 +57,28 @@ use crate::{
     sql_tables::{UserTable, UserTableChangeset},
 };
 
+const ENCRYPTION_KEY_LEN: usize = 32;
+const ENCRYPTION_KEY_ROUNDS: u32 = 10_000;
+const WRAP_NONCE_LEN: usize = 12;
+// The calls within the interval share the last profile fetch instead of
+// requesting the server again.
+const PROFILE_FETCH_INTERVAL: Duration = Duration::from_secs(5);
+
 pub struct UserSessionConfig {
-    root_dir: String,
+    root_dir: RwLock<String>,
     server_config: ClientServerConfiguration,
     session_cache_key: String,
+    // The key of the current session, it's unwrapped by the password at sign-in
+    encryption_key: RwLock<Option<Vec<u8>>>,
 }
 
 impl UserSessionConfig {
     pub fn new(root_dir: &str, server_config: &ClientServerConfiguration, session_cache_key: &str) -> Self {
         Self {
-            root_dir: root_dir.to_owned(),
+            root_dir: RwLock::new(root_dir.to_owned()),
             server_config: server_config.clone(),
             session_cache_key: session_cache_key.to_owned(),
+            encryption_key: RwLock::new(None),
         }
     }
 }
@@ -49,25 +89,39 @@ pub struct UserSession {
     #[allow(dead_code)]
     server: Server,
     session: RwLock<Option<Session>>,
+    user_profile: Arc<RwLock<Option<UserProfile>>>,
+    // The token and the time of the last profile fetch
+    last_profile_fetch: Mutex<Option<(String, Instant)>>,
+    session_notifier: broadcast::Sender<Option<Session>>,
+    // The unwrapped keys of the users that signed in since the launch, keyed by
+    // the user id. They are only kept in memory, so the documents on disk can't
+    // be decrypted without the password.
+    encryption_keys: RwLock<HashMap<String, Vec<u8>>>,
     pub notifier: UserNotifier,
 }
 
 impl UserSession {
     pub fn new(config: UserSessionConfig) -> Self {
-        let db = UserDB::new(&config.root_dir);
+        let db = UserDB::new(&config.root_dir.read());
         let server = construct_user_server(&config.server_config);
         let notifier = UserNotifier::new();
+        let (session_notifier, _) = broadcast::channel(10);
         Self {
             database: db,
             config,
             server,
             session: RwLock::new(None),
+            user_profile: Arc::new(RwLock::new(None)),
+            last_profile_fetch: Mutex::new(None),
+            session_notifier,
+            encryption_keys: RwLock::new(HashMap::new()),
             notifier,
         }
     }
 
     pub fn init(&self) {
         if let Ok(session) = self.get_session() {
+            self.restore_encryption_key(&session.user_id);
             self.notifier.notify_login(&session.token);
         }
     }
@@ -88,16 +142,29 @@ impl UserSession {
         self.database.get_pool(&user_id)
     }
 
+    /// Same as db_pool, but returns the database of the user that has a session
+    /// on this device, e.g. to merge the data of the current user into it.
+    pub fn db_pool_of(&self, user_id: &str) -> Result<Arc<ConnectionPool>, FlowyError> {
+        let session = self.session_of(user_id)?;
+        self.database.get_pool(&session.user_id)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_in(&self, params: SignInParams) -> Result<UserProfile, FlowyError> {
+        let _ = params.validate()?;
         if self.is_login(&params.email) {
+            let user_id = self.get_session()?.user_id;
+            let _ = self.unlock_encryption_key(&user_id, &params.password)?;
             self.user_profile().await
         } else {
+            let password = params.password.clone();
             let resp = self.server.sign_in(params).await?;
             let session: Session = resp.clone().into();
+            let _ = self.unlock_encryption_key(&session.user_id, &password)?;
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
             let user_profile: UserProfile = user_table.into();
+            *self.user_profile.write() = Some(user_profile.clone());
             self.notifier.notify_login(&user_profile.token);
             Ok(user_profile)
         }
@@ -105,14 +172,52 @@ impl UserSession {
 
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_up(&self, params: SignUpParams) -> Result<UserProfile, FlowyError> {
+        let _ = params.validate()?;
         if self.is_login(&params.email) {
+            let user_id = self.get_session()?.user_id;
+            let _ = self.unlock_encryption_key(&user_id, &params.password)?;
             self.user_profile().await
         } else {
+            let password = params.password.clone();
             let resp = self.server.sign_up(params).await?;
             let session: Session = resp.clone().into();
+            let _ = self.unlock_encryption_key(&session.user_id, &password)?;
             let _ = self.set_session(Some(session))?;
             let user_table = self.save_user(resp.into()).await?;
             let user_profile: UserProfile = user_table.into();
+            *self.user_profile.write() = Some(user_profile.clone());
+            let (ret, mut tx) = mpsc::channel(1);
+            self.notifier.notify_sign_up(ret, &user_profile);
+
+            let _ = tx.recv().await;
+            Ok(user_profile)
+        }
+    }
+
+    /// Sign up without a server round-trip, the user id and token are generated
+    /// locally. It's useful for trying the app without a backend.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn sign_up_local(&self, params: SignUpParams) -> Result<UserProfile, FlowyError> {
+        let _ = params.validate()?;
+        if self.is_login(&params.email) {
+            let user_id = self.get_session()?.user_id;
+            let _ = self.unlock_encryption_key(&user_id, &params.password)?;
+            self.user_profile().await
+        } else {
+            let user_id = uuid_string();
+            let _ = self.unlock_encryption_key(&user_id, &params.password)?;
+            let resp = SignUpResponse {
+                user_id: user_id.clone(),
+                name: params.name,
+                email: params.email,
+                token: user_id,
+                expires_at: 0,
+            };
+            let session: Session = resp.clone().into();
+            let _ = self.set_session(Some(session))?;
+            let user_table = self.save_user(resp.into()).await?;
+            let user_profile: UserProfile = user_table.into();
+            *self.user_profile.write() = Some(user_profile.clone());
             let (ret, mut tx) = mpsc::channel(1);
             self.notifier.notify_sign_up(ret, &user_profile);
 
@@ -123,53 +228,181 @@ impl UserSession {
 
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn sign_out(&self) -> Result<(), FlowyError> {
-        let session = self.get_session()?;
-        let _ =
-            diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*(self.db_connection()?))?;
+        // An expired session can still be signed out.
+        let session = self.read_session()?;
+        let conn = self.database.get_connection(&session.user_id)?;
+        let _ = diesel::delete(dsl::user_table.filter(dsl::id.eq(&session.user_id))).execute(&*conn)?;
         let _ = self.database.close_user_db(&session.user_id)?;
         let _ = self.set_session(None)?;
+        self.lock_encryption_key(&session.user_id);
         self.notifier.notify_logout(&session.token);
         let _ = self.sign_out_on_server(&session.token).await?;
 
         Ok(())
     }
 
+    /// Delete the account on the server after it verified the password, then
+    /// remove the local data of the user. If removing the local data fails,
+    /// calling it again only retries the removal because the server already
+    /// deleted the account.
+    #[tracing::instrument(level = "debug", skip(self, password), err)]
+    pub async fn delete_account(&self, password: &str) -> Result<(), FlowyError> {
+        let session = self.get_session()?;
+        let deleted_key = self.deleted_account_key(&session.user_id);
+        if !KV::get_bool(&deleted_key).unwrap_or(false) {
+            let _ = UserPassword::parse(password.to_owned())?;
+            let params = DeleteAccountParams::new(password);
+            let _ = self.server.delete_account(&session.token, params).await?;
+            KV::set_bool(&deleted_key, true);
+        }
+
+        let _ = self.database.delete_user_db(&session.user_id)?;
+        let _ = self.set_session(None)?;
+        self.lock_encryption_key(&session.user_id);
+        let _ = KV::remove(&self.wrapped_encryption_key_key(&session.user_id));
+        let _ = KV::remove(&deleted_key);
+        self.notifier.notify_logout(&session.token);
+        Ok(())
+    }
+
+    /// The password and the email can't be updated here, use `change_password`
+    /// and `request_email_change` instead.
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn update_user(&self, params: UpdateUserParams) -> Result<(), FlowyError> {
+        if params.password.is_some() {
+            return Err(FlowyError::password_change_requires_old_password());
+        }
+        if params.email.is_some() {
+            return Err(FlowyError::email_change_requires_verification());
+        }
         let session = self.get_session()?;
         let changeset = UserTableChangeset::new(params.clone());
         diesel_update_table!(user_table, changeset, &*self.db_connection()?);
+        *self.user_profile.write() = None;
 
-        let _ = self.update_user_on_server(&session.token, params).await?;
+        // Notify with the updated profile so that the UI can refresh immediately, e.g.
+        // the new icon.
+        let user_profile: UserProfile = dsl::user_table
+            .filter(user_table::id.eq(&session.user_id))
+            .first::<UserTable>(&*(self.db_connection()?))?
+            .into();
+        dart_notify(&session.user_id, UserNotification::UserProfileUpdated)
+            .payload(user_profile)
+            .send();
+
+        let _ = self
+            .update_user_on_server(&session.user_id, &session.token, params)
+            .await?;
+        Ok(())
+    }
+
+    /// Change the password after the server verified the old one. Nothing is
+    /// changed if the old password doesn't match, the error's code is
+    /// `ErrorCode::PasswordNotMatch` then. The encryption key must be unlocked,
+    /// e.g. by signing in after a restart, because it's wrapped by the new
+    /// password again.
+    #[tracing::instrument(level = "debug", skip(self, old_password, new_password), err)]
+    pub async fn change_password(&self, old_password: &str, new_password: &str) -> Result<(), FlowyError> {
+        let params = ChangePasswordParams::new(old_password, new_password);
+        let _ = params.validate()?;
+        let session = self.get_session()?;
+        // Otherwise the key stays wrapped by the old password and the next sign-in
+        // with the new one can't unlock it.
+        let key = match self.encryption_key() {
+            None => {
+                return Err(
+                    FlowyError::unauthorized().context("The encryption key is locked, sign in again to unlock it")
+                )
+            },
+            Some(key) => key,
+        };
+        match self.server.change_password(&session.token, params).await {
+            Ok(_) => {
+                // The documents keep their key, only the wrapping is changed.
+                let _ = self.save_wrapped_encryption_key(&session.user_id, &key, new_password)?;
+                Ok(())
+            },
+            Err(e) if e.code == ErrorCode::PasswordNotMatch.value() => Err(FlowyError::password_not_match()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Ask the server to send a verification code to the new email. Nothing is
+    /// changed locally until the code is confirmed by `confirm_email_change`.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn request_email_change(&self, new_email: &str) -> Result<(), FlowyError> {
+        let params = RequestEmailChangeParams::new(new_email);
+        let _ = params.validate()?;
+        let session = self.get_session()?;
+        self.server.request_email_change(&session.token, params).await
+    }
+
+    /// Apply the requested email once the server verified the code, the error's
+    /// code is `ErrorCode::EmailChangeCodeInvalid` or
+    /// `ErrorCode::EmailChangeCodeExpired` if it didn't.
+    #[tracing::instrument(level = "debug", skip(self, code), err)]
+    pub async fn confirm_email_change(&self, code: &str) -> Result<(), FlowyError> {
+        if code.trim().is_empty() {
+            return Err(FlowyError::email_change_code_invalid());
+        }
+        let mut session = self.get_session()?;
+        let params = ConfirmEmailChangeParams::new(code);
+        let response = self.server.confirm_email_change(&session.token, params).await?;
+
+        let changeset = UserTableChangeset {
+            id: session.user_id.clone(),
+            email: Some(response.email.clone()),
+            ..Default::default()
+        };
+        diesel_update_table!(user_table, changeset, &*self.db_connection()?);
+        session.email = response.email;
+        let _ = self.set_session(Some(session.clone()))?;
+        *self.user_profile.write() = None;
+
+        let user_profile: UserProfile = dsl::user_table
+            .filter(user_table::id.eq(&session.user_id))
+            .first::<UserTable>(&*(self.db_connection()?))?
+            .into();
+        dart_notify(&session.user_id, UserNotification::UserProfileUpdated)
+            .payload(user_profile)
+            .send();
         Ok(())
     }
 
     pub async fn init_user(&self) -> Result<(), FlowyError> { Ok(()) }
 
     pub async fn check_user(&self) -> Result<UserProfile, FlowyError> {
-        let (user_id, token) = self.get_session()?.into_part();
-
+        let session = self.get_session()?;
         let user = dsl::user_table
-            .filter(user_table::id.eq(&user_id))
+            .filter(user_table::id.eq(&session.user_id))
             .first::<UserTable>(&*(self.db_connection()?))?;
 
-        let _ = self.read_user_profile_on_server(&token)?;
+        let (user_id, token) = self.reconcile_session_email(session, &user)?.into_part();
+        let _ = self.read_user_profile_on_server(&user_id, &token)?;
         Ok(user.into())
     }
 
     pub async fn user_profile(&self) -> Result<UserProfile, FlowyError> {
         let (user_id, token) = self.get_session()?.into_part();
+        if let Some(user_profile) = self.user_profile.read().clone() {
+            if user_profile.id == user_id {
+                return Ok(user_profile);
+            }
+        }
+
         let user = dsl::user_table
             .filter(user_table::id.eq(&user_id))
             .first::<UserTable>(&*(self.db_connection()?))?;
 
-        let _ = self.read_user_profile_on_server(&token)?;
-        Ok(user.into())
+        let _ = self.read_user_profile_on_server(&user_id, &token)?;
+        let user_profile: UserProfile = user.into();
+        *self.user_profile.write() = Some(user_profile.clone());
+        Ok(user_profile)
     }
 
     pub fn user_dir(&self) -> Result<String, FlowyError> {
         let session = self.get_session()?;
-        Ok(format!("{}/{}", self.config.root_dir, session.user_id))
+        Ok(format!("{}/{}", self.config.root_dir.read(), session.user_id))
     }
 
     pub fn user_id(&self) -> Result<String, FlowyError> { Ok(self.get_session()?.user_id) }
@@ -177,58 +410,253 @@ impl UserSession {
     pub fn user_name(&self) -> Result<String, FlowyError> { Ok(self.get_session()?.name) }
 
     pub fn token(&self) -> Result<String, FlowyError> { Ok(self.get_session()?.token) }
+
+    pub fn token_of(&self, user_id: &str) -> Result<String, FlowyError> {
+        let session = self.session_of(user_id)?;
+        if session.is_expired() {
+            return Err(FlowyError::unauthorized().context(format!("Session of user: {} is expired", user_id)));
+        }
+        Ok(session.token)
+    }
+
+    /// Returns the key that is used to encrypt the documents at rest. It's
+    /// unlocked by the password when the user signs in and never written to
+    /// disk, so it's `None` after a restart until the user signs in again.
+    pub fn encryption_key(&self) -> Option<Vec<u8>> { self.config.encryption_key.read().clone() }
+
+    /// Replace the encryption key, e.g. `None` to stop encrypting the new
+    /// documents. The documents that are already encrypted can only be read
+    /// with the key they were saved with.
+    pub fn set_encryption_key(&self, key: Option<Vec<u8>>) { *self.config.encryption_key.write() = key; }
+
+    /// Returns true if the current session's token has passed its expiry, so
+    /// the user should be asked to sign in again. Returns false if there is
+    /// no session.
+    pub fn is_session_expired(&self) -> bool {
+        match self.read_session() {
+            Ok(session) => session.is_expired(),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns true if there is a session that isn't expired. Unlike
+    /// `is_login`, it doesn't care which user the session belongs to.
+    pub fn has_active_session(&self) -> bool { self.get_session().is_ok() }
+
+    /// Retry the server-side sign out of the tokens that were only cleared
+    /// locally because the server was unreachable. Call it on startup or when
+    /// the network reconnects.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn flush_pending_sign_outs(&self) {
+        let tokens = self.read_pending_sign_outs();
+        if tokens.is_empty() {
+            return;
+        }
+
+        let mut pending = vec![];
+        for token in tokens {
+            match self.server.sign_out(&token).await {
+                Ok(_) => {},
+                Err(e) => {
+                    log::error!("Retry sign out failed: {:?}", e);
+                    pending.push(token);
+                },
+            }
+        }
+        self.write_pending_sign_outs(pending);
+    }
+
+    /// Move the databases of all the signed in users from the current root
+    /// directory to `new_root`, then reopen the active user's database there.
+    /// Nothing is moved if any of the user directories already exists in
+    /// `new_root`.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub fn relocate_root(&self, new_root: &str) -> Result<(), FlowyError> {
+        let old_root = self.config.root_dir.read().clone();
+        if old_root == new_root {
+            return Ok(());
+        }
+
+        let user_ids = self.read_session_user_ids();
+        let moves = user_ids
+            .iter()
+            .map(|user_id| (Path::new(&old_root).join(user_id), Path::new(new_root).join(user_id)))
+            .filter(|(from, _)| from.exists())
+            .collect::<Vec<(PathBuf, PathBuf)>>();
+        if let Some((_, to)) = moves.iter().find(|(_, to)| to.exists()) {
+            return Err(FlowyError::internal().context(format!("{:?} already contains data", to)));
+        }
+
+        for user_id in &user_ids {
+            let _ = self.database.close_user_db(user_id)?;
+        }
+        let _ = fs::create_dir_all(new_root).map_err(internal_error)?;
+
+        let mut moved: Vec<&(PathBuf, PathBuf)> = vec![];
+        for item in &moves {
+            let (from, to) = item;
+            if let Err(e) = move_dir(from, to) {
+                // Move back what was moved so that the old root is still complete.
+                for (from, to) in moved.into_iter().rev() {
+                    if let Err(e) = move_dir(to, from) {
+                        log::error!("Move {:?} back to {:?} failed: {:?}", to, from, e);
+                    }
+                }
+                if let Ok(session) = self.get_session() {
+                    let _ = self.database.get_pool(&session.user_id);
+                }
+                return Err(FlowyError::internal().context(e));
+            }
+            moved.push(item);
+        }
+
+        *self.config.root_dir.write() = new_root.to_owned();
+        self.database.set_db_dir(new_root);
+        if let Ok(session) = self.get_session() {
+            let _ = self.database.get_pool(&session.user_id)?;
+        }
+        Ok(())
+    }
+
+    /// Subscribe to the changes of the active session, `None` means the user
+    /// signed out.
+    pub fn subscribe_session_change(&self) -> broadcast::Receiver<Option<Session>> { self.session_notifier.subscribe() }
+
+    pub fn list_sessions(&self) -> Vec<Session> {
+        self.read_session_user_ids()
+            .iter()
+            .filter_map(|user_id| KV::get_str(&self.session_key(user_id)).and_then(|s| Session::try_from(s).ok()))
+            .collect::<Vec<Session>>()
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn switch_session(&self, user_id: &str) -> Result<UserProfile, FlowyError> {
+        let session = self.session_of(user_id)?;
+        if let Ok(current) = self.get_session() {
+            if current.user_id == session.user_id {
+                return self.user_profile().await;
+            }
+            let _ = self.database.close_user_db(&current.user_id)?;
+            self.notifier.notify_logout(&current.token);
+        }
+
+        // Reload the database connection of the user we are switching to.
+        let _ = self.database.get_pool(&session.user_id)?;
+        let _ = self.set_session(Some(session.clone()))?;
+        self.restore_encryption_key(&session.user_id);
+        self.notifier.notify_login(&session.token);
+        self.user_profile().await
+    }
 }
 
 impl UserSession {
-    fn read_user_profile_on_server(&self, token: &str) -> Result<(), FlowyError> {
+    // The spawned tasks are attributed to the user by the user_id, the token is
+    // kept out of the logs and the notifications are routed by the user_id too.
+    fn read_user_profile_on_server(&self, user_id: &str, token: &str) -> Result<(), FlowyError> {
+        if !self.begin_profile_fetch(token) {
+            tracing::trace!("The user profile was read within {:?}", PROFILE_FETCH_INTERVAL);
+            return Ok(());
+        }
+
         let server = self.server.clone();
         let token = token.to_owned();
-        tokio::spawn(async move {
+        let user_id = user_id.to_owned();
+        let cache = self.user_profile.clone();
+        let span = tracing::debug_span!("read_user_profile_on_server", user_id = %user_id);
+        let fut = async move {
             match server.get_user(&token).await {
                 Ok(profile) => {
-                    dart_notify(&token, UserNotification::UserProfileUpdated)
+                    // Only refresh the cache if it still belongs to the same user.
+                    {
+                        let mut cache = cache.write();
+                        if matches!(&*cache, Some(cached) if cached.id == profile.id) {
+                            *cache = Some(profile.clone());
+                        }
+                    }
+                    dart_notify(&user_id, UserNotification::UserProfileUpdated)
                         .payload(profile)
                         .send();
                 },
                 Err(e) => {
-                    dart_notify(&token, UserNotification::UserProfileUpdated)
+                    tracing::error!("Read user profile failed: {:?}", e);
+                    dart_notify(&user_id, UserNotification::UserProfileUpdated)
                         .error(e)
                         .send();
                 },
             }
-        });
+        };
+        tokio::spawn(fut.instrument(span));
         Ok(())
     }
 
-    async fn update_user_on_server(&self, token: &str, params: UpdateUserParams) -> Result<(), FlowyError> {
+    async fn update_user_on_server(
+        &self,
+        user_id: &str,
+        token: &str,
+        params: UpdateUserParams,
+    ) -> Result<(), FlowyError> {
         let server = self.server.clone();
         let token = token.to_owned();
-        let _ = tokio::spawn(async move {
+        let span = tracing::debug_span!("update_user_on_server", user_id = %user_id);
+        let fut = async move {
             match server.update_user(&token, params).await {
                 Ok(_) => {},
                 Err(e) => {
                     // TODO: retry?
-                    log::error!("update user profile failed: {:?}", e);
+                    tracing::error!("update user profile failed: {:?}", e);
                 },
             }
-        })
-        .await;
+        };
+        let _ = tokio::spawn(fut.instrument(span)).await;
         Ok(())
     }
 
     async fn sign_out_on_server(&self, token: &str) -> Result<(), FlowyError> {
         let server = self.server.clone();
-        let token = token.to_owned();
-        let _ = tokio::spawn(async move {
-            match server.sign_out(&token).await {
-                Ok(_) => {},
-                Err(e) => log::error!("Sign out failed: {:?}", e),
+        let cloned_token = token.to_owned();
+        let is_sign_out = tokio::spawn(async move {
+            match server.sign_out(&cloned_token).await {
+                Ok(_) => true,
+                Err(e) => {
+                    log::error!("Sign out failed: {:?}", e);
+                    false
+                },
             }
         })
-        .await;
+        .await
+        .unwrap_or(false);
+
+        // The token was only cleared locally, retry it in flush_pending_sign_outs.
+        if !is_sign_out {
+            self.add_pending_sign_out(token);
+        }
         Ok(())
     }
 
+    // The email of the user_table is updated after changing the email, but the
+    // session keeps the email that it signed in with. Take the stored email if
+    // they diverge, otherwise signing in with the new email is treated as another
+    // user.
+    fn reconcile_session_email(&self, session: Session, user: &UserTable) -> Result<Session, FlowyError> {
+        if session.email == user.email {
+            return Ok(session);
+        }
+
+        tracing::info!("Update the session email of user: {}", session.user_id);
+        let session = Session {
+            email: user.email.clone(),
+            ..session
+        };
+        let _ = self.set_session(Some(session.clone()))?;
+
+        let user_profile: UserProfile = user.clone().into();
+        *self.user_profile.write() = Some(user_profile.clone());
+        dart_notify(&session.user_id, UserNotification::UserProfileUpdated)
+            .payload(user_profile)
+            .send();
+        Ok(session)
+    }
+
     async fn save_user(&self, user: UserTable) -> Result<UserTable, FlowyError> {
         let conn = self.db_connection()?;
         let _ = diesel::insert_into(user_table::table)
@@ -240,22 +668,104 @@ impl UserSession {
     fn set_session(&self, session: Option<Session>) -> Result<(), FlowyError> {
         tracing::debug!("Set user session: {:?}", session);
         match &session {
-            None => KV::remove(&self.config.session_cache_key).map_err(|e| FlowyError::new(ErrorCode::Internal, &e))?,
-            Some(session) => KV::set_str(&self.config.session_cache_key, session.clone().into()),
+            None => {
+                // Only the active session is removed, the others are kept for switching.
+                if let Some(user_id) = KV::get_str(&self.active_user_id_key()) {
+                    self.remove_session_user_id(&user_id);
+                    let _ = KV::remove(&self.session_key(&user_id))
+                        .map_err(|e| FlowyError::new(ErrorCode::Internal, &e))?;
+                }
+                let _ = KV::remove(&self.active_user_id_key()).map_err(|e| FlowyError::new(ErrorCode::Internal, &e))?;
+            },
+            Some(session) => {
+                KV::set_str(&self.session_key(&session.user_id), session.clone().into());
+                KV::set_str(&self.active_user_id_key(), session.user_id.clone());
+                self.add_session_user_id(&session.user_id);
+            },
         }
-        *self.session.write() = session;
+        if session.is_none() {
+            *self.user_profile.write() = None;
+        }
+        *self.session.write() = session.clone();
+        let _ = self.session_notifier.send(session);
         Ok(())
     }
 
     fn get_session(&self) -> Result<Session, FlowyError> {
+        let session = self.read_session()?;
+        if session.is_expired() {
+            return Err(FlowyError::unauthorized().context(format!("Session of user: {} is expired", session.user_id)));
+        }
+        Ok(session)
+    }
+
+    // Returns false if the profile of the token was fetched within the
+    // PROFILE_FETCH_INTERVAL, the callers will receive the result of that fetch
+    // from the UserProfileUpdated notification.
+    fn begin_profile_fetch(&self, token: &str) -> bool {
+        let now = Instant::now();
+        let mut last_profile_fetch = self.last_profile_fetch.lock();
+        match &*last_profile_fetch {
+            Some((last_token, fetched_at))
+                if last_token == token && now.duration_since(*fetched_at) < PROFILE_FETCH_INTERVAL =>
+            {
+                false
+            },
+            _ => {
+                *last_profile_fetch = Some((token.to_owned(), now));
+                true
+            },
+        }
+    }
+
+    fn session_of(&self, user_id: &str) -> Result<Session, FlowyError> {
+        let session = KV::get_str(&self.session_key(user_id)).and_then(|s| self.parse_session(Some(user_id), s));
+        match session {
+            None => Err(FlowyError::user_not_exist().context(format!("No session for user: {}", user_id))),
+            Some(session) => Ok(session),
+        }
+    }
+
+    // The corrupted session is removed and treated as no session, otherwise the
+    // empty session would be used and the requests fail with the empty token.
+    fn parse_session(&self, user_id: Option<&str>, s: String) -> Option<Session> {
+        match Session::try_from(s) {
+            Ok(session) => Some(session),
+            Err(e) => {
+                log::error!("Deserialize string to Session failed: {:?}", e);
+                if let Some(user_id) = user_id {
+                    self.remove_session_user_id(user_id);
+                    let _ = KV::remove(&self.session_key(user_id));
+                    if KV::get_str(&self.active_user_id_key()).as_deref() == Some(user_id) {
+                        let _ = KV::remove(&self.active_user_id_key());
+                    }
+                }
+                None
+            },
+        }
+    }
+
+    fn read_session(&self) -> Result<Session, FlowyError> {
         let mut session = { (*self.session.read()).clone() };
         if session.is_none() {
-            match KV::get_str(&self.config.session_cache_key) {
-                None => {},
-                Some(s) => {
-                    session = Some(Session::from(s));
-                    let _ = self.set_session(session.clone())?;
+            let cache = match KV::get_str(&self.active_user_id_key()) {
+                Some(user_id) => KV::get_str(&self.session_key(&user_id)).map(|s| (Some(user_id), s)),
+                None => {
+                    // Migrate the session that was stored under the session_cache_key before
+                    // supporting multiple sessions.
+                    let legacy = KV::get_str(&self.config.session_cache_key);
+                    if legacy.is_some() {
+                        let _ = KV::remove(&self.config.session_cache_key);
+                    }
+                    legacy.map(|s| (None, s))
                 },
+            };
+
+            if let Some((user_id, s)) = cache {
+                session = self.parse_session(user_id.as_deref(), s);
+                if session.is_some() {
+                    let _ = self.set_session(session.clone())?;
+                }
             }
         }
 
@@ -265,6 +775,132 @@ impl UserSession {
         }
     }
 
+    fn session_key(&self, user_id: &str) -> String { format!("{}:{}", self.config.session_cache_key, user_id) }
+
+    fn active_user_id_key(&self) -> String { format!("{}:active_user_id", self.config.session_cache_key) }
+
+    fn session_user_ids_key(&self) -> String { format!("{}:user_ids", self.config.session_cache_key) }
+
+    fn pending_sign_outs_key(&self) -> String { format!("{}:pending_sign_outs", self.config.session_cache_key) }
+
+    fn deleted_account_key(&self, user_id: &str) -> String {
+        format!("{}:deleted_account:{}", self.config.session_cache_key, user_id)
+    }
+
+    fn wrapped_encryption_key_key(&self, user_id: &str) -> String {
+        format!("{}:wrapped_encryption_key:{}", self.config.session_cache_key, user_id)
+    }
+
+    // Installs the user's encryption key, it's kept in memory until the user
+    // signs out or the app exits. The key is rejected if the password can't
+    // unwrap it, the documents that are already encrypted can't be decrypted by
+    // the key of another password.
+    fn unlock_encryption_key(&self, user_id: &str, password: &str) -> Result<(), FlowyError> {
+        let key = match KV::get_str(&self.wrapped_encryption_key_key(user_id))
+            .and_then(|s| serde_json::from_str::<WrappedEncryptionKey>(&s).ok())
+        {
+            Some(wrapped_key) => match wrapped_key.unwrap(password, user_id) {
+                Some(key) => key,
+                None => {
+                    return Err(FlowyError::password_not_match().context("The password can't unlock the encryption key"))
+                },
+            },
+            None => {
+                // The documents of the users that signed in before the key was
+                // wrapped are encrypted by the key of the password.
+                let key = derive_encryption_key(password, user_id);
+                let _ = self.save_wrapped_encryption_key(user_id, &key, password)?;
+                key
+            },
+        };
+
+        self.encryption_keys.write().insert(user_id.to_owned(), key.clone());
+        self.set_encryption_key(Some(key));
+        Ok(())
+    }
+
+    fn save_wrapped_encryption_key(&self, user_id: &str, key: &[u8], password: &str) -> Result<(), FlowyError> {
+        let wrapped_key = WrappedEncryptionKey::wrap(key, password, user_id)?;
+        let s = serde_json::to_string(&wrapped_key)
+            .map_err(|e| FlowyError::internal().context(format!("Serialize wrapped encryption key failed: {}", e)))?;
+        KV::set_str(&self.wrapped_encryption_key_key(user_id), s);
+        Ok(())
+    }
+
+    fn restore_encryption_key(&self, user_id: &str) {
+        let key = self.encryption_keys.read().get(user_id).cloned();
+        if key.is_none() {
+            log::warn!(
+                "The encryption key of user: {} is missing, sign in again to unlock it",
+                user_id
+            );
+        }
+        self.set_encryption_key(key);
+    }
+
+    // The wrapped key is kept, so signing in with the same password unlocks it
+    // again.
+    fn lock_encryption_key(&self, user_id: &str) {
+        self.encryption_keys.write().remove(user_id);
+        self.set_encryption_key(None);
+    }
+
+    fn read_session_user_ids(&self) -> Vec<String> {
+        match KV::get_str(&self.session_user_ids_key()) {
+            None => vec![],
+            Some(s) => serde_json::from_str(&s).unwrap_or_else(|e| {
+                log::error!("Deserialize session user ids failed: {:?}", e);
+                vec![]
+            }),
+        }
+    }
+
+    fn add_session_user_id(&self, user_id: &str) {
+        let mut user_ids = self.read_session_user_ids();
+        if !user_ids.iter().any(|id| id == user_id) {
+            user_ids.push(user_id.to_owned());
+            self.write_session_user_ids(user_ids);
+        }
+    }
+
+    fn remove_session_user_id(&self, user_id: &str) {
+        let mut user_ids = self.read_session_user_ids();
+        user_ids.retain(|id| id != user_id);
+        self.write_session_user_ids(user_ids);
+    }
+
+    fn write_session_user_ids(&self, user_ids: Vec<String>) {
+        match serde_json::to_string(&user_ids) {
+            Ok(s) => KV::set_str(&self.session_user_ids_key(), s),
+            Err(e) => log::error!("Serialize session user ids failed: {:?}", e),
+        }
+    }
+
+    fn read_pending_sign_outs(&self) -> Vec<String> {
+        match KV::get_str(&self.pending_sign_outs_key()) {
+            None => vec![],
+            Some(s) => serde_json::from_str(&s).unwrap_or_else(|e| {
+                log::error!("Deserialize pending sign outs failed: {:?}", e);
+                vec![]
+            }),
+        }
+    }
+
+    fn add_pending_sign_out(&self, token: &str) {
+        let mut tokens = self.read_pending_sign_outs();
+        if !tokens.iter().any(|t| t == token) {
+            tokens.push(token.to_owned());
+            self.write_pending_sign_outs(tokens);
+        }
+    }
+
+    fn write_pending_sign_outs(&self, tokens: Vec<String>) {
+        match serde_json::to_string(&tokens) {
+            Ok(s) => KV::set_str(&self.pending_sign_outs_key(), s),
+            Err(e) => log::error!("Serialize pending sign outs failed: {:?}", e),
+        }
+    }
+
     fn is_login(&self, email: &str) -> bool {
         match self.get_session() {
             Ok(session) => session.email == email,
@@ -273,6 +909,36 @@ impl UserSession {
     }
 }
 
+// fs::rename can't move across file systems, e.g. to an external drive, so fall
+// back to copying.
+fn move_dir(from: &Path, to: &Path) -> io::Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    match copy_dir(from, to) {
+        Ok(_) => fs::remove_dir_all(from),
+        Err(e) => {
+            let _ = fs::remove_dir_all(to);
+            Err(e)
+        },
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            copy_dir(&path, &to.join(entry.file_name()))?;
+        } else {
+            fs::copy(&path, to.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
 pub async fn update_user(
     _server: Server,
     pool: Arc<ConnectionPool>,
@@ -289,11 +955,14 @@ impl UserDatabaseConnection for UserSession {
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
-struct Session {
-    user_id: String,
-    token: String,
-    email: String,
-    name: String,
+pub struct Session {
+    pub user_id: String,
+    pub token: String,
+    pub email: String,
+    pub name: String,
+    // The unix timestamp in seconds, 0 means the session never expires.
+    #[serde(default)]
+    pub expires_at: i64,
 }
 
 impl std::convert::From<SignInResponse> for Session {
@@ -303,6 +972,7 @@ impl std::convert::From<SignInResponse> for Session {
             token: resp.token,
             email: resp.email,
             name: resp.name,
+            expires_at: resp.expires_at,
         }
     }
 }
@@ -314,24 +984,21 @@ impl std::convert::From<SignUpResponse> for Session {
             token: resp.token,
             email: resp.email,
             name: resp.name,
+            expires_at: resp.expires_at,
         }
     }
 }
 
 impl Session {
     pub fn into_part(self) -> (String, String) { (self.user_id, self.token) }
+
+    pub fn is_expired(&self) -> bool { self.expires_at > 0 && self.expires_at <= timestamp() }
 }
 
-impl std::convert::From<String> for Session {
-    fn from(s: String) -> Self {
-        match serde_json::from_str(&s) {
-            Ok(s) => s,
-            Err(e) => {
-                log::error!("Deserialize string to Session failed: {:?}", e);
-                Session::default()
-            },
-        }
-    }
+impl std::convert::TryFrom<String> for Session {
+    type Error = serde_json::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> { serde_json::from_str(&s) }
 }
 impl std::convert::From<Session> for String {
     fn from(session: Session) -> Self {
@@ -344,3 +1011,46 @@ impl std::convert::From<Session> for String {
         }
     }
 }
+
+// The encryption key encrypted by AES-256-GCM with the key of the password. The
+// authentication tag fails to verify if the password is wrong, so the wrong key
+// is never returned.
+#[derive(Serialize, Deserialize)]
+struct WrappedEncryptionKey {
+    nonce: Vec<u8>,
+    wrapped_key: Vec<u8>,
+}
+
+impl WrappedEncryptionKey {
+    fn wrap(key: &[u8], password: &str, user_id: &str) -> Result<Self, FlowyError> {
+        let password_key = derive_encryption_key(password, user_id);
+        let cipher = Aes256Gcm::new(Key::from_slice(&password_key));
+        let nonce = rand::random::<[u8; WRAP_NONCE_LEN]>();
+        let wrapped_key = cipher
+            .encrypt(Nonce::from_slice(&nonce), key)
+            .map_err(|e| FlowyError::internal().context(format!("Wrap encryption key failed: {}", e)))?;
+        Ok(Self {
+            nonce: nonce.to_vec(),
+            wrapped_key,
+        })
+    }
+
+    fn unwrap(&self, password: &str, user_id: &str) -> Option<Vec<u8>> {
+        if self.nonce.len() != WRAP_NONCE_LEN {
+            return None;
+        }
+        let password_key = derive_encryption_key(password, user_id);
+        let cipher = Aes256Gcm::new(Key::from_slice(&password_key));
+        cipher
+            .decrypt(Nonce::from_slice(&self.nonce), self.wrapped_key.as_slice())
+            .ok()
+    }
+}
+
+// The user id is used as the salt, so the same password derives different keys
+// for different users.
+fn derive_encryption_key(password: &str, user_id: &str) -> Vec<u8> {
+    let mut key = vec![0; ENCRYPTION_KEY_LEN];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), user_id.as_bytes(), ENCRYPTION_KEY_ROUNDS, &mut key);
+    key
+}
@@ -10,6 +10,7 @@ pub struct UserTable {
     pub(crate) token: String,
     pub(crate) email: String,
     pub(crate) workspace: String, // deprecated
+    pub(crate) icon_url: String,
 }
 
 impl UserTable {
@@ -20,6 +21,7 @@ impl UserTable {
             email,
             token,
             workspace: "".to_owned(),
+            icon_url: "".to_owned(),
         }
     }
 
@@ -44,6 +46,7 @@ impl std::convert::From<UserTable> for UserProfile {
             email: table.email,
             name: table.name,
             token: table.token,
+            icon_url: table.icon_url,
         }
     }
 }
@@ -55,6 +58,7 @@ pub struct UserTableChangeset {
     pub workspace: Option<String>, // deprecated
     pub name: Option<String>,
     pub email: Option<String>,
+    pub icon_url: Option<String>,
 }
 
 impl UserTableChangeset {
@@ -64,6 +68,7 @@ impl UserTableChangeset {
             workspace: None,
             name: params.name,
             email: params.email,
+            icon_url: params.icon_url,
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::helper::*;
+use flowy_database::kv::KV;
 use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
 use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
 
@@ -106,3 +107,145 @@ async fn sign_in_with_invalid_password() {
             .assert_error();
     }
 }
+
+#[tokio::test]
+async fn switch_session_between_two_users() {
+    let test = FlowySDKTest::setup();
+    let first = test.sign_up().await.user_profile;
+    let second = test.sign_up().await.user_profile;
+    assert_eq!(test.user_session.user_id().unwrap(), second.id);
+
+    let user_ids = test
+        .user_session
+        .list_sessions()
+        .into_iter()
+        .map(|session| session.user_id)
+        .collect::<Vec<String>>();
+    assert!(user_ids.contains(&first.id));
+    assert!(user_ids.contains(&second.id));
+
+    let user_profile = test.user_session.switch_session(&first.id).await.unwrap();
+    assert_eq!(user_profile.id, first.id);
+    assert_eq!(test.user_session.user_id().unwrap(), first.id);
+}
+
+#[tokio::test]
+async fn sign_in_with_wrong_password_keeps_encryption_key() {
+    let test = FlowySDKTest::setup();
+    let sign_up_context = test.sign_up().await;
+    let key = test.user_session.encryption_key();
+    assert!(key.is_some());
+
+    let request = SignInRequest {
+        email: sign_up_context.user_profile.email.clone(),
+        password: "HelloWorld!456".to_string(),
+        name: "".to_string(),
+    };
+    let error = UserModuleEventBuilder::new(test.clone())
+        .event(SignIn)
+        .request(request)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch.value());
+    assert_eq!(test.user_session.encryption_key(), key);
+}
+
+#[tokio::test]
+async fn sign_in_after_change_password_unlocks_same_key() {
+    let test = FlowySDKTest::setup();
+    let sign_up_context = test.sign_up().await;
+    let key = test.user_session.encryption_key();
+    let new_password = "HelloWorld!456".to_string();
+    test.user_session
+        .change_password(&sign_up_context.password, &new_password)
+        .await
+        .unwrap();
+
+    let request = SignInRequest {
+        email: sign_up_context.user_profile.email.clone(),
+        password: new_password,
+        name: "".to_string(),
+    };
+    let _ = UserModuleEventBuilder::new(test.clone())
+        .event(SignIn)
+        .request(request)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(test.user_session.encryption_key(), key);
+}
+
+#[tokio::test]
+async fn sign_up_session_is_not_expired() {
+    let test = FlowySDKTest::setup();
+    let _ = test.sign_up().await;
+    assert!(!test.user_session.is_session_expired());
+    assert!(test.user_session.token().is_ok());
+}
+
+#[tokio::test]
+async fn sign_out_has_no_active_session() {
+    let test = FlowySDKTest::setup();
+    assert!(!test.user_session.has_active_session());
+
+    let _ = test.sign_up().await;
+    assert!(test.user_session.has_active_session());
+
+    let _ = test.user_session.sign_out().await;
+    assert!(!test.user_session.has_active_session());
+}
+
+#[tokio::test]
+async fn sign_up_notify_session_change() {
+    let test = FlowySDKTest::setup();
+    let mut rx = test.user_session.subscribe_session_change();
+    let user_profile = test.sign_up().await.user_profile;
+    let session = rx.recv().await.unwrap().unwrap();
+    assert_eq!(session.user_id, user_profile.id);
+}
+
+#[tokio::test]
+async fn sign_up_local_success() {
+    let test = FlowySDKTest::setup();
+    let params = SignUpParams {
+        email: random_email(),
+        name: valid_name(),
+        password: login_password(),
+    };
+    let user_profile = test.user_session.sign_up_local(params).await.unwrap();
+    assert_eq!(test.user_session.user_id().unwrap(), user_profile.id);
+    assert_eq!(test.user_session.user_profile().await.unwrap(), user_profile);
+}
+
+#[tokio::test]
+async fn sign_in_params_with_invalid_email() {
+    let test = FlowySDKTest::setup();
+    for email in invalid_email_test_case() {
+        let params = SignInParams {
+            email,
+            password: login_password(),
+            name: valid_name(),
+        };
+        let error = test.user_session.sign_in(params).await.unwrap_err();
+        assert_eq!(error.code, ErrorCode::EmailFormatInvalid.value());
+    }
+}
+
+#[tokio::test]
+async fn corrupted_session_is_treated_as_no_session() {
+    let test = FlowySDKTest::setup();
+    let user = test.init_user().await;
+    let session_key = format!("{}:{}", test.config().session_cache_key(), user.id);
+    KV::set_str(&session_key, "{corrupted".to_owned());
+
+    let restarted = test.restart();
+    let error = UserModuleEventBuilder::new(restarted.clone())
+        .event(GetUserProfile)
+        .async_send()
+        .await
+        .error();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized.value());
+    assert!(KV::get_str(&session_key).is_none());
+    assert!(!restarted.user_session.has_active_session());
+}
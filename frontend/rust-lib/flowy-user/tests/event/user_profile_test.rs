@@ -1,8 +1,20 @@
 use crate::helper::*;
-use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
-use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*};
+use flowy_database::{prelude::RunQueryDsl, sql_query};
+use flowy_test::{
+    event_builder::UserModuleEventBuilder,
+    helper::{parse_notification, subscribe_notifications, wait_for_notification},
+    FlowySDKTest,
+};
+use flowy_user::{errors::ErrorCode, event::UserEvent::*, prelude::*, protobuf::UserNotification};
 use lib_infra::uuid_string;
 use serial_test::*;
+use std::sync::{Arc, Mutex};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, Layer, Registry};
 
 #[tokio::test]
 async fn user_profile_get_failed() {
@@ -50,22 +62,45 @@ async fn user_update_with_name() {
 
 #[tokio::test]
 #[serial]
-async fn user_update_with_email() {
+async fn user_update_with_icon_url() {
     let sdk = FlowySDKTest::setup();
     let user = sdk.init_user().await;
-    let new_email = format!("{}@gmail.com", uuid_string());
-    let request = UpdateUserRequest::new(&user.id).email(&new_email);
+    let new_icon_url = "https://appflowy.io/icon.png".to_owned();
+    let request = UpdateUserRequest::new(&user.id).icon_url(&new_icon_url);
     let _ = UserModuleEventBuilder::new(sdk.clone())
         .event(UpdateUser)
         .request(request)
         .sync_send();
+
     let user_profile = UserModuleEventBuilder::new(sdk.clone())
         .event(GetUserProfile)
         .assert_error()
         .sync_send()
         .parse::<UserProfile>();
 
-    assert_eq!(user_profile.email, new_email,);
+    assert_eq!(user_profile.icon_url, new_icon_url);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_update_with_email() {
+    let sdk = FlowySDKTest::setup();
+    let user = sdk.init_user().await;
+    let new_email = format!("{}@gmail.com", uuid_string());
+    let request = UpdateUserRequest::new(&user.id).email(&new_email);
+    let error = UserModuleEventBuilder::new(sdk.clone())
+        .event(UpdateUser)
+        .request(request)
+        .sync_send()
+        .error();
+    assert_eq!(error.code, ErrorCode::EmailChangeRequiresVerification.value());
+
+    let user_profile = UserModuleEventBuilder::new(sdk.clone())
+        .event(GetUserProfile)
+        .assert_error()
+        .sync_send()
+        .parse::<UserProfile>();
+    assert_eq!(user_profile.email, user.email);
 }
 
 #[tokio::test]
@@ -76,11 +111,12 @@ async fn user_update_with_password() {
     let new_password = "H123world!".to_owned();
     let request = UpdateUserRequest::new(&user.id).password(&new_password);
 
-    let _ = UserModuleEventBuilder::new(sdk.clone())
+    let error = UserModuleEventBuilder::new(sdk.clone())
         .event(UpdateUser)
         .request(request)
         .sync_send()
-        .assert_success();
+        .error();
+    assert_eq!(error.code, ErrorCode::PasswordChangeRequiresOldPassword.value());
 }
 
 #[tokio::test]
@@ -130,3 +166,252 @@ async fn user_update_with_invalid_name() {
         .sync_send()
         .assert_error();
 }
+
+#[tokio::test]
+#[serial]
+async fn user_change_password() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+    test.user_session
+        .change_password(&login_password(), "Hello123!")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn user_change_password_with_locked_encryption_key() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+    let key = test.user_session.encryption_key();
+    test.user_session.set_encryption_key(None);
+    let error = test
+        .user_session
+        .change_password(&login_password(), "Hello123!")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized.value());
+
+    // The password is kept, so the key can still be unlocked by it
+    test.user_session.set_encryption_key(key);
+    test.user_session
+        .change_password(&login_password(), "Hello123!")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn user_change_password_with_invalid_password() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+    for password in invalid_password_test_case() {
+        let result = test.user_session.change_password(&login_password(), &password).await;
+        assert!(result.is_err());
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn user_change_email() {
+    let test = FlowySDKTest::setup();
+    let user_profile = test.init_user().await;
+    let new_email = format!("{}@appflowy.io", uuid_string());
+    test.user_session.request_email_change(&new_email).await.unwrap();
+    assert_eq!(
+        test.user_session.user_profile().await.unwrap().email,
+        user_profile.email
+    );
+
+    let error = test.user_session.confirm_email_change("000000").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::EmailChangeCodeInvalid.value());
+
+    test.user_session
+        .confirm_email_change(MOCK_EMAIL_CHANGE_CODE)
+        .await
+        .unwrap();
+    assert_eq!(test.user_session.user_profile().await.unwrap().email, new_email);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_confirm_email_change_without_request() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+    let error = test
+        .user_session
+        .confirm_email_change(MOCK_EMAIL_CHANGE_CODE)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::EmailChangeCodeInvalid.value());
+}
+
+#[tokio::test]
+#[serial]
+async fn user_request_email_change_with_invalid_email() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+    for email in invalid_email_test_case() {
+        assert!(test.user_session.request_email_change(&email).await.is_err());
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn user_delete_account() {
+    let test = FlowySDKTest::setup();
+    let _ = test.init_user().await;
+    let user_dir = test.user_session.user_dir().unwrap();
+
+    test.user_session.delete_account(&login_password()).await.unwrap();
+    assert!(!test.user_session.has_active_session());
+    assert!(!std::path::Path::new(&user_dir).exists());
+}
+
+#[tokio::test]
+#[serial]
+async fn user_relocate_root() {
+    let test = FlowySDKTest::setup();
+    let user_profile = test.init_user().await;
+    let old_user_dir = test.user_session.user_dir().unwrap();
+
+    let new_root = format!("{}/{}", flowy_test::helper::root_dir(), uuid_string());
+    test.user_session.relocate_root(&new_root).unwrap();
+
+    let user_dir = test.user_session.user_dir().unwrap();
+    assert_eq!(user_dir, format!("{}/{}", new_root, user_profile.id));
+    assert!(std::path::Path::new(&user_dir).exists());
+    assert!(!std::path::Path::new(&old_user_dir).exists());
+    assert_eq!(test.user_session.user_profile().await.unwrap().id, user_profile.id);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_relocate_root_to_occupied_dir() {
+    let test = FlowySDKTest::setup();
+    let user_profile = test.init_user().await;
+    let old_user_dir = test.user_session.user_dir().unwrap();
+
+    let new_root = format!("{}/{}", flowy_test::helper::root_dir(), uuid_string());
+    std::fs::create_dir_all(format!("{}/{}", new_root, user_profile.id)).unwrap();
+    assert!(test.user_session.relocate_root(&new_root).is_err());
+    assert_eq!(test.user_session.user_dir().unwrap(), old_user_dir);
+    assert!(std::path::Path::new(&old_user_dir).exists());
+}
+
+#[tokio::test]
+#[serial]
+async fn user_profile_get_reads_server_once_within_interval() {
+    let test = FlowySDKTest::setup();
+    let user = test.init_user().await;
+    let mut rx = subscribe_notifications();
+    for _ in 0..3 {
+        let _ = UserModuleEventBuilder::new(test.clone())
+            .event(GetUserProfile)
+            .async_send()
+            .await;
+    }
+
+    let ty = UserNotification::UserProfileUpdated as i32;
+    assert!(wait_for_notification(&mut rx, ty, |subject| subject.id == user.id)
+        .await
+        .is_some());
+    assert!(wait_for_notification(&mut rx, ty, |subject| subject.id == user.id)
+        .await
+        .is_none());
+}
+
+// Records the name and the fields of the spans that are created.
+struct SpanRecorder(Arc<Mutex<Vec<(String, Vec<(String, String)>)>>>);
+
+impl<S: Subscriber> Layer<S> for SpanRecorder {
+    fn new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = FieldRecorder(vec![]);
+        attrs.record(&mut fields);
+        self.0
+            .lock()
+            .unwrap()
+            .push((attrs.metadata().name().to_owned(), fields.0));
+    }
+}
+
+struct FieldRecorder(Vec<(String, String)>);
+
+impl Visit for FieldRecorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push((field.name().to_owned(), format!("{:?}", value)));
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn user_profile_server_read_is_attributed_to_user_id() {
+    let test = FlowySDKTest::setup();
+    let user = test.init_user().await;
+    let spans = Arc::new(Mutex::new(vec![]));
+    {
+        let subscriber = Registry::default().with(SpanRecorder(spans.clone()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+        let _ = test.user_session.user_profile().await.unwrap();
+    }
+
+    let spans = spans.lock().unwrap();
+    let (_, fields) = spans
+        .iter()
+        .find(|(name, _)| name == "read_user_profile_on_server")
+        .unwrap();
+    assert!(fields.contains(&("user_id".to_owned(), user.id.clone())));
+    assert!(fields.iter().all(|(name, _)| name != "token"));
+}
+
+#[tokio::test]
+#[serial]
+async fn user_profile_updated_is_keyed_by_user_id() {
+    let test = FlowySDKTest::setup();
+    let user = test.init_user().await;
+    let token = test.user_session.token().unwrap();
+    assert_ne!(token, user.id);
+
+    let mut rx = subscribe_notifications();
+    let _ = test.user_session.user_profile().await.unwrap();
+    let ty = UserNotification::UserProfileUpdated as i32;
+    let subject = wait_for_notification(&mut rx, ty, |subject| subject.id == user.id || subject.id == token)
+        .await
+        .unwrap();
+    assert_eq!(subject.id, user.id);
+}
+
+#[tokio::test]
+#[serial]
+async fn user_check_reconciles_session_email() {
+    let test = FlowySDKTest::setup();
+    let user = test.init_user().await;
+    let new_email = random_email();
+    let update_email_sql = format!("UPDATE user_table SET email = '{}' WHERE id = '{}'", new_email, user.id);
+    let _ = sql_query(update_email_sql)
+        .execute(&*test.user_session.db_connection().unwrap())
+        .unwrap();
+
+    let mut rx = subscribe_notifications();
+    let user_profile = UserModuleEventBuilder::new(test.clone())
+        .event(CheckUser)
+        .async_send()
+        .await
+        .parse::<UserProfile>();
+    assert_eq!(user_profile.email, new_email);
+
+    let session = test
+        .user_session
+        .list_sessions()
+        .into_iter()
+        .find(|session| session.user_id == user.id)
+        .unwrap();
+    assert_eq!(session.email, new_email);
+
+    let ty = UserNotification::UserProfileUpdated as i32;
+    assert!(wait_for_notification(&mut rx, ty, |subject| {
+        subject.id == user.id && parse_notification::<UserProfile>(subject).email == new_email
+    })
+    .await
+    .is_some());
+}
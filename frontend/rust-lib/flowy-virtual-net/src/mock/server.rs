@@ -54,6 +54,8 @@ impl MockDocServer {
             DocumentClientWSDataType::ClientPing => {
                 todo!()
             },
+            // There is no other user to receive the cursor
+            DocumentClientWSDataType::ClientCursor => None,
         }
     }
 }
@@ -48,6 +48,12 @@ impl ClientServerConfiguration {
 
     pub fn user_profile_url(&self) -> String { format!("{}/api/user", self.base_url()) }
 
+    pub fn change_password_url(&self) -> String { format!("{}/api/password_change", self.base_url()) }
+
+    pub fn email_change_url(&self) -> String { format!("{}/api/email_change", self.base_url()) }
+
+    pub fn confirm_email_change_url(&self) -> String { format!("{}/api/email_change/confirm", self.base_url()) }
+
     pub fn workspace_url(&self) -> String { format!("{}/api/workspace", self.base_url()) }
 
     pub fn app_url(&self) -> String { format!("{}/api/app", self.base_url()) }
@@ -1,6 +1,6 @@
 use crate::response::FlowyResponse;
 use bytes::Bytes;
-use serde::{Deserialize, Serialize, __private::Formatter};
+use serde::{__private::Formatter, Deserialize, Serialize};
 use serde_repr::*;
 use std::{fmt, fmt::Debug};
 
@@ -30,6 +30,8 @@ impl ServerError {
     static_error!(payload_none, ErrorCode::PayloadUnexpectedNone);
     static_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_error!(password_not_match, ErrorCode::PasswordNotMatch);
+    static_error!(email_change_code_invalid, ErrorCode::EmailChangeCodeInvalid);
+    static_error!(email_change_code_expired, ErrorCode::EmailChangeCodeExpired);
     static_error!(params_invalid, ErrorCode::ParamsInvalid);
     static_error!(connect_timeout, ErrorCode::ConnectTimeout);
     static_error!(connect_close, ErrorCode::ConnectClose);
@@ -99,6 +101,11 @@ pub enum ErrorCode {
     #[display(fmt = "Username and password do not match")]
     PasswordNotMatch   = 51,
 
+    #[display(fmt = "The verification code is invalid")]
+    EmailChangeCodeInvalid = 52,
+    #[display(fmt = "The verification code is expired")]
+    EmailChangeCodeExpired = 53,
+
     #[display(fmt = "Connect refused")]
     ConnectRefused     = 100,
 
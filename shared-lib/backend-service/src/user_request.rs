@@ -41,6 +41,54 @@ pub async fn get_user_profile_request(token: &str, url: &str) -> Result<UserProf
     Ok(user_profile)
 }
 
+pub async fn change_password_request(token: &str, params: ChangePasswordParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn request_email_change_request(
+    token: &str,
+    params: RequestEmailChangeParams,
+    url: &str,
+) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn confirm_email_change_request(
+    token: &str,
+    params: ConfirmEmailChangeParams,
+    url: &str,
+) -> Result<ConfirmEmailChangeResponse, ServerError> {
+    let response = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(response)
+}
+
+pub async fn delete_account_request(token: &str, params: DeleteAccountParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
 pub async fn update_user_profile_request(token: &str, params: UpdateUserParams, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .patch(&url.to_owned())
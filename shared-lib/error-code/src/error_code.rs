@@ -15,6 +15,9 @@ pub enum ErrorCode {
     #[display(fmt = "RecordNotFound")]
     RecordNotFound       = 3,
 
+    #[display(fmt = "Serialize or deserialize failed")]
+    SerdeError           = 4,
+
     #[display(fmt = "Workspace name can not be empty or whitespace")]
     WorkspaceNameInvalid = 100,
 
@@ -54,9 +57,21 @@ pub enum ErrorCode {
     #[display(fmt = "View name too long")]
     ViewNameTooLong      = 125,
 
+    #[display(fmt = "View is read-only")]
+    ViewReadOnly         = 126,
+
+    #[display(fmt = "View is being rewritten")]
+    ViewBusy             = 127,
+
+    #[display(fmt = "View can not be converted to the type")]
+    UnsupportedViewConversion = 128,
+
     #[display(fmt = "Connection error")]
     ConnectError         = 200,
 
+    #[display(fmt = "Server is unavailable")]
+    ServerUnavailable    = 201,
+
     #[display(fmt = "Email can not be empty or whitespace")]
     EmailIsEmpty         = 300,
     #[display(fmt = "Email format is not valid")]
@@ -83,6 +98,14 @@ pub enum ErrorCode {
     UserIdInvalid        = 311,
     #[display(fmt = "User not exist")]
     UserNotExist         = 312,
+    #[display(fmt = "The verification code of the email change is invalid")]
+    EmailChangeCodeInvalid = 313,
+    #[display(fmt = "The verification code of the email change is expired")]
+    EmailChangeCodeExpired = 314,
+    #[display(fmt = "The password can only be changed with the old password")]
+    PasswordChangeRequiresOldPassword = 315,
+    #[display(fmt = "The email can only be changed after the new one is verified")]
+    EmailChangeRequiresVerification = 316,
 }
 
 impl ErrorCode {
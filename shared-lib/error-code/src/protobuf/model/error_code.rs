@@ -28,6 +28,7 @@ pub enum ErrorCode {
     Internal = 0,
     UserUnauthorized = 2,
     RecordNotFound = 3,
+    SerdeError = 4,
     WorkspaceNameInvalid = 100,
     WorkspaceIdInvalid = 101,
     AppColorStyleInvalid = 102,
@@ -41,7 +42,11 @@ pub enum ErrorCode {
     ViewDescTooLong = 123,
     ViewDataInvalid = 124,
     ViewNameTooLong = 125,
+    ViewReadOnly = 126,
+    ViewBusy = 127,
+    UnsupportedViewConversion = 128,
     ConnectError = 200,
+    ServerUnavailable = 201,
     EmailIsEmpty = 300,
     EmailFormatInvalid = 301,
     EmailAlreadyExists = 302,
@@ -55,6 +60,10 @@ pub enum ErrorCode {
     UserNameIsEmpty = 310,
     UserIdInvalid = 311,
     UserNotExist = 312,
+    EmailChangeCodeInvalid = 313,
+    EmailChangeCodeExpired = 314,
+    PasswordChangeRequiresOldPassword = 315,
+    EmailChangeRequiresVerification = 316,
 }
 
 impl ::protobuf::ProtobufEnum for ErrorCode {
@@ -67,6 +76,7 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             0 => ::std::option::Option::Some(ErrorCode::Internal),
             2 => ::std::option::Option::Some(ErrorCode::UserUnauthorized),
             3 => ::std::option::Option::Some(ErrorCode::RecordNotFound),
+            4 => ::std::option::Option::Some(ErrorCode::SerdeError),
             100 => ::std::option::Option::Some(ErrorCode::WorkspaceNameInvalid),
             101 => ::std::option::Option::Some(ErrorCode::WorkspaceIdInvalid),
             102 => ::std::option::Option::Some(ErrorCode::AppColorStyleInvalid),
@@ -80,7 +90,11 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             123 => ::std::option::Option::Some(ErrorCode::ViewDescTooLong),
             124 => ::std::option::Option::Some(ErrorCode::ViewDataInvalid),
             125 => ::std::option::Option::Some(ErrorCode::ViewNameTooLong),
+            126 => ::std::option::Option::Some(ErrorCode::ViewReadOnly),
+            127 => ::std::option::Option::Some(ErrorCode::ViewBusy),
+            128 => ::std::option::Option::Some(ErrorCode::UnsupportedViewConversion),
             200 => ::std::option::Option::Some(ErrorCode::ConnectError),
+            201 => ::std::option::Option::Some(ErrorCode::ServerUnavailable),
             300 => ::std::option::Option::Some(ErrorCode::EmailIsEmpty),
             301 => ::std::option::Option::Some(ErrorCode::EmailFormatInvalid),
             302 => ::std::option::Option::Some(ErrorCode::EmailAlreadyExists),
@@ -94,6 +108,10 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             310 => ::std::option::Option::Some(ErrorCode::UserNameIsEmpty),
             311 => ::std::option::Option::Some(ErrorCode::UserIdInvalid),
             312 => ::std::option::Option::Some(ErrorCode::UserNotExist),
+            313 => ::std::option::Option::Some(ErrorCode::EmailChangeCodeInvalid),
+            314 => ::std::option::Option::Some(ErrorCode::EmailChangeCodeExpired),
+            315 => ::std::option::Option::Some(ErrorCode::PasswordChangeRequiresOldPassword),
+            316 => ::std::option::Option::Some(ErrorCode::EmailChangeRequiresVerification),
             _ => ::std::option::Option::None
         }
     }
@@ -103,6 +121,7 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::Internal,
             ErrorCode::UserUnauthorized,
             ErrorCode::RecordNotFound,
+            ErrorCode::SerdeError,
             ErrorCode::WorkspaceNameInvalid,
             ErrorCode::WorkspaceIdInvalid,
             ErrorCode::AppColorStyleInvalid,
@@ -116,7 +135,11 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::ViewDescTooLong,
             ErrorCode::ViewDataInvalid,
             ErrorCode::ViewNameTooLong,
+            ErrorCode::ViewReadOnly,
+            ErrorCode::ViewBusy,
+            ErrorCode::UnsupportedViewConversion,
             ErrorCode::ConnectError,
+            ErrorCode::ServerUnavailable,
             ErrorCode::EmailIsEmpty,
             ErrorCode::EmailFormatInvalid,
             ErrorCode::EmailAlreadyExists,
@@ -130,6 +153,10 @@ impl ::protobuf::ProtobufEnum for ErrorCode {
             ErrorCode::UserNameIsEmpty,
             ErrorCode::UserIdInvalid,
             ErrorCode::UserNotExist,
+            ErrorCode::EmailChangeCodeInvalid,
+            ErrorCode::EmailChangeCodeExpired,
+            ErrorCode::PasswordChangeRequiresOldPassword,
+            ErrorCode::EmailChangeRequiresVerification,
         ];
         values
     }
@@ -158,86 +185,108 @@ impl ::protobuf::reflect::ProtobufValue for ErrorCode {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10error_code.proto*\xc4\x05\n\tErrorCode\x12\x0c\n\x08Internal\x10\0\
+    \n\x10error_code.proto*\xb4\x07\n\tErrorCode\x12\x0c\n\x08Internal\x10\0\
     \x12\x14\n\x10UserUnauthorized\x10\x02\x12\x12\n\x0eRecordNotFound\x10\
-    \x03\x12\x18\n\x14WorkspaceNameInvalid\x10d\x12\x16\n\x12WorkspaceIdInva\
-    lid\x10e\x12\x18\n\x14AppColorStyleInvalid\x10f\x12\x18\n\x14WorkspaceDe\
-    scTooLong\x10g\x12\x18\n\x14WorkspaceNameTooLong\x10h\x12\x10\n\x0cAppId\
-    Invalid\x10n\x12\x12\n\x0eAppNameInvalid\x10o\x12\x13\n\x0fViewNameInval\
-    id\x10x\x12\x18\n\x14ViewThumbnailInvalid\x10y\x12\x11\n\rViewIdInvalid\
-    \x10z\x12\x13\n\x0fViewDescTooLong\x10{\x12\x13\n\x0fViewDataInvalid\x10\
-    |\x12\x13\n\x0fViewNameTooLong\x10}\x12\x11\n\x0cConnectError\x10\xc8\
-    \x01\x12\x11\n\x0cEmailIsEmpty\x10\xac\x02\x12\x17\n\x12EmailFormatInval\
-    id\x10\xad\x02\x12\x17\n\x12EmailAlreadyExists\x10\xae\x02\x12\x14\n\x0f\
-    PasswordIsEmpty\x10\xaf\x02\x12\x14\n\x0fPasswordTooLong\x10\xb0\x02\x12\
-    %\n\x20PasswordContainsForbidCharacters\x10\xb1\x02\x12\x1a\n\x15Passwor\
-    dFormatInvalid\x10\xb2\x02\x12\x15\n\x10PasswordNotMatch\x10\xb3\x02\x12\
-    \x14\n\x0fUserNameTooLong\x10\xb4\x02\x12'\n\"UserNameContainForbiddenCh\
-    aracters\x10\xb5\x02\x12\x14\n\x0fUserNameIsEmpty\x10\xb6\x02\x12\x12\n\
-    \rUserIdInvalid\x10\xb7\x02\x12\x11\n\x0cUserNotExist\x10\xb8\x02J\xf8\t\
-    \n\x06\x12\x04\0\0!\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\
-    \x12\x04\x02\0!\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\
-    \x05\0\x02\0\x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\
-    \x04\x0c\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x0f\x10\n\x0b\n\x04\x05\
-    \0\x02\x01\x12\x03\x04\x04\x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\
-    \x04\x14\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\
-    \x05\0\x02\x02\x12\x03\x05\x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\
-    \x05\x04\x12\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\
-    \x04\x05\0\x02\x03\x12\x03\x06\x04\x1f\n\x0c\n\x05\x05\0\x02\x03\x01\x12\
-    \x03\x06\x04\x18\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x06\x1b\x1e\n\x0b\
-    \n\x04\x05\0\x02\x04\x12\x03\x07\x04\x1d\n\x0c\n\x05\x05\0\x02\x04\x01\
-    \x12\x03\x07\x04\x16\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x07\x19\x1c\n\
-    \x0b\n\x04\x05\0\x02\x05\x12\x03\x08\x04\x1f\n\x0c\n\x05\x05\0\x02\x05\
-    \x01\x12\x03\x08\x04\x18\n\x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x06\x12\x03\t\x04\x1f\n\x0c\n\x05\x05\0\x02\
-    \x06\x01\x12\x03\t\x04\x18\n\x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x07\x12\x03\n\x04\x1f\n\x0c\n\x05\x05\0\x02\
-    \x07\x01\x12\x03\n\x04\x18\n\x0c\n\x05\x05\0\x02\x07\x02\x12\x03\n\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x08\x12\x03\x0b\x04\x17\n\x0c\n\x05\x05\0\x02\
-    \x08\x01\x12\x03\x0b\x04\x10\n\x0c\n\x05\x05\0\x02\x08\x02\x12\x03\x0b\
-    \x13\x16\n\x0b\n\x04\x05\0\x02\t\x12\x03\x0c\x04\x19\n\x0c\n\x05\x05\0\
-    \x02\t\x01\x12\x03\x0c\x04\x12\n\x0c\n\x05\x05\0\x02\t\x02\x12\x03\x0c\
-    \x15\x18\n\x0b\n\x04\x05\0\x02\n\x12\x03\r\x04\x1a\n\x0c\n\x05\x05\0\x02\
-    \n\x01\x12\x03\r\x04\x13\n\x0c\n\x05\x05\0\x02\n\x02\x12\x03\r\x16\x19\n\
-    \x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\x1f\n\x0c\n\x05\x05\0\x02\x0b\
-    \x01\x12\x03\x0e\x04\x18\n\x0c\n\x05\x05\0\x02\x0b\x02\x12\x03\x0e\x1b\
-    \x1e\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\x04\x18\n\x0c\n\x05\x05\0\x02\
-    \x0c\x01\x12\x03\x0f\x04\x11\n\x0c\n\x05\x05\0\x02\x0c\x02\x12\x03\x0f\
-    \x14\x17\n\x0b\n\x04\x05\0\x02\r\x12\x03\x10\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\r\x01\x12\x03\x10\x04\x13\n\x0c\n\x05\x05\0\x02\r\x02\x12\x03\x10\
-    \x16\x19\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\x11\x04\x1a\n\x0c\n\x05\x05\0\
-    \x02\x0e\x01\x12\x03\x11\x04\x13\n\x0c\n\x05\x05\0\x02\x0e\x02\x12\x03\
-    \x11\x16\x19\n\x0b\n\x04\x05\0\x02\x0f\x12\x03\x12\x04\x1a\n\x0c\n\x05\
-    \x05\0\x02\x0f\x01\x12\x03\x12\x04\x13\n\x0c\n\x05\x05\0\x02\x0f\x02\x12\
-    \x03\x12\x16\x19\n\x0b\n\x04\x05\0\x02\x10\x12\x03\x13\x04\x17\n\x0c\n\
-    \x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x10\n\x0c\n\x05\x05\0\x02\x10\x02\
-    \x12\x03\x13\x13\x16\n\x0b\n\x04\x05\0\x02\x11\x12\x03\x14\x04\x17\n\x0c\
-    \n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\x10\n\x0c\n\x05\x05\0\x02\x11\
-    \x02\x12\x03\x14\x13\x16\n\x0b\n\x04\x05\0\x02\x12\x12\x03\x15\x04\x1d\n\
-    \x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\x04\x16\n\x0c\n\x05\x05\0\x02\
-    \x12\x02\x12\x03\x15\x19\x1c\n\x0b\n\x04\x05\0\x02\x13\x12\x03\x16\x04\
-    \x1d\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\x16\x04\x16\n\x0c\n\x05\x05\0\
-    \x02\x13\x02\x12\x03\x16\x19\x1c\n\x0b\n\x04\x05\0\x02\x14\x12\x03\x17\
-    \x04\x1a\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\x17\x04\x13\n\x0c\n\x05\
-    \x05\0\x02\x14\x02\x12\x03\x17\x16\x19\n\x0b\n\x04\x05\0\x02\x15\x12\x03\
-    \x18\x04\x1a\n\x0c\n\x05\x05\0\x02\x15\x01\x12\x03\x18\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x15\x02\x12\x03\x18\x16\x19\n\x0b\n\x04\x05\0\x02\x16\x12\
-    \x03\x19\x04+\n\x0c\n\x05\x05\0\x02\x16\x01\x12\x03\x19\x04$\n\x0c\n\x05\
-    \x05\0\x02\x16\x02\x12\x03\x19'*\n\x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\
-    \x04\x20\n\x0c\n\x05\x05\0\x02\x17\x01\x12\x03\x1a\x04\x19\n\x0c\n\x05\
-    \x05\0\x02\x17\x02\x12\x03\x1a\x1c\x1f\n\x0b\n\x04\x05\0\x02\x18\x12\x03\
-    \x1b\x04\x1b\n\x0c\n\x05\x05\0\x02\x18\x01\x12\x03\x1b\x04\x14\n\x0c\n\
-    \x05\x05\0\x02\x18\x02\x12\x03\x1b\x17\x1a\n\x0b\n\x04\x05\0\x02\x19\x12\
-    \x03\x1c\x04\x1a\n\x0c\n\x05\x05\0\x02\x19\x01\x12\x03\x1c\x04\x13\n\x0c\
-    \n\x05\x05\0\x02\x19\x02\x12\x03\x1c\x16\x19\n\x0b\n\x04\x05\0\x02\x1a\
-    \x12\x03\x1d\x04-\n\x0c\n\x05\x05\0\x02\x1a\x01\x12\x03\x1d\x04&\n\x0c\n\
-    \x05\x05\0\x02\x1a\x02\x12\x03\x1d),\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\
-    \x1e\x04\x1a\n\x0c\n\x05\x05\0\x02\x1b\x01\x12\x03\x1e\x04\x13\n\x0c\n\
-    \x05\x05\0\x02\x1b\x02\x12\x03\x1e\x16\x19\n\x0b\n\x04\x05\0\x02\x1c\x12\
-    \x03\x1f\x04\x18\n\x0c\n\x05\x05\0\x02\x1c\x01\x12\x03\x1f\x04\x11\n\x0c\
-    \n\x05\x05\0\x02\x1c\x02\x12\x03\x1f\x14\x17\n\x0b\n\x04\x05\0\x02\x1d\
-    \x12\x03\x20\x04\x17\n\x0c\n\x05\x05\0\x02\x1d\x01\x12\x03\x20\x04\x10\n\
-    \x0c\n\x05\x05\0\x02\x1d\x02\x12\x03\x20\x13\x16b\x06proto3\
+    \x03\x12\x0e\n\nSerdeError\x10\x04\x12\x18\n\x14WorkspaceNameInvalid\x10\
+    d\x12\x16\n\x12WorkspaceIdInvalid\x10e\x12\x18\n\x14AppColorStyleInvalid\
+    \x10f\x12\x18\n\x14WorkspaceDescTooLong\x10g\x12\x18\n\x14WorkspaceNameT\
+    ooLong\x10h\x12\x10\n\x0cAppIdInvalid\x10n\x12\x12\n\x0eAppNameInvalid\
+    \x10o\x12\x13\n\x0fViewNameInvalid\x10x\x12\x18\n\x14ViewThumbnailInvali\
+    d\x10y\x12\x11\n\rViewIdInvalid\x10z\x12\x13\n\x0fViewDescTooLong\x10{\
+    \x12\x13\n\x0fViewDataInvalid\x10|\x12\x13\n\x0fViewNameTooLong\x10}\x12\
+    \x10\n\x0cViewReadOnly\x10~\x12\x0c\n\x08ViewBusy\x10\x7f\x12\x1e\n\x19U\
+    nsupportedViewConversion\x10\x80\x01\x12\x11\n\x0cConnectError\x10\xc8\
+    \x01\x12\x16\n\x11ServerUnavailable\x10\xc9\x01\x12\x11\n\x0cEmailIsEmpt\
+    y\x10\xac\x02\x12\x17\n\x12EmailFormatInvalid\x10\xad\x02\x12\x17\n\x12E\
+    mailAlreadyExists\x10\xae\x02\x12\x14\n\x0fPasswordIsEmpty\x10\xaf\x02\
+    \x12\x14\n\x0fPasswordTooLong\x10\xb0\x02\x12%\n\x20PasswordContainsForb\
+    idCharacters\x10\xb1\x02\x12\x1a\n\x15PasswordFormatInvalid\x10\xb2\x02\
+    \x12\x15\n\x10PasswordNotMatch\x10\xb3\x02\x12\x14\n\x0fUserNameTooLong\
+    \x10\xb4\x02\x12'\n\"UserNameContainForbiddenCharacters\x10\xb5\x02\x12\
+    \x14\n\x0fUserNameIsEmpty\x10\xb6\x02\x12\x12\n\rUserIdInvalid\x10\xb7\
+    \x02\x12\x11\n\x0cUserNotExist\x10\xb8\x02\x12\x1b\n\x16EmailChangeCodeI\
+    nvalid\x10\xb9\x02\x12\x1b\n\x16EmailChangeCodeExpired\x10\xba\x02\x12&\
+    \n!PasswordChangeRequiresOldPassword\x10\xbb\x02\x12$\n\x1fEmailChangeRe\
+    quiresVerification\x10\xbc\x02J\xe9\x0c\n\x06\x12\x04\0\0*\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0*\x01\n\n\n\x03\
+    \x05\0\x01\x12\x03\x02\x05\x0e\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\
+    \x11\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x0c\n\x0c\n\x05\x05\0\
+    \x02\0\x02\x12\x03\x03\x0f\x10\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\x04\
+    \x19\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x05\0\
+    \x02\x01\x02\x12\x03\x04\x17\x18\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x05\
+    \x04\x17\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x05\x04\x12\n\x0c\n\x05\
+    \x05\0\x02\x02\x02\x12\x03\x05\x15\x16\n\x0b\n\x04\x05\0\x02\x03\x12\x03\
+    \x06\x04\x13\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x06\x04\x0e\n\x0c\n\
+    \x05\x05\0\x02\x03\x02\x12\x03\x06\x11\x12\n\x0b\n\x04\x05\0\x02\x04\x12\
+    \x03\x07\x04\x1f\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x07\x04\x18\n\x0c\
+    \n\x05\x05\0\x02\x04\x02\x12\x03\x07\x1b\x1e\n\x0b\n\x04\x05\0\x02\x05\
+    \x12\x03\x08\x04\x1d\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x08\x04\x16\n\
+    \x0c\n\x05\x05\0\x02\x05\x02\x12\x03\x08\x19\x1c\n\x0b\n\x04\x05\0\x02\
+    \x06\x12\x03\t\x04\x1f\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\t\x04\x18\n\
+    \x0c\n\x05\x05\0\x02\x06\x02\x12\x03\t\x1b\x1e\n\x0b\n\x04\x05\0\x02\x07\
+    \x12\x03\n\x04\x1f\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\n\x04\x18\n\x0c\
+    \n\x05\x05\0\x02\x07\x02\x12\x03\n\x1b\x1e\n\x0b\n\x04\x05\0\x02\x08\x12\
+    \x03\x0b\x04\x1f\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0b\x04\x18\n\x0c\
+    \n\x05\x05\0\x02\x08\x02\x12\x03\x0b\x1b\x1e\n\x0b\n\x04\x05\0\x02\t\x12\
+    \x03\x0c\x04\x17\n\x0c\n\x05\x05\0\x02\t\x01\x12\x03\x0c\x04\x10\n\x0c\n\
+    \x05\x05\0\x02\t\x02\x12\x03\x0c\x13\x16\n\x0b\n\x04\x05\0\x02\n\x12\x03\
+    \r\x04\x19\n\x0c\n\x05\x05\0\x02\n\x01\x12\x03\r\x04\x12\n\x0c\n\x05\x05\
+    \0\x02\n\x02\x12\x03\r\x15\x18\n\x0b\n\x04\x05\0\x02\x0b\x12\x03\x0e\x04\
+    \x1a\n\x0c\n\x05\x05\0\x02\x0b\x01\x12\x03\x0e\x04\x13\n\x0c\n\x05\x05\0\
+    \x02\x0b\x02\x12\x03\x0e\x16\x19\n\x0b\n\x04\x05\0\x02\x0c\x12\x03\x0f\
+    \x04\x1f\n\x0c\n\x05\x05\0\x02\x0c\x01\x12\x03\x0f\x04\x18\n\x0c\n\x05\
+    \x05\0\x02\x0c\x02\x12\x03\x0f\x1b\x1e\n\x0b\n\x04\x05\0\x02\r\x12\x03\
+    \x10\x04\x18\n\x0c\n\x05\x05\0\x02\r\x01\x12\x03\x10\x04\x11\n\x0c\n\x05\
+    \x05\0\x02\r\x02\x12\x03\x10\x14\x17\n\x0b\n\x04\x05\0\x02\x0e\x12\x03\
+    \x11\x04\x1a\n\x0c\n\x05\x05\0\x02\x0e\x01\x12\x03\x11\x04\x13\n\x0c\n\
+    \x05\x05\0\x02\x0e\x02\x12\x03\x11\x16\x19\n\x0b\n\x04\x05\0\x02\x0f\x12\
+    \x03\x12\x04\x1a\n\x0c\n\x05\x05\0\x02\x0f\x01\x12\x03\x12\x04\x13\n\x0c\
+    \n\x05\x05\0\x02\x0f\x02\x12\x03\x12\x16\x19\n\x0b\n\x04\x05\0\x02\x10\
+    \x12\x03\x13\x04\x1a\n\x0c\n\x05\x05\0\x02\x10\x01\x12\x03\x13\x04\x13\n\
+    \x0c\n\x05\x05\0\x02\x10\x02\x12\x03\x13\x16\x19\n\x0b\n\x04\x05\0\x02\
+    \x11\x12\x03\x14\x04\x17\n\x0c\n\x05\x05\0\x02\x11\x01\x12\x03\x14\x04\
+    \x10\n\x0c\n\x05\x05\0\x02\x11\x02\x12\x03\x14\x13\x16\n\x0b\n\x04\x05\0\
+    \x02\x12\x12\x03\x15\x04\x13\n\x0c\n\x05\x05\0\x02\x12\x01\x12\x03\x15\
+    \x04\x0c\n\x0c\n\x05\x05\0\x02\x12\x02\x12\x03\x15\x0f\x12\n\x0b\n\x04\
+    \x05\0\x02\x13\x12\x03\x16\x04$\n\x0c\n\x05\x05\0\x02\x13\x01\x12\x03\
+    \x16\x04\x1d\n\x0c\n\x05\x05\0\x02\x13\x02\x12\x03\x16\x20#\n\x0b\n\x04\
+    \x05\0\x02\x14\x12\x03\x17\x04\x17\n\x0c\n\x05\x05\0\x02\x14\x01\x12\x03\
+    \x17\x04\x10\n\x0c\n\x05\x05\0\x02\x14\x02\x12\x03\x17\x13\x16\n\x0b\n\
+    \x04\x05\0\x02\x15\x12\x03\x18\x04\x1c\n\x0c\n\x05\x05\0\x02\x15\x01\x12\
+    \x03\x18\x04\x15\n\x0c\n\x05\x05\0\x02\x15\x02\x12\x03\x18\x18\x1b\n\x0b\
+    \n\x04\x05\0\x02\x16\x12\x03\x19\x04\x17\n\x0c\n\x05\x05\0\x02\x16\x01\
+    \x12\x03\x19\x04\x10\n\x0c\n\x05\x05\0\x02\x16\x02\x12\x03\x19\x13\x16\n\
+    \x0b\n\x04\x05\0\x02\x17\x12\x03\x1a\x04\x1d\n\x0c\n\x05\x05\0\x02\x17\
+    \x01\x12\x03\x1a\x04\x16\n\x0c\n\x05\x05\0\x02\x17\x02\x12\x03\x1a\x19\
+    \x1c\n\x0b\n\x04\x05\0\x02\x18\x12\x03\x1b\x04\x1d\n\x0c\n\x05\x05\0\x02\
+    \x18\x01\x12\x03\x1b\x04\x16\n\x0c\n\x05\x05\0\x02\x18\x02\x12\x03\x1b\
+    \x19\x1c\n\x0b\n\x04\x05\0\x02\x19\x12\x03\x1c\x04\x1a\n\x0c\n\x05\x05\0\
+    \x02\x19\x01\x12\x03\x1c\x04\x13\n\x0c\n\x05\x05\0\x02\x19\x02\x12\x03\
+    \x1c\x16\x19\n\x0b\n\x04\x05\0\x02\x1a\x12\x03\x1d\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02\x1a\x01\x12\x03\x1d\x04\x13\n\x0c\n\x05\x05\0\x02\x1a\x02\x12\
+    \x03\x1d\x16\x19\n\x0b\n\x04\x05\0\x02\x1b\x12\x03\x1e\x04+\n\x0c\n\x05\
+    \x05\0\x02\x1b\x01\x12\x03\x1e\x04$\n\x0c\n\x05\x05\0\x02\x1b\x02\x12\
+    \x03\x1e'*\n\x0b\n\x04\x05\0\x02\x1c\x12\x03\x1f\x04\x20\n\x0c\n\x05\x05\
+    \0\x02\x1c\x01\x12\x03\x1f\x04\x19\n\x0c\n\x05\x05\0\x02\x1c\x02\x12\x03\
+    \x1f\x1c\x1f\n\x0b\n\x04\x05\0\x02\x1d\x12\x03\x20\x04\x1b\n\x0c\n\x05\
+    \x05\0\x02\x1d\x01\x12\x03\x20\x04\x14\n\x0c\n\x05\x05\0\x02\x1d\x02\x12\
+    \x03\x20\x17\x1a\n\x0b\n\x04\x05\0\x02\x1e\x12\x03!\x04\x1a\n\x0c\n\x05\
+    \x05\0\x02\x1e\x01\x12\x03!\x04\x13\n\x0c\n\x05\x05\0\x02\x1e\x02\x12\
+    \x03!\x16\x19\n\x0b\n\x04\x05\0\x02\x1f\x12\x03\"\x04-\n\x0c\n\x05\x05\0\
+    \x02\x1f\x01\x12\x03\"\x04&\n\x0c\n\x05\x05\0\x02\x1f\x02\x12\x03\"),\n\
+    \x0b\n\x04\x05\0\x02\x20\x12\x03#\x04\x1a\n\x0c\n\x05\x05\0\x02\x20\x01\
+    \x12\x03#\x04\x13\n\x0c\n\x05\x05\0\x02\x20\x02\x12\x03#\x16\x19\n\x0b\n\
+    \x04\x05\0\x02!\x12\x03$\x04\x18\n\x0c\n\x05\x05\0\x02!\x01\x12\x03$\x04\
+    \x11\n\x0c\n\x05\x05\0\x02!\x02\x12\x03$\x14\x17\n\x0b\n\x04\x05\0\x02\"\
+    \x12\x03%\x04\x17\n\x0c\n\x05\x05\0\x02\"\x01\x12\x03%\x04\x10\n\x0c\n\
+    \x05\x05\0\x02\"\x02\x12\x03%\x13\x16\n\x0b\n\x04\x05\0\x02#\x12\x03&\
+    \x04!\n\x0c\n\x05\x05\0\x02#\x01\x12\x03&\x04\x1a\n\x0c\n\x05\x05\0\x02#\
+    \x02\x12\x03&\x1d\x20\n\x0b\n\x04\x05\0\x02$\x12\x03'\x04!\n\x0c\n\x05\
+    \x05\0\x02$\x01\x12\x03'\x04\x1a\n\x0c\n\x05\x05\0\x02$\x02\x12\x03'\x1d\
+    \x20\n\x0b\n\x04\x05\0\x02%\x12\x03(\x04,\n\x0c\n\x05\x05\0\x02%\x01\x12\
+    \x03(\x04%\n\x0c\n\x05\x05\0\x02%\x02\x12\x03((+\n\x0b\n\x04\x05\0\x02&\
+    \x12\x03)\x04*\n\x0c\n\x05\x05\0\x02&\x01\x12\x03)\x04#\n\x0c\n\x05\x05\
+    \0\x02&\x02\x12\x03)&)b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,7 +1,7 @@
 use lib_ot::{core::DeltaBuilder, rich_text::RichTextDelta};
 
 #[inline]
-pub fn initial_delta() -> RichTextDelta { DeltaBuilder::new().insert("\n").build() }
+pub fn initial_delta() -> RichTextDelta { DeltaBuilder::new().insert_newline().build() }
 
 #[inline]
 pub fn initial_delta_string() -> String { initial_delta().to_json() }
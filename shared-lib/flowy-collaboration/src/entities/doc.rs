@@ -1,6 +1,7 @@
 use crate::{
     entities::revision::{RepeatedRevision, Revision},
     errors::CollaborateError,
+    util::md5,
 };
 use flowy_derive::ProtoBuf;
 use lib_ot::{core::OperationTransformable, errors::OTError, rich_text::RichTextDelta};
@@ -97,6 +98,51 @@ pub struct DocumentDelta {
 
     #[pb(index = 2)]
     pub delta_json: String,
+
+    // The md5 of the delta_json. It's the same as the md5 of the revision that
+    // the document was composed to, so it can be compared with the server's.
+    #[pb(index = 3, one_of)]
+    pub content_hash: Option<String>,
+}
+
+impl DocumentDelta {
+    pub fn with_content_hash(doc_id: &str, delta_json: String) -> Self {
+        let content_hash = Some(md5(&delta_json));
+        Self {
+            doc_id: doc_id.to_owned(),
+            delta_json,
+            content_hash,
+        }
+    }
+
+    // Returns false if the delta_json was changed after the content_hash was
+    // computed. The delta without the content_hash is always valid.
+    pub fn verify_content_hash(&self) -> bool {
+        match &self.content_hash {
+            None => true,
+            Some(content_hash) => content_hash == &md5(&self.delta_json),
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocumentDelta {
+    #[pb(index = 1)]
+    pub items: Vec<DocumentDelta>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocumentDeltaResult {
+    #[pb(index = 1)]
+    pub delta: DocumentDelta,
+
+    // Whether the incoming delta was composed after server revisions had been transformed
+    // against the local document.
+    #[pb(index = 2)]
+    pub was_transformed: bool,
+
+    #[pb(index = 3)]
+    pub applied_rev_id: i64,
 }
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
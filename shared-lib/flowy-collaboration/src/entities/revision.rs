@@ -169,6 +169,38 @@ impl RevisionRange {
     }
 }
 
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RevisionSummary {
+    #[pb(index = 1)]
+    pub rev_id: i64,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedRevisionSummary {
+    #[pb(index = 1)]
+    pub items: Vec<RevisionSummary>,
+}
+
+// The result of squashing the acked revisions of a document into one revision,
+// the bytes are counted from the revisions' delta data.
+#[derive(Debug, Clone, Default, ProtoBuf, PartialEq)]
+pub struct CompactReport {
+    #[pb(index = 1)]
+    pub rev_id: i64,
+
+    #[pb(index = 2)]
+    pub revisions_reclaimed: i64,
+
+    #[pb(index = 3)]
+    pub bytes_reclaimed: i64,
+}
+
 #[inline]
 pub fn md5<T: AsRef<[u8]>>(data: T) -> String {
     let md5 = format!("{:x}", md5::compute(data));
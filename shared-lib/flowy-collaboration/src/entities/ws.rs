@@ -10,6 +10,7 @@ use std::convert::{TryFrom, TryInto};
 pub enum DocumentClientWSDataType {
     ClientPushRev = 0,
     ClientPing    = 1,
+    ClientCursor  = 2,
 }
 
 impl DocumentClientWSDataType {
@@ -64,6 +65,17 @@ impl DocumentClientWSData {
         }
     }
 
+    // The id is the cursor's position, the server relays it to the other users
+    // of the document.
+    pub fn cursor(doc_id: &str, position: i64) -> Self {
+        Self {
+            doc_id: doc_id.to_owned(),
+            ty: DocumentClientWSDataType::ClientCursor,
+            revisions: RepeatedRevision::empty(),
+            id: position.to_string(),
+        }
+    }
+
     pub fn id(&self) -> String { self.id.clone() }
 }
 
@@ -73,6 +85,7 @@ pub enum DocumentServerWSDataType {
     ServerPushRev = 1,
     ServerPullRev = 2,
     UserConnect   = 3,
+    UserCursor    = 4,
 }
 
 impl std::default::Default for DocumentServerWSDataType {
@@ -111,6 +124,15 @@ impl DocumentServerWSDataBuilder {
         }
     }
 
+    pub fn build_cursor_message(doc_id: &str, cursor: CursorUpdate) -> DocumentServerWSData {
+        let bytes: Bytes = cursor.try_into().unwrap();
+        DocumentServerWSData {
+            doc_id: doc_id.to_string(),
+            ty: DocumentServerWSDataType::UserCursor,
+            data: bytes.to_vec(),
+        }
+    }
+
     pub fn build_ack_message(doc_id: &str, rev_id: i64) -> DocumentServerWSData {
         let rev_id: RevId = rev_id.into();
         let bytes: Bytes = rev_id.try_into().unwrap();
@@ -134,3 +156,12 @@ pub struct NewDocumentUser {
     #[pb(index = 3)]
     pub revision_data: Vec<u8>,
 }
+
+#[derive(ProtoBuf, Default, Debug, Clone, PartialEq, Eq)]
+pub struct CursorUpdate {
+    #[pb(index = 1)]
+    pub user_id: String,
+
+    #[pb(index = 2)]
+    pub position: i64,
+}
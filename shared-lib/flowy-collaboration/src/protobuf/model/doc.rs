@@ -731,6 +731,8 @@ pub struct DocumentDelta {
     // message fields
     pub doc_id: ::std::string::String,
     pub delta_json: ::std::string::String,
+    // message oneof groups
+    pub one_of_content_hash: ::std::option::Option<DocumentDelta_oneof_one_of_content_hash>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -742,6 +744,11 @@ impl<'a> ::std::default::Default for &'a DocumentDelta {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum DocumentDelta_oneof_one_of_content_hash {
+    content_hash(::std::string::String),
+}
+
 impl DocumentDelta {
     pub fn new() -> DocumentDelta {
         ::std::default::Default::default()
@@ -798,6 +805,55 @@ impl DocumentDelta {
     pub fn take_delta_json(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.delta_json, ::std::string::String::new())
     }
+
+    // string content_hash = 3;
+
+
+    pub fn get_content_hash(&self) -> &str {
+        match self.one_of_content_hash {
+            ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_content_hash(&mut self) {
+        self.one_of_content_hash = ::std::option::Option::None;
+    }
+
+    pub fn has_content_hash(&self) -> bool {
+        match self.one_of_content_hash {
+            ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content_hash(&mut self, v: ::std::string::String) {
+        self.one_of_content_hash = ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_content_hash(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(_)) = self.one_of_content_hash {
+        } else {
+            self.one_of_content_hash = ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(::std::string::String::new()));
+        }
+        match self.one_of_content_hash {
+            ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_content_hash(&mut self) -> ::std::string::String {
+        if self.has_content_hash() {
+            match self.one_of_content_hash.take() {
+                ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for DocumentDelta {
@@ -815,6 +871,12 @@ impl ::protobuf::Message for DocumentDelta {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.delta_json)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_content_hash = ::std::option::Option::Some(DocumentDelta_oneof_one_of_content_hash::content_hash(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -833,6 +895,13 @@ impl ::protobuf::Message for DocumentDelta {
         if !self.delta_json.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.delta_json);
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_content_hash {
+            match v {
+                &DocumentDelta_oneof_one_of_content_hash::content_hash(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -845,6 +914,13 @@ impl ::protobuf::Message for DocumentDelta {
         if !self.delta_json.is_empty() {
             os.write_string(2, &self.delta_json)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_content_hash {
+            match v {
+                &DocumentDelta_oneof_one_of_content_hash::content_hash(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -893,6 +969,11 @@ impl ::protobuf::Message for DocumentDelta {
                 |m: &DocumentDelta| { &m.delta_json },
                 |m: &mut DocumentDelta| { &mut m.delta_json },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "content_hash",
+                DocumentDelta::has_content_hash,
+                DocumentDelta::get_content_hash,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentDelta>(
                 "DocumentDelta",
                 fields,
@@ -911,6 +992,7 @@ impl ::protobuf::Clear for DocumentDelta {
     fn clear(&mut self) {
         self.doc_id.clear();
         self.delta_json.clear();
+        self.one_of_content_hash = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -927,6 +1009,416 @@ impl ::protobuf::reflect::ProtobufValue for DocumentDelta {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedDocumentDelta {
+    // message fields
+    pub items: ::protobuf::RepeatedField<DocumentDelta>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedDocumentDelta {
+    fn default() -> &'a RepeatedDocumentDelta {
+        <RepeatedDocumentDelta as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedDocumentDelta {
+    pub fn new() -> RepeatedDocumentDelta {
+        ::std::default::Default::default()
+    }
+
+    // repeated .DocumentDelta items = 1;
+
+
+    pub fn get_items(&self) -> &[DocumentDelta] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<DocumentDelta>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<DocumentDelta> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<DocumentDelta> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedDocumentDelta {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedDocumentDelta {
+        RepeatedDocumentDelta::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocumentDelta>>(
+                "items",
+                |m: &RepeatedDocumentDelta| { &m.items },
+                |m: &mut RepeatedDocumentDelta| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedDocumentDelta>(
+                "RepeatedDocumentDelta",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedDocumentDelta {
+        static instance: ::protobuf::rt::LazyV2<RepeatedDocumentDelta> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedDocumentDelta::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedDocumentDelta {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedDocumentDelta {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedDocumentDelta {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentDeltaResult {
+    // message fields
+    pub delta: ::protobuf::SingularPtrField<DocumentDelta>,
+    pub was_transformed: bool,
+    pub applied_rev_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentDeltaResult {
+    fn default() -> &'a DocumentDeltaResult {
+        <DocumentDeltaResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentDeltaResult {
+    pub fn new() -> DocumentDeltaResult {
+        ::std::default::Default::default()
+    }
+
+    // .DocumentDelta delta = 1;
+
+
+    pub fn get_delta(&self) -> &DocumentDelta {
+        self.delta.as_ref().unwrap_or_else(|| <DocumentDelta as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_delta(&mut self) {
+        self.delta.clear();
+    }
+
+    pub fn has_delta(&self) -> bool {
+        self.delta.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delta(&mut self, v: DocumentDelta) {
+        self.delta = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_delta(&mut self) -> &mut DocumentDelta {
+        if self.delta.is_none() {
+            self.delta.set_default();
+        }
+        self.delta.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_delta(&mut self) -> DocumentDelta {
+        self.delta.take().unwrap_or_else(|| DocumentDelta::new())
+    }
+
+    // bool was_transformed = 2;
+
+
+    pub fn get_was_transformed(&self) -> bool {
+        self.was_transformed
+    }
+    pub fn clear_was_transformed(&mut self) {
+        self.was_transformed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_was_transformed(&mut self, v: bool) {
+        self.was_transformed = v;
+    }
+
+    // int64 applied_rev_id = 3;
+
+
+    pub fn get_applied_rev_id(&self) -> i64 {
+        self.applied_rev_id
+    }
+    pub fn clear_applied_rev_id(&mut self) {
+        self.applied_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_applied_rev_id(&mut self, v: i64) {
+        self.applied_rev_id = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentDeltaResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.delta {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.delta)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.was_transformed = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.applied_rev_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.delta.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.was_transformed != false {
+            my_size += 2;
+        }
+        if self.applied_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.applied_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.delta.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.was_transformed != false {
+            os.write_bool(2, self.was_transformed)?;
+        }
+        if self.applied_rev_id != 0 {
+            os.write_int64(3, self.applied_rev_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentDeltaResult {
+        DocumentDeltaResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocumentDelta>>(
+                "delta",
+                |m: &DocumentDeltaResult| { &m.delta },
+                |m: &mut DocumentDeltaResult| { &mut m.delta },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "was_transformed",
+                |m: &DocumentDeltaResult| { &m.was_transformed },
+                |m: &mut DocumentDeltaResult| { &mut m.was_transformed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "applied_rev_id",
+                |m: &DocumentDeltaResult| { &m.applied_rev_id },
+                |m: &mut DocumentDeltaResult| { &mut m.applied_rev_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentDeltaResult>(
+                "DocumentDeltaResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentDeltaResult {
+        static instance: ::protobuf::rt::LazyV2<DocumentDeltaResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentDeltaResult::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentDeltaResult {
+    fn clear(&mut self) {
+        self.delta.clear();
+        self.was_transformed = false;
+        self.applied_rev_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentDeltaResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentDeltaResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct NewDocUser {
     // message fields
@@ -1330,59 +1822,82 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x04text\x12\x15\n\x06rev_id\x18\x03\x20\x01(\x03R\x05revId\x12\x1e\n\
     \x0bbase_rev_id\x18\x04\x20\x01(\x03R\tbaseRevId\"]\n\x13ResetDocumentPa\
     rams\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12/\n\trevisions\
-    \x18\x02\x20\x01(\x0b2\x11.RepeatedRevisionR\trevisions\"E\n\rDocumentDe\
-    lta\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x1d\n\ndelta_js\
-    on\x18\x02\x20\x01(\tR\tdeltaJson\"S\n\nNewDocUser\x12\x17\n\x07user_id\
-    \x18\x01\x20\x01(\tR\x06userId\x12\x15\n\x06rev_id\x18\x02\x20\x01(\x03R\
-    \x05revId\x12\x15\n\x06doc_id\x18\x03\x20\x01(\tR\x05docId\"#\n\nDocumen\
-    tId\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdJ\xaf\x07\n\x06\x12\
-    \x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\
-    \x01\0\x18\n\n\n\x02\x04\0\x12\x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\
-    \x03\x03\x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x12\n\x0c\n\x05\
-    \x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\
-    \x04\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x10\x11\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x05\x04#\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\
-    \x05\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x15\x1e\n\x0c\n\
-    \x05\x04\0\x02\x01\x03\x12\x03\x05!\"\n\n\n\x02\x04\x01\x12\x04\x07\0\
-    \x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x14\n\x0b\n\x04\x04\x01\
-    \x02\0\x12\x03\x08\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\
-    \n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\x11\n\x0c\n\x05\x04\x01\
-    \x02\0\x03\x12\x03\x08\x14\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\
-    \x14\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\
-    \x02\x01\x01\x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\
-    \x12\x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\n\x04\x15\n\x0c\n\x05\x04\
-    \x01\x02\x02\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \n\n\x10\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\n\x13\x14\n\x0b\n\x04\
-    \x04\x01\x02\x03\x12\x03\x0b\x04\x1a\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\
-    \x03\x0b\x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0b\n\x15\n\x0c\n\
-    \x05\x04\x01\x02\x03\x03\x12\x03\x0b\x18\x19\n\n\n\x02\x04\x02\x12\x04\r\
-    \0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\r\x08\x1b\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03\x0e\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\0\x03\x12\x03\x0e\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\
-    \x04#\n\x0c\n\x05\x04\x02\x02\x01\x06\x12\x03\x0f\x04\x14\n\x0c\n\x05\
-    \x04\x02\x02\x01\x01\x12\x03\x0f\x15\x1e\n\x0c\n\x05\x04\x02\x02\x01\x03\
-    \x12\x03\x0f!\"\n\n\n\x02\x04\x03\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\
-    \x03\x01\x12\x03\x11\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\
-    \x16\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x03\
-    \x02\0\x01\x12\x03\x12\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\
-    \x14\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\x04\x1a\n\x0c\n\x05\x04\
-    \x03\x02\x01\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
-    \x03\x13\x0b\x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x13\x18\x19\n\n\
-    \n\x02\x04\x04\x12\x04\x15\0\x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\
-    \x08\x12\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x16\x04\x17\n\x0c\n\x05\x04\
-    \x04\x02\0\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\
-    \x16\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x16\x15\x16\n\x0b\n\
-    \x04\x04\x04\x02\x01\x12\x03\x17\x04\x15\n\x0c\n\x05\x04\x04\x02\x01\x05\
-    \x12\x03\x17\x04\t\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x17\n\x10\n\
-    \x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x17\x13\x14\n\x0b\n\x04\x04\x04\
-    \x02\x02\x12\x03\x18\x04\x16\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\x03\x18\
-    \x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x18\x0b\x11\n\x0c\n\x05\
-    \x04\x04\x02\x02\x03\x12\x03\x18\x14\x15\n\n\n\x02\x04\x05\x12\x04\x1a\0\
-    \x1c\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1a\x08\x12\n\x0b\n\x04\x04\x05\
-    \x02\0\x12\x03\x1b\x04\x16\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1b\x04\
-    \n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\x05\
-    \x02\0\x03\x12\x03\x1b\x14\x15b\x06proto3\
+    \x18\x02\x20\x01(\x0b2\x11.RepeatedRevisionR\trevisions\"\x81\x01\n\rDoc\
+    umentDelta\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x1d\n\nd\
+    elta_json\x18\x02\x20\x01(\tR\tdeltaJson\x12#\n\x0ccontent_hash\x18\x03\
+    \x20\x01(\tH\0R\x0bcontentHashB\x15\n\x13one_of_content_hash\"=\n\x15Rep\
+    eatedDocumentDelta\x12$\n\x05items\x18\x01\x20\x03(\x0b2\x0e.DocumentDel\
+    taR\x05items\"\x8a\x01\n\x13DocumentDeltaResult\x12$\n\x05delta\x18\x01\
+    \x20\x01(\x0b2\x0e.DocumentDeltaR\x05delta\x12'\n\x0fwas_transformed\x18\
+    \x02\x20\x01(\x08R\x0ewasTransformed\x12$\n\x0eapplied_rev_id\x18\x03\
+    \x20\x01(\x03R\x0cappliedRevId\"S\n\nNewDocUser\x12\x17\n\x07user_id\x18\
+    \x01\x20\x01(\tR\x06userId\x12\x15\n\x06rev_id\x18\x02\x20\x01(\x03R\x05\
+    revId\x12\x15\n\x06doc_id\x18\x03\x20\x01(\tR\x05docId\"#\n\nDocumentId\
+    \x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docIdJ\x9b\n\n\x06\x12\x04\0\
+    \0%\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\
+    \x18\n\n\n\x02\x04\0\x12\x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\
+    \x03\x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x12\n\x0c\n\x05\x04\
+    \0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\
+    \x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x10\x11\n\x0b\n\x04\x04\0\
+    \x02\x01\x12\x03\x05\x04#\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x05\x04\
+    \x14\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x15\x1e\n\x0c\n\x05\x04\0\
+    \x02\x01\x03\x12\x03\x05!\"\n\n\n\x02\x04\x01\x12\x04\x07\0\x0c\x01\n\n\
+    \n\x03\x04\x01\x01\x12\x03\x07\x08\x14\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
+    \x08\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\
+    \x04\x01\x02\0\x01\x12\x03\x08\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
+    \x03\x08\x14\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\x14\n\x0c\n\
+    \x05\x04\x01\x02\x01\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\
+    \x12\x03\t\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\x12\x13\n\
+    \x0b\n\x04\x04\x01\x02\x02\x12\x03\n\x04\x15\n\x0c\n\x05\x04\x01\x02\x02\
+    \x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\n\n\x10\n\
+    \x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\n\x13\x14\n\x0b\n\x04\x04\x01\x02\
+    \x03\x12\x03\x0b\x04\x1a\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x0b\x04\
+    \t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0b\n\x15\n\x0c\n\x05\x04\x01\
+    \x02\x03\x03\x12\x03\x0b\x18\x19\n\n\n\x02\x04\x02\x12\x04\r\0\x10\x01\n\
+    \n\n\x03\x04\x02\x01\x12\x03\r\x08\x1b\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x0e\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x0e\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04#\n\x0c\n\
+    \x05\x04\x02\x02\x01\x06\x12\x03\x0f\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\
+    \x01\x12\x03\x0f\x15\x1e\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f!\"\
+    \n\n\n\x02\x04\x03\x12\x04\x11\0\x15\x01\n\n\n\x03\x04\x03\x01\x12\x03\
+    \x11\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\x16\n\x0c\n\x05\
+    \x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
+    \x03\x12\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\x14\x15\n\x0b\
+    \n\x04\x04\x03\x02\x01\x12\x03\x13\x04\x1a\n\x0c\n\x05\x04\x03\x02\x01\
+    \x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x13\x0b\
+    \x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x13\x18\x19\n\x0b\n\x04\x04\
+    \x03\x08\0\x12\x03\x14\x04:\n\x0c\n\x05\x04\x03\x08\0\x01\x12\x03\x14\n\
+    \x1d\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x14\x208\n\x0c\n\x05\x04\x03\
+    \x02\x02\x05\x12\x03\x14\x20&\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\
+    \x14'3\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x1467\n\n\n\x02\x04\x04\
+    \x12\x04\x16\0\x18\x01\n\n\n\x03\x04\x04\x01\x12\x03\x16\x08\x1d\n\x0b\n\
+    \x04\x04\x04\x02\0\x12\x03\x17\x04%\n\x0c\n\x05\x04\x04\x02\0\x04\x12\
+    \x03\x17\x04\x0c\n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03\x17\r\x1a\n\x0c\n\
+    \x05\x04\x04\x02\0\x01\x12\x03\x17\x1b\x20\n\x0c\n\x05\x04\x04\x02\0\x03\
+    \x12\x03\x17#$\n\n\n\x02\x04\x05\x12\x04\x19\0\x1d\x01\n\n\n\x03\x04\x05\
+    \x01\x12\x03\x19\x08\x1b\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1a\x04\x1c\n\
+    \x0c\n\x05\x04\x05\x02\0\x06\x12\x03\x1a\x04\x11\n\x0c\n\x05\x04\x05\x02\
+    \0\x01\x12\x03\x1a\x12\x17\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1a\x1a\
+    \x1b\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\x1b\x04\x1d\n\x0c\n\x05\x04\x05\
+    \x02\x01\x05\x12\x03\x1b\x04\x08\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\
+    \x1b\t\x18\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\x1b\x1b\x1c\n\x0b\n\
+    \x04\x04\x05\x02\x02\x12\x03\x1c\x04\x1d\n\x0c\n\x05\x04\x05\x02\x02\x05\
+    \x12\x03\x1c\x04\t\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03\x1c\n\x18\n\
+    \x0c\n\x05\x04\x05\x02\x02\x03\x12\x03\x1c\x1b\x1c\n\n\n\x02\x04\x06\x12\
+    \x04\x1e\0\"\x01\n\n\n\x03\x04\x06\x01\x12\x03\x1e\x08\x12\n\x0b\n\x04\
+    \x04\x06\x02\0\x12\x03\x1f\x04\x17\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03\
+    \x1f\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03\x1f\x0b\x12\n\x0c\n\x05\
+    \x04\x06\x02\0\x03\x12\x03\x1f\x15\x16\n\x0b\n\x04\x04\x06\x02\x01\x12\
+    \x03\x20\x04\x15\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03\x20\x04\t\n\x0c\
+    \n\x05\x04\x06\x02\x01\x01\x12\x03\x20\n\x10\n\x0c\n\x05\x04\x06\x02\x01\
+    \x03\x12\x03\x20\x13\x14\n\x0b\n\x04\x04\x06\x02\x02\x12\x03!\x04\x16\n\
+    \x0c\n\x05\x04\x06\x02\x02\x05\x12\x03!\x04\n\n\x0c\n\x05\x04\x06\x02\
+    \x02\x01\x12\x03!\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03!\x14\
+    \x15\n\n\n\x02\x04\x07\x12\x04#\0%\x01\n\n\n\x03\x04\x07\x01\x12\x03#\
+    \x08\x12\n\x0b\n\x04\x04\x07\x02\0\x12\x03$\x04\x16\n\x0c\n\x05\x04\x07\
+    \x02\0\x05\x12\x03$\x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03$\x0b\x11\
+    \n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03$\x14\x15b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -956,6 +956,623 @@ impl ::protobuf::reflect::ProtobufValue for RevisionRange {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RevisionSummary {
+    // message fields
+    pub rev_id: i64,
+    pub user_id: ::std::string::String,
+    pub timestamp: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RevisionSummary {
+    fn default() -> &'a RevisionSummary {
+        <RevisionSummary as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RevisionSummary {
+    pub fn new() -> RevisionSummary {
+        ::std::default::Default::default()
+    }
+
+    // int64 rev_id = 1;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // int64 timestamp = 3;
+
+
+    pub fn get_timestamp(&self) -> i64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: i64) {
+        self.timestamp = v;
+    }
+}
+
+impl ::protobuf::Message for RevisionSummary {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.timestamp = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.rev_id != 0 {
+            os.write_int64(1, self.rev_id)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if self.timestamp != 0 {
+            os.write_int64(3, self.timestamp)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RevisionSummary {
+        RevisionSummary::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &RevisionSummary| { &m.rev_id },
+                |m: &mut RevisionSummary| { &mut m.rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &RevisionSummary| { &m.user_id },
+                |m: &mut RevisionSummary| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "timestamp",
+                |m: &RevisionSummary| { &m.timestamp },
+                |m: &mut RevisionSummary| { &mut m.timestamp },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RevisionSummary>(
+                "RevisionSummary",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RevisionSummary {
+        static instance: ::protobuf::rt::LazyV2<RevisionSummary> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RevisionSummary::new)
+    }
+}
+
+impl ::protobuf::Clear for RevisionSummary {
+    fn clear(&mut self) {
+        self.rev_id = 0;
+        self.user_id.clear();
+        self.timestamp = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RevisionSummary {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RevisionSummary {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedRevisionSummary {
+    // message fields
+    pub items: ::protobuf::RepeatedField<RevisionSummary>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedRevisionSummary {
+    fn default() -> &'a RepeatedRevisionSummary {
+        <RepeatedRevisionSummary as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedRevisionSummary {
+    pub fn new() -> RepeatedRevisionSummary {
+        ::std::default::Default::default()
+    }
+
+    // repeated .RevisionSummary items = 1;
+
+
+    pub fn get_items(&self) -> &[RevisionSummary] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<RevisionSummary>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<RevisionSummary> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<RevisionSummary> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedRevisionSummary {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedRevisionSummary {
+        RepeatedRevisionSummary::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RevisionSummary>>(
+                "items",
+                |m: &RepeatedRevisionSummary| { &m.items },
+                |m: &mut RepeatedRevisionSummary| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedRevisionSummary>(
+                "RepeatedRevisionSummary",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedRevisionSummary {
+        static instance: ::protobuf::rt::LazyV2<RepeatedRevisionSummary> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedRevisionSummary::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedRevisionSummary {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedRevisionSummary {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedRevisionSummary {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CompactReport {
+    // message fields
+    pub rev_id: i64,
+    pub revisions_reclaimed: i64,
+    pub bytes_reclaimed: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CompactReport {
+    fn default() -> &'a CompactReport {
+        <CompactReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CompactReport {
+    pub fn new() -> CompactReport {
+        ::std::default::Default::default()
+    }
+
+    // int64 rev_id = 1;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+
+    // int64 revisions_reclaimed = 2;
+
+
+    pub fn get_revisions_reclaimed(&self) -> i64 {
+        self.revisions_reclaimed
+    }
+    pub fn clear_revisions_reclaimed(&mut self) {
+        self.revisions_reclaimed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revisions_reclaimed(&mut self, v: i64) {
+        self.revisions_reclaimed = v;
+    }
+
+    // int64 bytes_reclaimed = 3;
+
+
+    pub fn get_bytes_reclaimed(&self) -> i64 {
+        self.bytes_reclaimed
+    }
+    pub fn clear_bytes_reclaimed(&mut self) {
+        self.bytes_reclaimed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes_reclaimed(&mut self, v: i64) {
+        self.bytes_reclaimed = v;
+    }
+}
+
+impl ::protobuf::Message for CompactReport {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.revisions_reclaimed = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.bytes_reclaimed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.revisions_reclaimed != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.revisions_reclaimed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bytes_reclaimed != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.bytes_reclaimed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.rev_id != 0 {
+            os.write_int64(1, self.rev_id)?;
+        }
+        if self.revisions_reclaimed != 0 {
+            os.write_int64(2, self.revisions_reclaimed)?;
+        }
+        if self.bytes_reclaimed != 0 {
+            os.write_int64(3, self.bytes_reclaimed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CompactReport {
+        CompactReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &CompactReport| { &m.rev_id },
+                |m: &mut CompactReport| { &mut m.rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "revisions_reclaimed",
+                |m: &CompactReport| { &m.revisions_reclaimed },
+                |m: &mut CompactReport| { &mut m.revisions_reclaimed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "bytes_reclaimed",
+                |m: &CompactReport| { &m.bytes_reclaimed },
+                |m: &mut CompactReport| { &mut m.bytes_reclaimed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CompactReport>(
+                "CompactReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CompactReport {
+        static instance: ::protobuf::rt::LazyV2<CompactReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CompactReport::new)
+    }
+}
+
+impl ::protobuf::Clear for CompactReport {
+    fn clear(&mut self) {
+        self.rev_id = 0;
+        self.revisions_reclaimed = 0;
+        self.bytes_reclaimed = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompactReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompactReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum RevisionState {
     Local = 0,
@@ -1067,21 +1684,28 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \"\x1d\n\x05RevId\x12\x14\n\x05value\x18\x01\x20\x01(\x03R\x05value\"N\n\
     \rRevisionRange\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x14\
     \n\x05start\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03end\x18\x03\x20\
-    \x01(\x03R\x03end*#\n\rRevisionState\x12\t\n\x05Local\x10\0\x12\x07\n\
+    \x01(\x03R\x03end\"_\n\x0fRevisionSummary\x12\x15\n\x06rev_id\x18\x01\
+    \x20\x01(\x03R\x05revId\x12\x17\n\x07user_id\x18\x02\x20\x01(\tR\x06user\
+    Id\x12\x1c\n\ttimestamp\x18\x03\x20\x01(\x03R\ttimestamp\"A\n\x17Repeate\
+    dRevisionSummary\x12&\n\x05items\x18\x01\x20\x03(\x0b2\x10.RevisionSumma\
+    ryR\x05items\"\x80\x01\n\rCompactReport\x12\x15\n\x06rev_id\x18\x01\x20\
+    \x01(\x03R\x05revId\x12/\n\x13revisions_reclaimed\x18\x02\x20\x01(\x03R\
+    \x12revisionsReclaimed\x12'\n\x0fbytes_reclaimed\x18\x03\x20\x01(\x03R\
+    \x0ebytesReclaimed*#\n\rRevisionState\x12\t\n\x05Local\x10\0\x12\x07\n\
     \x03Ack\x10\x01*4\n\x07RevType\x12\x13\n\x0fDeprecatedLocal\x10\0\x12\
-    \x14\n\x10DeprecatedRemote\x10\x01J\xe8\x07\n\x06\x12\x04\0\0\x1d\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\
-    \x03\x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x04\x1a\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\
-    \x02\0\x01\x12\x03\x03\n\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x18\
-    \x19\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x15\n\x0c\n\x05\x04\0\x02\
-    \x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\
-    \x10\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x13\x14\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\x05\x04\x19\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\
-    \x04\t\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n\x14\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x03\x05\x17\x18\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\
-    \x04\x13\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\
-    \0\x02\x03\x01\x12\x03\x06\x0b\x0e\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\
+    \x14\n\x10DeprecatedRemote\x10\x01J\xbf\x0b\n\x06\x12\x04\0\0*\x01\n\x08\
+    \n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\n\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\
+    \x1a\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x03\n\x15\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x18\x19\
+    \n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x15\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\n\x10\n\
+    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x13\x14\n\x0b\n\x04\x04\0\x02\
+    \x02\x12\x03\x05\x04\x19\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x04\t\
+    \n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\n\x14\n\x0c\n\x05\x04\0\x02\
+    \x02\x03\x12\x03\x05\x17\x18\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\
+    \x13\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\
+    \x02\x03\x01\x12\x03\x06\x0b\x0e\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\
     \x06\x11\x12\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\x04\x16\n\x0c\n\x05\
     \x04\0\x02\x04\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\0\x02\x04\x01\x12\
     \x03\x07\x0b\x11\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x07\x14\x15\n\x0b\
@@ -1108,17 +1732,40 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x03\x12\x03\x13\x12\x13\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x14\x04\x12\
     \n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x14\x04\t\n\x0c\n\x05\x04\x03\
     \x02\x02\x01\x12\x03\x14\n\r\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x14\
-    \x10\x11\n\n\n\x02\x05\0\x12\x04\x16\0\x19\x01\n\n\n\x03\x05\0\x01\x12\
-    \x03\x16\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x17\x04\x0e\n\x0c\n\x05\
-    \x05\0\x02\0\x01\x12\x03\x17\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\
-    \x17\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x18\x04\x0c\n\x0c\n\x05\x05\
-    \0\x02\x01\x01\x12\x03\x18\x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x18\n\x0b\n\n\n\x02\x05\x01\x12\x04\x1a\0\x1d\x01\n\n\n\x03\x05\x01\x01\
-    \x12\x03\x1a\x05\x0c\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x1b\x04\x18\n\x0c\
-    \n\x05\x05\x01\x02\0\x01\x12\x03\x1b\x04\x13\n\x0c\n\x05\x05\x01\x02\0\
-    \x02\x12\x03\x1b\x16\x17\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x1c\x04\x19\
-    \n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x1c\x04\x14\n\x0c\n\x05\x05\x01\
-    \x02\x01\x02\x12\x03\x1c\x17\x18b\x06proto3\
+    \x10\x11\n\n\n\x02\x04\x04\x12\x04\x16\0\x1a\x01\n\n\n\x03\x04\x04\x01\
+    \x12\x03\x16\x08\x17\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x17\x04\x15\n\x0c\
+    \n\x05\x04\x04\x02\0\x05\x12\x03\x17\x04\t\n\x0c\n\x05\x04\x04\x02\0\x01\
+    \x12\x03\x17\n\x10\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x17\x13\x14\n\
+    \x0b\n\x04\x04\x04\x02\x01\x12\x03\x18\x04\x17\n\x0c\n\x05\x04\x04\x02\
+    \x01\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x18\
+    \x0b\x12\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x18\x15\x16\n\x0b\n\x04\
+    \x04\x04\x02\x02\x12\x03\x19\x04\x18\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\
+    \x03\x19\x04\t\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x19\n\x13\n\x0c\n\
+    \x05\x04\x04\x02\x02\x03\x12\x03\x19\x16\x17\n\n\n\x02\x04\x05\x12\x04\
+    \x1b\0\x1d\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1b\x08\x1f\n\x0b\n\x04\x04\
+    \x05\x02\0\x12\x03\x1c\x04'\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03\x1c\
+    \x04\x0c\n\x0c\n\x05\x04\x05\x02\0\x06\x12\x03\x1c\r\x1c\n\x0c\n\x05\x04\
+    \x05\x02\0\x01\x12\x03\x1c\x1d\"\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\
+    \x1c%&\n\n\n\x02\x04\x06\x12\x04\x1e\0\"\x01\n\n\n\x03\x04\x06\x01\x12\
+    \x03\x1e\x08\x15\n\x0b\n\x04\x04\x06\x02\0\x12\x03\x1f\x04\x15\n\x0c\n\
+    \x05\x04\x06\x02\0\x05\x12\x03\x1f\x04\t\n\x0c\n\x05\x04\x06\x02\0\x01\
+    \x12\x03\x1f\n\x10\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03\x1f\x13\x14\n\
+    \x0b\n\x04\x04\x06\x02\x01\x12\x03\x20\x04\"\n\x0c\n\x05\x04\x06\x02\x01\
+    \x05\x12\x03\x20\x04\t\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03\x20\n\x1d\
+    \n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03\x20\x20!\n\x0b\n\x04\x04\x06\
+    \x02\x02\x12\x03!\x04\x1e\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03!\x04\t\
+    \n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03!\n\x19\n\x0c\n\x05\x04\x06\x02\
+    \x02\x03\x12\x03!\x1c\x1d\n\n\n\x02\x05\0\x12\x04#\0&\x01\n\n\n\x03\x05\
+    \0\x01\x12\x03#\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03$\x04\x0e\n\x0c\n\
+    \x05\x05\0\x02\0\x01\x12\x03$\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03$\
+    \x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03%\x04\x0c\n\x0c\n\x05\x05\0\x02\
+    \x01\x01\x12\x03%\x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03%\n\x0b\n\
+    \n\n\x02\x05\x01\x12\x04'\0*\x01\n\n\n\x03\x05\x01\x01\x12\x03'\x05\x0c\
+    \n\x0b\n\x04\x05\x01\x02\0\x12\x03(\x04\x18\n\x0c\n\x05\x05\x01\x02\0\
+    \x01\x12\x03(\x04\x13\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03(\x16\x17\n\
+    \x0b\n\x04\x05\x01\x02\x01\x12\x03)\x04\x19\n\x0c\n\x05\x05\x01\x02\x01\
+    \x01\x12\x03)\x04\x14\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03)\x17\x18b\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -787,10 +787,205 @@ impl ::protobuf::reflect::ProtobufValue for NewDocumentUser {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct CursorUpdate {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub position: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CursorUpdate {
+    fn default() -> &'a CursorUpdate {
+        <CursorUpdate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CursorUpdate {
+    pub fn new() -> CursorUpdate {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // int64 position = 2;
+
+
+    pub fn get_position(&self) -> i64 {
+        self.position
+    }
+    pub fn clear_position(&mut self) {
+        self.position = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_position(&mut self, v: i64) {
+        self.position = v;
+    }
+}
+
+impl ::protobuf::Message for CursorUpdate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.position = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        if self.position != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.position, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        if self.position != 0 {
+            os.write_int64(2, self.position)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CursorUpdate {
+        CursorUpdate::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &CursorUpdate| { &m.user_id },
+                |m: &mut CursorUpdate| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "position",
+                |m: &CursorUpdate| { &m.position },
+                |m: &mut CursorUpdate| { &mut m.position },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CursorUpdate>(
+                "CursorUpdate",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CursorUpdate {
+        static instance: ::protobuf::rt::LazyV2<CursorUpdate> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CursorUpdate::new)
+    }
+}
+
+impl ::protobuf::Clear for CursorUpdate {
+    fn clear(&mut self) {
+        self.user_id.clear();
+        self.position = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CursorUpdate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CursorUpdate {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum DocumentClientWSDataType {
     ClientPushRev = 0,
     ClientPing = 1,
+    ClientCursor = 2,
 }
 
 impl ::protobuf::ProtobufEnum for DocumentClientWSDataType {
@@ -802,6 +997,7 @@ impl ::protobuf::ProtobufEnum for DocumentClientWSDataType {
         match value {
             0 => ::std::option::Option::Some(DocumentClientWSDataType::ClientPushRev),
             1 => ::std::option::Option::Some(DocumentClientWSDataType::ClientPing),
+            2 => ::std::option::Option::Some(DocumentClientWSDataType::ClientCursor),
             _ => ::std::option::Option::None
         }
     }
@@ -810,6 +1006,7 @@ impl ::protobuf::ProtobufEnum for DocumentClientWSDataType {
         static values: &'static [DocumentClientWSDataType] = &[
             DocumentClientWSDataType::ClientPushRev,
             DocumentClientWSDataType::ClientPing,
+            DocumentClientWSDataType::ClientCursor,
         ];
         values
     }
@@ -843,6 +1040,7 @@ pub enum DocumentServerWSDataType {
     ServerPushRev = 1,
     ServerPullRev = 2,
     UserConnect = 3,
+    UserCursor = 4,
 }
 
 impl ::protobuf::ProtobufEnum for DocumentServerWSDataType {
@@ -856,6 +1054,7 @@ impl ::protobuf::ProtobufEnum for DocumentServerWSDataType {
             1 => ::std::option::Option::Some(DocumentServerWSDataType::ServerPushRev),
             2 => ::std::option::Option::Some(DocumentServerWSDataType::ServerPullRev),
             3 => ::std::option::Option::Some(DocumentServerWSDataType::UserConnect),
+            4 => ::std::option::Option::Some(DocumentServerWSDataType::UserCursor),
             _ => ::std::option::Option::None
         }
     }
@@ -866,6 +1065,7 @@ impl ::protobuf::ProtobufEnum for DocumentServerWSDataType {
             DocumentServerWSDataType::ServerPushRev,
             DocumentServerWSDataType::ServerPullRev,
             DocumentServerWSDataType::UserConnect,
+            DocumentServerWSDataType::UserCursor,
         ];
         values
     }
@@ -903,57 +1103,71 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     umentServerWSDataTypeR\x02ty\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04\
     data\"f\n\x0fNewDocumentUser\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\
     \x06userId\x12\x15\n\x06doc_id\x18\x02\x20\x01(\tR\x05docId\x12#\n\rrevi\
-    sion_data\x18\x03\x20\x01(\x0cR\x0crevisionData*=\n\x18DocumentClientWSD\
-    ataType\x12\x11\n\rClientPushRev\x10\0\x12\x0e\n\nClientPing\x10\x01*`\n\
-    \x18DocumentServerWSDataType\x12\r\n\tServerAck\x10\0\x12\x11\n\rServerP\
-    ushRev\x10\x01\x12\x11\n\rServerPullRev\x10\x02\x12\x0f\n\x0bUserConnect\
-    \x10\x03J\xb1\x07\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\
-    \x12\n\t\n\x02\x03\0\x12\x03\x01\0\x18\n\n\n\x02\x04\0\x12\x04\x03\0\x08\
-    \x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1c\n\x0b\n\x04\x04\0\x02\0\x12\
-    \x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\
-    \x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\
-    \x03\x04\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04$\n\x0c\n\x05\
-    \x04\0\x02\x01\x06\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
-    \x03\x05\x1d\x1f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\"#\n\x0b\n\
-    \x04\x04\0\x02\x02\x12\x03\x06\x04#\n\x0c\n\x05\x04\0\x02\x02\x06\x12\
-    \x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x15\x1e\n\x0c\
-    \n\x05\x04\0\x02\x02\x03\x12\x03\x06!\"\n\x0b\n\x04\x04\0\x02\x03\x12\
-    \x03\x07\x04\x12\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x07\x04\n\n\x0c\n\
-    \x05\x04\0\x02\x03\x01\x12\x03\x07\x0b\r\n\x0c\n\x05\x04\0\x02\x03\x03\
-    \x12\x03\x07\x10\x11\n\n\n\x02\x04\x01\x12\x04\t\0\r\x01\n\n\n\x03\x04\
-    \x01\x01\x12\x03\t\x08\x1c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x04\x16\n\
-    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\0\
-    \x01\x12\x03\n\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x14\x15\n\
-    \x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04$\n\x0c\n\x05\x04\x01\x02\x01\
-    \x06\x12\x03\x0b\x04\x1c\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0b\x1d\
-    \x1f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\"#\n\x0b\n\x04\x04\x01\
-    \x02\x02\x12\x03\x0c\x04\x13\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x0c\
-    \x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0c\n\x0e\n\x0c\n\x05\x04\
-    \x01\x02\x02\x03\x12\x03\x0c\x11\x12\n\n\n\x02\x04\x02\x12\x04\x0e\0\x12\
-    \x01\n\n\n\x03\x04\x02\x01\x12\x03\x0e\x08\x17\n\x0b\n\x04\x04\x02\x02\0\
-    \x12\x03\x0f\x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0f\x04\n\n\
-    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0f\x0b\x12\n\x0c\n\x05\x04\x02\x02\
-    \0\x03\x12\x03\x0f\x15\x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x10\x04\
-    \x16\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\x10\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
-    \x03\x10\x14\x15\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x11\x04\x1c\n\x0c\n\
-    \x05\x04\x02\x02\x02\x05\x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x02\
-    \x01\x12\x03\x11\n\x17\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x11\x1a\
-    \x1b\n\n\n\x02\x05\0\x12\x04\x13\0\x16\x01\n\n\n\x03\x05\0\x01\x12\x03\
-    \x13\x05\x1d\n\x0b\n\x04\x05\0\x02\0\x12\x03\x14\x04\x16\n\x0c\n\x05\x05\
-    \0\x02\0\x01\x12\x03\x14\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x14\
-    \x14\x15\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x15\x04\x13\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x15\x04\x0e\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\
-    \x15\x11\x12\n\n\n\x02\x05\x01\x12\x04\x17\0\x1c\x01\n\n\n\x03\x05\x01\
-    \x01\x12\x03\x17\x05\x1d\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x18\x04\x12\n\
-    \x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x18\x04\r\n\x0c\n\x05\x05\x01\x02\0\
-    \x02\x12\x03\x18\x10\x11\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x19\x04\x16\
-    \n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\x19\x04\x11\n\x0c\n\x05\x05\x01\
-    \x02\x01\x02\x12\x03\x19\x14\x15\n\x0b\n\x04\x05\x01\x02\x02\x12\x03\x1a\
-    \x04\x16\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x1a\x04\x11\n\x0c\n\x05\
-    \x05\x01\x02\x02\x02\x12\x03\x1a\x14\x15\n\x0b\n\x04\x05\x01\x02\x03\x12\
-    \x03\x1b\x04\x14\n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x1b\x04\x0f\n\
-    \x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x1b\x12\x13b\x06proto3\
+    sion_data\x18\x03\x20\x01(\x0cR\x0crevisionData\"C\n\x0cCursorUpdate\x12\
+    \x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x1a\n\x08position\
+    \x18\x02\x20\x01(\x03R\x08position*O\n\x18DocumentClientWSDataType\x12\
+    \x11\n\rClientPushRev\x10\0\x12\x0e\n\nClientPing\x10\x01\x12\x10\n\x0cC\
+    lientCursor\x10\x02*p\n\x18DocumentServerWSDataType\x12\r\n\tServerAck\
+    \x10\0\x12\x11\n\rServerPushRev\x10\x01\x12\x11\n\rServerPullRev\x10\x02\
+    \x12\x0f\n\x0bUserConnect\x10\x03\x12\x0e\n\nUserCursor\x10\x04J\x89\t\n\
+    \x06\x12\x04\0\0\"\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\
+    \x12\x03\x01\0\x18\n\n\n\x02\x04\0\x12\x04\x03\0\x08\x01\n\n\n\x03\x04\0\
+    \x01\x12\x03\x03\x08\x1c\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x16\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x14\x15\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04$\n\x0c\n\x05\x04\0\x02\x01\x06\
+    \x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x1d\x1f\n\
+    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\"#\n\x0b\n\x04\x04\0\x02\x02\
+    \x12\x03\x06\x04#\n\x0c\n\x05\x04\0\x02\x02\x06\x12\x03\x06\x04\x14\n\
+    \x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x15\x1e\n\x0c\n\x05\x04\0\x02\
+    \x02\x03\x12\x03\x06!\"\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x07\x04\x12\n\
+    \x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\0\x02\x03\
+    \x01\x12\x03\x07\x0b\r\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x07\x10\x11\
+    \n\n\n\x02\x04\x01\x12\x04\t\0\r\x01\n\n\n\x03\x04\x01\x01\x12\x03\t\x08\
+    \x1c\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x04\x16\n\x0c\n\x05\x04\x01\x02\
+    \0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\n\x0b\x11\n\
+    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x14\x15\n\x0b\n\x04\x04\x01\x02\
+    \x01\x12\x03\x0b\x04$\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x0b\x04\
+    \x1c\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0b\x1d\x1f\n\x0c\n\x05\x04\
+    \x01\x02\x01\x03\x12\x03\x0b\"#\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0c\
+    \x04\x13\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x0c\x04\t\n\x0c\n\x05\
+    \x04\x01\x02\x02\x01\x12\x03\x0c\n\x0e\n\x0c\n\x05\x04\x01\x02\x02\x03\
+    \x12\x03\x0c\x11\x12\n\n\n\x02\x04\x02\x12\x04\x0e\0\x12\x01\n\n\n\x03\
+    \x04\x02\x01\x12\x03\x0e\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x0f\
+    \x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\
+    \x02\x02\0\x01\x12\x03\x0f\x0b\x12\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\
+    \x0f\x15\x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x10\x04\x16\n\x0c\n\x05\
+    \x04\x02\x02\x01\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\
+    \x12\x03\x10\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x10\x14\x15\
+    \n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x11\x04\x1c\n\x0c\n\x05\x04\x02\x02\
+    \x02\x05\x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x11\n\
+    \x17\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x11\x1a\x1b\n\n\n\x02\x04\
+    \x03\x12\x04\x13\0\x16\x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x14\n\
+    \x0b\n\x04\x04\x03\x02\0\x12\x03\x14\x04\x17\n\x0c\n\x05\x04\x03\x02\0\
+    \x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x14\x0b\x12\
+    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x15\x16\n\x0b\n\x04\x04\x03\
+    \x02\x01\x12\x03\x15\x04\x17\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x15\
+    \x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x15\n\x12\n\x0c\n\x05\x04\
+    \x03\x02\x01\x03\x12\x03\x15\x15\x16\n\n\n\x02\x05\0\x12\x04\x17\0\x1b\
+    \x01\n\n\n\x03\x05\0\x01\x12\x03\x17\x05\x1d\n\x0b\n\x04\x05\0\x02\0\x12\
+    \x03\x18\x04\x16\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x18\x04\x11\n\x0c\n\
+    \x05\x05\0\x02\0\x02\x12\x03\x18\x14\x15\n\x0b\n\x04\x05\0\x02\x01\x12\
+    \x03\x19\x04\x13\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x19\x04\x0e\n\x0c\
+    \n\x05\x05\0\x02\x01\x02\x12\x03\x19\x11\x12\n\x0b\n\x04\x05\0\x02\x02\
+    \x12\x03\x1a\x04\x15\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x1a\x04\x10\n\
+    \x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x1a\x13\x14\n\n\n\x02\x05\x01\x12\
+    \x04\x1c\0\"\x01\n\n\n\x03\x05\x01\x01\x12\x03\x1c\x05\x1d\n\x0b\n\x04\
+    \x05\x01\x02\0\x12\x03\x1d\x04\x12\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\
+    \x1d\x04\r\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\x1d\x10\x11\n\x0b\n\x04\
+    \x05\x01\x02\x01\x12\x03\x1e\x04\x16\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\
+    \x03\x1e\x04\x11\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x1e\x14\x15\n\
+    \x0b\n\x04\x05\x01\x02\x02\x12\x03\x1f\x04\x16\n\x0c\n\x05\x05\x01\x02\
+    \x02\x01\x12\x03\x1f\x04\x11\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x1f\
+    \x14\x15\n\x0b\n\x04\x05\x01\x02\x03\x12\x03\x20\x04\x14\n\x0c\n\x05\x05\
+    \x01\x02\x03\x01\x12\x03\x20\x04\x0f\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\
+    \x03\x20\x12\x13\n\x0b\n\x04\x05\x01\x02\x04\x12\x03!\x04\x13\n\x0c\n\
+    \x05\x05\x01\x02\x04\x01\x12\x03!\x04\x0e\n\x0c\n\x05\x05\x01\x02\x04\
+    \x02\x12\x03!\x11\x12b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
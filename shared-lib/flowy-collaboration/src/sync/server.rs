@@ -1,6 +1,9 @@
 use crate::{
     document::Document,
-    entities::{doc::DocumentInfo, ws::DocumentServerWSDataBuilder},
+    entities::{
+        doc::DocumentInfo,
+        ws::{CursorUpdate, DocumentServerWSDataBuilder},
+    },
     errors::{internal_error, CollaborateError, CollaborateResult},
     protobuf::{DocumentClientWSData, RepeatedRevision as RepeatedRevisionPB, Revision as RevisionPB},
     sync::{RevisionSynchronizer, RevisionUser, SyncResponse},
@@ -99,6 +102,28 @@ impl ServerDocumentManager {
         }
     }
 
+    // Relays the cursor of the user to the other users that opened the document.
+    pub async fn handle_client_cursor(
+        &self,
+        user: Arc<dyn RevisionUser>,
+        client_data: DocumentClientWSData,
+    ) -> Result<(), CollaborateError> {
+        let position = client_data.id.parse::<i64>().map_err(|e| {
+            CollaborateError::internal().context(format!("Parse cursor from {} failed. {}", client_data.id, e))
+        })?;
+        let doc_id = client_data.doc_id.clone();
+        match self.get_document_handler(&doc_id).await {
+            None => {
+                tracing::warn!("Document:{} doesn't exist, ignore the cursor", doc_id);
+                Ok(())
+            },
+            Some(handler) => {
+                handler.broadcast_cursor(position, user);
+                Ok(())
+            },
+        }
+    }
+
     pub async fn handle_document_reset(
         &self,
         doc_id: &str,
@@ -217,6 +242,22 @@ impl OpenDocHandle {
         result
     }
 
+    fn broadcast_cursor(&self, position: i64, user: Arc<dyn RevisionUser>) {
+        let user_id = user.user_id();
+        self.users.insert(user_id.clone(), user);
+        let cursor = CursorUpdate {
+            user_id: user_id.clone(),
+            position,
+        };
+        self.users
+            .iter()
+            .filter(|other| other.key() != &user_id)
+            .for_each(|other| {
+                let data = DocumentServerWSDataBuilder::build_cursor_message(&self.doc_id, cursor.clone());
+                other.value().receive(SyncResponse::Push(data));
+            });
+    }
+
     #[tracing::instrument(level = "debug", skip(self, repeated_revision), err)]
     async fn apply_document_reset(&self, repeated_revision: RepeatedRevisionPB) -> Result<(), CollaborateError> {
         let (ret, rx) = oneshot::channel();
@@ -7,18 +7,31 @@ pub enum ExportType {
     Text     = 0,
     Markdown = 1,
     Link     = 2,
+    Html     = 3,
 }
 
 impl std::default::Default for ExportType {
     fn default() -> Self { ExportType::Text }
 }
 
+impl ExportType {
+    pub fn file_extension(&self) -> &str {
+        match self {
+            ExportType::Text => "txt",
+            ExportType::Markdown => "md",
+            ExportType::Link => "txt",
+            ExportType::Html => "html",
+        }
+    }
+}
+
 impl std::convert::From<i32> for ExportType {
     fn from(val: i32) -> Self {
         match val {
             0 => ExportType::Text,
             1 => ExportType::Markdown,
             2 => ExportType::Link,
+            3 => ExportType::Html,
             _ => {
                 log::error!("Invalid export type: {}", val);
                 ExportType::Text
@@ -59,4 +72,26 @@ pub struct ExportData {
 
     #[pb(index = 2)]
     pub export_type: ExportType,
+
+    // The suggested file name, e.g. "Read me.md"
+    #[pb(index = 3)]
+    pub file_name: String,
+
+    #[pb(index = 4)]
+    pub size_in_bytes: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportAppRequest {
+    #[pb(index = 1)]
+    pub app_id: String,
+
+    #[pb(index = 2)]
+    pub export_type: ExportType,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RepeatedExportData {
+    #[pb(index = 1)]
+    pub items: Vec<ExportData>,
 }
@@ -1,7 +1,21 @@
+pub use view_consistency::*;
 pub use view_create::*;
+pub use view_delta::*;
+pub use view_merge::*;
+pub use view_progress::*;
 pub use view_query::*;
+pub use view_stats::*;
+pub use view_sync::*;
+pub use view_thumbnail::*;
 pub use view_update::*;
 
+mod view_consistency;
 mod view_create;
+mod view_delta;
+mod view_merge;
+mod view_progress;
 mod view_query;
+mod view_stats;
+mod view_sync;
+mod view_thumbnail;
 mod view_update;
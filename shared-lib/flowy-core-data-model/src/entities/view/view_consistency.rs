@@ -0,0 +1,56 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ConsistencyReport {
+    // The views that are not in the trash but have no document
+    #[pb(index = 1)]
+    pub orphaned_views: Vec<String>,
+
+    // The documents that have no view
+    #[pb(index = 2)]
+    pub orphaned_documents: Vec<String>,
+}
+
+impl ConsistencyReport {
+    pub fn is_consistent(&self) -> bool { self.orphaned_views.is_empty() && self.orphaned_documents.is_empty() }
+}
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum RepairStrategy {
+    // Seed a blank document for each orphaned view
+    CreateEmptyDocument = 0,
+    // Delete the orphaned views
+    DeleteDanglingView  = 1,
+}
+
+impl std::default::Default for RepairStrategy {
+    fn default() -> Self { RepairStrategy::CreateEmptyDocument }
+}
+
+impl std::convert::From<i32> for RepairStrategy {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => RepairStrategy::CreateEmptyDocument,
+            1 => RepairStrategy::DeleteDanglingView,
+            _ => {
+                log::error!("Invalid repair strategy: {}", val);
+                RepairStrategy::CreateEmptyDocument
+            },
+        }
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RepairViewsRequest {
+    #[pb(index = 1)]
+    pub strategy: RepairStrategy,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct RepairReport {
+    #[pb(index = 1)]
+    pub created_documents: i64,
+
+    #[pb(index = 2)]
+    pub deleted_views: i64,
+}
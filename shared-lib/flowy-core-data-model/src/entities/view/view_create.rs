@@ -4,14 +4,14 @@ use crate::{
     impl_def_and_def_mut,
     parser::{
         app::AppIdentify,
-        view::{ViewName, ViewThumbnail},
+        view::{ViewIdentify, ViewName, ViewThumbnail},
     },
 };
 use flowy_collaboration::document::default::initial_delta_string;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use std::convert::TryInto;
 
-#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, ProtoBuf_Enum, Clone)]
 pub enum ViewType {
     Blank = 0,
     Doc   = 1,
@@ -34,6 +34,37 @@ impl std::convert::From<i32> for ViewType {
     }
 }
 
+// Who can see the view when it's shared. It's only stored by the client, the
+// server decides whether the view can be read.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum ViewVisibility {
+    Private   = 0,
+    Workspace = 1,
+    Public    = 2,
+}
+
+impl std::default::Default for ViewVisibility {
+    fn default() -> Self { ViewVisibility::Private }
+}
+
+impl std::convert::From<i32> for ViewVisibility {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => ViewVisibility::Private,
+            1 => ViewVisibility::Workspace,
+            2 => ViewVisibility::Public,
+            _ => {
+                log::error!("Invalid view visibility: {}", val);
+                ViewVisibility::Private
+            },
+        }
+    }
+}
+
+impl ViewVisibility {
+    pub fn value(&self) -> i32 { self.clone() as i32 }
+}
+
 #[derive(Default, ProtoBuf)]
 pub struct CreateViewRequest {
     #[pb(index = 1)]
@@ -50,6 +81,18 @@ pub struct CreateViewRequest {
 
     #[pb(index = 5)]
     pub view_type: ViewType,
+
+    // The id of the sibling that the new view is placed right after, the view is
+    // appended to the siblings if it's None.
+    #[pb(index = 6, one_of)]
+    pub insert_after: Option<String>,
+
+    // The name of the template that the document starts with
+    #[pb(index = 7, one_of)]
+    pub template: Option<String>,
+
+    #[pb(index = 8, one_of)]
+    pub icon: Option<String>,
 }
 
 #[derive(Default, ProtoBuf, Debug, Clone)]
@@ -75,6 +118,17 @@ pub struct CreateViewParams {
 
     #[pb(index = 7)]
     pub view_id: String,
+
+    #[pb(index = 8, one_of)]
+    pub insert_after: Option<String>,
+
+    // The document is seeded from the template's delta instead of the view_data
+    // if it's set.
+    #[pb(index = 9, one_of)]
+    pub template: Option<String>,
+
+    #[pb(index = 10)]
+    pub icon: String,
 }
 
 impl CreateViewParams {
@@ -95,6 +149,101 @@ impl CreateViewParams {
             view_type,
             view_data,
             view_id,
+            insert_after: None,
+            template: None,
+            icon: "".to_owned(),
+        }
+    }
+}
+
+// Builds the CreateViewParams without the long argument list of
+// CreateViewParams::new. The view_id is generated if it's not set, the desc
+// and thumbnail are empty by default.
+pub struct CreateViewParamsBuilder {
+    belong_to_id: String,
+    name: String,
+    desc: String,
+    thumbnail: String,
+    view_type: ViewType,
+    view_data: String,
+    view_id: Option<String>,
+    insert_after: Option<String>,
+    template: Option<String>,
+    icon: String,
+}
+
+impl CreateViewParamsBuilder {
+    pub fn new(belong_to_id: &str, name: &str) -> Self {
+        Self {
+            belong_to_id: belong_to_id.to_owned(),
+            name: name.to_owned(),
+            desc: "".to_owned(),
+            thumbnail: "".to_owned(),
+            view_type: ViewType::Doc,
+            view_data: initial_delta_string(),
+            view_id: None,
+            insert_after: None,
+            template: None,
+            icon: "".to_owned(),
+        }
+    }
+
+    pub fn desc(mut self, desc: &str) -> Self {
+        self.desc = desc.to_owned();
+        self
+    }
+
+    pub fn thumbnail(mut self, thumbnail: &str) -> Self {
+        self.thumbnail = thumbnail.to_owned();
+        self
+    }
+
+    pub fn view_type(mut self, view_type: ViewType) -> Self {
+        self.view_type = view_type;
+        self
+    }
+
+    pub fn view_data(mut self, view_data: String) -> Self {
+        self.view_data = view_data;
+        self
+    }
+
+    pub fn view_id(mut self, view_id: &str) -> Self {
+        self.view_id = Some(view_id.to_owned());
+        self
+    }
+
+    pub fn insert_after(mut self, view_id: &str) -> Self {
+        self.insert_after = Some(view_id.to_owned());
+        self
+    }
+
+    pub fn template(mut self, name: &str) -> Self {
+        self.template = Some(name.to_owned());
+        self
+    }
+
+    pub fn icon(mut self, icon: &str) -> Self {
+        self.icon = icon.to_owned();
+        self
+    }
+
+    pub fn build(self) -> CreateViewParams {
+        let view_id = self.view_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let params = CreateViewParams::new(
+            self.belong_to_id,
+            self.name,
+            self.desc,
+            self.view_type,
+            self.thumbnail,
+            self.view_data,
+            view_id,
+        );
+        CreateViewParams {
+            insert_after: self.insert_after,
+            template: self.template,
+            icon: self.icon,
+            ..params
         }
     }
 }
@@ -111,8 +260,12 @@ impl TryInto<CreateViewParams> for CreateViewRequest {
             None => "".to_string(),
             Some(thumbnail) => ViewThumbnail::parse(thumbnail)?.0,
         };
+        let insert_after = match self.insert_after {
+            None => None,
+            Some(view_id) => Some(ViewIdentify::parse(view_id)?.0),
+        };
 
-        Ok(CreateViewParams::new(
+        let params = CreateViewParams::new(
             belong_to_id,
             name,
             self.desc,
@@ -120,7 +273,13 @@ impl TryInto<CreateViewParams> for CreateViewRequest {
             thumbnail,
             view_data,
             view_id,
-        ))
+        );
+        Ok(CreateViewParams {
+            insert_after,
+            template: self.template,
+            icon: self.icon.unwrap_or_default(),
+            ..params
+        })
     }
 }
 
@@ -152,6 +311,21 @@ pub struct View {
 
     #[pb(index = 9)]
     pub create_time: i64,
+
+    #[pb(index = 10)]
+    pub owner_id: String,
+
+    #[pb(index = 11)]
+    pub visibility: ViewVisibility,
+
+    // The id of the user that created the view, it's empty for the views that
+    // were created before it was recorded.
+    #[pb(index = 12)]
+    pub created_by: String,
+
+    // An emoji or the identifier of an icon, it's empty if the view has no icon.
+    #[pb(index = 13)]
+    pub icon: String,
 }
 
 #[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
@@ -162,6 +336,50 @@ pub struct RepeatedView {
 
 impl_def_and_def_mut!(RepeatedView, View);
 
+// The initial_rev_id is the rev_id of the revision that the view's document was
+// created with, the later revisions of the document are counted from it.
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct CreateViewResult {
+    #[pb(index = 1)]
+    pub view: View,
+
+    #[pb(index = 2)]
+    pub initial_rev_id: i64,
+}
+
+// Creates the view with the document imported from the delta json.
+#[derive(Default, ProtoBuf)]
+pub struct ImportViewRequest {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub delta_json: String,
+}
+
+pub struct ImportViewParams {
+    pub belong_to_id: String,
+    pub name: String,
+    pub delta_json: String,
+}
+
+impl TryInto<ImportViewParams> for ImportViewRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ImportViewParams, Self::Error> {
+        let name = ViewName::parse(self.name)?.0;
+        let belong_to_id = AppIdentify::parse(self.belong_to_id)?.0;
+        Ok(ImportViewParams {
+            belong_to_id,
+            name,
+            delta_json: self.delta_json,
+        })
+    }
+}
+
 impl std::convert::From<View> for Trash {
     fn from(view: View) -> Self {
         Trash {
@@ -0,0 +1,57 @@
+use crate::entities::view::{RepeatedView, View};
+use flowy_derive::ProtoBuf;
+
+// The changes of the views that belong to the same app or view. It's sent
+// instead of the full RepeatedView of the parent, so that the payload only
+// grows with the number of the changed views.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct AppViewsDelta {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub added: Vec<View>,
+
+    #[pb(index = 3)]
+    pub updated: Vec<View>,
+
+    #[pb(index = 4)]
+    pub removed: Vec<String>,
+}
+
+impl AppViewsDelta {
+    pub fn new(belong_to_id: &str) -> Self {
+        Self {
+            belong_to_id: belong_to_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool { self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty() }
+}
+
+// All the views of one parent, like the payload of the AppViewsChanged.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct AppViews {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub views: RepeatedView,
+}
+
+// The views of the parents that are changed by the same operation, so that
+// they're refreshed at once.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct RepeatedAppViews {
+    #[pb(index = 1)]
+    pub items: Vec<AppViews>,
+}
+
+// The deltas of the parents that are changed by the same operation, e.g. the
+// views of several apps moved to the trash at once.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct RepeatedAppViewsDelta {
+    #[pb(index = 1)]
+    pub items: Vec<AppViewsDelta>,
+}
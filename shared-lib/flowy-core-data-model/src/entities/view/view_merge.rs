@@ -0,0 +1,17 @@
+use flowy_derive::ProtoBuf;
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MergeLocalRequest {
+    #[pb(index = 1)]
+    pub target_user_id: String,
+}
+
+// The number of the apps and the views that were copied into the target user.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct MergeReport {
+    #[pb(index = 1)]
+    pub merged_apps: i64,
+
+    #[pb(index = 2)]
+    pub merged_views: i64,
+}
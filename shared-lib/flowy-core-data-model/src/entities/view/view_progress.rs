@@ -0,0 +1,15 @@
+use flowy_derive::ProtoBuf;
+
+// The progress of an operation that handles a view and all of its descendant
+// views, e.g. duplicating or deleting a folder.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewProgress {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub processed: i64,
+
+    #[pb(index = 3)]
+    pub total: i64,
+}
@@ -1,4 +1,8 @@
-use crate::{errors::ErrorCode, parser::view::ViewIdentify};
+use crate::{
+    entities::view::{RepeatedView, ViewType},
+    errors::ErrorCode,
+    parser::view::ViewIdentify,
+};
 use flowy_collaboration::entities::doc::DocumentId;
 use flowy_derive::ProtoBuf;
 use std::convert::TryInto;
@@ -43,6 +47,92 @@ impl TryInto<ViewId> for QueryViewRequest {
     }
 }
 
+#[derive(Default, ProtoBuf)]
+pub struct QueryViewsBelongToRequest {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub view_type: ViewType,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryViewsPageRequest {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub offset: i64,
+
+    #[pb(index = 3)]
+    pub limit: i64,
+}
+
+pub struct ViewsPageParams {
+    pub belong_to_id: String,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+impl TryInto<ViewsPageParams> for QueryViewsPageRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewsPageParams, Self::Error> {
+        let belong_to_id = ViewIdentify::parse(self.belong_to_id)?.0;
+        Ok(ViewsPageParams {
+            belong_to_id,
+            offset: self.offset.max(0) as usize,
+            limit: self.limit.max(0) as usize,
+        })
+    }
+}
+
+// The total is the number of all the views that are not in the trash, so the
+// number of the pages can be computed from it.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewsPage {
+    #[pb(index = 1)]
+    pub items: RepeatedView,
+
+    #[pb(index = 2)]
+    pub total: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryViewsCountRequest {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+}
+
+// The count doesn't include the views that are in the trash.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewCount {
+    #[pb(index = 1)]
+    pub belong_to_id: String,
+
+    #[pb(index = 2)]
+    pub count: i64,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct RepeatedViewCount {
+    #[pb(index = 1)]
+    pub items: Vec<ViewCount>,
+}
+
+// The views that have the same document content.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct DuplicateViews {
+    #[pb(index = 1)]
+    pub view_ids: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct RepeatedDuplicateViews {
+    #[pb(index = 1)]
+    pub items: Vec<DuplicateViews>,
+}
+
 #[derive(Default, ProtoBuf)]
 pub struct RepeatedViewId {
     #[pb(index = 1)]
@@ -63,3 +153,53 @@ impl TryInto<RepeatedViewId> for QueryViewRequest {
         Ok(RepeatedViewId { items: view_ids })
     }
 }
+
+#[derive(Default, ProtoBuf)]
+pub struct QueryDocumentHistoryRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub limit: i64,
+}
+
+pub struct DocumentHistoryParams {
+    pub doc_id: String,
+    pub limit: usize,
+}
+
+impl TryInto<DocumentHistoryParams> for QueryDocumentHistoryRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DocumentHistoryParams, Self::Error> {
+        let doc_id = ViewIdentify::parse(self.doc_id)?.0;
+        let limit = self.limit.max(0) as usize;
+        Ok(DocumentHistoryParams { doc_id, limit })
+    }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RestoreDocumentRequest {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub rev_id: i64,
+}
+
+pub struct RestoreDocumentParams {
+    pub doc_id: String,
+    pub rev_id: i64,
+}
+
+impl TryInto<RestoreDocumentParams> for RestoreDocumentRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RestoreDocumentParams, Self::Error> {
+        let doc_id = ViewIdentify::parse(self.doc_id)?.0;
+        Ok(RestoreDocumentParams {
+            doc_id,
+            rev_id: self.rev_id,
+        })
+    }
+}
@@ -0,0 +1,13 @@
+use flowy_derive::ProtoBuf;
+
+#[derive(Default, ProtoBuf, Debug, PartialEq, Clone)]
+pub struct DocumentStats {
+    #[pb(index = 1)]
+    pub word_count: i64,
+
+    #[pb(index = 2)]
+    pub char_count: i64,
+
+    #[pb(index = 3)]
+    pub line_count: i64,
+}
@@ -0,0 +1,35 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum ViewSyncState {
+    Synced  = 0,
+    Pending = 1,
+    Failed  = 2,
+}
+
+impl std::default::Default for ViewSyncState {
+    fn default() -> Self { ViewSyncState::Synced }
+}
+
+impl std::convert::From<i32> for ViewSyncState {
+    fn from(val: i32) -> Self {
+        match val {
+            0 => ViewSyncState::Synced,
+            1 => ViewSyncState::Pending,
+            2 => ViewSyncState::Failed,
+            _ => {
+                log::error!("Invalid view sync state: {}", val);
+                ViewSyncState::Synced
+            },
+        }
+    }
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewSyncStatus {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub state: ViewSyncState,
+}
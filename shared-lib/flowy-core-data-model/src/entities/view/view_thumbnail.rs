@@ -0,0 +1,24 @@
+use flowy_derive::ProtoBuf;
+
+// The thumbnail that is stored as an image, e.g. a small png. The string
+// thumbnail of the view is kept for the url or the emoji.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct SetViewThumbnailRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub data: Vec<u8>,
+
+    #[pb(index = 3)]
+    pub mime: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug, PartialEq)]
+pub struct ViewThumbnail {
+    #[pb(index = 1)]
+    pub data: Vec<u8>,
+
+    #[pb(index = 2)]
+    pub mime: String,
+}
@@ -1,4 +1,5 @@
 use crate::{
+    entities::view::ViewVisibility,
     errors::ErrorCode,
     parser::view::{ViewDesc, ViewIdentify, ViewName, ViewThumbnail},
 };
@@ -18,6 +19,49 @@ pub struct UpdateViewRequest {
 
     #[pb(index = 4, one_of)]
     pub thumbnail: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub is_readonly: Option<bool>,
+
+    #[pb(index = 6, one_of)]
+    pub icon: Option<String>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RenameViewRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct SetViewReadonlyRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub is_readonly: bool,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct SetViewVisibilityRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub visibility: ViewVisibility,
+}
+
+// The view's icon is removed if the icon is empty.
+#[derive(Default, ProtoBuf)]
+pub struct SetViewIconRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub icon: String,
 }
 
 #[derive(Default, ProtoBuf, Clone, Debug)]
@@ -33,6 +77,16 @@ pub struct UpdateViewParams {
 
     #[pb(index = 4, one_of)]
     pub thumbnail: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub is_readonly: Option<bool>,
+
+    // The value of the ViewVisibility, the one_of field can't be an enum.
+    #[pb(index = 6, one_of)]
+    pub visibility: Option<i32>,
+
+    #[pb(index = 7, one_of)]
+    pub icon: Option<String>,
 }
 
 impl UpdateViewParams {
@@ -52,6 +106,26 @@ impl UpdateViewParams {
         self.desc = Some(desc.to_owned());
         self
     }
+
+    pub fn thumbnail(mut self, thumbnail: &str) -> Self {
+        self.thumbnail = Some(thumbnail.to_owned());
+        self
+    }
+
+    pub fn readonly(mut self, is_readonly: bool) -> Self {
+        self.is_readonly = Some(is_readonly);
+        self
+    }
+
+    pub fn visibility(mut self, visibility: ViewVisibility) -> Self {
+        self.visibility = Some(visibility.value());
+        self
+    }
+
+    pub fn icon(mut self, icon: &str) -> Self {
+        self.icon = Some(icon.to_owned());
+        self
+    }
 }
 
 impl TryInto<UpdateViewParams> for UpdateViewRequest {
@@ -80,6 +154,9 @@ impl TryInto<UpdateViewParams> for UpdateViewRequest {
             name,
             desc,
             thumbnail,
+            is_readonly: self.is_readonly,
+            visibility: None,
+            icon: self.icon,
         })
     }
 }
@@ -218,6 +218,8 @@ pub struct ExportData {
     // message fields
     pub data: ::std::string::String,
     pub export_type: ExportType,
+    pub file_name: ::std::string::String,
+    pub size_in_bytes: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -274,6 +276,47 @@ impl ExportData {
     pub fn set_export_type(&mut self, v: ExportType) {
         self.export_type = v;
     }
+
+    // string file_name = 3;
+
+
+    pub fn get_file_name(&self) -> &str {
+        &self.file_name
+    }
+    pub fn clear_file_name(&mut self) {
+        self.file_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_file_name(&mut self, v: ::std::string::String) {
+        self.file_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_file_name(&mut self) -> &mut ::std::string::String {
+        &mut self.file_name
+    }
+
+    // Take field
+    pub fn take_file_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.file_name, ::std::string::String::new())
+    }
+
+    // int64 size_in_bytes = 4;
+
+
+    pub fn get_size_in_bytes(&self) -> i64 {
+        self.size_in_bytes
+    }
+    pub fn clear_size_in_bytes(&mut self) {
+        self.size_in_bytes = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size_in_bytes(&mut self, v: i64) {
+        self.size_in_bytes = v;
+    }
 }
 
 impl ::protobuf::Message for ExportData {
@@ -291,6 +334,16 @@ impl ::protobuf::Message for ExportData {
                 2 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.export_type, 2, &mut self.unknown_fields)?
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.file_name)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.size_in_bytes = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -309,6 +362,12 @@ impl ::protobuf::Message for ExportData {
         if self.export_type != ExportType::Text {
             my_size += ::protobuf::rt::enum_size(2, self.export_type);
         }
+        if !self.file_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.file_name);
+        }
+        if self.size_in_bytes != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.size_in_bytes, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -321,6 +380,12 @@ impl ::protobuf::Message for ExportData {
         if self.export_type != ExportType::Text {
             os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.export_type))?;
         }
+        if !self.file_name.is_empty() {
+            os.write_string(3, &self.file_name)?;
+        }
+        if self.size_in_bytes != 0 {
+            os.write_int64(4, self.size_in_bytes)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -369,6 +434,16 @@ impl ::protobuf::Message for ExportData {
                 |m: &ExportData| { &m.export_type },
                 |m: &mut ExportData| { &mut m.export_type },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "file_name",
+                |m: &ExportData| { &m.file_name },
+                |m: &mut ExportData| { &mut m.file_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "size_in_bytes",
+                |m: &ExportData| { &m.size_in_bytes },
+                |m: &mut ExportData| { &mut m.size_in_bytes },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportData>(
                 "ExportData",
                 fields,
@@ -387,6 +462,8 @@ impl ::protobuf::Clear for ExportData {
     fn clear(&mut self) {
         self.data.clear();
         self.export_type = ExportType::Text;
+        self.file_name.clear();
+        self.size_in_bytes = 0;
         self.unknown_fields.clear();
     }
 }
@@ -403,11 +480,368 @@ impl ::protobuf::reflect::ProtobufValue for ExportData {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct ExportAppRequest {
+    // message fields
+    pub app_id: ::std::string::String,
+    pub export_type: ExportType,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ExportAppRequest {
+    fn default() -> &'a ExportAppRequest {
+        <ExportAppRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ExportAppRequest {
+    pub fn new() -> ExportAppRequest {
+        ::std::default::Default::default()
+    }
+
+    // string app_id = 1;
+
+
+    pub fn get_app_id(&self) -> &str {
+        &self.app_id
+    }
+    pub fn clear_app_id(&mut self) {
+        self.app_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_app_id(&mut self, v: ::std::string::String) {
+        self.app_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_app_id(&mut self) -> &mut ::std::string::String {
+        &mut self.app_id
+    }
+
+    // Take field
+    pub fn take_app_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.app_id, ::std::string::String::new())
+    }
+
+    // .ExportType export_type = 2;
+
+
+    pub fn get_export_type(&self) -> ExportType {
+        self.export_type
+    }
+    pub fn clear_export_type(&mut self) {
+        self.export_type = ExportType::Text;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_export_type(&mut self, v: ExportType) {
+        self.export_type = v;
+    }
+}
+
+impl ::protobuf::Message for ExportAppRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.app_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.export_type, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.app_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.app_id);
+        }
+        if self.export_type != ExportType::Text {
+            my_size += ::protobuf::rt::enum_size(2, self.export_type);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.app_id.is_empty() {
+            os.write_string(1, &self.app_id)?;
+        }
+        if self.export_type != ExportType::Text {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.export_type))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ExportAppRequest {
+        ExportAppRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "app_id",
+                |m: &ExportAppRequest| { &m.app_id },
+                |m: &mut ExportAppRequest| { &mut m.app_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ExportType>>(
+                "export_type",
+                |m: &ExportAppRequest| { &m.export_type },
+                |m: &mut ExportAppRequest| { &mut m.export_type },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ExportAppRequest>(
+                "ExportAppRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ExportAppRequest {
+        static instance: ::protobuf::rt::LazyV2<ExportAppRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ExportAppRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ExportAppRequest {
+    fn clear(&mut self) {
+        self.app_id.clear();
+        self.export_type = ExportType::Text;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ExportAppRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ExportAppRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedExportData {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ExportData>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedExportData {
+    fn default() -> &'a RepeatedExportData {
+        <RepeatedExportData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedExportData {
+    pub fn new() -> RepeatedExportData {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ExportData items = 1;
+
+
+    pub fn get_items(&self) -> &[ExportData] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ExportData>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ExportData> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ExportData> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedExportData {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedExportData {
+        RepeatedExportData::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ExportData>>(
+                "items",
+                |m: &RepeatedExportData| { &m.items },
+                |m: &mut RepeatedExportData| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedExportData>(
+                "RepeatedExportData",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedExportData {
+        static instance: ::protobuf::rt::LazyV2<RepeatedExportData> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedExportData::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedExportData {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedExportData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedExportData {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum ExportType {
     Text = 0,
     Markdown = 1,
     Link = 2,
+    Html = 3,
 }
 
 impl ::protobuf::ProtobufEnum for ExportType {
@@ -420,6 +854,7 @@ impl ::protobuf::ProtobufEnum for ExportType {
             0 => ::std::option::Option::Some(ExportType::Text),
             1 => ::std::option::Option::Some(ExportType::Markdown),
             2 => ::std::option::Option::Some(ExportType::Link),
+            3 => ::std::option::Option::Some(ExportType::Html),
             _ => ::std::option::Option::None
         }
     }
@@ -429,6 +864,7 @@ impl ::protobuf::ProtobufEnum for ExportType {
             ExportType::Text,
             ExportType::Markdown,
             ExportType::Link,
+            ExportType::Html,
         ];
         values
     }
@@ -459,31 +895,55 @@ impl ::protobuf::reflect::ProtobufValue for ExportType {
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0cexport.proto\"T\n\rExportRequest\x12\x15\n\x06doc_id\x18\x01\x20\
     \x01(\tR\x05docId\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.Export\
-    TypeR\nexportType\"N\n\nExportData\x12\x12\n\x04data\x18\x01\x20\x01(\tR\
-    \x04data\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.ExportTypeR\nex\
-    portType*.\n\nExportType\x12\x08\n\x04Text\x10\0\x12\x0c\n\x08Markdown\
-    \x10\x01\x12\x08\n\x04Link\x10\x02J\xb1\x03\n\x06\x12\x04\0\0\x0e\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\
-    \n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\
-    \x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
-    \x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x1f\n\x0c\n\x05\x04\0\
-    \x02\x01\x06\x12\x03\x04\x04\x0e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
-    \x04\x0f\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x1d\x1e\n\n\n\x02\
-    \x04\x01\x12\x04\x06\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x12\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\x14\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\x0f\
-    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\x12\x13\n\x0b\n\x04\x04\x01\
-    \x02\x01\x12\x03\x08\x04\x1f\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x08\
-    \x04\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x08\x0f\x1a\n\x0c\n\x05\
-    \x04\x01\x02\x01\x03\x12\x03\x08\x1d\x1e\n\n\n\x02\x05\0\x12\x04\n\0\x0e\
-    \x01\n\n\n\x03\x05\0\x01\x12\x03\n\x05\x0f\n\x0b\n\x04\x05\0\x02\0\x12\
-    \x03\x0b\x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0b\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\0\x02\x12\x03\x0b\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\
-    \x03\x0c\x04\x11\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x0c\x04\x0c\n\x0c\
-    \n\x05\x05\0\x02\x01\x02\x12\x03\x0c\x0f\x10\n\x0b\n\x04\x05\0\x02\x02\
-    \x12\x03\r\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\r\x04\x08\n\x0c\n\
-    \x05\x05\0\x02\x02\x02\x12\x03\r\x0b\x0cb\x06proto3\
+    TypeR\nexportType\"\x8f\x01\n\nExportData\x12\x12\n\x04data\x18\x01\x20\
+    \x01(\tR\x04data\x12,\n\x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.ExportT\
+    ypeR\nexportType\x12\x1b\n\tfile_name\x18\x03\x20\x01(\tR\x08fileName\
+    \x12\"\n\rsize_in_bytes\x18\x04\x20\x01(\x03R\x0bsizeInBytes\"W\n\x10Exp\
+    ortAppRequest\x12\x15\n\x06app_id\x18\x01\x20\x01(\tR\x05appId\x12,\n\
+    \x0bexport_type\x18\x02\x20\x01(\x0e2\x0b.ExportTypeR\nexportType\"7\n\
+    \x12RepeatedExportData\x12!\n\x05items\x18\x01\x20\x03(\x0b2\x0b.ExportD\
+    ataR\x05items*8\n\nExportType\x12\x08\n\x04Text\x10\0\x12\x0c\n\x08Markd\
+    own\x10\x01\x12\x08\n\x04Link\x10\x02\x12\x08\n\x04Html\x10\x03J\xab\x06\
+    \n\x06\x12\x04\0\0\x18\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\
+    \0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x11\n\x0c\n\x05\
+    \x04\0\x02\0\x03\x12\x03\x03\x14\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
+    \x04\x04\x1f\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x04\x04\x0e\n\x0c\n\
+    \x05\x04\0\x02\x01\x01\x12\x03\x04\x0f\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\
+    \x12\x03\x04\x1d\x1e\n\n\n\x02\x04\x01\x12\x04\x06\0\x0b\x01\n\n\n\x03\
+    \x04\x01\x01\x12\x03\x06\x08\x12\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x07\
+    \x04\x14\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03\x07\x0b\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
+    \x07\x12\x13\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x08\x04\x1f\n\x0c\n\x05\
+    \x04\x01\x02\x01\x06\x12\x03\x08\x04\x0e\n\x0c\n\x05\x04\x01\x02\x01\x01\
+    \x12\x03\x08\x0f\x1a\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x08\x1d\x1e\
+    \n\x0b\n\x04\x04\x01\x02\x02\x12\x03\t\x04\x19\n\x0c\n\x05\x04\x01\x02\
+    \x02\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\t\x0b\
+    \x14\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\t\x17\x18\n\x0b\n\x04\x04\
+    \x01\x02\x03\x12\x03\n\x04\x1c\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\n\
+    \x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\n\n\x17\n\x0c\n\x05\x04\
+    \x01\x02\x03\x03\x12\x03\n\x1a\x1b\n\n\n\x02\x04\x02\x12\x04\x0c\0\x0f\
+    \x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x18\n\x0b\n\x04\x04\x02\x02\0\
+    \x12\x03\r\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\
+    \x05\x04\x02\x02\0\x01\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03\r\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x1f\n\x0c\
+    \n\x05\x04\x02\x02\x01\x06\x12\x03\x0e\x04\x0e\n\x0c\n\x05\x04\x02\x02\
+    \x01\x01\x12\x03\x0e\x0f\x1a\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\
+    \x1d\x1e\n\n\n\x02\x04\x03\x12\x04\x10\0\x12\x01\n\n\n\x03\x04\x03\x01\
+    \x12\x03\x10\x08\x1a\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x11\x04\"\n\x0c\n\
+    \x05\x04\x03\x02\0\x04\x12\x03\x11\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\x06\
+    \x12\x03\x11\r\x17\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x11\x18\x1d\n\
+    \x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x11\x20!\n\n\n\x02\x05\0\x12\x04\
+    \x13\0\x18\x01\n\n\n\x03\x05\0\x01\x12\x03\x13\x05\x0f\n\x0b\n\x04\x05\0\
+    \x02\0\x12\x03\x14\x04\r\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x14\x04\x08\
+    \n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x14\x0b\x0c\n\x0b\n\x04\x05\0\x02\
+    \x01\x12\x03\x15\x04\x11\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x15\x04\
+    \x0c\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x15\x0f\x10\n\x0b\n\x04\x05\0\
+    \x02\x02\x12\x03\x16\x04\r\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x16\x04\
+    \x08\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x16\x0b\x0c\n\x0b\n\x04\x05\0\
+    \x02\x03\x12\x03\x17\x04\r\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x17\x04\
+    \x08\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x17\x0b\x0cb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -39,3 +39,24 @@ pub use trash_create::*;
 
 mod export;
 pub use export::*;
+
+mod view_stats;
+pub use view_stats::*;
+
+mod view_sync;
+pub use view_sync::*;
+
+mod view_progress;
+pub use view_progress::*;
+
+mod view_consistency;
+pub use view_consistency::*;
+
+mod view_delta;
+pub use view_delta::*;
+
+mod view_thumbnail;
+pub use view_thumbnail::*;
+
+mod view_merge;
+pub use view_merge::*;
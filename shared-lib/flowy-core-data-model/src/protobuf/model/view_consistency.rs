@@ -0,0 +1,655 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_consistency.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConsistencyReport {
+    // message fields
+    pub orphaned_views: ::protobuf::RepeatedField<::std::string::String>,
+    pub orphaned_documents: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConsistencyReport {
+    fn default() -> &'a ConsistencyReport {
+        <ConsistencyReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ConsistencyReport {
+    pub fn new() -> ConsistencyReport {
+        ::std::default::Default::default()
+    }
+
+    // repeated string orphaned_views = 1;
+
+
+    pub fn get_orphaned_views(&self) -> &[::std::string::String] {
+        &self.orphaned_views
+    }
+    pub fn clear_orphaned_views(&mut self) {
+        self.orphaned_views.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_orphaned_views(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.orphaned_views = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_orphaned_views(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.orphaned_views
+    }
+
+    // Take field
+    pub fn take_orphaned_views(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.orphaned_views, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string orphaned_documents = 2;
+
+
+    pub fn get_orphaned_documents(&self) -> &[::std::string::String] {
+        &self.orphaned_documents
+    }
+    pub fn clear_orphaned_documents(&mut self) {
+        self.orphaned_documents.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_orphaned_documents(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.orphaned_documents = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_orphaned_documents(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.orphaned_documents
+    }
+
+    // Take field
+    pub fn take_orphaned_documents(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.orphaned_documents, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ConsistencyReport {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.orphaned_views)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.orphaned_documents)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.orphaned_views {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.orphaned_documents {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.orphaned_views {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.orphaned_documents {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConsistencyReport {
+        ConsistencyReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "orphaned_views",
+                |m: &ConsistencyReport| { &m.orphaned_views },
+                |m: &mut ConsistencyReport| { &mut m.orphaned_views },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "orphaned_documents",
+                |m: &ConsistencyReport| { &m.orphaned_documents },
+                |m: &mut ConsistencyReport| { &mut m.orphaned_documents },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConsistencyReport>(
+                "ConsistencyReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConsistencyReport {
+        static instance: ::protobuf::rt::LazyV2<ConsistencyReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConsistencyReport::new)
+    }
+}
+
+impl ::protobuf::Clear for ConsistencyReport {
+    fn clear(&mut self) {
+        self.orphaned_views.clear();
+        self.orphaned_documents.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConsistencyReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConsistencyReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepairViewsRequest {
+    // message fields
+    pub strategy: RepairStrategy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepairViewsRequest {
+    fn default() -> &'a RepairViewsRequest {
+        <RepairViewsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepairViewsRequest {
+    pub fn new() -> RepairViewsRequest {
+        ::std::default::Default::default()
+    }
+
+    // .RepairStrategy strategy = 1;
+
+
+    pub fn get_strategy(&self) -> RepairStrategy {
+        self.strategy
+    }
+    pub fn clear_strategy(&mut self) {
+        self.strategy = RepairStrategy::CreateEmptyDocument;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_strategy(&mut self, v: RepairStrategy) {
+        self.strategy = v;
+    }
+}
+
+impl ::protobuf::Message for RepairViewsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.strategy, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.strategy != RepairStrategy::CreateEmptyDocument {
+            my_size += ::protobuf::rt::enum_size(1, self.strategy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.strategy != RepairStrategy::CreateEmptyDocument {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.strategy))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepairViewsRequest {
+        RepairViewsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RepairStrategy>>(
+                "strategy",
+                |m: &RepairViewsRequest| { &m.strategy },
+                |m: &mut RepairViewsRequest| { &mut m.strategy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepairViewsRequest>(
+                "RepairViewsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepairViewsRequest {
+        static instance: ::protobuf::rt::LazyV2<RepairViewsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepairViewsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RepairViewsRequest {
+    fn clear(&mut self) {
+        self.strategy = RepairStrategy::CreateEmptyDocument;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepairViewsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepairViewsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepairReport {
+    // message fields
+    pub created_documents: i64,
+    pub deleted_views: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepairReport {
+    fn default() -> &'a RepairReport {
+        <RepairReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepairReport {
+    pub fn new() -> RepairReport {
+        ::std::default::Default::default()
+    }
+
+    // int64 created_documents = 1;
+
+
+    pub fn get_created_documents(&self) -> i64 {
+        self.created_documents
+    }
+    pub fn clear_created_documents(&mut self) {
+        self.created_documents = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_documents(&mut self, v: i64) {
+        self.created_documents = v;
+    }
+
+    // int64 deleted_views = 2;
+
+
+    pub fn get_deleted_views(&self) -> i64 {
+        self.deleted_views
+    }
+    pub fn clear_deleted_views(&mut self) {
+        self.deleted_views = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted_views(&mut self, v: i64) {
+        self.deleted_views = v;
+    }
+}
+
+impl ::protobuf::Message for RepairReport {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.created_documents = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.deleted_views = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.created_documents != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.created_documents, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.deleted_views != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.deleted_views, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.created_documents != 0 {
+            os.write_int64(1, self.created_documents)?;
+        }
+        if self.deleted_views != 0 {
+            os.write_int64(2, self.deleted_views)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepairReport {
+        RepairReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "created_documents",
+                |m: &RepairReport| { &m.created_documents },
+                |m: &mut RepairReport| { &mut m.created_documents },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "deleted_views",
+                |m: &RepairReport| { &m.deleted_views },
+                |m: &mut RepairReport| { &mut m.deleted_views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepairReport>(
+                "RepairReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepairReport {
+        static instance: ::protobuf::rt::LazyV2<RepairReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepairReport::new)
+    }
+}
+
+impl ::protobuf::Clear for RepairReport {
+    fn clear(&mut self) {
+        self.created_documents = 0;
+        self.deleted_views = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepairReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepairReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum RepairStrategy {
+    CreateEmptyDocument = 0,
+    DeleteDanglingView = 1,
+}
+
+impl ::protobuf::ProtobufEnum for RepairStrategy {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<RepairStrategy> {
+        match value {
+            0 => ::std::option::Option::Some(RepairStrategy::CreateEmptyDocument),
+            1 => ::std::option::Option::Some(RepairStrategy::DeleteDanglingView),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [RepairStrategy] = &[
+            RepairStrategy::CreateEmptyDocument,
+            RepairStrategy::DeleteDanglingView,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<RepairStrategy>("RepairStrategy", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for RepairStrategy {
+}
+
+impl ::std::default::Default for RepairStrategy {
+    fn default() -> Self {
+        RepairStrategy::CreateEmptyDocument
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepairStrategy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16view_consistency.proto\"i\n\x11ConsistencyReport\x12%\n\x0eorphane\
+    d_views\x18\x01\x20\x03(\tR\rorphanedViews\x12-\n\x12orphaned_documents\
+    \x18\x02\x20\x03(\tR\x11orphanedDocuments\"A\n\x12RepairViewsRequest\x12\
+    +\n\x08strategy\x18\x01\x20\x01(\x0e2\x0f.RepairStrategyR\x08strategy\"`\
+    \n\x0cRepairReport\x12+\n\x11created_documents\x18\x01\x20\x01(\x03R\x10\
+    createdDocuments\x12#\n\rdeleted_views\x18\x02\x20\x01(\x03R\x0cdeletedV\
+    iews*A\n\x0eRepairStrategy\x12\x17\n\x13CreateEmptyDocument\x10\0\x12\
+    \x16\n\x12DeleteDanglingView\x10\x01J\xf3\x03\n\x06\x12\x04\0\0\x10\x01\
+    \n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\
+    \n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\x0b\n\x04\x04\0\x02\0\x12\x03\
+    \x03\x04'\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\
+    \x04\0\x02\0\x05\x12\x03\x03\r\x13\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\
+    \x03\x14\"\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03%&\n\x0b\n\x04\x04\0\
+    \x02\x01\x12\x03\x04\x04+\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x04\x04\
+    \x0c\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\r\x13\n\x0c\n\x05\x04\0\
+    \x02\x01\x01\x12\x03\x04\x14&\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04)\
+    *\n\n\n\x02\x04\x01\x12\x04\x06\0\x08\x01\n\n\n\x03\x04\x01\x01\x12\x03\
+    \x06\x08\x1a\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x07\x04\x20\n\x0c\n\x05\
+    \x04\x01\x02\0\x06\x12\x03\x07\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
+    \x03\x07\x13\x1b\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x07\x1e\x1f\n\n\n\
+    \x02\x04\x02\x12\x04\t\0\x0c\x01\n\n\n\x03\x04\x02\x01\x12\x03\t\x08\x14\
+    \n\x0b\n\x04\x04\x02\x02\0\x12\x03\n\x04\x20\n\x0c\n\x05\x04\x02\x02\0\
+    \x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\n\n\x1b\n\x0c\
+    \n\x05\x04\x02\x02\0\x03\x12\x03\n\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x01\
+    \x12\x03\x0b\x04\x1c\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0b\x04\t\n\
+    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0b\n\x17\n\x0c\n\x05\x04\x02\x02\
+    \x01\x03\x12\x03\x0b\x1a\x1b\n\n\n\x02\x05\0\x12\x04\r\0\x10\x01\n\n\n\
+    \x03\x05\0\x01\x12\x03\r\x05\x13\n\x0b\n\x04\x05\0\x02\0\x12\x03\x0e\x04\
+    \x1c\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x0e\x04\x17\n\x0c\n\x05\x05\0\
+    \x02\0\x02\x12\x03\x0e\x1a\x1b\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x0f\x04\
+    \x1b\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x0f\x04\x16\n\x0c\n\x05\x05\0\
+    \x02\x01\x02\x12\x03\x0f\x19\x1ab\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -32,6 +32,9 @@ pub struct CreateViewRequest {
     pub view_type: ViewType,
     // message oneof groups
     pub one_of_thumbnail: ::std::option::Option<CreateViewRequest_oneof_one_of_thumbnail>,
+    pub one_of_insert_after: ::std::option::Option<CreateViewRequest_oneof_one_of_insert_after>,
+    pub one_of_template: ::std::option::Option<CreateViewRequest_oneof_one_of_template>,
+    pub one_of_icon: ::std::option::Option<CreateViewRequest_oneof_one_of_icon>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -48,6 +51,21 @@ pub enum CreateViewRequest_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewRequest_oneof_one_of_insert_after {
+    insert_after(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewRequest_oneof_one_of_template {
+    template(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewRequest_oneof_one_of_icon {
+    icon(::std::string::String),
+}
+
 impl CreateViewRequest {
     pub fn new() -> CreateViewRequest {
         ::std::default::Default::default()
@@ -194,6 +212,153 @@ impl CreateViewRequest {
     pub fn set_view_type(&mut self, v: ViewType) {
         self.view_type = v;
     }
+
+    // string insert_after = 6;
+
+
+    pub fn get_insert_after(&self) -> &str {
+        match self.one_of_insert_after {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_insert_after(&mut self) {
+        self.one_of_insert_after = ::std::option::Option::None;
+    }
+
+    pub fn has_insert_after(&self) -> bool {
+        match self.one_of_insert_after {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_insert_after(&mut self, v: ::std::string::String) {
+        self.one_of_insert_after = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_insert_after(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(_)) = self.one_of_insert_after {
+        } else {
+            self.one_of_insert_after = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(::std::string::String::new()));
+        }
+        match self.one_of_insert_after {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_insert_after(&mut self) -> ::std::string::String {
+        if self.has_insert_after() {
+            match self.one_of_insert_after.take() {
+                ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string template = 7;
+
+
+    pub fn get_template(&self) -> &str {
+        match self.one_of_template {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_template(&mut self) {
+        self.one_of_template = ::std::option::Option::None;
+    }
+
+    pub fn has_template(&self) -> bool {
+        match self.one_of_template {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_template(&mut self, v: ::std::string::String) {
+        self.one_of_template = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_template(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(_)) = self.one_of_template {
+        } else {
+            self.one_of_template = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(::std::string::String::new()));
+        }
+        match self.one_of_template {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_template(&mut self) -> ::std::string::String {
+        if self.has_template() {
+            match self.one_of_template.take() {
+                ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string icon = 8;
+
+
+    pub fn get_icon(&self) -> &str {
+        match self.one_of_icon {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_icon(&mut self) {
+        self.one_of_icon = ::std::option::Option::None;
+    }
+
+    pub fn has_icon(&self) -> bool {
+        match self.one_of_icon {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.one_of_icon = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(_)) = self.one_of_icon {
+        } else {
+            self.one_of_icon = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(::std::string::String::new()));
+        }
+        match self.one_of_icon {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        if self.has_icon() {
+            match self.one_of_icon.take() {
+                ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for CreateViewRequest {
@@ -223,6 +388,24 @@ impl ::protobuf::Message for CreateViewRequest {
                 5 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.view_type, 5, &mut self.unknown_fields)?
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_insert_after = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_insert_after::insert_after(is.read_string()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_template = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_template::template(is.read_string()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_icon = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_icon::icon(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -254,6 +437,27 @@ impl ::protobuf::Message for CreateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_insert_after {
+            match v {
+                &CreateViewRequest_oneof_one_of_insert_after::insert_after(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_template {
+            match v {
+                &CreateViewRequest_oneof_one_of_template::template(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &CreateViewRequest_oneof_one_of_icon::icon(ref v) => {
+                    my_size += ::protobuf::rt::string_size(8, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -279,6 +483,27 @@ impl ::protobuf::Message for CreateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_insert_after {
+            match v {
+                &CreateViewRequest_oneof_one_of_insert_after::insert_after(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_template {
+            match v {
+                &CreateViewRequest_oneof_one_of_template::template(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &CreateViewRequest_oneof_one_of_icon::icon(ref v) => {
+                    os.write_string(8, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -342,6 +567,21 @@ impl ::protobuf::Message for CreateViewRequest {
                 |m: &CreateViewRequest| { &m.view_type },
                 |m: &mut CreateViewRequest| { &mut m.view_type },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "insert_after",
+                CreateViewRequest::has_insert_after,
+                CreateViewRequest::get_insert_after,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "template",
+                CreateViewRequest::has_template,
+                CreateViewRequest::get_template,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "icon",
+                CreateViewRequest::has_icon,
+                CreateViewRequest::get_icon,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewRequest>(
                 "CreateViewRequest",
                 fields,
@@ -363,6 +603,9 @@ impl ::protobuf::Clear for CreateViewRequest {
         self.desc.clear();
         self.one_of_thumbnail = ::std::option::Option::None;
         self.view_type = ViewType::Blank;
+        self.one_of_insert_after = ::std::option::Option::None;
+        self.one_of_template = ::std::option::Option::None;
+        self.one_of_icon = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -389,6 +632,10 @@ pub struct CreateViewParams {
     pub view_type: ViewType,
     pub view_data: ::std::string::String,
     pub view_id: ::std::string::String,
+    pub icon: ::std::string::String,
+    // message oneof groups
+    pub one_of_insert_after: ::std::option::Option<CreateViewParams_oneof_one_of_insert_after>,
+    pub one_of_template: ::std::option::Option<CreateViewParams_oneof_one_of_template>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -400,6 +647,16 @@ impl<'a> ::std::default::Default for &'a CreateViewParams {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewParams_oneof_one_of_insert_after {
+    insert_after(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewParams_oneof_one_of_template {
+    template(::std::string::String),
+}
+
 impl CreateViewParams {
     pub fn new() -> CreateViewParams {
         ::std::default::Default::default()
@@ -575,6 +832,130 @@ impl CreateViewParams {
     pub fn take_view_id(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
     }
+
+    // string insert_after = 8;
+
+
+    pub fn get_insert_after(&self) -> &str {
+        match self.one_of_insert_after {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_insert_after(&mut self) {
+        self.one_of_insert_after = ::std::option::Option::None;
+    }
+
+    pub fn has_insert_after(&self) -> bool {
+        match self.one_of_insert_after {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_insert_after(&mut self, v: ::std::string::String) {
+        self.one_of_insert_after = ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_insert_after(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(_)) = self.one_of_insert_after {
+        } else {
+            self.one_of_insert_after = ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(::std::string::String::new()));
+        }
+        match self.one_of_insert_after {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_insert_after(&mut self) -> ::std::string::String {
+        if self.has_insert_after() {
+            match self.one_of_insert_after.take() {
+                ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string template = 9;
+
+
+    pub fn get_template(&self) -> &str {
+        match self.one_of_template {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_template(&mut self) {
+        self.one_of_template = ::std::option::Option::None;
+    }
+
+    pub fn has_template(&self) -> bool {
+        match self.one_of_template {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_template(&mut self, v: ::std::string::String) {
+        self.one_of_template = ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_template(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(_)) = self.one_of_template {
+        } else {
+            self.one_of_template = ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(::std::string::String::new()));
+        }
+        match self.one_of_template {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_template(&mut self) -> ::std::string::String {
+        if self.has_template() {
+            match self.one_of_template.take() {
+                ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string icon = 10;
+
+
+    pub fn get_icon(&self) -> &str {
+        &self.icon
+    }
+    pub fn clear_icon(&mut self) {
+        self.icon.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.icon = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        &mut self.icon
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.icon, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for CreateViewParams {
@@ -607,6 +988,21 @@ impl ::protobuf::Message for CreateViewParams {
                 7 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
                 },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_insert_after = ::std::option::Option::Some(CreateViewParams_oneof_one_of_insert_after::insert_after(is.read_string()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_template = ::std::option::Option::Some(CreateViewParams_oneof_one_of_template::template(is.read_string()?));
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.icon)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -640,6 +1036,23 @@ impl ::protobuf::Message for CreateViewParams {
         if !self.view_id.is_empty() {
             my_size += ::protobuf::rt::string_size(7, &self.view_id);
         }
+        if !self.icon.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.icon);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_insert_after {
+            match v {
+                &CreateViewParams_oneof_one_of_insert_after::insert_after(ref v) => {
+                    my_size += ::protobuf::rt::string_size(8, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_template {
+            match v {
+                &CreateViewParams_oneof_one_of_template::template(ref v) => {
+                    my_size += ::protobuf::rt::string_size(9, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -667,6 +1080,23 @@ impl ::protobuf::Message for CreateViewParams {
         if !self.view_id.is_empty() {
             os.write_string(7, &self.view_id)?;
         }
+        if !self.icon.is_empty() {
+            os.write_string(10, &self.icon)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_insert_after {
+            match v {
+                &CreateViewParams_oneof_one_of_insert_after::insert_after(ref v) => {
+                    os.write_string(8, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_template {
+            match v {
+                &CreateViewParams_oneof_one_of_template::template(ref v) => {
+                    os.write_string(9, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -740,6 +1170,21 @@ impl ::protobuf::Message for CreateViewParams {
                 |m: &CreateViewParams| { &m.view_id },
                 |m: &mut CreateViewParams| { &mut m.view_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "insert_after",
+                CreateViewParams::has_insert_after,
+                CreateViewParams::get_insert_after,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "template",
+                CreateViewParams::has_template,
+                CreateViewParams::get_template,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "icon",
+                |m: &CreateViewParams| { &m.icon },
+                |m: &mut CreateViewParams| { &mut m.icon },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewParams>(
                 "CreateViewParams",
                 fields,
@@ -763,6 +1208,9 @@ impl ::protobuf::Clear for CreateViewParams {
         self.view_type = ViewType::Blank;
         self.view_data.clear();
         self.view_id.clear();
+        self.one_of_insert_after = ::std::option::Option::None;
+        self.one_of_template = ::std::option::Option::None;
+        self.icon.clear();
         self.unknown_fields.clear();
     }
 }
@@ -791,6 +1239,10 @@ pub struct View {
     pub belongings: ::protobuf::SingularPtrField<RepeatedView>,
     pub modified_time: i64,
     pub create_time: i64,
+    pub owner_id: ::std::string::String,
+    pub visibility: ViewVisibility,
+    pub created_by: ::std::string::String,
+    pub icon: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1003,6 +1455,99 @@ impl View {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // string owner_id = 10;
+
+
+    pub fn get_owner_id(&self) -> &str {
+        &self.owner_id
+    }
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: ::std::string::String) {
+        self.owner_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_owner_id(&mut self) -> &mut ::std::string::String {
+        &mut self.owner_id
+    }
+
+    // Take field
+    pub fn take_owner_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.owner_id, ::std::string::String::new())
+    }
+
+    // .ViewVisibility visibility = 11;
+
+
+    pub fn get_visibility(&self) -> ViewVisibility {
+        self.visibility
+    }
+    pub fn clear_visibility(&mut self) {
+        self.visibility = ViewVisibility::Private;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_visibility(&mut self, v: ViewVisibility) {
+        self.visibility = v;
+    }
+
+    // string created_by = 12;
+
+
+    pub fn get_created_by(&self) -> &str {
+        &self.created_by
+    }
+    pub fn clear_created_by(&mut self) {
+        self.created_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_by(&mut self, v: ::std::string::String) {
+        self.created_by = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_by(&mut self) -> &mut ::std::string::String {
+        &mut self.created_by
+    }
+
+    // Take field
+    pub fn take_created_by(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.created_by, ::std::string::String::new())
+    }
+
+    // string icon = 13;
+
+
+    pub fn get_icon(&self) -> &str {
+        &self.icon
+    }
+    pub fn clear_icon(&mut self) {
+        self.icon.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.icon = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        &mut self.icon
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.icon, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for View {
@@ -1058,6 +1603,18 @@ impl ::protobuf::Message for View {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.owner_id)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.visibility, 11, &mut self.unknown_fields)?
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.created_by)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.icon)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1098,6 +1655,18 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(9, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.owner_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.owner_id);
+        }
+        if self.visibility != ViewVisibility::Private {
+            my_size += ::protobuf::rt::enum_size(11, self.visibility);
+        }
+        if !self.created_by.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.created_by);
+        }
+        if !self.icon.is_empty() {
+            my_size += ::protobuf::rt::string_size(13, &self.icon);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1133,6 +1702,18 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             os.write_int64(9, self.create_time)?;
         }
+        if !self.owner_id.is_empty() {
+            os.write_string(10, &self.owner_id)?;
+        }
+        if self.visibility != ViewVisibility::Private {
+            os.write_enum(11, ::protobuf::ProtobufEnum::value(&self.visibility))?;
+        }
+        if !self.created_by.is_empty() {
+            os.write_string(12, &self.created_by)?;
+        }
+        if !self.icon.is_empty() {
+            os.write_string(13, &self.icon)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1216,6 +1797,26 @@ impl ::protobuf::Message for View {
                 |m: &View| { &m.create_time },
                 |m: &mut View| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "owner_id",
+                |m: &View| { &m.owner_id },
+                |m: &mut View| { &mut m.owner_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ViewVisibility>>(
+                "visibility",
+                |m: &View| { &m.visibility },
+                |m: &mut View| { &mut m.visibility },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "created_by",
+                |m: &View| { &m.created_by },
+                |m: &mut View| { &mut m.created_by },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "icon",
+                |m: &View| { &m.icon },
+                |m: &mut View| { &mut m.icon },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<View>(
                 "View",
                 fields,
@@ -1241,6 +1842,10 @@ impl ::protobuf::Clear for View {
         self.belongings.clear();
         self.modified_time = 0;
         self.create_time = 0;
+        self.owner_id.clear();
+        self.visibility = ViewVisibility::Private;
+        self.created_by.clear();
+        self.icon.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1423,6 +2028,458 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedView {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateViewResult {
+    // message fields
+    pub view: ::protobuf::SingularPtrField<View>,
+    pub initial_rev_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateViewResult {
+    fn default() -> &'a CreateViewResult {
+        <CreateViewResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateViewResult {
+    pub fn new() -> CreateViewResult {
+        ::std::default::Default::default()
+    }
+
+    // .View view = 1;
+
+
+    pub fn get_view(&self) -> &View {
+        self.view.as_ref().unwrap_or_else(|| <View as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_view(&mut self) {
+        self.view.clear();
+    }
+
+    pub fn has_view(&self) -> bool {
+        self.view.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view(&mut self, v: View) {
+        self.view = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view(&mut self) -> &mut View {
+        if self.view.is_none() {
+            self.view.set_default();
+        }
+        self.view.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_view(&mut self) -> View {
+        self.view.take().unwrap_or_else(|| View::new())
+    }
+
+    // int64 initial_rev_id = 2;
+
+
+    pub fn get_initial_rev_id(&self) -> i64 {
+        self.initial_rev_id
+    }
+    pub fn clear_initial_rev_id(&mut self) {
+        self.initial_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_initial_rev_id(&mut self, v: i64) {
+        self.initial_rev_id = v;
+    }
+}
+
+impl ::protobuf::Message for CreateViewResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.view {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.view)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.initial_rev_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.view.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.initial_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.initial_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.view.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.initial_rev_id != 0 {
+            os.write_int64(2, self.initial_rev_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateViewResult {
+        CreateViewResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<View>>(
+                "view",
+                |m: &CreateViewResult| { &m.view },
+                |m: &mut CreateViewResult| { &mut m.view },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "initial_rev_id",
+                |m: &CreateViewResult| { &m.initial_rev_id },
+                |m: &mut CreateViewResult| { &mut m.initial_rev_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewResult>(
+                "CreateViewResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateViewResult {
+        static instance: ::protobuf::rt::LazyV2<CreateViewResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateViewResult::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateViewResult {
+    fn clear(&mut self) {
+        self.view.clear();
+        self.initial_rev_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateViewResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateViewResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ImportViewRequest {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    pub name: ::std::string::String,
+    pub delta_json: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ImportViewRequest {
+    fn default() -> &'a ImportViewRequest {
+        <ImportViewRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ImportViewRequest {
+    pub fn new() -> ImportViewRequest {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string delta_json = 3;
+
+
+    pub fn get_delta_json(&self) -> &str {
+        &self.delta_json
+    }
+    pub fn clear_delta_json(&mut self) {
+        self.delta_json.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delta_json(&mut self, v: ::std::string::String) {
+        self.delta_json = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_delta_json(&mut self) -> &mut ::std::string::String {
+        &mut self.delta_json
+    }
+
+    // Take field
+    pub fn take_delta_json(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.delta_json, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ImportViewRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.delta_json)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.delta_json.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.delta_json);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.delta_json.is_empty() {
+            os.write_string(3, &self.delta_json)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ImportViewRequest {
+        ImportViewRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &ImportViewRequest| { &m.belong_to_id },
+                |m: &mut ImportViewRequest| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &ImportViewRequest| { &m.name },
+                |m: &mut ImportViewRequest| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "delta_json",
+                |m: &ImportViewRequest| { &m.delta_json },
+                |m: &mut ImportViewRequest| { &mut m.delta_json },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ImportViewRequest>(
+                "ImportViewRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ImportViewRequest {
+        static instance: ::protobuf::rt::LazyV2<ImportViewRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ImportViewRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for ImportViewRequest {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.name.clear();
+        self.delta_json.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ImportViewRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ImportViewRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum ViewType {
     Blank = 0,
@@ -1473,100 +2530,223 @@ impl ::protobuf::reflect::ProtobufValue for ViewType {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ViewVisibility {
+    Private = 0,
+    Workspace = 1,
+    Public = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ViewVisibility {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ViewVisibility> {
+        match value {
+            0 => ::std::option::Option::Some(ViewVisibility::Private),
+            1 => ::std::option::Option::Some(ViewVisibility::Workspace),
+            2 => ::std::option::Option::Some(ViewVisibility::Public),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ViewVisibility] = &[
+            ViewVisibility::Private,
+            ViewVisibility::Workspace,
+            ViewVisibility::Public,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ViewVisibility>("ViewVisibility", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ViewVisibility {
+}
+
+impl ::std::default::Default for ViewVisibility {
+    fn default() -> Self {
+        ViewVisibility::Private
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewVisibility {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11view_create.proto\"\xb9\x01\n\x11CreateViewRequest\x12\x20\n\x0cbe\
+    \n\x11view_create.proto\"\xcb\x02\n\x11CreateViewRequest\x12\x20\n\x0cbe\
     long_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x02\x20\
     \x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x1e\n\
     \tthumbnail\x18\x04\x20\x01(\tH\0R\tthumbnail\x12&\n\tview_type\x18\x05\
-    \x20\x01(\x0e2\t.ViewTypeR\x08viewTypeB\x12\n\x10one_of_thumbnail\"\xd8\
-    \x01\n\x10CreateViewParams\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\
-    \nbelongToId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04\
-    desc\x18\x03\x20\x01(\tR\x04desc\x12\x1c\n\tthumbnail\x18\x04\x20\x01(\t\
-    R\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08view\
-    Type\x12\x1b\n\tview_data\x18\x06\x20\x01(\tR\x08viewData\x12\x17\n\x07v\
-    iew_id\x18\x07\x20\x01(\tR\x06viewId\"\x97\x02\n\x04View\x12\x0e\n\x02id\
-    \x18\x01\x20\x01(\tR\x02id\x12\x20\n\x0cbelong_to_id\x18\x02\x20\x01(\tR\
-    \nbelongToId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04\
-    desc\x18\x04\x20\x01(\tR\x04desc\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\
-    \t.ViewTypeR\x08viewType\x12\x18\n\x07version\x18\x06\x20\x01(\x03R\x07v\
-    ersion\x12-\n\nbelongings\x18\x07\x20\x01(\x0b2\r.RepeatedViewR\nbelongi\
-    ngs\x12#\n\rmodified_time\x18\x08\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\
-    \n\x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTime\"+\n\x0cRepeatedView\
-    \x12\x1b\n\x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\x05items*\x1e\n\x08V\
-    iewType\x12\t\n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\x01J\xbf\x0b\n\x06\
-    \x12\x04\0\0#\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\
-    \x04\x02\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\
-    \x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\n\x05\x04\0\
-    \x02\0\x03\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
-    \x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
-    \x04\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\
-    \x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
-    \x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\x0b\
-    \n\x04\x04\0\x08\0\x12\x03\x06\x044\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\
-    \x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\
-    \x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06$\
-    -\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0601\n\x0b\n\x04\x04\0\x02\x04\
-    \x12\x03\x07\x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x07\x04\x0c\n\
-    \x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\x0c\n\x05\x04\0\x02\x04\
-    \x03\x12\x03\x07\x19\x1a\n\n\n\x02\x04\x01\x12\x04\t\0\x11\x01\n\n\n\x03\
-    \x04\x01\x01\x12\x03\t\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x04\
-    \x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\
-    \x02\0\x01\x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x1a\
-    \x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\x14\n\x0c\n\x05\x04\x01\
-    \x02\x01\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\
-    \x0b\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\x12\x13\n\x0b\n\
-    \x04\x04\x01\x02\x02\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\
-    \x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0c\x0b\x0f\n\
-    \x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0c\x12\x13\n\x0b\n\x04\x04\x01\
-    \x02\x03\x12\x03\r\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\r\x04\
-    \n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\r\x0b\x14\n\x0c\n\x05\x04\x01\
-    \x02\x03\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x0e\
-    \x04\x1b\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x0e\x04\x0c\n\x0c\n\x05\
-    \x04\x01\x02\x04\x01\x12\x03\x0e\r\x16\n\x0c\n\x05\x04\x01\x02\x04\x03\
-    \x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x0f\x04\x19\n\
-    \x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x01\x02\
-    \x05\x01\x12\x03\x0f\x0b\x14\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x0f\
-    \x17\x18\n\x0b\n\x04\x04\x01\x02\x06\x12\x03\x10\x04\x17\n\x0c\n\x05\x04\
-    \x01\x02\x06\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x01\x02\x06\x01\x12\
-    \x03\x10\x0b\x12\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03\x10\x15\x16\n\n\
-    \n\x02\x04\x02\x12\x04\x12\0\x1c\x01\n\n\n\x03\x04\x02\x01\x12\x03\x12\
-    \x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x13\x04\x12\n\x0c\n\x05\x04\
-    \x02\x02\0\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
-    \x13\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x13\x10\x11\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03\x14\x04\x1c\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\
-    \x03\x14\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x14\x0b\x17\n\x0c\
-    \n\x05\x04\x02\x02\x01\x03\x12\x03\x14\x1a\x1b\n\x0b\n\x04\x04\x02\x02\
-    \x02\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x15\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x15\x0b\x0f\n\x0c\n\x05\x04\
-    \x02\x02\x02\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\
-    \x16\x04\x14\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x16\x04\n\n\x0c\n\
-    \x05\x04\x02\x02\x03\x01\x12\x03\x16\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x03\
-    \x03\x12\x03\x16\x12\x13\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x17\x04\x1b\
-    \n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x17\x04\x0c\n\x0c\n\x05\x04\x02\
-    \x02\x04\x01\x12\x03\x17\r\x16\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\
-    \x17\x19\x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x18\x04\x16\n\x0c\n\x05\
-    \x04\x02\x02\x05\x05\x12\x03\x18\x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\
-    \x12\x03\x18\n\x11\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x18\x14\x15\n\
-    \x0b\n\x04\x04\x02\x02\x06\x12\x03\x19\x04\x20\n\x0c\n\x05\x04\x02\x02\
-    \x06\x06\x12\x03\x19\x04\x10\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03\x19\
-    \x11\x1b\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\x19\x1e\x1f\n\x0b\n\x04\
-    \x04\x02\x02\x07\x12\x03\x1a\x04\x1c\n\x0c\n\x05\x04\x02\x02\x07\x05\x12\
-    \x03\x1a\x04\t\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03\x1a\n\x17\n\x0c\n\
-    \x05\x04\x02\x02\x07\x03\x12\x03\x1a\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x08\
-    \x12\x03\x1b\x04\x1a\n\x0c\n\x05\x04\x02\x02\x08\x05\x12\x03\x1b\x04\t\n\
-    \x0c\n\x05\x04\x02\x02\x08\x01\x12\x03\x1b\n\x15\n\x0c\n\x05\x04\x02\x02\
-    \x08\x03\x12\x03\x1b\x18\x19\n\n\n\x02\x04\x03\x12\x04\x1d\0\x1f\x01\n\n\
-    \n\x03\x04\x03\x01\x12\x03\x1d\x08\x14\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
-    \x1e\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x1e\x04\x0c\n\x0c\n\
-    \x05\x04\x03\x02\0\x06\x12\x03\x1e\r\x11\n\x0c\n\x05\x04\x03\x02\0\x01\
-    \x12\x03\x1e\x12\x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x1e\x1a\x1b\n\
-    \n\n\x02\x05\0\x12\x04\x20\0#\x01\n\n\n\x03\x05\0\x01\x12\x03\x20\x05\r\
-    \n\x0b\n\x04\x05\0\x02\0\x12\x03!\x04\x0e\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03!\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03!\x0c\r\n\x0b\n\x04\
-    \x05\0\x02\x01\x12\x03\"\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\"\
-    \x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\"\n\x0bb\x06proto3\
+    \x20\x01(\x0e2\t.ViewTypeR\x08viewType\x12#\n\x0cinsert_after\x18\x06\
+    \x20\x01(\tH\x01R\x0binsertAfter\x12\x1c\n\x08template\x18\x07\x20\x01(\
+    \tH\x02R\x08template\x12\x14\n\x04icon\x18\x08\x20\x01(\tH\x03R\x04iconB\
+    \x12\n\x10one_of_thumbnailB\x15\n\x13one_of_insert_afterB\x11\n\x0fone_o\
+    f_templateB\r\n\x0bone_of_icon\"\xd9\x02\n\x10CreateViewParams\x12\x20\n\
+    \x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\
+    \x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\
+    \x12\x1c\n\tthumbnail\x18\x04\x20\x01(\tR\tthumbnail\x12&\n\tview_type\
+    \x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08viewType\x12\x1b\n\tview_data\x18\
+    \x06\x20\x01(\tR\x08viewData\x12\x17\n\x07view_id\x18\x07\x20\x01(\tR\
+    \x06viewId\x12#\n\x0cinsert_after\x18\x08\x20\x01(\tH\0R\x0binsertAfter\
+    \x12\x1c\n\x08template\x18\t\x20\x01(\tH\x01R\x08template\x12\x12\n\x04i\
+    con\x18\n\x20\x01(\tR\x04iconB\x15\n\x13one_of_insert_afterB\x11\n\x0fon\
+    e_of_template\"\x96\x03\n\x04View\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\
+    \x02id\x12\x20\n\x0cbelong_to_id\x18\x02\x20\x01(\tR\nbelongToId\x12\x12\
+    \n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x04\x20\x01\
+    (\tR\x04desc\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08view\
+    Type\x12\x18\n\x07version\x18\x06\x20\x01(\x03R\x07version\x12-\n\nbelon\
+    gings\x18\x07\x20\x01(\x0b2\r.RepeatedViewR\nbelongings\x12#\n\rmodified\
+    _time\x18\x08\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\
+    \t\x20\x01(\x03R\ncreateTime\x12\x19\n\x08owner_id\x18\n\x20\x01(\tR\x07\
+    ownerId\x12/\n\nvisibility\x18\x0b\x20\x01(\x0e2\x0f.ViewVisibilityR\nvi\
+    sibility\x12\x1d\n\ncreated_by\x18\x0c\x20\x01(\tR\tcreatedBy\x12\x12\n\
+    \x04icon\x18\r\x20\x01(\tR\x04icon\"+\n\x0cRepeatedView\x12\x1b\n\x05ite\
+    ms\x18\x01\x20\x03(\x0b2\x05.ViewR\x05items\"S\n\x10CreateViewResult\x12\
+    \x19\n\x04view\x18\x01\x20\x01(\x0b2\x05.ViewR\x04view\x12$\n\x0einitial\
+    _rev_id\x18\x02\x20\x01(\x03R\x0cinitialRevId\"h\n\x11ImportViewRequest\
+    \x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04\
+    name\x18\x02\x20\x01(\tR\x04name\x12\x1d\n\ndelta_json\x18\x03\x20\x01(\
+    \tR\tdeltaJson*\x1e\n\x08ViewType\x12\t\n\x05Blank\x10\0\x12\x07\n\x03Do\
+    c\x10\x01*8\n\x0eViewVisibility\x12\x0b\n\x07Private\x10\0\x12\r\n\tWork\
+    space\x10\x01\x12\n\n\x06Public\x10\x02J\xc2\x14\n\x06\x12\x04\0\0;\x01\
+    \n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x0b\x01\n\
+    \n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\x0b\n\x04\x04\0\x02\0\x12\x03\
+    \x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\
+    \x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\
+    \x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\
+    \x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
+    \x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x12\x13\n\x0b\
+    \n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x02\x05\
+    \x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x0f\n\
+    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\x0b\n\x04\x04\0\x08\0\
+    \x12\x03\x06\x044\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x06\n\x1a\n\x0b\n\
+    \x04\x04\0\x02\x03\x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\x02\x03\x05\x12\
+    \x03\x06\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06$-\n\x0c\n\x05\
+    \x04\0\x02\x03\x03\x12\x03\x0601\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x07\
+    \x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x07\x04\x0c\n\x0c\n\x05\
+    \x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\x0c\n\x05\x04\0\x02\x04\x03\x12\
+    \x03\x07\x19\x1a\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x08\x04:\n\x0c\n\x05\
+    \x04\0\x08\x01\x01\x12\x03\x08\n\x1d\n\x0b\n\x04\x04\0\x02\x05\x12\x03\
+    \x08\x208\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\x20&\n\x0c\n\x05\x04\
+    \0\x02\x05\x01\x12\x03\x08'3\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x0867\
+    \n\x0b\n\x04\x04\0\x08\x02\x12\x03\t\x042\n\x0c\n\x05\x04\0\x08\x02\x01\
+    \x12\x03\t\n\x19\n\x0b\n\x04\x04\0\x02\x06\x12\x03\t\x1c0\n\x0c\n\x05\
+    \x04\0\x02\x06\x05\x12\x03\t\x1c\"\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03\
+    \t#+\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\t./\n\x0b\n\x04\x04\0\x08\x03\
+    \x12\x03\n\x04*\n\x0c\n\x05\x04\0\x08\x03\x01\x12\x03\n\n\x15\n\x0b\n\
+    \x04\x04\0\x02\x07\x12\x03\n\x18(\n\x0c\n\x05\x04\0\x02\x07\x05\x12\x03\
+    \n\x18\x1e\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\n\x1f#\n\x0c\n\x05\x04\
+    \0\x02\x07\x03\x12\x03\n&'\n\n\n\x02\x04\x01\x12\x04\x0c\0\x17\x01\n\n\n\
+    \x03\x04\x01\x01\x12\x03\x0c\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\r\
+    \x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\
+    \x01\x02\0\x01\x12\x03\r\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\r\
+    \x1a\x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x04\
+    \x01\x02\x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\
+    \x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0e\x12\x13\n\
+    \x0b\n\x04\x04\x01\x02\x02\x12\x03\x0f\x04\x14\n\x0c\n\x05\x04\x01\x02\
+    \x02\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0f\
+    \x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0f\x12\x13\n\x0b\n\x04\
+    \x04\x01\x02\x03\x12\x03\x10\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\
+    \x03\x10\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x10\x0b\x14\n\x0c\
+    \n\x05\x04\x01\x02\x03\x03\x12\x03\x10\x17\x18\n\x0b\n\x04\x04\x01\x02\
+    \x04\x12\x03\x11\x04\x1b\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x11\x04\
+    \x0c\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x11\r\x16\n\x0c\n\x05\x04\
+    \x01\x02\x04\x03\x12\x03\x11\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\
+    \x12\x04\x19\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x12\x04\n\n\x0c\n\
+    \x05\x04\x01\x02\x05\x01\x12\x03\x12\x0b\x14\n\x0c\n\x05\x04\x01\x02\x05\
+    \x03\x12\x03\x12\x17\x18\n\x0b\n\x04\x04\x01\x02\x06\x12\x03\x13\x04\x17\
+    \n\x0c\n\x05\x04\x01\x02\x06\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x01\
+    \x02\x06\x01\x12\x03\x13\x0b\x12\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03\
+    \x13\x15\x16\n\x0b\n\x04\x04\x01\x08\0\x12\x03\x14\x04:\n\x0c\n\x05\x04\
+    \x01\x08\0\x01\x12\x03\x14\n\x1d\n\x0b\n\x04\x04\x01\x02\x07\x12\x03\x14\
+    \x208\n\x0c\n\x05\x04\x01\x02\x07\x05\x12\x03\x14\x20&\n\x0c\n\x05\x04\
+    \x01\x02\x07\x01\x12\x03\x14'3\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x03\
+    \x1467\n\x0b\n\x04\x04\x01\x08\x01\x12\x03\x15\x042\n\x0c\n\x05\x04\x01\
+    \x08\x01\x01\x12\x03\x15\n\x19\n\x0b\n\x04\x04\x01\x02\x08\x12\x03\x15\
+    \x1c0\n\x0c\n\x05\x04\x01\x02\x08\x05\x12\x03\x15\x1c\"\n\x0c\n\x05\x04\
+    \x01\x02\x08\x01\x12\x03\x15#+\n\x0c\n\x05\x04\x01\x02\x08\x03\x12\x03\
+    \x15./\n\x0b\n\x04\x04\x01\x02\t\x12\x03\x16\x04\x15\n\x0c\n\x05\x04\x01\
+    \x02\t\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x01\x02\t\x01\x12\x03\x16\
+    \x0b\x0f\n\x0c\n\x05\x04\x01\x02\t\x03\x12\x03\x16\x12\x14\n\n\n\x02\x04\
+    \x02\x12\x04\x18\0&\x01\n\n\n\x03\x04\x02\x01\x12\x03\x18\x08\x0c\n\x0b\
+    \n\x04\x04\x02\x02\0\x12\x03\x19\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\
+    \x12\x03\x19\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x19\x0b\r\n\x0c\
+    \n\x05\x04\x02\x02\0\x03\x12\x03\x19\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\
+    \x12\x03\x1a\x04\x1c\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x1a\x04\n\n\
+    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x1a\x0b\x17\n\x0c\n\x05\x04\x02\
+    \x02\x01\x03\x12\x03\x1a\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x1b\
+    \x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\x02\x01\x12\x03\x1b\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\
+    \x12\x03\x1b\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x1c\x04\x14\n\
+    \x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x02\x02\
+    \x03\x01\x12\x03\x1c\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x1c\
+    \x12\x13\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x1d\x04\x1b\n\x0c\n\x05\x04\
+    \x02\x02\x04\x06\x12\x03\x1d\x04\x0c\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\
+    \x03\x1d\r\x16\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x1d\x19\x1a\n\x0b\
+    \n\x04\x04\x02\x02\x05\x12\x03\x1e\x04\x16\n\x0c\n\x05\x04\x02\x02\x05\
+    \x05\x12\x03\x1e\x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03\x1e\n\x11\
+    \n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x1e\x14\x15\n\x0b\n\x04\x04\x02\
+    \x02\x06\x12\x03\x1f\x04\x20\n\x0c\n\x05\x04\x02\x02\x06\x06\x12\x03\x1f\
+    \x04\x10\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03\x1f\x11\x1b\n\x0c\n\x05\
+    \x04\x02\x02\x06\x03\x12\x03\x1f\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x07\x12\
+    \x03\x20\x04\x1c\n\x0c\n\x05\x04\x02\x02\x07\x05\x12\x03\x20\x04\t\n\x0c\
+    \n\x05\x04\x02\x02\x07\x01\x12\x03\x20\n\x17\n\x0c\n\x05\x04\x02\x02\x07\
+    \x03\x12\x03\x20\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x08\x12\x03!\x04\x1a\n\
+    \x0c\n\x05\x04\x02\x02\x08\x05\x12\x03!\x04\t\n\x0c\n\x05\x04\x02\x02\
+    \x08\x01\x12\x03!\n\x15\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\x03!\x18\x19\
+    \n\x0b\n\x04\x04\x02\x02\t\x12\x03\"\x04\x19\n\x0c\n\x05\x04\x02\x02\t\
+    \x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x02\x02\t\x01\x12\x03\"\x0b\x13\n\
+    \x0c\n\x05\x04\x02\x02\t\x03\x12\x03\"\x16\x18\n\x0b\n\x04\x04\x02\x02\n\
+    \x12\x03#\x04#\n\x0c\n\x05\x04\x02\x02\n\x06\x12\x03#\x04\x12\n\x0c\n\
+    \x05\x04\x02\x02\n\x01\x12\x03#\x13\x1d\n\x0c\n\x05\x04\x02\x02\n\x03\
+    \x12\x03#\x20\"\n\x0b\n\x04\x04\x02\x02\x0b\x12\x03$\x04\x1b\n\x0c\n\x05\
+    \x04\x02\x02\x0b\x05\x12\x03$\x04\n\n\x0c\n\x05\x04\x02\x02\x0b\x01\x12\
+    \x03$\x0b\x15\n\x0c\n\x05\x04\x02\x02\x0b\x03\x12\x03$\x18\x1a\n\x0b\n\
+    \x04\x04\x02\x02\x0c\x12\x03%\x04\x15\n\x0c\n\x05\x04\x02\x02\x0c\x05\
+    \x12\x03%\x04\n\n\x0c\n\x05\x04\x02\x02\x0c\x01\x12\x03%\x0b\x0f\n\x0c\n\
+    \x05\x04\x02\x02\x0c\x03\x12\x03%\x12\x14\n\n\n\x02\x04\x03\x12\x04'\0)\
+    \x01\n\n\n\x03\x04\x03\x01\x12\x03'\x08\x14\n\x0b\n\x04\x04\x03\x02\0\
+    \x12\x03(\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03(\x04\x0c\n\x0c\n\
+    \x05\x04\x03\x02\0\x06\x12\x03(\r\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
+    \x03(\x12\x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03(\x1a\x1b\n\n\n\x02\
+    \x04\x04\x12\x04*\0-\x01\n\n\n\x03\x04\x04\x01\x12\x03*\x08\x18\n\x0b\n\
+    \x04\x04\x04\x02\0\x12\x03+\x04\x12\n\x0c\n\x05\x04\x04\x02\0\x06\x12\
+    \x03+\x04\x08\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03+\t\r\n\x0c\n\x05\x04\
+    \x04\x02\0\x03\x12\x03+\x10\x11\n\x0b\n\x04\x04\x04\x02\x01\x12\x03,\x04\
+    \x1d\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03,\x04\t\n\x0c\n\x05\x04\x04\
+    \x02\x01\x01\x12\x03,\n\x18\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03,\x1b\
+    \x1c\n\n\n\x02\x04\x05\x12\x04.\02\x01\n\n\n\x03\x04\x05\x01\x12\x03.\
+    \x08\x19\n\x0b\n\x04\x04\x05\x02\0\x12\x03/\x04\x1c\n\x0c\n\x05\x04\x05\
+    \x02\0\x05\x12\x03/\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03/\x0b\x17\
+    \n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03/\x1a\x1b\n\x0b\n\x04\x04\x05\x02\
+    \x01\x12\x030\x04\x14\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x030\x04\n\n\
+    \x0c\n\x05\x04\x05\x02\x01\x01\x12\x030\x0b\x0f\n\x0c\n\x05\x04\x05\x02\
+    \x01\x03\x12\x030\x12\x13\n\x0b\n\x04\x04\x05\x02\x02\x12\x031\x04\x1a\n\
+    \x0c\n\x05\x04\x05\x02\x02\x05\x12\x031\x04\n\n\x0c\n\x05\x04\x05\x02\
+    \x02\x01\x12\x031\x0b\x15\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x031\x18\
+    \x19\n\n\n\x02\x05\0\x12\x043\06\x01\n\n\n\x03\x05\0\x01\x12\x033\x05\r\
+    \n\x0b\n\x04\x05\0\x02\0\x12\x034\x04\x0e\n\x0c\n\x05\x05\0\x02\0\x01\
+    \x12\x034\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x034\x0c\r\n\x0b\n\x04\
+    \x05\0\x02\x01\x12\x035\x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x035\
+    \x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x035\n\x0b\n\n\n\x02\x05\x01\
+    \x12\x047\0;\x01\n\n\n\x03\x05\x01\x01\x12\x037\x05\x13\n\x0b\n\x04\x05\
+    \x01\x02\0\x12\x038\x04\x10\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x038\x04\
+    \x0b\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x038\x0e\x0f\n\x0b\n\x04\x05\x01\
+    \x02\x01\x12\x039\x04\x12\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x039\x04\r\
+    \n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x039\x10\x11\n\x0b\n\x04\x05\x01\
+    \x02\x02\x12\x03:\x04\x0f\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03:\x04\n\
+    \n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03:\r\x0eb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
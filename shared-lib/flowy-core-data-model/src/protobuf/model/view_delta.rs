@@ -0,0 +1,925 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_delta.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AppViewsDelta {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    pub added: ::protobuf::RepeatedField<super::view_create::View>,
+    pub updated: ::protobuf::RepeatedField<super::view_create::View>,
+    pub removed: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AppViewsDelta {
+    fn default() -> &'a AppViewsDelta {
+        <AppViewsDelta as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppViewsDelta {
+    pub fn new() -> AppViewsDelta {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // repeated .View added = 2;
+
+
+    pub fn get_added(&self) -> &[super::view_create::View] {
+        &self.added
+    }
+    pub fn clear_added(&mut self) {
+        self.added.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_added(&mut self, v: ::protobuf::RepeatedField<super::view_create::View>) {
+        self.added = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_added(&mut self) -> &mut ::protobuf::RepeatedField<super::view_create::View> {
+        &mut self.added
+    }
+
+    // Take field
+    pub fn take_added(&mut self) -> ::protobuf::RepeatedField<super::view_create::View> {
+        ::std::mem::replace(&mut self.added, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .View updated = 3;
+
+
+    pub fn get_updated(&self) -> &[super::view_create::View] {
+        &self.updated
+    }
+    pub fn clear_updated(&mut self) {
+        self.updated.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated(&mut self, v: ::protobuf::RepeatedField<super::view_create::View>) {
+        self.updated = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_updated(&mut self) -> &mut ::protobuf::RepeatedField<super::view_create::View> {
+        &mut self.updated
+    }
+
+    // Take field
+    pub fn take_updated(&mut self) -> ::protobuf::RepeatedField<super::view_create::View> {
+        ::std::mem::replace(&mut self.updated, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string removed = 4;
+
+
+    pub fn get_removed(&self) -> &[::std::string::String] {
+        &self.removed
+    }
+    pub fn clear_removed(&mut self) {
+        self.removed.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_removed(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.removed = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_removed(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.removed
+    }
+
+    // Take field
+    pub fn take_removed(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.removed, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for AppViewsDelta {
+    fn is_initialized(&self) -> bool {
+        for v in &self.added {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.updated {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.added)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.updated)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.removed)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        for value in &self.added {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.updated {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.removed {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        for v in &self.added {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.updated {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.removed {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AppViewsDelta {
+        AppViewsDelta::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &AppViewsDelta| { &m.belong_to_id },
+                |m: &mut AppViewsDelta| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::View>>(
+                "added",
+                |m: &AppViewsDelta| { &m.added },
+                |m: &mut AppViewsDelta| { &mut m.added },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::View>>(
+                "updated",
+                |m: &AppViewsDelta| { &m.updated },
+                |m: &mut AppViewsDelta| { &mut m.updated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "removed",
+                |m: &AppViewsDelta| { &m.removed },
+                |m: &mut AppViewsDelta| { &mut m.removed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppViewsDelta>(
+                "AppViewsDelta",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AppViewsDelta {
+        static instance: ::protobuf::rt::LazyV2<AppViewsDelta> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AppViewsDelta::new)
+    }
+}
+
+impl ::protobuf::Clear for AppViewsDelta {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.added.clear();
+        self.updated.clear();
+        self.removed.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AppViewsDelta {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppViewsDelta {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AppViews {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    pub views: ::protobuf::SingularPtrField<super::view_create::RepeatedView>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AppViews {
+    fn default() -> &'a AppViews {
+        <AppViews as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AppViews {
+    pub fn new() -> AppViews {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // .RepeatedView views = 2;
+
+
+    pub fn get_views(&self) -> &super::view_create::RepeatedView {
+        self.views.as_ref().unwrap_or_else(|| <super::view_create::RepeatedView as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_views(&mut self) {
+        self.views.clear();
+    }
+
+    pub fn has_views(&self) -> bool {
+        self.views.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_views(&mut self, v: super::view_create::RepeatedView) {
+        self.views = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_views(&mut self) -> &mut super::view_create::RepeatedView {
+        if self.views.is_none() {
+            self.views.set_default();
+        }
+        self.views.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_views(&mut self) -> super::view_create::RepeatedView {
+        self.views.take().unwrap_or_else(|| super::view_create::RepeatedView::new())
+    }
+}
+
+impl ::protobuf::Message for AppViews {
+    fn is_initialized(&self) -> bool {
+        for v in &self.views {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.views)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        if let Some(ref v) = self.views.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AppViews {
+        AppViews::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &AppViews| { &m.belong_to_id },
+                |m: &mut AppViews| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::RepeatedView>>(
+                "views",
+                |m: &AppViews| { &m.views },
+                |m: &mut AppViews| { &mut m.views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AppViews>(
+                "AppViews",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AppViews {
+        static instance: ::protobuf::rt::LazyV2<AppViews> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AppViews::new)
+    }
+}
+
+impl ::protobuf::Clear for AppViews {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.views.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AppViews {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AppViews {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedAppViews {
+    // message fields
+    pub items: ::protobuf::RepeatedField<AppViews>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedAppViews {
+    fn default() -> &'a RepeatedAppViews {
+        <RepeatedAppViews as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedAppViews {
+    pub fn new() -> RepeatedAppViews {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AppViews items = 1;
+
+
+    pub fn get_items(&self) -> &[AppViews] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<AppViews>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<AppViews> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<AppViews> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedAppViews {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedAppViews {
+        RepeatedAppViews::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AppViews>>(
+                "items",
+                |m: &RepeatedAppViews| { &m.items },
+                |m: &mut RepeatedAppViews| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedAppViews>(
+                "RepeatedAppViews",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedAppViews {
+        static instance: ::protobuf::rt::LazyV2<RepeatedAppViews> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedAppViews::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedAppViews {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedAppViews {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedAppViews {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedAppViewsDelta {
+    // message fields
+    pub items: ::protobuf::RepeatedField<AppViewsDelta>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedAppViewsDelta {
+    fn default() -> &'a RepeatedAppViewsDelta {
+        <RepeatedAppViewsDelta as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedAppViewsDelta {
+    pub fn new() -> RepeatedAppViewsDelta {
+        ::std::default::Default::default()
+    }
+
+    // repeated .AppViewsDelta items = 1;
+
+
+    pub fn get_items(&self) -> &[AppViewsDelta] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<AppViewsDelta>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<AppViewsDelta> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<AppViewsDelta> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedAppViewsDelta {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedAppViewsDelta {
+        RepeatedAppViewsDelta::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AppViewsDelta>>(
+                "items",
+                |m: &RepeatedAppViewsDelta| { &m.items },
+                |m: &mut RepeatedAppViewsDelta| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedAppViewsDelta>(
+                "RepeatedAppViewsDelta",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedAppViewsDelta {
+        static instance: ::protobuf::rt::LazyV2<RepeatedAppViewsDelta> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedAppViewsDelta::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedAppViewsDelta {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedAppViewsDelta {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedAppViewsDelta {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10view_delta.proto\x1a\x11view_create.proto\"\x89\x01\n\rAppViewsDel\
+    ta\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x1b\n\
+    \x05added\x18\x02\x20\x03(\x0b2\x05.ViewR\x05added\x12\x1f\n\x07updated\
+    \x18\x03\x20\x03(\x0b2\x05.ViewR\x07updated\x12\x18\n\x07removed\x18\x04\
+    \x20\x03(\tR\x07removed\"Q\n\x08AppViews\x12\x20\n\x0cbelong_to_id\x18\
+    \x01\x20\x01(\tR\nbelongToId\x12#\n\x05views\x18\x02\x20\x01(\x0b2\r.Rep\
+    eatedViewR\x05views\"3\n\x10RepeatedAppViews\x12\x1f\n\x05items\x18\x01\
+    \x20\x03(\x0b2\t.AppViewsR\x05items\"=\n\x15RepeatedAppViewsDelta\x12$\n\
+    \x05items\x18\x01\x20\x03(\x0b2\x0e.AppViewsDeltaR\x05itemsJ\xfb\x04\n\
+    \x06\x12\x04\0\0\x12\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\
+    \x12\x03\x01\0\x1b\n\n\n\x02\x04\0\x12\x04\x03\0\x08\x01\n\n\n\x03\x04\0\
+    \x01\x12\x03\x03\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x1c\n\
+    \x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\
+    \x12\x03\x04\x0b\x17\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x1a\x1b\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x1c\n\x0c\n\x05\x04\0\x02\x01\
+    \x04\x12\x03\x05\x04\x0c\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x05\r\x11\
+    \n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x12\x17\n\x0c\n\x05\x04\0\x02\
+    \x01\x03\x12\x03\x05\x1a\x1b\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x06\x04\
+    \x1e\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\x06\x04\x0c\n\x0c\n\x05\x04\0\
+    \x02\x02\x06\x12\x03\x06\r\x11\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\
+    \x12\x19\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x06\x1c\x1d\n\x0b\n\x04\
+    \x04\0\x02\x03\x12\x03\x07\x04\x20\n\x0c\n\x05\x04\0\x02\x03\x04\x12\x03\
+    \x07\x04\x0c\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x07\r\x13\n\x0c\n\x05\
+    \x04\0\x02\x03\x01\x12\x03\x07\x14\x1b\n\x0c\n\x05\x04\0\x02\x03\x03\x12\
+    \x03\x07\x1e\x1f\n\n\n\x02\x04\x01\x12\x04\t\0\x0c\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\t\x08\x10\n\x0b\n\x04\x04\x01\x02\0\x12\x03\n\x04\x1c\n\x0c\
+    \n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\n\x1a\x1b\n\x0b\
+    \n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\x1b\n\x0c\n\x05\x04\x01\x02\x01\
+    \x06\x12\x03\x0b\x04\x10\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0b\x11\
+    \x16\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\x19\x1a\n\n\n\x02\x04\
+    \x02\x12\x04\r\0\x0f\x01\n\n\n\x03\x04\x02\x01\x12\x03\r\x08\x18\n\x0b\n\
+    \x04\x04\x02\x02\0\x12\x03\x0e\x04\x20\n\x0c\n\x05\x04\x02\x02\0\x04\x12\
+    \x03\x0e\x04\x0c\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x0e\r\x15\n\x0c\n\
+    \x05\x04\x02\x02\0\x01\x12\x03\x0e\x16\x1b\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03\x0e\x1e\x1f\n\n\n\x02\x04\x03\x12\x04\x10\0\x12\x01\n\n\n\x03\
+    \x04\x03\x01\x12\x03\x10\x08\x1d\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x11\
+    \x04%\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x11\x04\x0c\n\x0c\n\x05\x04\
+    \x03\x02\0\x06\x12\x03\x11\r\x1a\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\
+    \x11\x1b\x20\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x11#$b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,400 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_merge.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MergeLocalRequest {
+    // message fields
+    pub target_user_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeLocalRequest {
+    fn default() -> &'a MergeLocalRequest {
+        <MergeLocalRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeLocalRequest {
+    pub fn new() -> MergeLocalRequest {
+        ::std::default::Default::default()
+    }
+
+    // string target_user_id = 1;
+
+
+    pub fn get_target_user_id(&self) -> &str {
+        &self.target_user_id
+    }
+    pub fn clear_target_user_id(&mut self) {
+        self.target_user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_user_id(&mut self, v: ::std::string::String) {
+        self.target_user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_user_id
+    }
+
+    // Take field
+    pub fn take_target_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_user_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for MergeLocalRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_user_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.target_user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.target_user_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.target_user_id.is_empty() {
+            os.write_string(1, &self.target_user_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeLocalRequest {
+        MergeLocalRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_user_id",
+                |m: &MergeLocalRequest| { &m.target_user_id },
+                |m: &mut MergeLocalRequest| { &mut m.target_user_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MergeLocalRequest>(
+                "MergeLocalRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MergeLocalRequest {
+        static instance: ::protobuf::rt::LazyV2<MergeLocalRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MergeLocalRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MergeLocalRequest {
+    fn clear(&mut self) {
+        self.target_user_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeLocalRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeLocalRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MergeReport {
+    // message fields
+    pub merged_apps: i64,
+    pub merged_views: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeReport {
+    fn default() -> &'a MergeReport {
+        <MergeReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeReport {
+    pub fn new() -> MergeReport {
+        ::std::default::Default::default()
+    }
+
+    // int64 merged_apps = 1;
+
+
+    pub fn get_merged_apps(&self) -> i64 {
+        self.merged_apps
+    }
+    pub fn clear_merged_apps(&mut self) {
+        self.merged_apps = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merged_apps(&mut self, v: i64) {
+        self.merged_apps = v;
+    }
+
+    // int64 merged_views = 2;
+
+
+    pub fn get_merged_views(&self) -> i64 {
+        self.merged_views
+    }
+    pub fn clear_merged_views(&mut self) {
+        self.merged_views = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_merged_views(&mut self, v: i64) {
+        self.merged_views = v;
+    }
+}
+
+impl ::protobuf::Message for MergeReport {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.merged_apps = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.merged_views = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.merged_apps != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.merged_apps, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.merged_views != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.merged_views, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.merged_apps != 0 {
+            os.write_int64(1, self.merged_apps)?;
+        }
+        if self.merged_views != 0 {
+            os.write_int64(2, self.merged_views)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeReport {
+        MergeReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "merged_apps",
+                |m: &MergeReport| { &m.merged_apps },
+                |m: &mut MergeReport| { &mut m.merged_apps },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "merged_views",
+                |m: &MergeReport| { &m.merged_views },
+                |m: &mut MergeReport| { &mut m.merged_views },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MergeReport>(
+                "MergeReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MergeReport {
+        static instance: ::protobuf::rt::LazyV2<MergeReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MergeReport::new)
+    }
+}
+
+impl ::protobuf::Clear for MergeReport {
+    fn clear(&mut self) {
+        self.merged_apps = 0;
+        self.merged_views = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10view_merge.proto\"9\n\x11MergeLocalRequest\x12$\n\x0etarget_user_i\
+    d\x18\x01\x20\x01(\tR\x0ctargetUserId\"Q\n\x0bMergeReport\x12\x1f\n\x0bm\
+    erged_apps\x18\x01\x20\x01(\x03R\nmergedApps\x12!\n\x0cmerged_views\x18\
+    \x02\x20\x01(\x03R\x0bmergedViewsJ\xe7\x01\n\x06\x12\x04\0\0\x08\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\
+    \n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
+    \x04\x1e\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\
+    \x02\0\x01\x12\x03\x03\x0b\x19\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\
+    \x1c\x1d\n\n\n\x02\x04\x01\x12\x04\x05\0\x08\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03\x05\x08\x13\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\x04\x1a\n\x0c\
+    \n\x05\x04\x01\x02\0\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x06\n\x15\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x06\x18\x19\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\x07\x04\x1b\n\x0c\n\x05\x04\x01\x02\
+    \x01\x05\x12\x03\x07\x04\t\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x07\n\
+    \x16\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x07\x19\x1ab\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -340,53 +340,1699 @@ impl ::protobuf::reflect::ProtobufValue for ViewId {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryViewsBelongToRequest {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    pub view_type: super::view_create::ViewType,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryViewsBelongToRequest {
+    fn default() -> &'a QueryViewsBelongToRequest {
+        <QueryViewsBelongToRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryViewsBelongToRequest {
+    pub fn new() -> QueryViewsBelongToRequest {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // .ViewType view_type = 2;
+
+
+    pub fn get_view_type(&self) -> super::view_create::ViewType {
+        self.view_type
+    }
+    pub fn clear_view_type(&mut self) {
+        self.view_type = super::view_create::ViewType::Blank;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_type(&mut self, v: super::view_create::ViewType) {
+        self.view_type = v;
+    }
+}
+
+impl ::protobuf::Message for QueryViewsBelongToRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.view_type, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        if self.view_type != super::view_create::ViewType::Blank {
+            my_size += ::protobuf::rt::enum_size(2, self.view_type);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        if self.view_type != super::view_create::ViewType::Blank {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.view_type))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryViewsBelongToRequest {
+        QueryViewsBelongToRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &QueryViewsBelongToRequest| { &m.belong_to_id },
+                |m: &mut QueryViewsBelongToRequest| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::view_create::ViewType>>(
+                "view_type",
+                |m: &QueryViewsBelongToRequest| { &m.view_type },
+                |m: &mut QueryViewsBelongToRequest| { &mut m.view_type },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryViewsBelongToRequest>(
+                "QueryViewsBelongToRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryViewsBelongToRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryViewsBelongToRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryViewsBelongToRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryViewsBelongToRequest {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.view_type = super::view_create::ViewType::Blank;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryViewsBelongToRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryViewsBelongToRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryViewsPageRequest {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    pub offset: i64,
+    pub limit: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryViewsPageRequest {
+    fn default() -> &'a QueryViewsPageRequest {
+        <QueryViewsPageRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryViewsPageRequest {
+    pub fn new() -> QueryViewsPageRequest {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // int64 offset = 2;
+
+
+    pub fn get_offset(&self) -> i64 {
+        self.offset
+    }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
+    }
+
+    // int64 limit = 3;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
+    }
+}
+
+impl ::protobuf::Message for QueryViewsPageRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(2, self.offset)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(3, self.limit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryViewsPageRequest {
+        QueryViewsPageRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &QueryViewsPageRequest| { &m.belong_to_id },
+                |m: &mut QueryViewsPageRequest| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "offset",
+                |m: &QueryViewsPageRequest| { &m.offset },
+                |m: &mut QueryViewsPageRequest| { &mut m.offset },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &QueryViewsPageRequest| { &m.limit },
+                |m: &mut QueryViewsPageRequest| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryViewsPageRequest>(
+                "QueryViewsPageRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryViewsPageRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryViewsPageRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryViewsPageRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryViewsPageRequest {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.offset = 0;
+        self.limit = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryViewsPageRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryViewsPageRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewsPage {
+    // message fields
+    pub items: ::protobuf::SingularPtrField<super::view_create::RepeatedView>,
+    pub total: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewsPage {
+    fn default() -> &'a ViewsPage {
+        <ViewsPage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ViewsPage {
+    pub fn new() -> ViewsPage {
+        ::std::default::Default::default()
+    }
+
+    // .RepeatedView items = 1;
+
+
+    pub fn get_items(&self) -> &super::view_create::RepeatedView {
+        self.items.as_ref().unwrap_or_else(|| <super::view_create::RepeatedView as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    pub fn has_items(&self) -> bool {
+        self.items.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: super::view_create::RepeatedView) {
+        self.items = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_items(&mut self) -> &mut super::view_create::RepeatedView {
+        if self.items.is_none() {
+            self.items.set_default();
+        }
+        self.items.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> super::view_create::RepeatedView {
+        self.items.take().unwrap_or_else(|| super::view_create::RepeatedView::new())
+    }
+
+    // int64 total = 2;
+
+
+    pub fn get_total(&self) -> i64 {
+        self.total
+    }
+    pub fn clear_total(&mut self) {
+        self.total = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: i64) {
+        self.total = v;
+    }
+}
+
+impl ::protobuf::Message for ViewsPage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.items)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.total = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.items.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.total, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.items.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.total != 0 {
+            os.write_int64(2, self.total)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewsPage {
+        ViewsPage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::view_create::RepeatedView>>(
+                "items",
+                |m: &ViewsPage| { &m.items },
+                |m: &mut ViewsPage| { &mut m.items },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "total",
+                |m: &ViewsPage| { &m.total },
+                |m: &mut ViewsPage| { &mut m.total },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewsPage>(
+                "ViewsPage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewsPage {
+        static instance: ::protobuf::rt::LazyV2<ViewsPage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewsPage::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewsPage {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.total = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewsPage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewsPage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryViewsCountRequest {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryViewsCountRequest {
+    fn default() -> &'a QueryViewsCountRequest {
+        <QueryViewsCountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryViewsCountRequest {
+    pub fn new() -> QueryViewsCountRequest {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryViewsCountRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryViewsCountRequest {
+        QueryViewsCountRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &QueryViewsCountRequest| { &m.belong_to_id },
+                |m: &mut QueryViewsCountRequest| { &mut m.belong_to_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryViewsCountRequest>(
+                "QueryViewsCountRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QueryViewsCountRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryViewsCountRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryViewsCountRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for QueryViewsCountRequest {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryViewsCountRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryViewsCountRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ViewCount {
+    // message fields
+    pub belong_to_id: ::std::string::String,
+    pub count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ViewCount {
+    fn default() -> &'a ViewCount {
+        <ViewCount as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ViewCount {
+    pub fn new() -> ViewCount {
+        ::std::default::Default::default()
+    }
+
+    // string belong_to_id = 1;
+
+
+    pub fn get_belong_to_id(&self) -> &str {
+        &self.belong_to_id
+    }
+    pub fn clear_belong_to_id(&mut self) {
+        self.belong_to_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_belong_to_id(&mut self, v: ::std::string::String) {
+        self.belong_to_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_belong_to_id(&mut self) -> &mut ::std::string::String {
+        &mut self.belong_to_id
+    }
+
+    // Take field
+    pub fn take_belong_to_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.belong_to_id, ::std::string::String::new())
+    }
+
+    // int64 count = 2;
+
+
+    pub fn get_count(&self) -> i64 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: i64) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for ViewCount {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.belong_to_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.belong_to_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.belong_to_id);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.belong_to_id.is_empty() {
+            os.write_string(1, &self.belong_to_id)?;
+        }
+        if self.count != 0 {
+            os.write_int64(2, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ViewCount {
+        ViewCount::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "belong_to_id",
+                |m: &ViewCount| { &m.belong_to_id },
+                |m: &mut ViewCount| { &mut m.belong_to_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "count",
+                |m: &ViewCount| { &m.count },
+                |m: &mut ViewCount| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ViewCount>(
+                "ViewCount",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ViewCount {
+        static instance: ::protobuf::rt::LazyV2<ViewCount> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ViewCount::new)
+    }
+}
+
+impl ::protobuf::Clear for ViewCount {
+    fn clear(&mut self) {
+        self.belong_to_id.clear();
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ViewCount {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ViewCount {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedViewCount {
+    // message fields
+    pub items: ::protobuf::RepeatedField<ViewCount>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedViewCount {
+    fn default() -> &'a RepeatedViewCount {
+        <RepeatedViewCount as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedViewCount {
+    pub fn new() -> RepeatedViewCount {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ViewCount items = 1;
+
+
+    pub fn get_items(&self) -> &[ViewCount] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<ViewCount>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<ViewCount> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<ViewCount> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedViewCount {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedViewCount {
+        RepeatedViewCount::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ViewCount>>(
+                "items",
+                |m: &RepeatedViewCount| { &m.items },
+                |m: &mut RepeatedViewCount| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedViewCount>(
+                "RepeatedViewCount",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedViewCount {
+        static instance: ::protobuf::rt::LazyV2<RepeatedViewCount> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedViewCount::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedViewCount {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedViewCount {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedViewCount {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DuplicateViews {
+    // message fields
+    pub view_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DuplicateViews {
+    fn default() -> &'a DuplicateViews {
+        <DuplicateViews as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DuplicateViews {
+    pub fn new() -> DuplicateViews {
+        ::std::default::Default::default()
+    }
+
+    // repeated string view_ids = 1;
+
+
+    pub fn get_view_ids(&self) -> &[::std::string::String] {
+        &self.view_ids
+    }
+    pub fn clear_view_ids(&mut self) {
+        self.view_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.view_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_view_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.view_ids
+    }
+
+    // Take field
+    pub fn take_view_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.view_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DuplicateViews {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.view_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.view_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.view_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DuplicateViews {
+        DuplicateViews::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_ids",
+                |m: &DuplicateViews| { &m.view_ids },
+                |m: &mut DuplicateViews| { &mut m.view_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DuplicateViews>(
+                "DuplicateViews",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DuplicateViews {
+        static instance: ::protobuf::rt::LazyV2<DuplicateViews> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DuplicateViews::new)
+    }
+}
+
+impl ::protobuf::Clear for DuplicateViews {
+    fn clear(&mut self) {
+        self.view_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DuplicateViews {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DuplicateViews {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedDuplicateViews {
+    // message fields
+    pub items: ::protobuf::RepeatedField<DuplicateViews>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedDuplicateViews {
+    fn default() -> &'a RepeatedDuplicateViews {
+        <RepeatedDuplicateViews as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedDuplicateViews {
+    pub fn new() -> RepeatedDuplicateViews {
+        ::std::default::Default::default()
+    }
+
+    // repeated .DuplicateViews items = 1;
+
+
+    pub fn get_items(&self) -> &[DuplicateViews] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<DuplicateViews>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<DuplicateViews> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<DuplicateViews> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedDuplicateViews {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedDuplicateViews {
+        RepeatedDuplicateViews::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DuplicateViews>>(
+                "items",
+                |m: &RepeatedDuplicateViews| { &m.items },
+                |m: &mut RepeatedDuplicateViews| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedDuplicateViews>(
+                "RepeatedDuplicateViews",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedDuplicateViews {
+        static instance: ::protobuf::rt::LazyV2<RepeatedDuplicateViews> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedDuplicateViews::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedDuplicateViews {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedDuplicateViews {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedDuplicateViews {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct RepeatedViewId {
     // message fields
-    pub items: ::protobuf::RepeatedField<::std::string::String>,
+    pub items: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedViewId {
+    fn default() -> &'a RepeatedViewId {
+        <RepeatedViewId as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedViewId {
+    pub fn new() -> RepeatedViewId {
+        ::std::default::Default::default()
+    }
+
+    // repeated string items = 1;
+
+
+    pub fn get_items(&self) -> &[::std::string::String] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedViewId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedViewId {
+        RepeatedViewId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "items",
+                |m: &RepeatedViewId| { &m.items },
+                |m: &mut RepeatedViewId| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedViewId>(
+                "RepeatedViewId",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedViewId {
+        static instance: ::protobuf::rt::LazyV2<RepeatedViewId> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedViewId::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedViewId {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedViewId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedViewId {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryDocumentHistoryRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub limit: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RepeatedViewId {
-    fn default() -> &'a RepeatedViewId {
-        <RepeatedViewId as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryDocumentHistoryRequest {
+    fn default() -> &'a QueryDocumentHistoryRequest {
+        <QueryDocumentHistoryRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RepeatedViewId {
-    pub fn new() -> RepeatedViewId {
+impl QueryDocumentHistoryRequest {
+    pub fn new() -> QueryDocumentHistoryRequest {
         ::std::default::Default::default()
     }
 
-    // repeated string items = 1;
+    // string doc_id = 1;
 
 
-    pub fn get_items(&self) -> &[::std::string::String] {
-        &self.items
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
     }
-    pub fn clear_items(&mut self) {
-        self.items.clear();
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.items = v;
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.items
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
     }
 
     // Take field
-    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 limit = 2;
+
+
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: i64) {
+        self.limit = v;
     }
 }
 
-impl ::protobuf::Message for RepeatedViewId {
+impl ::protobuf::Message for QueryDocumentHistoryRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -396,7 +2042,14 @@ impl ::protobuf::Message for RepeatedViewId {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.items)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.limit = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -410,18 +2063,24 @@ impl ::protobuf::Message for RepeatedViewId {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.items {
-            my_size += ::protobuf::rt::string_size(1, &value);
-        };
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.items {
-            os.write_string(1, &v)?;
-        };
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.limit != 0 {
+            os.write_int64(2, self.limit)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -452,71 +2111,347 @@ impl ::protobuf::Message for RepeatedViewId {
         Self::descriptor_static()
     }
 
-    fn new() -> RepeatedViewId {
-        RepeatedViewId::new()
+    fn new() -> QueryDocumentHistoryRequest {
+        QueryDocumentHistoryRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "items",
-                |m: &RepeatedViewId| { &m.items },
-                |m: &mut RepeatedViewId| { &mut m.items },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &QueryDocumentHistoryRequest| { &m.doc_id },
+                |m: &mut QueryDocumentHistoryRequest| { &mut m.doc_id },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedViewId>(
-                "RepeatedViewId",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "limit",
+                |m: &QueryDocumentHistoryRequest| { &m.limit },
+                |m: &mut QueryDocumentHistoryRequest| { &mut m.limit },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QueryDocumentHistoryRequest>(
+                "QueryDocumentHistoryRequest",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static RepeatedViewId {
-        static instance: ::protobuf::rt::LazyV2<RepeatedViewId> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RepeatedViewId::new)
+    fn default_instance() -> &'static QueryDocumentHistoryRequest {
+        static instance: ::protobuf::rt::LazyV2<QueryDocumentHistoryRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QueryDocumentHistoryRequest::new)
     }
 }
 
-impl ::protobuf::Clear for RepeatedViewId {
+impl ::protobuf::Clear for QueryDocumentHistoryRequest {
     fn clear(&mut self) {
-        self.items.clear();
+        self.doc_id.clear();
+        self.limit = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RepeatedViewId {
+impl ::std::fmt::Debug for QueryDocumentHistoryRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RepeatedViewId {
+impl ::protobuf::reflect::ProtobufValue for QueryDocumentHistoryRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RestoreDocumentRequest {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub rev_id: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RestoreDocumentRequest {
+    fn default() -> &'a RestoreDocumentRequest {
+        <RestoreDocumentRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RestoreDocumentRequest {
+    pub fn new() -> RestoreDocumentRequest {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 rev_id = 2;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+}
+
+impl ::protobuf::Message for RestoreDocumentRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.rev_id != 0 {
+            os.write_int64(2, self.rev_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RestoreDocumentRequest {
+        RestoreDocumentRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &RestoreDocumentRequest| { &m.doc_id },
+                |m: &mut RestoreDocumentRequest| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &RestoreDocumentRequest| { &m.rev_id },
+                |m: &mut RestoreDocumentRequest| { &mut m.rev_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RestoreDocumentRequest>(
+                "RestoreDocumentRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RestoreDocumentRequest {
+        static instance: ::protobuf::rt::LazyV2<RestoreDocumentRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RestoreDocumentRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RestoreDocumentRequest {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.rev_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RestoreDocumentRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RestoreDocumentRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10view_query.proto\"-\n\x10QueryViewRequest\x12\x19\n\x08view_ids\
-    \x18\x01\x20\x03(\tR\x07viewIds\"!\n\x06ViewId\x12\x17\n\x07view_id\x18\
-    \x01\x20\x01(\tR\x06viewId\"&\n\x0eRepeatedViewId\x12\x14\n\x05items\x18\
-    \x01\x20\x03(\tR\x05itemsJ\x9b\x02\n\x06\x12\x04\0\0\n\x01\n\x08\n\x01\
-    \x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x04\
-    \0\x01\x12\x03\x02\x08\x18\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04!\n\
-    \x0c\n\x05\x04\0\x02\0\x04\x12\x03\x03\x04\x0c\n\x0c\n\x05\x04\0\x02\0\
-    \x05\x12\x03\x03\r\x13\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x14\x1c\n\
-    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x1f\x20\n\n\n\x02\x04\x01\x12\x04\
-    \x05\0\x07\x01\n\n\n\x03\x04\x01\x01\x12\x03\x05\x08\x0e\n\x0b\n\x04\x04\
-    \x01\x02\0\x12\x03\x06\x04\x17\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x06\
-    \x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x06\x0b\x12\n\x0c\n\x05\x04\
-    \x01\x02\0\x03\x12\x03\x06\x15\x16\n\n\n\x02\x04\x02\x12\x04\x08\0\n\x01\
-    \n\n\n\x03\x04\x02\x01\x12\x03\x08\x08\x16\n\x0b\n\x04\x04\x02\x02\0\x12\
-    \x03\t\x04\x1e\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\t\x04\x0c\n\x0c\n\
-    \x05\x04\x02\x02\0\x05\x12\x03\t\r\x13\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03\t\x14\x19\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\t\x1c\x1db\x06proto\
-    3\
+    \n\x10view_query.proto\x1a\x11view_create.proto\"-\n\x10QueryViewRequest\
+    \x12\x19\n\x08view_ids\x18\x01\x20\x03(\tR\x07viewIds\"!\n\x06ViewId\x12\
+    \x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\"e\n\x19QueryViewsBelong\
+    ToRequest\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12&\
+    \n\tview_type\x18\x02\x20\x01(\x0e2\t.ViewTypeR\x08viewType\"g\n\x15Quer\
+    yViewsPageRequest\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\nbelongT\
+    oId\x12\x16\n\x06offset\x18\x02\x20\x01(\x03R\x06offset\x12\x14\n\x05lim\
+    it\x18\x03\x20\x01(\x03R\x05limit\"F\n\tViewsPage\x12#\n\x05items\x18\
+    \x01\x20\x01(\x0b2\r.RepeatedViewR\x05items\x12\x14\n\x05total\x18\x02\
+    \x20\x01(\x03R\x05total\":\n\x16QueryViewsCountRequest\x12\x20\n\x0cbelo\
+    ng_to_id\x18\x01\x20\x01(\tR\nbelongToId\"C\n\tViewCount\x12\x20\n\x0cbe\
+    long_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x14\n\x05count\x18\x02\
+    \x20\x01(\x03R\x05count\"5\n\x11RepeatedViewCount\x12\x20\n\x05items\x18\
+    \x01\x20\x03(\x0b2\n.ViewCountR\x05items\"+\n\x0eDuplicateViews\x12\x19\
+    \n\x08view_ids\x18\x01\x20\x03(\tR\x07viewIds\"?\n\x16RepeatedDuplicateV\
+    iews\x12%\n\x05items\x18\x01\x20\x03(\x0b2\x0f.DuplicateViewsR\x05items\
+    \"&\n\x0eRepeatedViewId\x12\x14\n\x05items\x18\x01\x20\x03(\tR\x05items\
+    \"J\n\x1bQueryDocumentHistoryRequest\x12\x15\n\x06doc_id\x18\x01\x20\x01\
+    (\tR\x05docId\x12\x14\n\x05limit\x18\x02\x20\x01(\x03R\x05limit\"F\n\x16\
+    RestoreDocumentRequest\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\
+    \x12\x15\n\x06rev_id\x18\x02\x20\x01(\x03R\x05revIdJ\xe7\x0b\n\x06\x12\
+    \x04\0\00\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\
+    \x01\0\x1b\n\n\n\x02\x04\0\x12\x04\x03\0\x05\x01\n\n\n\x03\x04\0\x01\x12\
+    \x03\x03\x08\x18\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04!\n\x0c\n\x05\
+    \x04\0\x02\0\x04\x12\x03\x04\x04\x0c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x04\r\x13\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x14\x1c\n\x0c\n\x05\
+    \x04\0\x02\0\x03\x12\x03\x04\x1f\x20\n\n\n\x02\x04\x01\x12\x04\x06\0\x08\
+    \x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x0e\n\x0b\n\x04\x04\x01\x02\0\
+    \x12\x03\x07\x04\x17\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\x12\n\x0c\n\x05\x04\x01\x02\
+    \0\x03\x12\x03\x07\x15\x16\n\n\n\x02\x04\x02\x12\x04\t\0\x0c\x01\n\n\n\
+    \x03\x04\x02\x01\x12\x03\t\x08!\n\x0b\n\x04\x04\x02\x02\0\x12\x03\n\x04\
+    \x1c\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x02\
+    \x02\0\x01\x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\n\x1a\
+    \x1b\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0b\x04\x1b\n\x0c\n\x05\x04\x02\
+    \x02\x01\x06\x12\x03\x0b\x04\x0c\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\
+    \x0b\r\x16\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0b\x19\x1a\n\n\n\x02\
+    \x04\x03\x12\x04\r\0\x11\x01\n\n\n\x03\x04\x03\x01\x12\x03\r\x08\x1d\n\
+    \x0b\n\x04\x04\x03\x02\0\x12\x03\x0e\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\
+    \x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x0e\x0b\x17\
+    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x0e\x1a\x1b\n\x0b\n\x04\x04\x03\
+    \x02\x01\x12\x03\x0f\x04\x15\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x0f\
+    \x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x0f\n\x10\n\x0c\n\x05\x04\
+    \x03\x02\x01\x03\x12\x03\x0f\x13\x14\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\
+    \x10\x04\x14\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x10\x04\t\n\x0c\n\
+    \x05\x04\x03\x02\x02\x01\x12\x03\x10\n\x0f\n\x0c\n\x05\x04\x03\x02\x02\
+    \x03\x12\x03\x10\x12\x13\n\n\n\x02\x04\x04\x12\x04\x12\0\x15\x01\n\n\n\
+    \x03\x04\x04\x01\x12\x03\x12\x08\x11\n\x0b\n\x04\x04\x04\x02\0\x12\x03\
+    \x13\x04\x1b\n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03\x13\x04\x10\n\x0c\n\
+    \x05\x04\x04\x02\0\x01\x12\x03\x13\x11\x16\n\x0c\n\x05\x04\x04\x02\0\x03\
+    \x12\x03\x13\x19\x1a\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x14\x04\x14\n\
+    \x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x14\x04\t\n\x0c\n\x05\x04\x04\x02\
+    \x01\x01\x12\x03\x14\n\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x14\
+    \x12\x13\n\n\n\x02\x04\x05\x12\x04\x16\0\x18\x01\n\n\n\x03\x04\x05\x01\
+    \x12\x03\x16\x08\x1e\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x17\x04\x1c\n\x0c\
+    \n\x05\x04\x05\x02\0\x05\x12\x03\x17\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\
+    \x12\x03\x17\x0b\x17\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x17\x1a\x1b\n\
+    \n\n\x02\x04\x06\x12\x04\x19\0\x1c\x01\n\n\n\x03\x04\x06\x01\x12\x03\x19\
+    \x08\x11\n\x0b\n\x04\x04\x06\x02\0\x12\x03\x1a\x04\x1c\n\x0c\n\x05\x04\
+    \x06\x02\0\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03\
+    \x1a\x0b\x17\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03\x1a\x1a\x1b\n\x0b\n\
+    \x04\x04\x06\x02\x01\x12\x03\x1b\x04\x14\n\x0c\n\x05\x04\x06\x02\x01\x05\
+    \x12\x03\x1b\x04\t\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03\x1b\n\x0f\n\
+    \x0c\n\x05\x04\x06\x02\x01\x03\x12\x03\x1b\x12\x13\n\n\n\x02\x04\x07\x12\
+    \x04\x1d\0\x1f\x01\n\n\n\x03\x04\x07\x01\x12\x03\x1d\x08\x19\n\x0b\n\x04\
+    \x04\x07\x02\0\x12\x03\x1e\x04!\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03\
+    \x1e\x04\x0c\n\x0c\n\x05\x04\x07\x02\0\x06\x12\x03\x1e\r\x16\n\x0c\n\x05\
+    \x04\x07\x02\0\x01\x12\x03\x1e\x17\x1c\n\x0c\n\x05\x04\x07\x02\0\x03\x12\
+    \x03\x1e\x1f\x20\n\n\n\x02\x04\x08\x12\x04\x20\0\"\x01\n\n\n\x03\x04\x08\
+    \x01\x12\x03\x20\x08\x16\n\x0b\n\x04\x04\x08\x02\0\x12\x03!\x04!\n\x0c\n\
+    \x05\x04\x08\x02\0\x04\x12\x03!\x04\x0c\n\x0c\n\x05\x04\x08\x02\0\x05\
+    \x12\x03!\r\x13\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03!\x14\x1c\n\x0c\n\
+    \x05\x04\x08\x02\0\x03\x12\x03!\x1f\x20\n\n\n\x02\x04\t\x12\x04#\0%\x01\
+    \n\n\n\x03\x04\t\x01\x12\x03#\x08\x1e\n\x0b\n\x04\x04\t\x02\0\x12\x03$\
+    \x04&\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03$\x04\x0c\n\x0c\n\x05\x04\t\x02\
+    \0\x06\x12\x03$\r\x1b\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03$\x1c!\n\x0c\n\
+    \x05\x04\t\x02\0\x03\x12\x03$$%\n\n\n\x02\x04\n\x12\x04&\0(\x01\n\n\n\
+    \x03\x04\n\x01\x12\x03&\x08\x16\n\x0b\n\x04\x04\n\x02\0\x12\x03'\x04\x1e\
+    \n\x0c\n\x05\x04\n\x02\0\x04\x12\x03'\x04\x0c\n\x0c\n\x05\x04\n\x02\0\
+    \x05\x12\x03'\r\x13\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03'\x14\x19\n\x0c\n\
+    \x05\x04\n\x02\0\x03\x12\x03'\x1c\x1d\n\n\n\x02\x04\x0b\x12\x04)\0,\x01\
+    \n\n\n\x03\x04\x0b\x01\x12\x03)\x08#\n\x0b\n\x04\x04\x0b\x02\0\x12\x03*\
+    \x04\x16\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03*\x04\n\n\x0c\n\x05\x04\
+    \x0b\x02\0\x01\x12\x03*\x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03*\
+    \x14\x15\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03+\x04\x14\n\x0c\n\x05\x04\
+    \x0b\x02\x01\x05\x12\x03+\x04\t\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03+\
+    \n\x0f\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03+\x12\x13\n\n\n\x02\x04\
+    \x0c\x12\x04-\00\x01\n\n\n\x03\x04\x0c\x01\x12\x03-\x08\x1e\n\x0b\n\x04\
+    \x04\x0c\x02\0\x12\x03.\x04\x16\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03.\
+    \x04\n\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03.\x0b\x11\n\x0c\n\x05\x04\
+    \x0c\x02\0\x03\x12\x03.\x14\x15\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03/\x04\
+    \x15\n\x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03/\x04\t\n\x0c\n\x05\x04\x0c\
+    \x02\x01\x01\x12\x03/\n\x10\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03/\x13\
+    \x14b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,275 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `view_stats.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocumentStats {
+    // message fields
+    pub word_count: i64,
+    pub char_count: i64,
+    pub line_count: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocumentStats {
+    fn default() -> &'a DocumentStats {
+        <DocumentStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocumentStats {
+    pub fn new() -> DocumentStats {
+        ::std::default::Default::default()
+    }
+
+    // int64 word_count = 1;
+
+
+    pub fn get_word_count(&self) -> i64 {
+        self.word_count
+    }
+    pub fn clear_word_count(&mut self) {
+        self.word_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_word_count(&mut self, v: i64) {
+        self.word_count = v;
+    }
+
+    // int64 char_count = 2;
+
+
+    pub fn get_char_count(&self) -> i64 {
+        self.char_count
+    }
+    pub fn clear_char_count(&mut self) {
+        self.char_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_char_count(&mut self, v: i64) {
+        self.char_count = v;
+    }
+
+    // int64 line_count = 3;
+
+
+    pub fn get_line_count(&self) -> i64 {
+        self.line_count
+    }
+    pub fn clear_line_count(&mut self) {
+        self.line_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_line_count(&mut self, v: i64) {
+        self.line_count = v;
+    }
+}
+
+impl ::protobuf::Message for DocumentStats {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.word_count = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.char_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.line_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.word_count != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.word_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.char_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.char_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.line_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.line_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.word_count != 0 {
+            os.write_int64(1, self.word_count)?;
+        }
+        if self.char_count != 0 {
+            os.write_int64(2, self.char_count)?;
+        }
+        if self.line_count != 0 {
+            os.write_int64(3, self.line_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocumentStats {
+        DocumentStats::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "word_count",
+                |m: &DocumentStats| { &m.word_count },
+                |m: &mut DocumentStats| { &mut m.word_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "char_count",
+                |m: &DocumentStats| { &m.char_count },
+                |m: &mut DocumentStats| { &mut m.char_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "line_count",
+                |m: &DocumentStats| { &m.line_count },
+                |m: &mut DocumentStats| { &mut m.line_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocumentStats>(
+                "DocumentStats",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocumentStats {
+        static instance: ::protobuf::rt::LazyV2<DocumentStats> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocumentStats::new)
+    }
+}
+
+impl ::protobuf::Clear for DocumentStats {
+    fn clear(&mut self) {
+        self.word_count = 0;
+        self.char_count = 0;
+        self.line_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocumentStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocumentStats {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10view_stats.proto\"l\n\rDocumentStats\x12\x1d\n\nword_count\x18\x01\
+    \x20\x01(\x03R\twordCount\x12\x1d\n\nchar_count\x18\x02\x20\x01(\x03R\tc\
+    harCount\x12\x1d\n\nline_count\x18\x03\x20\x01(\x03R\tlineCountJ\xcf\x01\
+    \n\x06\x12\x04\0\0\x06\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\
+    \0\x12\x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x03\x04\x19\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x03\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\n\x14\n\x0c\n\x05\x04\
+    \0\x02\0\x03\x12\x03\x03\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\
+    \x04\x19\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\t\n\x0c\n\x05\x04\
+    \0\x02\x01\x01\x12\x03\x04\n\x14\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
+    \x04\x17\x18\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x19\n\x0c\n\x05\
+    \x04\0\x02\x02\x05\x12\x03\x05\x04\t\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
+    \x03\x05\n\x14\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x17\x18b\x06pro\
+    to3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
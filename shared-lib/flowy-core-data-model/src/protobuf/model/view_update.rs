@@ -31,6 +31,8 @@ pub struct UpdateViewRequest {
     pub one_of_name: ::std::option::Option<UpdateViewRequest_oneof_one_of_name>,
     pub one_of_desc: ::std::option::Option<UpdateViewRequest_oneof_one_of_desc>,
     pub one_of_thumbnail: ::std::option::Option<UpdateViewRequest_oneof_one_of_thumbnail>,
+    pub one_of_is_readonly: ::std::option::Option<UpdateViewRequest_oneof_one_of_is_readonly>,
+    pub one_of_icon: ::std::option::Option<UpdateViewRequest_oneof_one_of_icon>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -57,6 +59,16 @@ pub enum UpdateViewRequest_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewRequest_oneof_one_of_is_readonly {
+    is_readonly(bool),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewRequest_oneof_one_of_icon {
+    icon(::std::string::String),
+}
+
 impl UpdateViewRequest {
     pub fn new() -> UpdateViewRequest {
         ::std::default::Default::default()
@@ -158,85 +170,1060 @@ impl UpdateViewRequest {
     }
 
     // Param is passed by value, moved
-    pub fn set_desc(&mut self, v: ::std::string::String) {
-        self.one_of_desc = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(v))
+    pub fn set_desc(&mut self, v: ::std::string::String) {
+        self.one_of_desc = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_desc(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(_)) = self.one_of_desc {
+        } else {
+            self.one_of_desc = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(::std::string::String::new()));
+        }
+        match self.one_of_desc {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_desc(&mut self) -> ::std::string::String {
+        if self.has_desc() {
+            match self.one_of_desc.take() {
+                ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string thumbnail = 4;
+
+
+    pub fn get_thumbnail(&self) -> &str {
+        match self.one_of_thumbnail {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_thumbnail(&mut self) {
+        self.one_of_thumbnail = ::std::option::Option::None;
+    }
+
+    pub fn has_thumbnail(&self) -> bool {
+        match self.one_of_thumbnail {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_thumbnail(&mut self, v: ::std::string::String) {
+        self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_thumbnail(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(_)) = self.one_of_thumbnail {
+        } else {
+            self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(::std::string::String::new()));
+        }
+        match self.one_of_thumbnail {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_thumbnail(&mut self) -> ::std::string::String {
+        if self.has_thumbnail() {
+            match self.one_of_thumbnail.take() {
+                ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // bool is_readonly = 5;
+
+
+    pub fn get_is_readonly(&self) -> bool {
+        match self.one_of_is_readonly {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_is_readonly::is_readonly(v)) => v,
+            _ => false,
+        }
+    }
+    pub fn clear_is_readonly(&mut self) {
+        self.one_of_is_readonly = ::std::option::Option::None;
+    }
+
+    pub fn has_is_readonly(&self) -> bool {
+        match self.one_of_is_readonly {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_is_readonly::is_readonly(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_readonly(&mut self, v: bool) {
+        self.one_of_is_readonly = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_is_readonly::is_readonly(v))
+    }
+
+    // string icon = 6;
+
+
+    pub fn get_icon(&self) -> &str {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_icon(&mut self) {
+        self.one_of_icon = ::std::option::Option::None;
+    }
+
+    pub fn has_icon(&self) -> bool {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.one_of_icon = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(_)) = self.one_of_icon {
+        } else {
+            self.one_of_icon = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(::std::string::String::new()));
+        }
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        if self.has_icon() {
+            match self.one_of_icon.take() {
+                ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for UpdateViewRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_name = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_name::name(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_desc = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(is.read_string()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_is_readonly = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_is_readonly::is_readonly(is.read_bool()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_icon = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_icon::icon(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateViewRequest_oneof_one_of_name::name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_desc {
+            match v {
+                &UpdateViewRequest_oneof_one_of_desc::desc(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
+            match v {
+                &UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_is_readonly {
+            match v {
+                &UpdateViewRequest_oneof_one_of_is_readonly::is_readonly(v) => {
+                    my_size += 2;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewRequest_oneof_one_of_icon::icon(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_name {
+            match v {
+                &UpdateViewRequest_oneof_one_of_name::name(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_desc {
+            match v {
+                &UpdateViewRequest_oneof_one_of_desc::desc(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
+            match v {
+                &UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
+                    os.write_string(4, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_is_readonly {
+            match v {
+                &UpdateViewRequest_oneof_one_of_is_readonly::is_readonly(v) => {
+                    os.write_bool(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewRequest_oneof_one_of_icon::icon(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UpdateViewRequest {
+        UpdateViewRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &UpdateViewRequest| { &m.view_id },
+                |m: &mut UpdateViewRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "name",
+                UpdateViewRequest::has_name,
+                UpdateViewRequest::get_name,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "desc",
+                UpdateViewRequest::has_desc,
+                UpdateViewRequest::get_desc,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "thumbnail",
+                UpdateViewRequest::has_thumbnail,
+                UpdateViewRequest::get_thumbnail,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                "is_readonly",
+                UpdateViewRequest::has_is_readonly,
+                UpdateViewRequest::get_is_readonly,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "icon",
+                UpdateViewRequest::has_icon,
+                UpdateViewRequest::get_icon,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewRequest>(
+                "UpdateViewRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UpdateViewRequest {
+        static instance: ::protobuf::rt::LazyV2<UpdateViewRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateViewRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UpdateViewRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.one_of_name = ::std::option::Option::None;
+        self.one_of_desc = ::std::option::Option::None;
+        self.one_of_thumbnail = ::std::option::Option::None;
+        self.one_of_is_readonly = ::std::option::Option::None;
+        self.one_of_icon = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UpdateViewRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateViewRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RenameViewRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RenameViewRequest {
+    fn default() -> &'a RenameViewRequest {
+        <RenameViewRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RenameViewRequest {
+    pub fn new() -> RenameViewRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RenameViewRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RenameViewRequest {
+        RenameViewRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &RenameViewRequest| { &m.view_id },
+                |m: &mut RenameViewRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &RenameViewRequest| { &m.name },
+                |m: &mut RenameViewRequest| { &mut m.name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RenameViewRequest>(
+                "RenameViewRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RenameViewRequest {
+        static instance: ::protobuf::rt::LazyV2<RenameViewRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RenameViewRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RenameViewRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RenameViewRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenameViewRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetViewReadonlyRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub is_readonly: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetViewReadonlyRequest {
+    fn default() -> &'a SetViewReadonlyRequest {
+        <SetViewReadonlyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetViewReadonlyRequest {
+    pub fn new() -> SetViewReadonlyRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // bool is_readonly = 2;
+
+
+    pub fn get_is_readonly(&self) -> bool {
+        self.is_readonly
+    }
+    pub fn clear_is_readonly(&mut self) {
+        self.is_readonly = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_readonly(&mut self, v: bool) {
+        self.is_readonly = v;
+    }
+}
+
+impl ::protobuf::Message for SetViewReadonlyRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_readonly = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.is_readonly != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.is_readonly != false {
+            os.write_bool(2, self.is_readonly)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetViewReadonlyRequest {
+        SetViewReadonlyRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &SetViewReadonlyRequest| { &m.view_id },
+                |m: &mut SetViewReadonlyRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_readonly",
+                |m: &SetViewReadonlyRequest| { &m.is_readonly },
+                |m: &mut SetViewReadonlyRequest| { &mut m.is_readonly },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetViewReadonlyRequest>(
+                "SetViewReadonlyRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetViewReadonlyRequest {
+        static instance: ::protobuf::rt::LazyV2<SetViewReadonlyRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetViewReadonlyRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetViewReadonlyRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.is_readonly = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetViewReadonlyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetViewReadonlyRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetViewVisibilityRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub visibility: super::view_create::ViewVisibility,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetViewVisibilityRequest {
+    fn default() -> &'a SetViewVisibilityRequest {
+        <SetViewVisibilityRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetViewVisibilityRequest {
+    pub fn new() -> SetViewVisibilityRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // .ViewVisibility visibility = 2;
+
+
+    pub fn get_visibility(&self) -> super::view_create::ViewVisibility {
+        self.visibility
+    }
+    pub fn clear_visibility(&mut self) {
+        self.visibility = super::view_create::ViewVisibility::Private;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_visibility(&mut self, v: super::view_create::ViewVisibility) {
+        self.visibility = v;
+    }
+}
+
+impl ::protobuf::Message for SetViewVisibilityRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.visibility, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.visibility != super::view_create::ViewVisibility::Private {
+            my_size += ::protobuf::rt::enum_size(2, self.visibility);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.visibility != super::view_create::ViewVisibility::Private {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.visibility))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SetViewVisibilityRequest {
+        SetViewVisibilityRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &SetViewVisibilityRequest| { &m.view_id },
+                |m: &mut SetViewVisibilityRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::view_create::ViewVisibility>>(
+                "visibility",
+                |m: &SetViewVisibilityRequest| { &m.visibility },
+                |m: &mut SetViewVisibilityRequest| { &mut m.visibility },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetViewVisibilityRequest>(
+                "SetViewVisibilityRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static SetViewVisibilityRequest {
+        static instance: ::protobuf::rt::LazyV2<SetViewVisibilityRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetViewVisibilityRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for SetViewVisibilityRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.visibility = super::view_create::ViewVisibility::Private;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetViewVisibilityRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetViewVisibilityRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetViewIconRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub icon: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetViewIconRequest {
+    fn default() -> &'a SetViewIconRequest {
+        <SetViewIconRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetViewIconRequest {
+    pub fn new() -> SetViewIconRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_desc(&mut self) -> &mut ::std::string::String {
-        if let ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(_)) = self.one_of_desc {
-        } else {
-            self.one_of_desc = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(::std::string::String::new()));
-        }
-        match self.one_of_desc {
-            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
     }
 
     // Take field
-    pub fn take_desc(&mut self) -> ::std::string::String {
-        if self.has_desc() {
-            match self.one_of_desc.take() {
-                ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::string::String::new()
-        }
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
     }
 
-    // string thumbnail = 4;
+    // string icon = 2;
 
 
-    pub fn get_thumbnail(&self) -> &str {
-        match self.one_of_thumbnail {
-            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v)) => v,
-            _ => "",
-        }
+    pub fn get_icon(&self) -> &str {
+        &self.icon
     }
-    pub fn clear_thumbnail(&mut self) {
-        self.one_of_thumbnail = ::std::option::Option::None;
-    }
-
-    pub fn has_thumbnail(&self) -> bool {
-        match self.one_of_thumbnail {
-            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(..)) => true,
-            _ => false,
-        }
+    pub fn clear_icon(&mut self) {
+        self.icon.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_thumbnail(&mut self, v: ::std::string::String) {
-        self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(v))
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.icon = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_thumbnail(&mut self) -> &mut ::std::string::String {
-        if let ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(_)) = self.one_of_thumbnail {
-        } else {
-            self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(::std::string::String::new()));
-        }
-        match self.one_of_thumbnail {
-            ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        &mut self.icon
     }
 
     // Take field
-    pub fn take_thumbnail(&mut self) -> ::std::string::String {
-        if self.has_thumbnail() {
-            match self.one_of_thumbnail.take() {
-                ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::string::String::new()
-        }
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.icon, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for UpdateViewRequest {
+impl ::protobuf::Message for SetViewIconRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -249,22 +1236,7 @@ impl ::protobuf::Message for UpdateViewRequest {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.one_of_name = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_name::name(is.read_string()?));
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.one_of_desc = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_desc::desc(is.read_string()?));
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(is.read_string()?));
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.icon)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -281,26 +1253,8 @@ impl ::protobuf::Message for UpdateViewRequest {
         if !self.view_id.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.view_id);
         }
-        if let ::std::option::Option::Some(ref v) = self.one_of_name {
-            match v {
-                &UpdateViewRequest_oneof_one_of_name::name(ref v) => {
-                    my_size += ::protobuf::rt::string_size(2, &v);
-                },
-            };
-        }
-        if let ::std::option::Option::Some(ref v) = self.one_of_desc {
-            match v {
-                &UpdateViewRequest_oneof_one_of_desc::desc(ref v) => {
-                    my_size += ::protobuf::rt::string_size(3, &v);
-                },
-            };
-        }
-        if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
-            match v {
-                &UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
-                    my_size += ::protobuf::rt::string_size(4, &v);
-                },
-            };
+        if !self.icon.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.icon);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -311,26 +1265,8 @@ impl ::protobuf::Message for UpdateViewRequest {
         if !self.view_id.is_empty() {
             os.write_string(1, &self.view_id)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.one_of_name {
-            match v {
-                &UpdateViewRequest_oneof_one_of_name::name(ref v) => {
-                    os.write_string(2, v)?;
-                },
-            };
-        }
-        if let ::std::option::Option::Some(ref v) = self.one_of_desc {
-            match v {
-                &UpdateViewRequest_oneof_one_of_desc::desc(ref v) => {
-                    os.write_string(3, v)?;
-                },
-            };
-        }
-        if let ::std::option::Option::Some(ref v) = self.one_of_thumbnail {
-            match v {
-                &UpdateViewRequest_oneof_one_of_thumbnail::thumbnail(ref v) => {
-                    os.write_string(4, v)?;
-                },
-            };
+        if !self.icon.is_empty() {
+            os.write_string(2, &self.icon)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -362,8 +1298,8 @@ impl ::protobuf::Message for UpdateViewRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> UpdateViewRequest {
-        UpdateViewRequest::new()
+    fn new() -> SetViewIconRequest {
+        SetViewIconRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -372,55 +1308,43 @@ impl ::protobuf::Message for UpdateViewRequest {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                 "view_id",
-                |m: &UpdateViewRequest| { &m.view_id },
-                |m: &mut UpdateViewRequest| { &mut m.view_id },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
-                "name",
-                UpdateViewRequest::has_name,
-                UpdateViewRequest::get_name,
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
-                "desc",
-                UpdateViewRequest::has_desc,
-                UpdateViewRequest::get_desc,
+                |m: &SetViewIconRequest| { &m.view_id },
+                |m: &mut SetViewIconRequest| { &mut m.view_id },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
-                "thumbnail",
-                UpdateViewRequest::has_thumbnail,
-                UpdateViewRequest::get_thumbnail,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "icon",
+                |m: &SetViewIconRequest| { &m.icon },
+                |m: &mut SetViewIconRequest| { &mut m.icon },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewRequest>(
-                "UpdateViewRequest",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<SetViewIconRequest>(
+                "SetViewIconRequest",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static UpdateViewRequest {
-        static instance: ::protobuf::rt::LazyV2<UpdateViewRequest> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(UpdateViewRequest::new)
+    fn default_instance() -> &'static SetViewIconRequest {
+        static instance: ::protobuf::rt::LazyV2<SetViewIconRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(SetViewIconRequest::new)
     }
 }
 
-impl ::protobuf::Clear for UpdateViewRequest {
+impl ::protobuf::Clear for SetViewIconRequest {
     fn clear(&mut self) {
         self.view_id.clear();
-        self.one_of_name = ::std::option::Option::None;
-        self.one_of_desc = ::std::option::Option::None;
-        self.one_of_thumbnail = ::std::option::Option::None;
+        self.icon.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for UpdateViewRequest {
+impl ::std::fmt::Debug for SetViewIconRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UpdateViewRequest {
+impl ::protobuf::reflect::ProtobufValue for SetViewIconRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -434,6 +1358,9 @@ pub struct UpdateViewParams {
     pub one_of_name: ::std::option::Option<UpdateViewParams_oneof_one_of_name>,
     pub one_of_desc: ::std::option::Option<UpdateViewParams_oneof_one_of_desc>,
     pub one_of_thumbnail: ::std::option::Option<UpdateViewParams_oneof_one_of_thumbnail>,
+    pub one_of_is_readonly: ::std::option::Option<UpdateViewParams_oneof_one_of_is_readonly>,
+    pub one_of_visibility: ::std::option::Option<UpdateViewParams_oneof_one_of_visibility>,
+    pub one_of_icon: ::std::option::Option<UpdateViewParams_oneof_one_of_icon>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -460,6 +1387,21 @@ pub enum UpdateViewParams_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewParams_oneof_one_of_is_readonly {
+    is_readonly(bool),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewParams_oneof_one_of_visibility {
+    visibility(i32),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateViewParams_oneof_one_of_icon {
+    icon(::std::string::String),
+}
+
 impl UpdateViewParams {
     pub fn new() -> UpdateViewParams {
         ::std::default::Default::default()
@@ -637,6 +1579,105 @@ impl UpdateViewParams {
             ::std::string::String::new()
         }
     }
+
+    // bool is_readonly = 5;
+
+
+    pub fn get_is_readonly(&self) -> bool {
+        match self.one_of_is_readonly {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_is_readonly::is_readonly(v)) => v,
+            _ => false,
+        }
+    }
+    pub fn clear_is_readonly(&mut self) {
+        self.one_of_is_readonly = ::std::option::Option::None;
+    }
+
+    pub fn has_is_readonly(&self) -> bool {
+        match self.one_of_is_readonly {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_is_readonly::is_readonly(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_readonly(&mut self, v: bool) {
+        self.one_of_is_readonly = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_is_readonly::is_readonly(v))
+    }
+
+    // int32 visibility = 6;
+
+
+    pub fn get_visibility(&self) -> i32 {
+        match self.one_of_visibility {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_visibility::visibility(v)) => v,
+            _ => 0,
+        }
+    }
+    pub fn clear_visibility(&mut self) {
+        self.one_of_visibility = ::std::option::Option::None;
+    }
+
+    pub fn has_visibility(&self) -> bool {
+        match self.one_of_visibility {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_visibility::visibility(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_visibility(&mut self, v: i32) {
+        self.one_of_visibility = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_visibility::visibility(v))
+    }
+
+    // string icon = 7;
+
+
+    pub fn get_icon(&self) -> &str {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_icon(&mut self) {
+        self.one_of_icon = ::std::option::Option::None;
+    }
+
+    pub fn has_icon(&self) -> bool {
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon(&mut self, v: ::std::string::String) {
+        self.one_of_icon = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_icon(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(_)) = self.one_of_icon {
+        } else {
+            self.one_of_icon = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(::std::string::String::new()));
+        }
+        match self.one_of_icon {
+            ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_icon(&mut self) -> ::std::string::String {
+        if self.has_icon() {
+            match self.one_of_icon.take() {
+                ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateViewParams {
@@ -669,6 +1710,24 @@ impl ::protobuf::Message for UpdateViewParams {
                     }
                     self.one_of_thumbnail = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_thumbnail::thumbnail(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_is_readonly = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_is_readonly::is_readonly(is.read_bool()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_visibility = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_visibility::visibility(is.read_int32()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_icon = ::std::option::Option::Some(UpdateViewParams_oneof_one_of_icon::icon(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -705,6 +1764,27 @@ impl ::protobuf::Message for UpdateViewParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_is_readonly {
+            match v {
+                &UpdateViewParams_oneof_one_of_is_readonly::is_readonly(v) => {
+                    my_size += 2;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_visibility {
+            match v {
+                &UpdateViewParams_oneof_one_of_visibility::visibility(v) => {
+                    my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewParams_oneof_one_of_icon::icon(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -735,6 +1815,27 @@ impl ::protobuf::Message for UpdateViewParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_is_readonly {
+            match v {
+                &UpdateViewParams_oneof_one_of_is_readonly::is_readonly(v) => {
+                    os.write_bool(5, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_visibility {
+            match v {
+                &UpdateViewParams_oneof_one_of_visibility::visibility(v) => {
+                    os.write_int32(6, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon {
+            match v {
+                &UpdateViewParams_oneof_one_of_icon::icon(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -793,6 +1894,21 @@ impl ::protobuf::Message for UpdateViewParams {
                 UpdateViewParams::has_thumbnail,
                 UpdateViewParams::get_thumbnail,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                "is_readonly",
+                UpdateViewParams::has_is_readonly,
+                UpdateViewParams::get_is_readonly,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_i32_accessor::<_>(
+                "visibility",
+                UpdateViewParams::has_visibility,
+                UpdateViewParams::get_visibility,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "icon",
+                UpdateViewParams::has_icon,
+                UpdateViewParams::get_icon,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateViewParams>(
                 "UpdateViewParams",
                 fields,
@@ -813,6 +1929,9 @@ impl ::protobuf::Clear for UpdateViewParams {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_desc = ::std::option::Option::None;
         self.one_of_thumbnail = ::std::option::Option::None;
+        self.one_of_is_readonly = ::std::option::Option::None;
+        self.one_of_visibility = ::std::option::Option::None;
+        self.one_of_icon = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -830,47 +1949,108 @@ impl ::protobuf::reflect::ProtobufValue for UpdateViewParams {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11view_update.proto\"\xaa\x01\n\x11UpdateViewRequest\x12\x17\n\x07vi\
-    ew_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04name\x18\x02\x20\x01(\t\
-    H\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04desc\x12\x1e\
-    \n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\r\n\x0bone_of_nameB\r\
-    \n\x0bone_of_descB\x12\n\x10one_of_thumbnail\"\xa9\x01\n\x10UpdateViewPa\
-    rams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04nam\
-    e\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\
-    \x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\
-    \r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnailJ\x9c\
-    \x05\n\x06\x12\x04\0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x04\0\x12\x04\x02\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x12\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x08\0\x12\
-    \x03\x04\x04*\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x04\n\x15\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x04\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
-    \x04\x18\x1e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x1f#\n\x0c\n\x05\
-    \x04\0\x02\x01\x03\x12\x03\x04&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x05\
-    \x04*\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x18\
-    \x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x06\x044\n\
-    \x0c\n\x05\x04\0\x08\x02\x01\x12\x03\x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\
-    \x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\
-    \x05\x04\0\x02\x03\x01\x12\x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\
-    \x03\x0601\n\n\n\x02\x04\x01\x12\x04\x08\0\r\x01\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x08\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\t\x04\x17\n\x0c\n\
-    \x05\x04\x01\x02\0\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\t\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x15\x16\n\x0b\n\
-    \x04\x04\x01\x08\0\x12\x03\n\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\
-    \n\n\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x01\x05\x12\x03\n\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\
-    \x1f#\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\n&'\n\x0b\n\x04\x04\x01\
-    \x08\x01\x12\x03\x0b\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\x0b\n\
-    \x15\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0b\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\x0b\x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0b\x1f#\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0b&'\n\x0b\n\x04\x04\
-    \x01\x08\x02\x12\x03\x0c\x044\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\
-    \x0c\n\x1a\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0c\x1d2\n\x0c\n\x05\x04\
-    \x01\x02\x03\x05\x12\x03\x0c\x1d#\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\
-    \x03\x0c$-\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0c01b\x06proto3\
+    \n\x11view_update.proto\x1a\x11view_create.proto\"\x88\x02\n\x11UpdateVi\
+    ewRequest\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\
+    \x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\
+    \x01(\tH\x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthu\
+    mbnail\x12!\n\x0bis_readonly\x18\x05\x20\x01(\x08H\x03R\nisReadonly\x12\
+    \x14\n\x04icon\x18\x06\x20\x01(\tH\x04R\x04iconB\r\n\x0bone_of_nameB\r\n\
+    \x0bone_of_descB\x12\n\x10one_of_thumbnailB\x14\n\x12one_of_is_readonlyB\
+    \r\n\x0bone_of_icon\"@\n\x11RenameViewRequest\x12\x17\n\x07view_id\x18\
+    \x01\x20\x01(\tR\x06viewId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\
+    \"R\n\x16SetViewReadonlyRequest\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\
+    \x06viewId\x12\x1f\n\x0bis_readonly\x18\x02\x20\x01(\x08R\nisReadonly\"d\
+    \n\x18SetViewVisibilityRequest\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\
+    \x06viewId\x12/\n\nvisibility\x18\x02\x20\x01(\x0e2\x0f.ViewVisibilityR\
+    \nvisibility\"A\n\x12SetViewIconRequest\x12\x17\n\x07view_id\x18\x01\x20\
+    \x01(\tR\x06viewId\x12\x12\n\x04icon\x18\x02\x20\x01(\tR\x04icon\"\xbe\
+    \x02\n\x10UpdateViewParams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06v\
+    iewId\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\
+    \x18\x03\x20\x01(\tH\x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\
+    \tH\x02R\tthumbnail\x12!\n\x0bis_readonly\x18\x05\x20\x01(\x08H\x03R\nis\
+    Readonly\x12\x20\n\nvisibility\x18\x06\x20\x01(\x05H\x04R\nvisibility\
+    \x12\x14\n\x04icon\x18\x07\x20\x01(\tH\x05R\x04iconB\r\n\x0bone_of_nameB\
+    \r\n\x0bone_of_descB\x12\n\x10one_of_thumbnailB\x14\n\x12one_of_is_reado\
+    nlyB\x13\n\x11one_of_visibilityB\r\n\x0bone_of_iconJ\xd9\x0c\n\x06\x12\
+    \x04\0\0#\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\
+    \x01\0\x1b\n\n\n\x02\x04\0\x12\x04\x03\0\n\x01\n\n\n\x03\x04\0\x01\x12\
+    \x03\x03\x08\x19\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x17\n\x0c\n\x05\
+    \x04\0\x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\
+    \x04\x0b\x12\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x15\x16\n\x0b\n\x04\
+    \x04\0\x08\0\x12\x03\x05\x04*\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x05\n\
+    \x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\
+    \x01\x05\x12\x03\x05\x18\x1e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\
+    \x1f#\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\
+    \x01\x12\x03\x06\x04*\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x06\n\x15\n\
+    \x0b\n\x04\x04\0\x02\x02\x12\x03\x06\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\
+    \x12\x03\x06\x18\x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x1f#\n\
+    \x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x06&'\n\x0b\n\x04\x04\0\x08\x02\x12\
+    \x03\x07\x044\n\x0c\n\x05\x04\0\x08\x02\x01\x12\x03\x07\n\x1a\n\x0b\n\
+    \x04\x04\0\x02\x03\x12\x03\x07\x1d2\n\x0c\n\x05\x04\0\x02\x03\x05\x12\
+    \x03\x07\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x07$-\n\x0c\n\x05\
+    \x04\0\x02\x03\x03\x12\x03\x0701\n\x0b\n\x04\x04\0\x08\x03\x12\x03\x08\
+    \x046\n\x0c\n\x05\x04\0\x08\x03\x01\x12\x03\x08\n\x1c\n\x0b\n\x04\x04\0\
+    \x02\x04\x12\x03\x08\x1f4\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x08\x1f#\
+    \n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x08$/\n\x0c\n\x05\x04\0\x02\x04\
+    \x03\x12\x03\x0823\n\x0b\n\x04\x04\0\x08\x04\x12\x03\t\x04*\n\x0c\n\x05\
+    \x04\0\x08\x04\x01\x12\x03\t\n\x15\n\x0b\n\x04\x04\0\x02\x05\x12\x03\t\
+    \x18(\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\t\x18\x1e\n\x0c\n\x05\x04\0\
+    \x02\x05\x01\x12\x03\t\x1f#\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\t&'\n\
+    \n\n\x02\x04\x01\x12\x04\x0b\0\x0e\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0b\
+    \x08\x19\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0c\x04\x17\n\x0c\n\x05\x04\
+    \x01\x02\0\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\
+    \x0c\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x15\x16\n\x0b\n\
+    \x04\x04\x01\x02\x01\x12\x03\r\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\
+    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\r\x0b\x0f\n\x0c\
+    \n\x05\x04\x01\x02\x01\x03\x12\x03\r\x12\x13\n\n\n\x02\x04\x02\x12\x04\
+    \x0f\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0f\x08\x1e\n\x0b\n\x04\x04\
+    \x02\x02\0\x12\x03\x10\x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x10\
+    \x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x10\x0b\x12\n\x0c\n\x05\x04\
+    \x02\x02\0\x03\x12\x03\x10\x15\x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\
+    \x11\x04\x19\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x11\x04\x08\n\x0c\n\
+    \x05\x04\x02\x02\x01\x01\x12\x03\x11\t\x14\n\x0c\n\x05\x04\x02\x02\x01\
+    \x03\x12\x03\x11\x17\x18\n\n\n\x02\x04\x03\x12\x04\x13\0\x16\x01\n\n\n\
+    \x03\x04\x03\x01\x12\x03\x13\x08\x20\n\x0b\n\x04\x04\x03\x02\0\x12\x03\
+    \x14\x04\x17\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x14\x04\n\n\x0c\n\x05\
+    \x04\x03\x02\0\x01\x12\x03\x14\x0b\x12\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
+    \x03\x14\x15\x16\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x15\x04\"\n\x0c\n\
+    \x05\x04\x03\x02\x01\x06\x12\x03\x15\x04\x12\n\x0c\n\x05\x04\x03\x02\x01\
+    \x01\x12\x03\x15\x13\x1d\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x15\x20\
+    !\n\n\n\x02\x04\x04\x12\x04\x17\0\x1a\x01\n\n\n\x03\x04\x04\x01\x12\x03\
+    \x17\x08\x1a\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x18\x04\x17\n\x0c\n\x05\
+    \x04\x04\x02\0\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\
+    \x03\x18\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x18\x15\x16\n\x0b\
+    \n\x04\x04\x04\x02\x01\x12\x03\x19\x04\x14\n\x0c\n\x05\x04\x04\x02\x01\
+    \x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x19\x0b\
+    \x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x19\x12\x13\n\n\n\x02\x04\
+    \x05\x12\x04\x1b\0#\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1b\x08\x18\n\x0b\
+    \n\x04\x04\x05\x02\0\x12\x03\x1c\x04\x17\n\x0c\n\x05\x04\x05\x02\0\x05\
+    \x12\x03\x1c\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1c\x0b\x12\n\
+    \x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1c\x15\x16\n\x0b\n\x04\x04\x05\x08\
+    \0\x12\x03\x1d\x04*\n\x0c\n\x05\x04\x05\x08\0\x01\x12\x03\x1d\n\x15\n\
+    \x0b\n\x04\x04\x05\x02\x01\x12\x03\x1d\x18(\n\x0c\n\x05\x04\x05\x02\x01\
+    \x05\x12\x03\x1d\x18\x1e\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\x1d\x1f\
+    #\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\x1d&'\n\x0b\n\x04\x04\x05\x08\
+    \x01\x12\x03\x1e\x04*\n\x0c\n\x05\x04\x05\x08\x01\x01\x12\x03\x1e\n\x15\
+    \n\x0b\n\x04\x04\x05\x02\x02\x12\x03\x1e\x18(\n\x0c\n\x05\x04\x05\x02\
+    \x02\x05\x12\x03\x1e\x18\x1e\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03\x1e\
+    \x1f#\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03\x1e&'\n\x0b\n\x04\x04\x05\
+    \x08\x02\x12\x03\x1f\x044\n\x0c\n\x05\x04\x05\x08\x02\x01\x12\x03\x1f\n\
+    \x1a\n\x0b\n\x04\x04\x05\x02\x03\x12\x03\x1f\x1d2\n\x0c\n\x05\x04\x05\
+    \x02\x03\x05\x12\x03\x1f\x1d#\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03\
+    \x1f$-\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03\x1f01\n\x0b\n\x04\x04\x05\
+    \x08\x03\x12\x03\x20\x046\n\x0c\n\x05\x04\x05\x08\x03\x01\x12\x03\x20\n\
+    \x1c\n\x0b\n\x04\x04\x05\x02\x04\x12\x03\x20\x1f4\n\x0c\n\x05\x04\x05\
+    \x02\x04\x05\x12\x03\x20\x1f#\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\x03\
+    \x20$/\n\x0c\n\x05\x04\x05\x02\x04\x03\x12\x03\x2023\n\x0b\n\x04\x04\x05\
+    \x08\x04\x12\x03!\x045\n\x0c\n\x05\x04\x05\x08\x04\x01\x12\x03!\n\x1b\n\
+    \x0b\n\x04\x04\x05\x02\x05\x12\x03!\x1e3\n\x0c\n\x05\x04\x05\x02\x05\x05\
+    \x12\x03!\x1e#\n\x0c\n\x05\x04\x05\x02\x05\x01\x12\x03!$.\n\x0c\n\x05\
+    \x04\x05\x02\x05\x03\x12\x03!12\n\x0b\n\x04\x04\x05\x08\x05\x12\x03\"\
+    \x04*\n\x0c\n\x05\x04\x05\x08\x05\x01\x12\x03\"\n\x15\n\x0b\n\x04\x04\
+    \x05\x02\x06\x12\x03\"\x18(\n\x0c\n\x05\x04\x05\x02\x06\x05\x12\x03\"\
+    \x18\x1e\n\x0c\n\x05\x04\x05\x02\x06\x01\x12\x03\"\x1f#\n\x0c\n\x05\x04\
+    \x05\x02\x06\x03\x12\x03\"&'b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,6 +1,6 @@
 use crate::entities::{
     app::{App, RepeatedApp},
-    view::{RepeatedView, View, ViewType},
+    view::{RepeatedView, View, ViewType, ViewVisibility},
     workspace::Workspace,
 };
 use chrono::Utc;
@@ -61,5 +61,9 @@ fn create_default_view(app_id: String, time: chrono::DateTime<Utc>) -> View {
         belongings: Default::default(),
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        owner_id: "".to_owned(),
+        visibility: ViewVisibility::default(),
+        created_by: "".to_owned(),
+        icon: "".to_owned(),
     }
 }
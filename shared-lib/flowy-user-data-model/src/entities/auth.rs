@@ -28,6 +28,14 @@ pub struct SignInParams {
     pub name: String,
 }
 
+impl SignInParams {
+    pub fn validate(&self) -> Result<(), ErrorCode> {
+        let _ = UserEmail::parse(self.email.clone())?;
+        let _ = UserPassword::parse(self.password.clone())?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Default, ProtoBuf, Clone)]
 pub struct SignInResponse {
     #[pb(index = 1)]
@@ -41,6 +49,9 @@ pub struct SignInResponse {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub expires_at: i64,
 }
 
 impl TryInto<SignInParams> for SignInRequest {
@@ -97,6 +108,14 @@ pub struct SignUpParams {
     pub password: String,
 }
 
+impl SignUpParams {
+    pub fn validate(&self) -> Result<(), ErrorCode> {
+        let _ = UserEmail::parse(self.email.clone())?;
+        let _ = UserPassword::parse(self.password.clone())?;
+        Ok(())
+    }
+}
+
 #[derive(ProtoBuf, Debug, Default, Clone)]
 pub struct SignUpResponse {
     #[pb(index = 1)]
@@ -110,4 +129,7 @@ pub struct SignUpResponse {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub expires_at: i64,
 }
@@ -25,6 +25,9 @@ pub struct UserProfile {
 
     #[pb(index = 4)]
     pub token: String,
+
+    #[pb(index = 5)]
+    pub icon_url: String,
 }
 
 #[derive(ProtoBuf, Default)]
@@ -40,6 +43,9 @@ pub struct UpdateUserRequest {
 
     #[pb(index = 4, one_of)]
     pub password: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub icon_url: Option<String>,
 }
 
 impl UpdateUserRequest {
@@ -64,6 +70,11 @@ impl UpdateUserRequest {
         self.password = Some(password.to_owned());
         self
     }
+
+    pub fn icon_url(mut self, icon_url: &str) -> Self {
+        self.icon_url = Some(icon_url.to_owned());
+        self
+    }
 }
 
 #[derive(ProtoBuf, Default, Clone, Debug)]
@@ -79,6 +90,9 @@ pub struct UpdateUserParams {
 
     #[pb(index = 4, one_of)]
     pub password: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub icon_url: Option<String>,
 }
 
 impl UpdateUserParams {
@@ -103,6 +117,86 @@ impl UpdateUserParams {
         self.password = Some(password.to_owned());
         self
     }
+
+    pub fn icon_url(mut self, icon_url: &str) -> Self {
+        self.icon_url = Some(icon_url.to_owned());
+        self
+    }
+}
+
+#[derive(ProtoBuf, Default, Clone, Debug)]
+pub struct ChangePasswordParams {
+    #[pb(index = 1)]
+    pub old_password: String,
+
+    #[pb(index = 2)]
+    pub new_password: String,
+}
+
+impl ChangePasswordParams {
+    pub fn new(old_password: &str, new_password: &str) -> Self {
+        Self {
+            old_password: old_password.to_owned(),
+            new_password: new_password.to_owned(),
+        }
+    }
+
+    // The old password is only checked on the server, so it isn't parsed here.
+    pub fn validate(&self) -> Result<(), ErrorCode> {
+        let _ = UserPassword::parse(self.new_password.clone())?;
+        Ok(())
+    }
+}
+
+#[derive(ProtoBuf, Default, Clone, Debug)]
+pub struct DeleteAccountParams {
+    #[pb(index = 1)]
+    pub password: String,
+}
+
+impl DeleteAccountParams {
+    pub fn new(password: &str) -> Self {
+        Self {
+            password: password.to_owned(),
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Clone, Debug)]
+pub struct RequestEmailChangeParams {
+    #[pb(index = 1)]
+    pub new_email: String,
+}
+
+impl RequestEmailChangeParams {
+    pub fn new(new_email: &str) -> Self {
+        Self {
+            new_email: new_email.to_owned(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ErrorCode> {
+        let _ = UserEmail::parse(self.new_email.clone())?;
+        Ok(())
+    }
+}
+
+#[derive(ProtoBuf, Default, Clone, Debug)]
+pub struct ConfirmEmailChangeParams {
+    #[pb(index = 1)]
+    pub code: String,
+}
+
+impl ConfirmEmailChangeParams {
+    pub fn new(code: &str) -> Self { Self { code: code.to_owned() } }
+}
+
+// The email that the verification code was sent to, it replaces the user's
+// email once the code is confirmed.
+#[derive(ProtoBuf, Default, Clone, Debug)]
+pub struct ConfirmEmailChangeResponse {
+    #[pb(index = 1)]
+    pub email: String,
 }
 
 impl TryInto<UpdateUserParams> for UpdateUserRequest {
@@ -131,6 +225,7 @@ impl TryInto<UpdateUserParams> for UpdateUserRequest {
             name,
             email,
             password,
+            icon_url: self.icon_url,
         })
     }
 }
@@ -516,6 +516,7 @@ pub struct SignInResponse {
     pub name: ::std::string::String,
     pub email: ::std::string::String,
     pub token: ::std::string::String,
+    pub expires_at: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -635,6 +636,21 @@ impl SignInResponse {
     pub fn take_token(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.token, ::std::string::String::new())
     }
+
+    // int64 expires_at = 5;
+
+
+    pub fn get_expires_at(&self) -> i64 {
+        self.expires_at
+    }
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: i64) {
+        self.expires_at = v;
+    }
 }
 
 impl ::protobuf::Message for SignInResponse {
@@ -658,6 +674,13 @@ impl ::protobuf::Message for SignInResponse {
                 4 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.expires_at = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -682,6 +705,9 @@ impl ::protobuf::Message for SignInResponse {
         if !self.token.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.token);
         }
+        if self.expires_at != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.expires_at, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -700,6 +726,9 @@ impl ::protobuf::Message for SignInResponse {
         if !self.token.is_empty() {
             os.write_string(4, &self.token)?;
         }
+        if self.expires_at != 0 {
+            os.write_int64(5, self.expires_at)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -758,6 +787,11 @@ impl ::protobuf::Message for SignInResponse {
                 |m: &SignInResponse| { &m.token },
                 |m: &mut SignInResponse| { &mut m.token },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "expires_at",
+                |m: &SignInResponse| { &m.expires_at },
+                |m: &mut SignInResponse| { &mut m.expires_at },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<SignInResponse>(
                 "SignInResponse",
                 fields,
@@ -778,6 +812,7 @@ impl ::protobuf::Clear for SignInResponse {
         self.name.clear();
         self.email.clear();
         self.token.clear();
+        self.expires_at = 0;
         self.unknown_fields.clear();
     }
 }
@@ -1287,6 +1322,7 @@ pub struct SignUpResponse {
     pub name: ::std::string::String,
     pub email: ::std::string::String,
     pub token: ::std::string::String,
+    pub expires_at: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1406,6 +1442,21 @@ impl SignUpResponse {
     pub fn take_token(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.token, ::std::string::String::new())
     }
+
+    // int64 expires_at = 5;
+
+
+    pub fn get_expires_at(&self) -> i64 {
+        self.expires_at
+    }
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: i64) {
+        self.expires_at = v;
+    }
 }
 
 impl ::protobuf::Message for SignUpResponse {
@@ -1429,6 +1480,13 @@ impl ::protobuf::Message for SignUpResponse {
                 4 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.expires_at = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1453,6 +1511,9 @@ impl ::protobuf::Message for SignUpResponse {
         if !self.token.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.token);
         }
+        if self.expires_at != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.expires_at, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1471,6 +1532,9 @@ impl ::protobuf::Message for SignUpResponse {
         if !self.token.is_empty() {
             os.write_string(4, &self.token)?;
         }
+        if self.expires_at != 0 {
+            os.write_int64(5, self.expires_at)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1529,6 +1593,11 @@ impl ::protobuf::Message for SignUpResponse {
                 |m: &SignUpResponse| { &m.token },
                 |m: &mut SignUpResponse| { &mut m.token },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "expires_at",
+                |m: &SignUpResponse| { &m.expires_at },
+                |m: &mut SignUpResponse| { &mut m.expires_at },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<SignUpResponse>(
                 "SignUpResponse",
                 fields,
@@ -1549,6 +1618,7 @@ impl ::protobuf::Clear for SignUpResponse {
         self.name.clear();
         self.email.clear();
         self.token.clear();
+        self.expires_at = 0;
         self.unknown_fields.clear();
     }
 }
@@ -1570,82 +1640,89 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     R\x05email\x12\x1a\n\x08password\x18\x02\x20\x01(\tR\x08password\x12\x12\
     \n\x04name\x18\x03\x20\x01(\tR\x04name\"T\n\x0cSignInParams\x12\x14\n\
     \x05email\x18\x01\x20\x01(\tR\x05email\x12\x1a\n\x08password\x18\x02\x20\
-    \x01(\tR\x08password\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\"i\n\
-    \x0eSignInResponse\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\
-    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05email\x18\
+    \x01(\tR\x08password\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\"\x88\
+    \x01\n\x0eSignInResponse\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06use\
+    rId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05email\x18\
     \x03\x20\x01(\tR\x05email\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05toke\
-    n\"U\n\rSignUpRequest\x12\x14\n\x05email\x18\x01\x20\x01(\tR\x05email\
-    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08password\x18\
-    \x03\x20\x01(\tR\x08password\"T\n\x0cSignUpParams\x12\x14\n\x05email\x18\
-    \x01\x20\x01(\tR\x05email\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\
-    \x12\x1a\n\x08password\x18\x03\x20\x01(\tR\x08password\"i\n\x0eSignUpRes\
-    ponse\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x12\n\x04na\
-    me\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05email\x18\x03\x20\x01(\tR\
-    \x05email\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05tokenJ\xee\t\n\x06\
-    \x12\x04\0\0!\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\
-    \x04\x02\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\
-    \x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\
-    \x02\0\x03\x12\x03\x03\x13\x14\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\
-    \x18\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\
-    \x02\x01\x01\x12\x03\x04\x0b\x13\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
-    \x04\x16\x17\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\
-    \x04\0\x02\x02\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
-    \x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x12\x13\n\n\n\
-    \x02\x04\x01\x12\x04\x07\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\
-    \x14\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x15\n\x0c\n\x05\x04\x01\
-    \x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\
-    \x0b\x10\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x13\x14\n\x0b\n\x04\
-    \x04\x01\x02\x01\x12\x03\t\x04\x18\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\
-    \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\x13\n\x0c\n\
-    \x05\x04\x01\x02\x01\x03\x12\x03\t\x16\x17\n\x0b\n\x04\x04\x01\x02\x02\
-    \x12\x03\n\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\n\x04\n\n\x0c\
-    \n\x05\x04\x01\x02\x02\x01\x12\x03\n\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\
-    \x03\x12\x03\n\x12\x13\n\n\n\x02\x04\x02\x12\x04\x0c\0\x11\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03\x0c\x08\x16\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\
-    \x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\
-    \x02\0\x01\x12\x03\r\x0b\x12\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x15\
-    \x16\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x04\x02\
-    \x02\x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\
-    \x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x12\x13\n\x0b\n\
-    \x04\x04\x02\x02\x02\x12\x03\x0f\x04\x15\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x0b\x10\n\
-    \x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x13\x14\n\x0b\n\x04\x04\x02\
-    \x02\x03\x12\x03\x10\x04\x15\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x10\
-    \x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10\x0b\x10\n\x0c\n\x05\
-    \x04\x02\x02\x03\x03\x12\x03\x10\x13\x14\n\n\n\x02\x04\x03\x12\x04\x12\0\
-    \x16\x01\n\n\n\x03\x04\x03\x01\x12\x03\x12\x08\x15\n\x0b\n\x04\x04\x03\
-    \x02\0\x12\x03\x13\x04\x15\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x13\x04\
-    \n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x13\x0b\x10\n\x0c\n\x05\x04\x03\
-    \x02\0\x03\x12\x03\x13\x13\x14\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x14\
-    \x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x14\x04\n\n\x0c\n\x05\
-    \x04\x03\x02\x01\x01\x12\x03\x14\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\
-    \x12\x03\x14\x12\x13\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x15\x04\x18\n\
-    \x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x03\x02\
-    \x02\x01\x12\x03\x15\x0b\x13\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x15\
-    \x16\x17\n\n\n\x02\x04\x04\x12\x04\x17\0\x1b\x01\n\n\n\x03\x04\x04\x01\
-    \x12\x03\x17\x08\x14\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x18\x04\x15\n\x0c\
-    \n\x05\x04\x04\x02\0\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\
-    \x12\x03\x18\x0b\x10\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x18\x13\x14\n\
-    \x0b\n\x04\x04\x04\x02\x01\x12\x03\x19\x04\x14\n\x0c\n\x05\x04\x04\x02\
-    \x01\x05\x12\x03\x19\x04\n\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x19\
-    \x0b\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x19\x12\x13\n\x0b\n\x04\
-    \x04\x04\x02\x02\x12\x03\x1a\x04\x18\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\
-    \x03\x1a\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x1a\x0b\x13\n\x0c\
-    \n\x05\x04\x04\x02\x02\x03\x12\x03\x1a\x16\x17\n\n\n\x02\x04\x05\x12\x04\
-    \x1c\0!\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1c\x08\x16\n\x0b\n\x04\x04\
-    \x05\x02\0\x12\x03\x1d\x04\x17\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1d\
-    \x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1d\x0b\x12\n\x0c\n\x05\x04\
-    \x05\x02\0\x03\x12\x03\x1d\x15\x16\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\
-    \x1e\x04\x14\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\x1e\x04\n\n\x0c\n\
-    \x05\x04\x05\x02\x01\x01\x12\x03\x1e\x0b\x0f\n\x0c\n\x05\x04\x05\x02\x01\
-    \x03\x12\x03\x1e\x12\x13\n\x0b\n\x04\x04\x05\x02\x02\x12\x03\x1f\x04\x15\
-    \n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03\x1f\x04\n\n\x0c\n\x05\x04\x05\
-    \x02\x02\x01\x12\x03\x1f\x0b\x10\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03\
-    \x1f\x13\x14\n\x0b\n\x04\x04\x05\x02\x03\x12\x03\x20\x04\x15\n\x0c\n\x05\
-    \x04\x05\x02\x03\x05\x12\x03\x20\x04\n\n\x0c\n\x05\x04\x05\x02\x03\x01\
-    \x12\x03\x20\x0b\x10\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x03\x20\x13\x14\
-    b\x06proto3\
+    n\x12\x1d\n\nexpires_at\x18\x05\x20\x01(\x03R\texpiresAt\"U\n\rSignUpReq\
+    uest\x12\x14\n\x05email\x18\x01\x20\x01(\tR\x05email\x12\x12\n\x04name\
+    \x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08password\x18\x03\x20\x01(\tR\
+    \x08password\"T\n\x0cSignUpParams\x12\x14\n\x05email\x18\x01\x20\x01(\tR\
+    \x05email\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x1a\n\x08pas\
+    sword\x18\x03\x20\x01(\tR\x08password\"\x88\x01\n\x0eSignUpResponse\x12\
+    \x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x12\n\x04name\x18\
+    \x02\x20\x01(\tR\x04name\x12\x14\n\x05email\x18\x03\x20\x01(\tR\x05email\
+    \x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05token\x12\x1d\n\nexpires_at\
+    \x18\x05\x20\x01(\x03R\texpiresAtJ\xdc\n\n\x06\x12\x04\0\0#\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x06\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x02\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\
+    \x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x13\
+    \x14\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x18\n\x0c\n\x05\x04\0\x02\
+    \x01\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\
+    \x13\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x16\x17\n\x0b\n\x04\x04\0\
+    \x02\x02\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\
+    \x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\
+    \0\x02\x02\x03\x12\x03\x05\x12\x13\n\n\n\x02\x04\x01\x12\x04\x07\0\x0b\
+    \x01\n\n\n\x03\x04\x01\x01\x12\x03\x07\x08\x14\n\x0b\n\x04\x04\x01\x02\0\
+    \x12\x03\x08\x04\x15\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x08\x0b\x10\n\x0c\n\x05\x04\x01\x02\
+    \0\x03\x12\x03\x08\x13\x14\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\x18\
+    \n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\
+    \x01\x01\x12\x03\t\x0b\x13\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\x16\
+    \x17\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\n\x04\x14\n\x0c\n\x05\x04\x01\
+    \x02\x02\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\n\
+    \x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\n\x12\x13\n\n\n\x02\x04\
+    \x02\x12\x04\x0c\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x16\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\
+    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\r\x0b\x12\n\x0c\n\
+    \x05\x04\x02\x02\0\x03\x12\x03\r\x15\x16\n\x0b\n\x04\x04\x02\x02\x01\x12\
+    \x03\x0e\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x04\n\n\x0c\
+    \n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\
+    \x01\x03\x12\x03\x0e\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x04\
+    \x15\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\
+    \x02\x02\x02\x01\x12\x03\x0f\x0b\x10\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\
+    \x03\x0f\x13\x14\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x10\x04\x15\n\x0c\n\
+    \x05\x04\x02\x02\x03\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x02\x02\x03\
+    \x01\x12\x03\x10\x0b\x10\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x10\x13\
+    \x14\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x11\x04\x19\n\x0c\n\x05\x04\x02\
+    \x02\x04\x05\x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\
+    \x11\n\x14\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x11\x17\x18\n\n\n\x02\
+    \x04\x03\x12\x04\x13\0\x17\x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x15\
+    \n\x0b\n\x04\x04\x03\x02\0\x12\x03\x14\x04\x15\n\x0c\n\x05\x04\x03\x02\0\
+    \x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x14\x0b\x10\
+    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x13\x14\n\x0b\n\x04\x04\x03\
+    \x02\x01\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x15\
+    \x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x15\x0b\x0f\n\x0c\n\x05\
+    \x04\x03\x02\x01\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x03\x02\x02\x12\
+    \x03\x16\x04\x18\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x16\x04\n\n\x0c\
+    \n\x05\x04\x03\x02\x02\x01\x12\x03\x16\x0b\x13\n\x0c\n\x05\x04\x03\x02\
+    \x02\x03\x12\x03\x16\x16\x17\n\n\n\x02\x04\x04\x12\x04\x18\0\x1c\x01\n\n\
+    \n\x03\x04\x04\x01\x12\x03\x18\x08\x14\n\x0b\n\x04\x04\x04\x02\0\x12\x03\
+    \x19\x04\x15\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x19\x04\n\n\x0c\n\x05\
+    \x04\x04\x02\0\x01\x12\x03\x19\x0b\x10\n\x0c\n\x05\x04\x04\x02\0\x03\x12\
+    \x03\x19\x13\x14\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x1a\x04\x14\n\x0c\n\
+    \x05\x04\x04\x02\x01\x05\x12\x03\x1a\x04\n\n\x0c\n\x05\x04\x04\x02\x01\
+    \x01\x12\x03\x1a\x0b\x0f\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x1a\x12\
+    \x13\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x1b\x04\x18\n\x0c\n\x05\x04\x04\
+    \x02\x02\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\
+    \x1b\x0b\x13\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x1b\x16\x17\n\n\n\
+    \x02\x04\x05\x12\x04\x1d\0#\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1d\x08\
+    \x16\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1e\x04\x17\n\x0c\n\x05\x04\x05\
+    \x02\0\x05\x12\x03\x1e\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1e\
+    \x0b\x12\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1e\x15\x16\n\x0b\n\x04\
+    \x04\x05\x02\x01\x12\x03\x1f\x04\x14\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\
+    \x03\x1f\x04\n\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03\x1f\x0b\x0f\n\x0c\
+    \n\x05\x04\x05\x02\x01\x03\x12\x03\x1f\x12\x13\n\x0b\n\x04\x04\x05\x02\
+    \x02\x12\x03\x20\x04\x15\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03\x20\x04\
+    \n\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x03\x20\x0b\x10\n\x0c\n\x05\x04\
+    \x05\x02\x02\x03\x12\x03\x20\x13\x14\n\x0b\n\x04\x04\x05\x02\x03\x12\x03\
+    !\x04\x15\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\x03!\x04\n\n\x0c\n\x05\x04\
+    \x05\x02\x03\x01\x12\x03!\x0b\x10\n\x0c\n\x05\x04\x05\x02\x03\x03\x12\
+    \x03!\x13\x14\n\x0b\n\x04\x04\x05\x02\x04\x12\x03\"\x04\x19\n\x0c\n\x05\
+    \x04\x05\x02\x04\x05\x12\x03\"\x04\t\n\x0c\n\x05\x04\x05\x02\x04\x01\x12\
+    \x03\"\n\x14\n\x0c\n\x05\x04\x05\x02\x04\x03\x12\x03\"\x17\x18b\x06proto\
+    3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
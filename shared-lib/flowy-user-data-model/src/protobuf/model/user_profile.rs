@@ -189,6 +189,7 @@ pub struct UserProfile {
     pub email: ::std::string::String,
     pub name: ::std::string::String,
     pub token: ::std::string::String,
+    pub icon_url: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -308,6 +309,32 @@ impl UserProfile {
     pub fn take_token(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.token, ::std::string::String::new())
     }
+
+    // string icon_url = 5;
+
+
+    pub fn get_icon_url(&self) -> &str {
+        &self.icon_url
+    }
+    pub fn clear_icon_url(&mut self) {
+        self.icon_url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon_url(&mut self, v: ::std::string::String) {
+        self.icon_url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_icon_url(&mut self) -> &mut ::std::string::String {
+        &mut self.icon_url
+    }
+
+    // Take field
+    pub fn take_icon_url(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.icon_url, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for UserProfile {
@@ -331,6 +358,9 @@ impl ::protobuf::Message for UserProfile {
                 4 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
                 },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.icon_url)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -355,6 +385,9 @@ impl ::protobuf::Message for UserProfile {
         if !self.token.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.token);
         }
+        if !self.icon_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.icon_url);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -373,6 +406,9 @@ impl ::protobuf::Message for UserProfile {
         if !self.token.is_empty() {
             os.write_string(4, &self.token)?;
         }
+        if !self.icon_url.is_empty() {
+            os.write_string(5, &self.icon_url)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -431,6 +467,11 @@ impl ::protobuf::Message for UserProfile {
                 |m: &UserProfile| { &m.token },
                 |m: &mut UserProfile| { &mut m.token },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "icon_url",
+                |m: &UserProfile| { &m.icon_url },
+                |m: &mut UserProfile| { &mut m.icon_url },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserProfile>(
                 "UserProfile",
                 fields,
@@ -451,6 +492,7 @@ impl ::protobuf::Clear for UserProfile {
         self.email.clear();
         self.name.clear();
         self.token.clear();
+        self.icon_url.clear();
         self.unknown_fields.clear();
     }
 }
@@ -475,6 +517,7 @@ pub struct UpdateUserRequest {
     pub one_of_name: ::std::option::Option<UpdateUserRequest_oneof_one_of_name>,
     pub one_of_email: ::std::option::Option<UpdateUserRequest_oneof_one_of_email>,
     pub one_of_password: ::std::option::Option<UpdateUserRequest_oneof_one_of_password>,
+    pub one_of_icon_url: ::std::option::Option<UpdateUserRequest_oneof_one_of_icon_url>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -501,6 +544,11 @@ pub enum UpdateUserRequest_oneof_one_of_password {
     password(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateUserRequest_oneof_one_of_icon_url {
+    icon_url(::std::string::String),
+}
+
 impl UpdateUserRequest {
     pub fn new() -> UpdateUserRequest {
         ::std::default::Default::default()
@@ -678,6 +726,55 @@ impl UpdateUserRequest {
             ::std::string::String::new()
         }
     }
+
+    // string icon_url = 5;
+
+
+    pub fn get_icon_url(&self) -> &str {
+        match self.one_of_icon_url {
+            ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_icon_url(&mut self) {
+        self.one_of_icon_url = ::std::option::Option::None;
+    }
+
+    pub fn has_icon_url(&self) -> bool {
+        match self.one_of_icon_url {
+            ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon_url(&mut self, v: ::std::string::String) {
+        self.one_of_icon_url = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_icon_url(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(_)) = self.one_of_icon_url {
+        } else {
+            self.one_of_icon_url = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(::std::string::String::new()));
+        }
+        match self.one_of_icon_url {
+            ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_icon_url(&mut self) -> ::std::string::String {
+        if self.has_icon_url() {
+            match self.one_of_icon_url.take() {
+                ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateUserRequest {
@@ -710,6 +807,12 @@ impl ::protobuf::Message for UpdateUserRequest {
                     }
                     self.one_of_password = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_password::password(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_icon_url = ::std::option::Option::Some(UpdateUserRequest_oneof_one_of_icon_url::icon_url(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -746,6 +849,13 @@ impl ::protobuf::Message for UpdateUserRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon_url {
+            match v {
+                &UpdateUserRequest_oneof_one_of_icon_url::icon_url(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -776,6 +886,13 @@ impl ::protobuf::Message for UpdateUserRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon_url {
+            match v {
+                &UpdateUserRequest_oneof_one_of_icon_url::icon_url(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -834,6 +951,11 @@ impl ::protobuf::Message for UpdateUserRequest {
                 UpdateUserRequest::has_password,
                 UpdateUserRequest::get_password,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "icon_url",
+                UpdateUserRequest::has_icon_url,
+                UpdateUserRequest::get_icon_url,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateUserRequest>(
                 "UpdateUserRequest",
                 fields,
@@ -854,6 +976,7 @@ impl ::protobuf::Clear for UpdateUserRequest {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_email = ::std::option::Option::None;
         self.one_of_password = ::std::option::Option::None;
+        self.one_of_icon_url = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -878,6 +1001,7 @@ pub struct UpdateUserParams {
     pub one_of_name: ::std::option::Option<UpdateUserParams_oneof_one_of_name>,
     pub one_of_email: ::std::option::Option<UpdateUserParams_oneof_one_of_email>,
     pub one_of_password: ::std::option::Option<UpdateUserParams_oneof_one_of_password>,
+    pub one_of_icon_url: ::std::option::Option<UpdateUserParams_oneof_one_of_icon_url>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -904,6 +1028,11 @@ pub enum UpdateUserParams_oneof_one_of_password {
     password(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateUserParams_oneof_one_of_icon_url {
+    icon_url(::std::string::String),
+}
+
 impl UpdateUserParams {
     pub fn new() -> UpdateUserParams {
         ::std::default::Default::default()
@@ -1081,6 +1210,55 @@ impl UpdateUserParams {
             ::std::string::String::new()
         }
     }
+
+    // string icon_url = 5;
+
+
+    pub fn get_icon_url(&self) -> &str {
+        match self.one_of_icon_url {
+            ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_icon_url(&mut self) {
+        self.one_of_icon_url = ::std::option::Option::None;
+    }
+
+    pub fn has_icon_url(&self) -> bool {
+        match self.one_of_icon_url {
+            ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_icon_url(&mut self, v: ::std::string::String) {
+        self.one_of_icon_url = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_icon_url(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(_)) = self.one_of_icon_url {
+        } else {
+            self.one_of_icon_url = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(::std::string::String::new()));
+        }
+        match self.one_of_icon_url {
+            ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_icon_url(&mut self) -> ::std::string::String {
+        if self.has_icon_url() {
+            match self.one_of_icon_url.take() {
+                ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateUserParams {
@@ -1113,6 +1291,12 @@ impl ::protobuf::Message for UpdateUserParams {
                     }
                     self.one_of_password = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_password::password(is.read_string()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_icon_url = ::std::option::Option::Some(UpdateUserParams_oneof_one_of_icon_url::icon_url(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1149,6 +1333,13 @@ impl ::protobuf::Message for UpdateUserParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon_url {
+            match v {
+                &UpdateUserParams_oneof_one_of_icon_url::icon_url(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1179,6 +1370,13 @@ impl ::protobuf::Message for UpdateUserParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_icon_url {
+            match v {
+                &UpdateUserParams_oneof_one_of_icon_url::icon_url(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1237,6 +1435,11 @@ impl ::protobuf::Message for UpdateUserParams {
                 UpdateUserParams::has_password,
                 UpdateUserParams::get_password,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "icon_url",
+                UpdateUserParams::has_icon_url,
+                UpdateUserParams::get_icon_url,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateUserParams>(
                 "UpdateUserParams",
                 fields,
@@ -1257,6 +1460,7 @@ impl ::protobuf::Clear for UpdateUserParams {
         self.one_of_name = ::std::option::Option::None;
         self.one_of_email = ::std::option::Option::None;
         self.one_of_password = ::std::option::Option::None;
+        self.one_of_icon_url = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -1273,69 +1477,948 @@ impl ::protobuf::reflect::ProtobufValue for UpdateUserParams {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct ChangePasswordParams {
+    // message fields
+    pub old_password: ::std::string::String,
+    pub new_password: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChangePasswordParams {
+    fn default() -> &'a ChangePasswordParams {
+        <ChangePasswordParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChangePasswordParams {
+    pub fn new() -> ChangePasswordParams {
+        ::std::default::Default::default()
+    }
+
+    // string old_password = 1;
+
+
+    pub fn get_old_password(&self) -> &str {
+        &self.old_password
+    }
+    pub fn clear_old_password(&mut self) {
+        self.old_password.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_old_password(&mut self, v: ::std::string::String) {
+        self.old_password = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_old_password(&mut self) -> &mut ::std::string::String {
+        &mut self.old_password
+    }
+
+    // Take field
+    pub fn take_old_password(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.old_password, ::std::string::String::new())
+    }
+
+    // string new_password = 2;
+
+
+    pub fn get_new_password(&self) -> &str {
+        &self.new_password
+    }
+    pub fn clear_new_password(&mut self) {
+        self.new_password.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_password(&mut self, v: ::std::string::String) {
+        self.new_password = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_password(&mut self) -> &mut ::std::string::String {
+        &mut self.new_password
+    }
+
+    // Take field
+    pub fn take_new_password(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_password, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ChangePasswordParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.old_password)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_password)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.old_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.old_password);
+        }
+        if !self.new_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_password);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.old_password.is_empty() {
+            os.write_string(1, &self.old_password)?;
+        }
+        if !self.new_password.is_empty() {
+            os.write_string(2, &self.new_password)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ChangePasswordParams {
+        ChangePasswordParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "old_password",
+                |m: &ChangePasswordParams| { &m.old_password },
+                |m: &mut ChangePasswordParams| { &mut m.old_password },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "new_password",
+                |m: &ChangePasswordParams| { &m.new_password },
+                |m: &mut ChangePasswordParams| { &mut m.new_password },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ChangePasswordParams>(
+                "ChangePasswordParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ChangePasswordParams {
+        static instance: ::protobuf::rt::LazyV2<ChangePasswordParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ChangePasswordParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ChangePasswordParams {
+    fn clear(&mut self) {
+        self.old_password.clear();
+        self.new_password.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChangePasswordParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChangePasswordParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DeleteAccountParams {
+    // message fields
+    pub password: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteAccountParams {
+    fn default() -> &'a DeleteAccountParams {
+        <DeleteAccountParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteAccountParams {
+    pub fn new() -> DeleteAccountParams {
+        ::std::default::Default::default()
+    }
+
+    // string password = 1;
+
+
+    pub fn get_password(&self) -> &str {
+        &self.password
+    }
+    pub fn clear_password(&mut self) {
+        self.password.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password(&mut self, v: ::std::string::String) {
+        self.password = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_password(&mut self) -> &mut ::std::string::String {
+        &mut self.password
+    }
+
+    // Take field
+    pub fn take_password(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.password, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for DeleteAccountParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.password)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.password.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.password);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.password.is_empty() {
+            os.write_string(1, &self.password)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DeleteAccountParams {
+        DeleteAccountParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "password",
+                |m: &DeleteAccountParams| { &m.password },
+                |m: &mut DeleteAccountParams| { &mut m.password },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DeleteAccountParams>(
+                "DeleteAccountParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DeleteAccountParams {
+        static instance: ::protobuf::rt::LazyV2<DeleteAccountParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DeleteAccountParams::new)
+    }
+}
+
+impl ::protobuf::Clear for DeleteAccountParams {
+    fn clear(&mut self) {
+        self.password.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteAccountParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteAccountParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RequestEmailChangeParams {
+    // message fields
+    pub new_email: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RequestEmailChangeParams {
+    fn default() -> &'a RequestEmailChangeParams {
+        <RequestEmailChangeParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RequestEmailChangeParams {
+    pub fn new() -> RequestEmailChangeParams {
+        ::std::default::Default::default()
+    }
+
+    // string new_email = 1;
+
+
+    pub fn get_new_email(&self) -> &str {
+        &self.new_email
+    }
+    pub fn clear_new_email(&mut self) {
+        self.new_email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_new_email(&mut self, v: ::std::string::String) {
+        self.new_email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_new_email(&mut self) -> &mut ::std::string::String {
+        &mut self.new_email
+    }
+
+    // Take field
+    pub fn take_new_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.new_email, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RequestEmailChangeParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.new_email)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.new_email.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.new_email);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.new_email.is_empty() {
+            os.write_string(1, &self.new_email)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RequestEmailChangeParams {
+        RequestEmailChangeParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "new_email",
+                |m: &RequestEmailChangeParams| { &m.new_email },
+                |m: &mut RequestEmailChangeParams| { &mut m.new_email },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RequestEmailChangeParams>(
+                "RequestEmailChangeParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RequestEmailChangeParams {
+        static instance: ::protobuf::rt::LazyV2<RequestEmailChangeParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RequestEmailChangeParams::new)
+    }
+}
+
+impl ::protobuf::Clear for RequestEmailChangeParams {
+    fn clear(&mut self) {
+        self.new_email.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RequestEmailChangeParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RequestEmailChangeParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfirmEmailChangeParams {
+    // message fields
+    pub code: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConfirmEmailChangeParams {
+    fn default() -> &'a ConfirmEmailChangeParams {
+        <ConfirmEmailChangeParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ConfirmEmailChangeParams {
+    pub fn new() -> ConfirmEmailChangeParams {
+        ::std::default::Default::default()
+    }
+
+    // string code = 1;
+
+
+    pub fn get_code(&self) -> &str {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::string::String) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::string::String {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.code, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ConfirmEmailChangeParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.code)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.code);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.code.is_empty() {
+            os.write_string(1, &self.code)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfirmEmailChangeParams {
+        ConfirmEmailChangeParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "code",
+                |m: &ConfirmEmailChangeParams| { &m.code },
+                |m: &mut ConfirmEmailChangeParams| { &mut m.code },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConfirmEmailChangeParams>(
+                "ConfirmEmailChangeParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConfirmEmailChangeParams {
+        static instance: ::protobuf::rt::LazyV2<ConfirmEmailChangeParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConfirmEmailChangeParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ConfirmEmailChangeParams {
+    fn clear(&mut self) {
+        self.code.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfirmEmailChangeParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfirmEmailChangeParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ConfirmEmailChangeResponse {
+    // message fields
+    pub email: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ConfirmEmailChangeResponse {
+    fn default() -> &'a ConfirmEmailChangeResponse {
+        <ConfirmEmailChangeResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ConfirmEmailChangeResponse {
+    pub fn new() -> ConfirmEmailChangeResponse {
+        ::std::default::Default::default()
+    }
+
+    // string email = 1;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ConfirmEmailChangeResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.email);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.email.is_empty() {
+            os.write_string(1, &self.email)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ConfirmEmailChangeResponse {
+        ConfirmEmailChangeResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &ConfirmEmailChangeResponse| { &m.email },
+                |m: &mut ConfirmEmailChangeResponse| { &mut m.email },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ConfirmEmailChangeResponse>(
+                "ConfirmEmailChangeResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ConfirmEmailChangeResponse {
+        static instance: ::protobuf::rt::LazyV2<ConfirmEmailChangeResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ConfirmEmailChangeResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for ConfirmEmailChangeResponse {
+    fn clear(&mut self) {
+        self.email.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ConfirmEmailChangeResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ConfirmEmailChangeResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x12user_profile.proto\"!\n\tUserToken\x12\x14\n\x05token\x18\x01\x20\
-    \x01(\tR\x05token\"]\n\x0bUserProfile\x12\x0e\n\x02id\x18\x01\x20\x01(\t\
+    \x01(\tR\x05token\"x\n\x0bUserProfile\x12\x0e\n\x02id\x18\x01\x20\x01(\t\
     R\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\tR\x05email\x12\x12\n\x04nam\
     e\x18\x03\x20\x01(\tR\x04name\x12\x14\n\x05token\x18\x04\x20\x01(\tR\x05\
-    token\"\xa1\x01\n\x11UpdateUserRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\
-    \tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x16\n\x05\
-    email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\x08password\x18\x04\
-    \x20\x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\x0e\n\x0cone_of_email\
-    B\x11\n\x0fone_of_password\"\xa0\x01\n\x10UpdateUserParams\x12\x0e\n\x02\
-    id\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\
-    \x04name\x12\x16\n\x05email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\
-    \x08password\x18\x04\x20\x01(\tH\x02R\x08passwordB\r\n\x0bone_of_nameB\
-    \x0e\n\x0cone_of_emailB\x11\n\x0fone_of_passwordJ\xdf\x07\n\x06\x12\x04\
-    \0\0\x16\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\
-    \0\x04\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x11\n\x0b\n\x04\x04\0\x02\
-    \0\x12\x03\x03\x04\x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\
-    \x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x03\x13\x14\n\n\n\x02\x04\x01\x12\x04\x05\0\n\x01\n\n\n\x03\
-    \x04\x01\x01\x12\x03\x05\x08\x13\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\
-    \x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\
-    \x01\x02\0\x01\x12\x03\x06\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\
-    \x06\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x07\x04\x15\n\x0c\n\x05\
-    \x04\x01\x02\x01\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\
-    \x12\x03\x07\x0b\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x07\x13\x14\
-    \n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x08\x04\x14\n\x0c\n\x05\x04\x01\x02\
-    \x02\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x08\
-    \x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x08\x12\x13\n\x0b\n\x04\
-    \x04\x01\x02\x03\x12\x03\t\x04\x15\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\
-    \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\t\x0b\x10\n\x0c\n\
-    \x05\x04\x01\x02\x03\x03\x12\x03\t\x13\x14\n\n\n\x02\x04\x02\x12\x04\x0b\
-    \0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x19\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03\x0c\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0c\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\r\n\x0c\n\x05\x04\x02\
-    \x02\0\x03\x12\x03\x0c\x10\x11\n\x0b\n\x04\x04\x02\x08\0\x12\x03\r\x04*\
-    \n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\r\n\x15\n\x0b\n\x04\x04\x02\x02\
-    \x01\x12\x03\r\x18(\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x18\x1e\n\
-    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\r\x1f#\n\x0c\n\x05\x04\x02\x02\
-    \x01\x03\x12\x03\r&'\n\x0b\n\x04\x04\x02\x08\x01\x12\x03\x0e\x04,\n\x0c\
-    \n\x05\x04\x02\x08\x01\x01\x12\x03\x0e\n\x16\n\x0b\n\x04\x04\x02\x02\x02\
-    \x12\x03\x0e\x19*\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0e\x19\x1f\n\
-    \x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0e\x20%\n\x0c\n\x05\x04\x02\x02\
-    \x02\x03\x12\x03\x0e()\n\x0b\n\x04\x04\x02\x08\x02\x12\x03\x0f\x042\n\
-    \x0c\n\x05\x04\x02\x08\x02\x01\x12\x03\x0f\n\x19\n\x0b\n\x04\x04\x02\x02\
-    \x03\x12\x03\x0f\x1c0\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x0f\x1c\"\
-    \n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x0f#+\n\x0c\n\x05\x04\x02\x02\
-    \x03\x03\x12\x03\x0f./\n\n\n\x02\x04\x03\x12\x04\x11\0\x16\x01\n\n\n\x03\
-    \x04\x03\x01\x12\x03\x11\x08\x18\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\
-    \x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\
-    \x03\x02\0\x01\x12\x03\x12\x0b\r\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\
-    \x12\x10\x11\n\x0b\n\x04\x04\x03\x08\0\x12\x03\x13\x04*\n\x0c\n\x05\x04\
-    \x03\x08\0\x01\x12\x03\x13\n\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\
-    \x18(\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x13\x18\x1e\n\x0c\n\x05\
-    \x04\x03\x02\x01\x01\x12\x03\x13\x1f#\n\x0c\n\x05\x04\x03\x02\x01\x03\
-    \x12\x03\x13&'\n\x0b\n\x04\x04\x03\x08\x01\x12\x03\x14\x04,\n\x0c\n\x05\
-    \x04\x03\x08\x01\x01\x12\x03\x14\n\x16\n\x0b\n\x04\x04\x03\x02\x02\x12\
-    \x03\x14\x19*\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x14\x19\x1f\n\x0c\
-    \n\x05\x04\x03\x02\x02\x01\x12\x03\x14\x20%\n\x0c\n\x05\x04\x03\x02\x02\
-    \x03\x12\x03\x14()\n\x0b\n\x04\x04\x03\x08\x02\x12\x03\x15\x042\n\x0c\n\
-    \x05\x04\x03\x08\x02\x01\x12\x03\x15\n\x19\n\x0b\n\x04\x04\x03\x02\x03\
-    \x12\x03\x15\x1c0\n\x0c\n\x05\x04\x03\x02\x03\x05\x12\x03\x15\x1c\"\n\
-    \x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x15#+\n\x0c\n\x05\x04\x03\x02\x03\
-    \x03\x12\x03\x15./b\x06proto3\
+    token\x12\x19\n\x08icon_url\x18\x05\x20\x01(\tR\x07iconUrl\"\xd1\x01\n\
+    \x11UpdateUserRequest\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x14\
+    \n\x04name\x18\x02\x20\x01(\tH\0R\x04name\x12\x16\n\x05email\x18\x03\x20\
+    \x01(\tH\x01R\x05email\x12\x1c\n\x08password\x18\x04\x20\x01(\tH\x02R\
+    \x08password\x12\x1b\n\x08icon_url\x18\x05\x20\x01(\tH\x03R\x07iconUrlB\
+    \r\n\x0bone_of_nameB\x0e\n\x0cone_of_emailB\x11\n\x0fone_of_passwordB\
+    \x11\n\x0fone_of_icon_url\"\xd0\x01\n\x10UpdateUserParams\x12\x0e\n\x02i\
+    d\x18\x01\x20\x01(\tR\x02id\x12\x14\n\x04name\x18\x02\x20\x01(\tH\0R\x04\
+    name\x12\x16\n\x05email\x18\x03\x20\x01(\tH\x01R\x05email\x12\x1c\n\x08p\
+    assword\x18\x04\x20\x01(\tH\x02R\x08password\x12\x1b\n\x08icon_url\x18\
+    \x05\x20\x01(\tH\x03R\x07iconUrlB\r\n\x0bone_of_nameB\x0e\n\x0cone_of_em\
+    ailB\x11\n\x0fone_of_passwordB\x11\n\x0fone_of_icon_url\"\\\n\x14ChangeP\
+    asswordParams\x12!\n\x0cold_password\x18\x01\x20\x01(\tR\x0boldPassword\
+    \x12!\n\x0cnew_password\x18\x02\x20\x01(\tR\x0bnewPassword\"1\n\x13Delet\
+    eAccountParams\x12\x1a\n\x08password\x18\x01\x20\x01(\tR\x08password\"7\
+    \n\x18RequestEmailChangeParams\x12\x1b\n\tnew_email\x18\x01\x20\x01(\tR\
+    \x08newEmail\".\n\x18ConfirmEmailChangeParams\x12\x12\n\x04code\x18\x01\
+    \x20\x01(\tR\x04code\"2\n\x1aConfirmEmailChangeResponse\x12\x14\n\x05ema\
+    il\x18\x01\x20\x01(\tR\x05emailJ\xfc\x0c\n\x06\x12\x04\0\0)\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x02\x08\x11\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\
+    \x15\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x03\x0b\x10\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x13\
+    \x14\n\n\n\x02\x04\x01\x12\x04\x05\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\
+    \x03\x05\x08\x13\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\x04\x12\n\x0c\n\
+    \x05\x04\x01\x02\0\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\
+    \x12\x03\x06\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x06\x10\x11\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\x07\x04\x15\n\x0c\n\x05\x04\x01\x02\
+    \x01\x05\x12\x03\x07\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x07\
+    \x0b\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x07\x13\x14\n\x0b\n\x04\
+    \x04\x01\x02\x02\x12\x03\x08\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\
+    \x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x08\x0b\x0f\n\x0c\
+    \n\x05\x04\x01\x02\x02\x03\x12\x03\x08\x12\x13\n\x0b\n\x04\x04\x01\x02\
+    \x03\x12\x03\t\x04\x15\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\t\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\t\x0b\x10\n\x0c\n\x05\x04\x01\x02\
+    \x03\x03\x12\x03\t\x13\x14\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\n\x04\x18\
+    \n\x0c\n\x05\x04\x01\x02\x04\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\
+    \x04\x01\x12\x03\n\x0b\x13\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\n\x16\
+    \x17\n\n\n\x02\x04\x02\x12\x04\x0c\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\
+    \x03\x0c\x08\x19\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x12\n\x0c\n\x05\
+    \x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \r\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x10\x11\n\x0b\n\x04\x04\
+    \x02\x08\0\x12\x03\x0e\x04*\n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\x0e\n\
+    \x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x18(\n\x0c\n\x05\x04\x02\
+    \x02\x01\x05\x12\x03\x0e\x18\x1e\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\
+    \x0e\x1f#\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e&'\n\x0b\n\x04\x04\
+    \x02\x08\x01\x12\x03\x0f\x04,\n\x0c\n\x05\x04\x02\x08\x01\x01\x12\x03\
+    \x0f\n\x16\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x19*\n\x0c\n\x05\x04\
+    \x02\x02\x02\x05\x12\x03\x0f\x19\x1f\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\
+    \x03\x0f\x20%\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f()\n\x0b\n\x04\
+    \x04\x02\x08\x02\x12\x03\x10\x042\n\x0c\n\x05\x04\x02\x08\x02\x01\x12\
+    \x03\x10\n\x19\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x10\x1c0\n\x0c\n\x05\
+    \x04\x02\x02\x03\x05\x12\x03\x10\x1c\"\n\x0c\n\x05\x04\x02\x02\x03\x01\
+    \x12\x03\x10#+\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x10./\n\x0b\n\x04\
+    \x04\x02\x08\x03\x12\x03\x11\x042\n\x0c\n\x05\x04\x02\x08\x03\x01\x12\
+    \x03\x11\n\x19\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x11\x1c0\n\x0c\n\x05\
+    \x04\x02\x02\x04\x05\x12\x03\x11\x1c\"\n\x0c\n\x05\x04\x02\x02\x04\x01\
+    \x12\x03\x11#+\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x11./\n\n\n\x02\
+    \x04\x03\x12\x04\x13\0\x19\x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x18\
+    \n\x0b\n\x04\x04\x03\x02\0\x12\x03\x14\x04\x12\n\x0c\n\x05\x04\x03\x02\0\
+    \x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x14\x0b\r\n\
+    \x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x10\x11\n\x0b\n\x04\x04\x03\x08\
+    \0\x12\x03\x15\x04*\n\x0c\n\x05\x04\x03\x08\0\x01\x12\x03\x15\n\x15\n\
+    \x0b\n\x04\x04\x03\x02\x01\x12\x03\x15\x18(\n\x0c\n\x05\x04\x03\x02\x01\
+    \x05\x12\x03\x15\x18\x1e\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x15\x1f\
+    #\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x15&'\n\x0b\n\x04\x04\x03\x08\
+    \x01\x12\x03\x16\x04,\n\x0c\n\x05\x04\x03\x08\x01\x01\x12\x03\x16\n\x16\
+    \n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x16\x19*\n\x0c\n\x05\x04\x03\x02\
+    \x02\x05\x12\x03\x16\x19\x1f\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x16\
+    \x20%\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x16()\n\x0b\n\x04\x04\x03\
+    \x08\x02\x12\x03\x17\x042\n\x0c\n\x05\x04\x03\x08\x02\x01\x12\x03\x17\n\
+    \x19\n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x17\x1c0\n\x0c\n\x05\x04\x03\
+    \x02\x03\x05\x12\x03\x17\x1c\"\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\
+    \x17#+\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x17./\n\x0b\n\x04\x04\x03\
+    \x08\x03\x12\x03\x18\x042\n\x0c\n\x05\x04\x03\x08\x03\x01\x12\x03\x18\n\
+    \x19\n\x0b\n\x04\x04\x03\x02\x04\x12\x03\x18\x1c0\n\x0c\n\x05\x04\x03\
+    \x02\x04\x05\x12\x03\x18\x1c\"\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\
+    \x18#+\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x18./\n\n\n\x02\x04\x04\
+    \x12\x04\x1a\0\x1d\x01\n\n\n\x03\x04\x04\x01\x12\x03\x1a\x08\x1c\n\x0b\n\
+    \x04\x04\x04\x02\0\x12\x03\x1b\x04\x1c\n\x0c\n\x05\x04\x04\x02\0\x05\x12\
+    \x03\x1b\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1b\x0b\x17\n\x0c\n\
+    \x05\x04\x04\x02\0\x03\x12\x03\x1b\x1a\x1b\n\x0b\n\x04\x04\x04\x02\x01\
+    \x12\x03\x1c\x04\x1c\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1c\x04\n\n\
+    \x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x1c\x0b\x17\n\x0c\n\x05\x04\x04\
+    \x02\x01\x03\x12\x03\x1c\x1a\x1b\n\n\n\x02\x04\x05\x12\x04\x1e\0\x20\x01\
+    \n\n\n\x03\x04\x05\x01\x12\x03\x1e\x08\x1b\n\x0b\n\x04\x04\x05\x02\0\x12\
+    \x03\x1f\x04\x18\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1f\x04\n\n\x0c\n\
+    \x05\x04\x05\x02\0\x01\x12\x03\x1f\x0b\x13\n\x0c\n\x05\x04\x05\x02\0\x03\
+    \x12\x03\x1f\x16\x17\n\n\n\x02\x04\x06\x12\x04!\0#\x01\n\n\n\x03\x04\x06\
+    \x01\x12\x03!\x08\x20\n\x0b\n\x04\x04\x06\x02\0\x12\x03\"\x04\x19\n\x0c\
+    \n\x05\x04\x06\x02\0\x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\
+    \x12\x03\"\x0b\x14\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03\"\x17\x18\n\n\n\
+    \x02\x04\x07\x12\x04$\0&\x01\n\n\n\x03\x04\x07\x01\x12\x03$\x08\x20\n\
+    \x0b\n\x04\x04\x07\x02\0\x12\x03%\x04\x14\n\x0c\n\x05\x04\x07\x02\0\x05\
+    \x12\x03%\x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03%\x0b\x0f\n\x0c\n\
+    \x05\x04\x07\x02\0\x03\x12\x03%\x12\x13\n\n\n\x02\x04\x08\x12\x04'\0)\
+    \x01\n\n\n\x03\x04\x08\x01\x12\x03'\x08\"\n\x0b\n\x04\x04\x08\x02\0\x12\
+    \x03(\x04\x15\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03(\x04\n\n\x0c\n\x05\
+    \x04\x08\x02\0\x01\x12\x03(\x0b\x10\n\x0c\n\x05\x04\x08\x02\0\x03\x12\
+    \x03(\x13\x14b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -1,14 +1,26 @@
-use crate::core::{Attributes, Delta, Operation};
+use crate::{
+    core::{Attributes, Delta, DeltaIter, Interval, Operation, OperationTransformable, NEW_LINE},
+    errors::{ErrorBuilder, OTError, OTErrorCode},
+};
 
 pub struct DeltaBuilder<T: Attributes> {
     delta: Delta<T>,
+    normalize: bool,
+    // The index of the base document that the next operation applies at.
+    position: usize,
 }
 
 impl<T> std::default::Default for DeltaBuilder<T>
 where
     T: Attributes,
 {
-    fn default() -> Self { Self { delta: Delta::new() } }
+    fn default() -> Self {
+        Self {
+            delta: Delta::new(),
+            normalize: false,
+            position: 0,
+        }
+    }
 }
 
 impl<T> DeltaBuilder<T>
@@ -17,21 +29,52 @@ where
 {
     pub fn new() -> Self { DeltaBuilder::default() }
 
+    // Builds the delta of the text line by line, the lines are separated by the
+    // newline ops with the default attributes.
+    pub fn from_plain_text(text: &str) -> Delta<T> {
+        let mut builder = DeltaBuilder::new();
+        for (index, line) in text.split(NEW_LINE).enumerate() {
+            if index > 0 {
+                builder = builder.insert_newline();
+            }
+            if !line.is_empty() {
+                builder = builder.insert(line);
+            }
+        }
+        builder.build()
+    }
+
     pub fn retain_with_attributes(mut self, n: usize, attrs: T) -> Self {
         self.delta.retain(n, attrs);
+        self.position += n;
         self
     }
 
     pub fn retain(mut self, n: usize) -> Self {
         self.delta.retain(n, T::default());
+        self.position += n;
         self
     }
 
     pub fn delete(mut self, n: usize) -> Self {
         self.delta.delete(n);
+        self.position += n;
         self
     }
 
+    // Delete with the attributes of the deleted range, e.g. to keep what was
+    // deleted as the tombstone. The attributes don't change the length of the
+    // delete.
+    pub fn delete_with_attributes(mut self, n: usize, attrs: T) -> Self {
+        self.delta.delete_with_attributes(n, attrs);
+        self.position += n;
+        self
+    }
+
+    // Returns the index of the base document that is covered by the retains and
+    // deletes so far, the inserts don't move it.
+    pub fn current_position(&self) -> usize { self.position }
+
     pub fn insert_with_attributes(mut self, s: &str, attrs: T) -> Self {
         self.delta.insert(s, attrs);
         self
@@ -42,12 +85,58 @@ where
         self
     }
 
+    // Insert with the attributes built by f from the default attributes, e.g.
+    // insert_with("123", |mut attrs| { attrs.add(RichTextAttribute::Bold(true));
+    // attrs })
+    pub fn insert_with(self, s: &str, f: impl FnOnce(T) -> T) -> Self {
+        self.insert_with_attributes(s, f(T::default()))
+    }
+
+    pub fn insert_newline_with_attributes(self, attrs: T) -> Self { self.insert_with_attributes(NEW_LINE, attrs) }
+
+    pub fn insert_newline(self) -> Self { self.insert(NEW_LINE) }
+
+    pub fn insert_embed(mut self, value: serde_json::Value, attrs: T) -> Self {
+        self.delta.insert_embed(value, attrs);
+        self
+    }
+
     pub fn trim(mut self) -> Self {
         trim(&mut self.delta);
         self
     }
 
-    pub fn build(self) -> Delta<T> { self.delta }
+    // Compose the delta built so far with other, and continue building on the
+    // composed delta.
+    pub fn compose(mut self, other: Delta<T>) -> Result<Self, OTError> {
+        self.delta = self.delta.compose(&other)?;
+        self.position = self.delta.base_len;
+        Ok(self)
+    }
+
+    // Returns the delta that undoes the delta built so far, that is composing it
+    // after the built delta over base restores base.
+    pub fn invert(&self, base: &Delta<T>) -> Delta<T> { self.delta.invert(base) }
+
+    // Merge the adjacent operations of the whole delta when building it.
+    pub fn normalize(mut self) -> Self {
+        self.normalize = true;
+        self
+    }
+
+    pub fn build(mut self) -> Delta<T> {
+        if self.normalize {
+            normalize(&mut self.delta);
+        }
+        self.delta
+    }
+
+    // Same as build, but verifies the delta is well-formed before returning it.
+    pub fn try_build(self) -> Result<Delta<T>, OTError> {
+        let delta = self.build();
+        validate(&delta)?;
+        Ok(delta)
+    }
 }
 
 pub fn trim<T: Attributes>(delta: &mut Delta<T>) {
@@ -56,6 +145,7 @@ pub fn trim<T: Attributes>(delta: &mut Delta<T>) {
         Some(op) => match op {
             Operation::Delete(_) => false,
             Operation::Retain(retain) => retain.is_plain(),
+            // Trailing inserts, including embeds, are never trimmed.
             Operation::Insert(_) => false,
         },
     };
@@ -63,3 +153,59 @@ pub fn trim<T: Attributes>(delta: &mut Delta<T>) {
         delta.ops.pop();
     }
 }
+
+// Merges the adjacent same-kind operations that have equal attributes, e.g.
+// retain(3) retain(2) => retain(5). The base_len and target_len are
+// recalculated.
+pub fn normalize<T: Attributes>(delta: &mut Delta<T>) {
+    let ops = std::mem::take(&mut delta.ops);
+    let mut normalized = Delta::with_capacity(ops.len());
+    ops.into_iter().for_each(|op| normalized.add(op));
+    *delta = normalized;
+}
+
+// Returns the operations that cover [start, start + len) of the delta, the
+// operations across the boundaries are split. The range is measured in the
+// length of the operations, so it's the character range for a document delta.
+pub fn slice<T: Attributes>(delta: &Delta<T>, start: usize, len: usize) -> Delta<T> {
+    let interval = Interval::new(start, start.saturating_add(len));
+    let mut sliced = Delta::new();
+    DeltaIter::from_interval(delta, interval).for_each(|op| sliced.add(op));
+    sliced
+}
+
+// Transforms the two deltas that apply to the same document concurrently, the
+// returned (a', b') satisfies a.compose(b') == b.compose(a'). The insert of a
+// comes first if a and b insert at the same index.
+pub fn transform<T: Attributes>(a: &Delta<T>, b: &Delta<T>) -> Result<(Delta<T>, Delta<T>), OTError> { a.transform(b) }
+
+// Checks that no operation is zero-length, no insert is empty and that the
+// base_len and target_len match the operations.
+pub fn validate<T: Attributes>(delta: &Delta<T>) -> Result<(), OTError> {
+    let mut base_len = 0;
+    let mut target_len = 0;
+    for (index, op) in delta.ops.iter().enumerate() {
+        if op.is_empty() {
+            let msg = format!("The operation at {} is empty: {}", index, op);
+            return Err(ErrorBuilder::new(OTErrorCode::InvalidOperation).msg(msg).build());
+        }
+
+        match op {
+            Operation::Delete(delete) => base_len += delete.n,
+            Operation::Retain(retain) => {
+                base_len += retain.n;
+                target_len += retain.n;
+            },
+            Operation::Insert(insert) => target_len += insert.count_of_code_units(),
+        }
+    }
+
+    if delta.base_len != base_len || delta.target_len != target_len {
+        let msg = format!(
+            "Expected base length: {}, target length: {}, but receive base length: {}, target length: {}",
+            base_len, target_len, delta.base_len, delta.target_len
+        );
+        return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength).msg(msg).build());
+    }
+    Ok(())
+}
@@ -1,5 +1,14 @@
 use crate::{
-    core::{operation::*, DeltaIter, FlowyStr, Interval, OperationTransformable, MAX_IV_LEN},
+    core::{
+        count_utf16_code_units,
+        operation::*,
+        slice,
+        DeltaIter,
+        FlowyStr,
+        Interval,
+        OperationTransformable,
+        MAX_IV_LEN,
+    },
     errors::{ErrorBuilder, OTError, OTErrorCode},
 };
 
@@ -7,6 +16,7 @@ use bytes::Bytes;
 use serde::de::DeserializeOwned;
 use std::{
     cmp::{min, Ordering},
+    collections::HashSet,
     fmt,
     iter::FromIterator,
     str,
@@ -79,21 +89,28 @@ where
 
     pub fn add(&mut self, op: Operation<T>) {
         match op {
-            Operation::Delete(i) => self.delete(i),
-            Operation::Insert(i) => self.insert(&i.s, i.attributes),
+            Operation::Delete(d) => self.delete_with_attributes(d.n, d.attributes),
+            Operation::Insert(i) => match i.embed {
+                Some(value) => self.insert_embed(value, i.attributes),
+                None => self.insert(&i.s, i.attributes),
+            },
             Operation::Retain(r) => self.retain(r.n, r.attributes),
         }
     }
 
-    pub fn delete(&mut self, n: usize) {
+    pub fn delete(&mut self, n: usize) { self.delete_with_attributes(n, T::default()); }
+
+    pub fn delete_with_attributes(&mut self, n: usize, attributes: T) {
         if n == 0 {
             return;
         }
         self.base_len += n as usize;
-        if let Some(Operation::Delete(n_last)) = self.ops.last_mut() {
-            *n_last += n;
+        if let Some(Operation::Delete(delete)) = self.ops.last_mut() {
+            if let Some(new_op) = delete.merge_or_new(n, attributes) {
+                self.ops.push(new_op);
+            }
         } else {
-            self.ops.push(OpBuilder::delete(n).build());
+            self.ops.push(OpBuilder::delete(n).attributes(attributes).build());
         }
     }
 
@@ -104,29 +121,33 @@ where
         }
 
         self.target_len += s.count_utf16_code_units();
-        let new_last = match self.ops.as_mut_slice() {
-            [.., Operation::<T>::Insert(insert)] => {
-                //
-                insert.merge_or_new_op(&s, attributes)
-            },
-            [.., Operation::<T>::Insert(pre_insert), Operation::Delete(_)] => {
-                //
-                pre_insert.merge_or_new_op(&s, attributes)
-            },
-            [.., op_last @ Operation::<T>::Delete(_)] => {
-                let new_last = op_last.clone();
-                *op_last = OpBuilder::<T>::insert(&s).attributes(attributes).build();
-                Some(new_last)
-            },
+        // The insert is kept before the trailing deletes, there may be more than
+        // one of them because the deletes with different attributes aren't merged.
+        let index = self.index_before_trailing_deletes();
+        let new_op = match index.checked_sub(1).map(|last| &mut self.ops[last]) {
+            Some(Operation::<T>::Insert(insert)) => insert.merge_or_new_op(&s, attributes),
             _ => Some(OpBuilder::<T>::insert(&s).attributes(attributes).build()),
         };
 
-        match new_last {
+        match new_op {
             None => {},
-            Some(new_last) => self.ops.push(new_last),
+            Some(new_op) => self.ops.insert(index, new_op),
         }
     }
 
+    pub fn insert_embed(&mut self, value: serde_json::Value, attributes: T) {
+        let embed = OpBuilder::<T>::insert_embed(value).attributes(attributes).build();
+        self.target_len += embed.len();
+        // Embeds are never merged with the previous insert. Keep the insert before
+        // the deletes, just like the string insert does.
+        let index = self.index_before_trailing_deletes();
+        self.ops.insert(index, embed);
+    }
+
+    fn index_before_trailing_deletes(&self) -> usize {
+        self.ops.len() - self.ops.iter().rev().take_while(|op| op.is_delete()).count()
+    }
+
     pub fn retain(&mut self, n: usize, attributes: T) {
         if n == 0 {
             return;
@@ -159,7 +180,7 @@ where
                     }
                 },
                 Operation::Delete(delete) => {
-                    for _ in 0..*delete {
+                    for _ in 0..delete.n {
                         chars.next();
                     }
                 },
@@ -171,6 +192,50 @@ where
         Ok(new_s)
     }
 
+    /// Applies the delta to the base like `apply`, but the base may be longer
+    /// than the delta's base_len, the remaining base is kept after the
+    /// delta's operations. Returns the LengthMismatch error if the retains
+    /// and deletes run past the end of the base.
+    pub fn apply_checked(&self, base: &str) -> Result<String, OTError> {
+        let actual = count_utf16_code_units(base);
+        if self.base_len > actual {
+            return Err(OTError::length_mismatch(self.base_len, actual));
+        }
+
+        let mut new_s = String::new();
+        let chars = &mut base.chars();
+        for op in &self.ops {
+            match &op {
+                Operation::Retain(retain) => {
+                    new_s.extend(take_utf16_code_units(chars, retain.n));
+                },
+                Operation::Delete(delete) => {
+                    let _ = take_utf16_code_units(chars, delete.n);
+                },
+                Operation::Insert(insert) => {
+                    new_s += &insert.s;
+                },
+            }
+        }
+        new_s.extend(chars);
+        Ok(new_s)
+    }
+
+    /// Returns the distinct attribute keys of the inserts and retains, e.g. to
+    /// check that the document only uses the formats that the exporter
+    /// supports.
+    pub fn collect_attributes(&self) -> HashSet<String> {
+        let mut keys = HashSet::new();
+        for op in &self.ops {
+            match op {
+                Operation::Insert(insert) => keys.extend(insert.attributes.keys()),
+                Operation::Retain(retain) => keys.extend(retain.attributes.keys()),
+                Operation::Delete(_) => {},
+            }
+        }
+        keys
+    }
+
     /// Computes the inverse of an operation. The inverse of an operation is the
     /// operation that reverts the effects of the operation
     pub fn invert_str(&self, s: &str) -> Self {
@@ -190,7 +255,10 @@ where
                     inverted.delete(insert.count_of_code_units());
                 },
                 Operation::Delete(delete) => {
-                    inverted.insert(&chars.take(*delete as usize).collect::<String>(), op.get_attributes());
+                    inverted.insert(
+                        &chars.take(delete.n as usize).collect::<String>(),
+                        delete.attributes.clone(),
+                    );
                 },
             }
         }
@@ -204,6 +272,29 @@ where
     pub fn is_empty(&self) -> bool { self.ops.is_empty() }
 
     pub fn extend(&mut self, other: Self) { other.ops.into_iter().for_each(|op| self.add(op)); }
+
+    /// Returns the operations that cover the range [start, start + len), e.g.
+    /// to load a large document window by window.
+    pub fn slice(&self, start: usize, len: usize) -> Self { slice(self, start, len) }
+
+    /// Returns the attributes of the insert or retain that covers the index.
+    /// The index at the boundary of two operations belongs to the latter
+    /// one, and the default attributes are returned if the index is out of
+    /// range. The delete doesn't take up any position, so it's skipped.
+    pub fn attributes_at(&self, index: usize) -> T {
+        let mut offset = 0;
+        for op in &self.ops {
+            if op.is_delete() {
+                continue;
+            }
+
+            offset += op.len();
+            if index < offset {
+                return op.get_attributes();
+            }
+        }
+        T::default()
+    }
 }
 
 impl<T> OperationTransformable for Delta<T>
@@ -252,7 +343,9 @@ where
                 (Operation::Insert(insert), Operation::Retain(other_retain)) => {
                     let mut composed_attrs = insert.attributes.compose(&other_retain.attributes)?;
                     composed_attrs.remove_empty();
-                    new_delta.add(OpBuilder::insert(op.get_data()).attributes(composed_attrs).build())
+                    let mut composed_insert = insert.clone();
+                    composed_insert.attributes = composed_attrs;
+                    new_delta.add(Operation::Insert(composed_insert))
                 },
                 (Operation::Retain(_), Operation::Delete(_)) => {
                     new_delta.add(other_op);
@@ -294,14 +387,16 @@ where
                 (None, None) => break,
                 (Some(Operation::Insert(insert)), _) => {
                     // let composed_attrs = transform_attributes(&next_op1, &next_op2, true);
-                    a_prime.insert(&insert.s, insert.attributes.clone());
+                    a_prime.add(Operation::Insert(insert.clone()));
                     b_prime.retain(insert.count_of_code_units(), insert.attributes.clone());
                     next_op1 = ops1.next();
                 },
                 (_, Some(Operation::Insert(o_insert))) => {
                     let composed_attrs = transform_op_attribute(&next_op1, &next_op2)?;
                     a_prime.retain(o_insert.count_of_code_units(), composed_attrs.clone());
-                    b_prime.insert(&o_insert.s, composed_attrs);
+                    let mut o_insert = o_insert.clone();
+                    o_insert.attributes = composed_attrs;
+                    b_prime.add(Operation::Insert(o_insert));
                     next_op2 = ops2.next();
                 },
                 (None, _) => {
@@ -335,7 +430,7 @@ where
                 },
                 (Some(Operation::Delete(i)), Some(Operation::Delete(j))) => match i.cmp(&j) {
                     Ordering::Less => {
-                        next_op2 = Some(OpBuilder::delete(*j - *i).build());
+                        next_op2 = Some(OpBuilder::delete(j.n - i.n).attributes(j.attributes.clone()).build());
                         next_op1 = ops1.next();
                     },
                     Ordering::Equal => {
@@ -343,25 +438,29 @@ where
                         next_op2 = ops2.next();
                     },
                     Ordering::Greater => {
-                        next_op1 = Some(OpBuilder::delete(*i - *j).build());
+                        next_op1 = Some(OpBuilder::delete(i.n - j.n).attributes(i.attributes.clone()).build());
                         next_op2 = ops2.next();
                     },
                 },
                 (Some(Operation::Delete(i)), Some(Operation::Retain(o_retain))) => {
                     match i.cmp(&o_retain) {
                         Ordering::Less => {
-                            a_prime.delete(*i);
-                            next_op2 = Some(OpBuilder::retain(o_retain.n - *i).build());
+                            a_prime.delete_with_attributes(i.n, i.attributes.clone());
+                            next_op2 = Some(OpBuilder::retain(o_retain.n - i.n).build());
                             next_op1 = ops1.next();
                         },
                         Ordering::Equal => {
-                            a_prime.delete(*i);
+                            a_prime.delete_with_attributes(i.n, i.attributes.clone());
                             next_op1 = ops1.next();
                             next_op2 = ops2.next();
                         },
                         Ordering::Greater => {
-                            a_prime.delete(o_retain.n);
-                            next_op1 = Some(OpBuilder::delete(*i - o_retain.n).build());
+                            a_prime.delete_with_attributes(o_retain.n, i.attributes.clone());
+                            next_op1 = Some(
+                                OpBuilder::delete(i.n - o_retain.n)
+                                    .attributes(i.attributes.clone())
+                                    .build(),
+                            );
                             next_op2 = ops2.next();
                         },
                     };
@@ -369,18 +468,22 @@ where
                 (Some(Operation::Retain(retain)), Some(Operation::Delete(j))) => {
                     match retain.cmp(&j) {
                         Ordering::Less => {
-                            b_prime.delete(retain.n);
-                            next_op2 = Some(OpBuilder::delete(*j - retain.n).build());
+                            b_prime.delete_with_attributes(retain.n, j.attributes.clone());
+                            next_op2 = Some(
+                                OpBuilder::delete(j.n - retain.n)
+                                    .attributes(j.attributes.clone())
+                                    .build(),
+                            );
                             next_op1 = ops1.next();
                         },
                         Ordering::Equal => {
-                            b_prime.delete(retain.n);
+                            b_prime.delete_with_attributes(retain.n, j.attributes.clone());
                             next_op1 = ops1.next();
                             next_op2 = ops2.next();
                         },
                         Ordering::Greater => {
-                            b_prime.delete(*j);
-                            next_op1 = Some(OpBuilder::retain(retain.n - *j).build());
+                            b_prime.delete_with_attributes(j.n, j.attributes.clone());
+                            next_op1 = Some(OpBuilder::retain(retain.n - j.n).build());
                             next_op2 = ops2.next();
                         },
                     };
@@ -402,8 +505,8 @@ where
         for op in &self.ops {
             let len: usize = op.len() as usize;
             match op {
-                Operation::Delete(n) => {
-                    invert_from_other(&mut inverted, other, op, index, index + *n);
+                Operation::Delete(delete) => {
+                    invert_from_other(&mut inverted, other, op, index, index + delete.n);
                     index += len;
                 },
                 Operation::Retain(_) => {
@@ -428,6 +531,23 @@ where
     }
 }
 
+// Takes the chars until n utf16 code units are taken, the operations' lengths
+// are counted in utf16 code units.
+fn take_utf16_code_units(chars: &mut str::Chars, n: usize) -> String {
+    let mut taken = String::new();
+    let mut len = 0;
+    while len < n {
+        match chars.next() {
+            None => break,
+            Some(c) => {
+                len += c.len_utf16();
+                taken.push(c);
+            },
+        }
+    }
+    taken
+}
+
 fn invert_from_other<T: Attributes>(
     base: &mut Delta<T>,
     other: &Delta<T>,
@@ -467,7 +587,11 @@ fn transform_op_attribute<T: Attributes>(
         }
         return Ok(right.as_ref().unwrap().get_attributes());
     }
-    let left = left.as_ref().unwrap().get_attributes();
+    // The tombstone of the delete isn't applied to the transformed attributes
+    let left = match left.as_ref().unwrap() {
+        Operation::Delete(_) => T::default(),
+        op => op.get_attributes(),
+    };
     let right = right.as_ref().unwrap().get_attributes();
     // TODO: replace with anyhow and thiserror.
     Ok(left.transform(&right)?.0)
@@ -12,3 +12,4 @@ pub use iterator::*;
 
 pub const NEW_LINE: &str = "\n";
 pub const WHITESPACE: &str = " ";
+pub const OBJECT_REPLACEMENT: &str = "\u{FFFC}";
@@ -1,5 +1,5 @@
 use crate::{
-    core::{Attributes, Operation},
+    core::{Attributes, Insert, Operation},
     rich_text::RichTextAttributes,
 };
 
@@ -23,10 +23,14 @@ where
 
     pub fn retain(n: usize) -> OpBuilder<T> { OpBuilder::new(Operation::Retain(n.into())) }
 
-    pub fn delete(n: usize) -> OpBuilder<T> { OpBuilder::new(Operation::Delete(n)) }
+    pub fn delete(n: usize) -> OpBuilder<T> { OpBuilder::new(Operation::Delete(n.into())) }
 
     pub fn insert(s: &str) -> OpBuilder<T> { OpBuilder::new(Operation::Insert(s.into())) }
 
+    pub fn insert_embed(value: serde_json::Value) -> OpBuilder<T> {
+        OpBuilder::new(Operation::Insert(Insert::embed(value)))
+    }
+
     pub fn attributes(mut self, attrs: T) -> OpBuilder<T> {
         self.attrs = attrs;
         self
@@ -35,7 +39,7 @@ where
     pub fn build(self) -> Operation<T> {
         let mut operation = self.ty;
         match &mut operation {
-            Operation::Delete(_) => {},
+            Operation::Delete(delete) => delete.attributes = self.attrs,
             Operation::Retain(retain) => retain.attributes = self.attrs,
             Operation::Insert(insert) => insert.attributes = self.attrs,
         }
@@ -1,5 +1,5 @@
 use crate::{
-    core::{FlowyStr, Interval, OpBuilder, OperationTransformable},
+    core::{FlowyStr, Interval, OpBuilder, OperationTransformable, OBJECT_REPLACEMENT},
     rich_text::{RichTextAttribute, RichTextAttributes},
 };
 use serde::__private::Formatter;
@@ -17,6 +17,9 @@ pub trait Attributes: fmt::Display + Eq + PartialEq + Default + Clone + Debug +
     fn remove_empty(&mut self);
 
     fn extend_other(&mut self, other: Self);
+
+    // The keys of the attributes that have a value, named as they are serialized.
+    fn keys(&self) -> Vec<String>;
 }
 
 pub type RichTextOperation = Operation<RichTextAttributes>;
@@ -28,7 +31,7 @@ impl RichTextOperation {
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Operation<T: Attributes> {
-    Delete(usize),
+    Delete(Delete<T>),
     Retain(Retain<T>),
     Insert(Insert<T>),
 }
@@ -45,9 +48,12 @@ where
         }
     }
 
+    // The attributes of the delete are the tombstone of the deleted range, just
+    // like the invert reinserts them. They are metadata only, the transform doesn't
+    // apply them.
     pub fn get_attributes(&self) -> T {
         match self {
-            Operation::Delete(_) => T::default(),
+            Operation::Delete(delete) => delete.attributes.clone(),
             Operation::Retain(retain) => retain.attributes.clone(),
             Operation::Insert(insert) => insert.attributes.clone(),
         }
@@ -55,7 +61,7 @@ where
 
     pub fn set_attributes(&mut self, attributes: T) {
         match self {
-            Operation::Delete(_) => log::error!("Delete should not contains attributes"),
+            Operation::Delete(delete) => delete.attributes = attributes,
             Operation::Retain(retain) => retain.attributes = attributes,
             Operation::Insert(insert) => insert.attributes = attributes,
         }
@@ -65,7 +71,7 @@ where
 
     pub fn len(&self) -> usize {
         match self {
-            Operation::Delete(n) => *n,
+            Operation::Delete(d) => d.n,
             Operation::Retain(r) => r.n,
             Operation::Insert(i) => i.count_of_code_units(),
         }
@@ -79,14 +85,27 @@ where
         let left;
         let right;
         match self {
-            Operation::Delete(n) => {
-                left = Some(OpBuilder::<T>::delete(index).build());
-                right = Some(OpBuilder::<T>::delete(*n - index).build());
+            Operation::Delete(delete) => {
+                left = Some(
+                    OpBuilder::<T>::delete(index)
+                        .attributes(delete.attributes.clone())
+                        .build(),
+                );
+                right = Some(
+                    OpBuilder::<T>::delete(delete.n - index)
+                        .attributes(delete.attributes.clone())
+                        .build(),
+                );
             },
             Operation::Retain(retain) => {
                 left = Some(OpBuilder::<T>::delete(index).build());
                 right = Some(OpBuilder::<T>::delete(retain.n - index).build());
             },
+            Operation::Insert(insert) if insert.is_embed() => {
+                // An embed occupies a single unit and can't be divided.
+                left = None;
+                right = Some(self.clone());
+            },
             Operation::Insert(insert) => {
                 let attributes = self.get_attributes();
                 left = Some(
@@ -107,10 +126,18 @@ where
 
     pub fn shrink(&self, interval: Interval) -> Option<Operation<T>> {
         let op = match self {
-            Operation::Delete(n) => OpBuilder::delete(min(*n, interval.size())).build(),
+            Operation::Delete(delete) => OpBuilder::delete(min(delete.n, interval.size()))
+                .attributes(delete.attributes.clone())
+                .build(),
             Operation::Retain(retain) => OpBuilder::retain(min(retain.n, interval.size()))
                 .attributes(retain.attributes.clone())
                 .build(),
+            Operation::Insert(insert) if insert.is_embed() => {
+                if interval.is_empty() || interval.start >= insert.count_of_code_units() {
+                    return None;
+                }
+                Operation::Insert(insert.clone())
+            },
             Operation::Insert(insert) => {
                 if interval.start > insert.count_of_code_units() {
                     OpBuilder::insert("").build()
@@ -148,6 +175,13 @@ where
         false
     }
 
+    pub fn is_embed(&self) -> bool {
+        if let Operation::Insert(insert) = self {
+            return insert.is_embed();
+        }
+        false
+    }
+
     pub fn is_retain(&self) -> bool {
         if let Operation::Retain(_) = self {
             return true;
@@ -171,8 +205,8 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("{")?;
         match self {
-            Operation::Delete(n) => {
-                f.write_fmt(format_args!("delete: {}", n))?;
+            Operation::Delete(d) => {
+                f.write_fmt(format_args!("{}", d))?;
             },
             Operation::Retain(r) => {
                 f.write_fmt(format_args!("{}", r))?;
@@ -186,6 +220,62 @@ where
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delete<T: Attributes> {
+    pub n: usize,
+    // The attributes of the deleted range. They are only the metadata of the
+    // delete, e.g. to record what was deleted, and never change its length.
+    pub attributes: T,
+}
+
+impl<T> fmt::Display for Delete<T>
+where
+    T: Attributes,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.attributes.is_empty() {
+            f.write_fmt(format_args!("delete: {}", self.n))
+        } else {
+            f.write_fmt(format_args!("delete: {}, attributes: {}", self.n, self.attributes))
+        }
+    }
+}
+
+impl<T> Delete<T>
+where
+    T: Attributes,
+{
+    pub fn merge_or_new(&mut self, n: usize, attributes: T) -> Option<Operation<T>> {
+        if self.attributes == attributes {
+            self.n += n;
+            None
+        } else {
+            Some(OpBuilder::delete(n).attributes(attributes).build())
+        }
+    }
+}
+
+impl<T> std::convert::From<usize> for Delete<T>
+where
+    T: Attributes,
+{
+    fn from(n: usize) -> Self {
+        Delete {
+            n,
+            attributes: T::default(),
+        }
+    }
+}
+
+impl<T> Deref for Delete<T>
+where
+    T: Attributes,
+{
+    type Target = usize;
+
+    fn deref(&self) -> &Self::Target { &self.n }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Retain<T: Attributes> {
     // #[serde(rename(serialize = "retain", deserialize = "retain"))]
@@ -262,6 +352,10 @@ pub struct Insert<T: Attributes> {
     // #[serde(rename(serialize = "insert", deserialize = "insert"))]
     pub s: FlowyStr,
 
+    // The structured value of an embed, e.g. an image or a divider. The `s` of
+    // an embed is always the OBJECT_REPLACEMENT placeholder.
+    pub embed: Option<serde_json::Value>,
+
     // #[serde(skip_serializing_if = "is_empty")]
     pub attributes: T,
 }
@@ -271,6 +365,14 @@ where
     T: Attributes,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(embed) = &self.embed {
+            return if self.attributes.is_empty() {
+                f.write_fmt(format_args!("embed: {}", embed))
+            } else {
+                f.write_fmt(format_args!("embed: {}, attributes: {}", embed, self.attributes))
+            };
+        }
+
         let mut s = self.s.clone();
         if s.ends_with('\n') {
             s.pop();
@@ -293,8 +395,18 @@ where
 {
     pub fn count_of_code_units(&self) -> usize { self.s.count_utf16_code_units() }
 
+    pub fn embed(value: serde_json::Value) -> Self {
+        Insert {
+            s: OBJECT_REPLACEMENT.into(),
+            embed: Some(value),
+            attributes: T::default(),
+        }
+    }
+
+    pub fn is_embed(&self) -> bool { self.embed.is_some() }
+
     pub fn merge_or_new_op(&mut self, s: &str, attributes: T) -> Option<Operation<T>> {
-        if self.attributes == attributes {
+        if !self.is_embed() && self.attributes == attributes {
             self.s += s;
             None
         } else {
@@ -312,6 +424,7 @@ where
     fn from(s: String) -> Self {
         Insert {
             s: s.into(),
+            embed: None,
             attributes: T::default(),
         }
     }
@@ -331,7 +444,20 @@ where
     fn from(s: FlowyStr) -> Self {
         Insert {
             s,
+            embed: None,
             attributes: T::default(),
         }
     }
 }
+
+impl<T> std::convert::From<serde_json::Value> for Insert<T>
+where
+    T: Attributes,
+{
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::String(s) => Insert::from(s),
+            value => Insert::embed(value),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::{Attributes, FlowyStr, Insert, Operation, Retain};
+use crate::core::{Attributes, Insert, Operation, Retain};
 use serde::{
     de,
     de::{MapAccess, SeqAccess, Visitor},
@@ -20,9 +20,13 @@ where
     {
         match self {
             Operation::Retain(retain) => retain.serialize(serializer),
-            Operation::Delete(i) => {
-                let mut map = serializer.serialize_map(Some(1))?;
-                map.serialize_entry("delete", i)?;
+            Operation::Delete(delete) => {
+                let len = if delete.attributes.is_empty() { 1 } else { 2 };
+                let mut map = serializer.serialize_map(Some(len))?;
+                map.serialize_entry("delete", &delete.n)?;
+                if !delete.attributes.is_empty() {
+                    map.serialize_entry("attributes", &delete.attributes)?;
+                }
                 map.end()
             },
             Operation::Insert(insert) => insert.serialize(serializer),
@@ -63,7 +67,8 @@ where
                             if operation.is_some() {
                                 return Err(de::Error::duplicate_field("operation"));
                             }
-                            operation = Some(Operation::<T>::Delete(map.next_value()?));
+                            let i: usize = map.next_value()?;
+                            operation = Some(Operation::<T>::Delete(i.into()));
                         },
                         "retain" => {
                             if operation.is_some() {
@@ -76,7 +81,7 @@ where
                             if operation.is_some() {
                                 return Err(de::Error::duplicate_field("operation"));
                             }
-                            let i: String = map.next_value()?;
+                            let i: serde_json::Value = map.next_value()?;
                             operation = Some(Operation::<T>::Insert(i.into()));
                         },
                         "attributes" => {
@@ -92,9 +97,7 @@ where
                 match operation {
                     None => Err(de::Error::missing_field("operation")),
                     Some(mut operation) => {
-                        if !operation.is_delete() {
-                            operation.set_attributes(attributes.unwrap_or_default());
-                        }
+                        operation.set_attributes(attributes.unwrap_or_default());
                         Ok(operation)
                     },
                 }
@@ -216,7 +219,10 @@ where
     {
         let len = false as usize + 1 + if self.attributes.is_empty() { 0 } else { 1 };
         let mut serde_state = serializer.serialize_struct("Insert", len)?;
-        let _ = serde::ser::SerializeStruct::serialize_field(&mut serde_state, "insert", &self.s)?;
+        match &self.embed {
+            None => serde::ser::SerializeStruct::serialize_field(&mut serde_state, "insert", &self.s)?,
+            Some(embed) => serde::ser::SerializeStruct::serialize_field(&mut serde_state, "insert", embed)?,
+        }
         if !self.attributes.is_empty() {
             let _ = serde::ser::SerializeStruct::serialize_field(&mut serde_state, "attributes", &self.attributes)?;
         }
@@ -247,8 +253,8 @@ where
             where
                 A: SeqAccess<'de>,
             {
-                let s = match serde::de::SeqAccess::next_element::<FlowyStr>(&mut seq)? {
-                    Some(val) => val,
+                let insert = match serde::de::SeqAccess::next_element::<serde_json::Value>(&mut seq)? {
+                    Some(val) => Insert::<T>::from(val),
                     None => {
                         return Err(de::Error::invalid_length(0, &"struct Insert with 2 elements"));
                     },
@@ -261,7 +267,7 @@ where
                     },
                 };
 
-                Ok(Insert::<T> { s, attributes })
+                Ok(Insert::<T> { attributes, ..insert })
             }
 
             #[inline]
@@ -269,15 +275,16 @@ where
             where
                 V: MapAccess<'de>,
             {
-                let mut s: Option<FlowyStr> = None;
+                let mut insert: Option<Insert<T>> = None;
                 let mut attributes: Option<T> = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         "insert" => {
-                            if s.is_some() {
+                            if insert.is_some() {
                                 return Err(de::Error::duplicate_field("insert"));
                             }
-                            s = Some(map.next_value()?);
+                            let value: serde_json::Value = map.next_value()?;
+                            insert = Some(value.into());
                         },
                         "attributes" => {
                             if attributes.is_some() {
@@ -289,7 +296,7 @@ where
                     }
                 }
 
-                if s.is_none() {
+                if insert.is_none() {
                     return Err(de::Error::missing_field("s"));
                 }
 
@@ -297,8 +304,8 @@ where
                     return Err(de::Error::missing_field("attributes"));
                 }
                 Ok(Insert::<T> {
-                    s: s.unwrap(),
                     attributes: attributes.unwrap(),
+                    ..insert.unwrap()
                 })
             }
         }
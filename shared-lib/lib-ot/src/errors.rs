@@ -38,6 +38,14 @@ impl OTError {
     static_ot_error!(duplicate_revision, OTErrorCode::DuplicatedRevision);
     static_ot_error!(revision_id_conflict, OTErrorCode::RevisionIDConflict);
     static_ot_error!(internal, OTErrorCode::Internal);
+
+    pub fn length_mismatch(expected: usize, actual: usize) -> OTError {
+        let msg = format!(
+            "The delta expects the base's length to be at least {}, but receive {}",
+            expected, actual
+        );
+        OTError::new(OTErrorCode::LengthMismatch { expected, actual }, &msg)
+    }
 }
 
 impl fmt::Display for OTError {
@@ -55,6 +63,9 @@ impl std::convert::From<Utf8Error> for OTError {
 #[derive(Debug, Clone)]
 pub enum OTErrorCode {
     IncompatibleLength,
+    // The retains and deletes of the delta run past the end of the base
+    LengthMismatch { expected: usize, actual: usize },
+    InvalidOperation,
     ApplyInsertFail,
     ApplyDeleteFail,
     ApplyFormatFail,
@@ -102,6 +102,17 @@ impl Attributes for RichTextAttributes {
     fn remove_empty(&mut self) { self.inner.retain(|_, v| v.0.is_some()); }
 
     fn extend_other(&mut self, other: Self) { self.inner.extend(other.inner); }
+
+    fn keys(&self) -> Vec<String> {
+        self.inner
+            .iter()
+            .filter(|(_, v)| v.0.is_some())
+            .flat_map(|(k, _)| match serde_json::to_value(k) {
+                Ok(serde_json::Value::String(key)) => Some(key),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl OperationTransformable for RichTextAttributes {